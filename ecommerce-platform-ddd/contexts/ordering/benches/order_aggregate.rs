@@ -0,0 +1,85 @@
+//! Cost of the order aggregate alone, without storage nor HTTP:
+//! `cargo bench -p ordering-context --bench order_aggregate`
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use ordering_context::domain::{
+    CustomerId, Money, Order, OrderItem, ProductId, SystemClock, Tenant, UuidV7Generator,
+};
+use rust_decimal::Decimal;
+use std::hint::black_box;
+
+/// From a single line to the most an order may hold
+const ITEM_COUNTS: [usize; 4] = [1, 10, 25, Order::MAX_ITEMS];
+
+fn items(count: usize) -> Vec<OrderItem> {
+    (0..count)
+        .map(|i| {
+            OrderItem::new(
+                ProductId::new(),
+                format!("Product {}", i),
+                (i % 5 + 1) as u32,
+                Money::eur(Decimal::new(1999 + i as i64, 2)).unwrap(),
+                &UuidV7Generator,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+fn create_order(c: &mut Criterion) {
+    let tenant = Tenant::default();
+    let mut group = c.benchmark_group("Order::create");
+    for count in ITEM_COUNTS {
+        let items = items(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &items, |b, items| {
+            b.iter(|| {
+                Order::create(
+                    &tenant,
+                    CustomerId::new(),
+                    black_box(items.clone()),
+                    &UuidV7Generator,
+                    &SystemClock,
+                )
+                .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn add_items(c: &mut Criterion) {
+    let tenant = Tenant::default();
+    let mut group = c.benchmark_group("Order::add_item");
+    for count in ITEM_COUNTS {
+        let items = items(count);
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &items, |b, items| {
+            // Start from a one-line order and add the others, keeping the total up to date
+            b.iter_batched(
+                || {
+                    let order = Order::create(
+                        &tenant,
+                        CustomerId::new(),
+                        items[..1].to_vec(),
+                        &UuidV7Generator,
+                        &SystemClock,
+                    )
+                    .unwrap();
+                    (order, items[1..].to_vec())
+                },
+                |(mut order, rest)| {
+                    for item in rest {
+                        order.add_item(black_box(item), &SystemClock).unwrap();
+                    }
+                    order
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, create_order, add_items);
+criterion_main!(benches);
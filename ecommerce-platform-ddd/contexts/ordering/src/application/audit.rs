@@ -0,0 +1,234 @@
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    errors::InfrastructureError,
+    events::{OrderEvent, ERASED},
+    value_objects::{CustomerId, OrderId, OrderStatus, TenantId},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Who issued a command
+///
+/// Rendered as `user:<id>`, `system:<name>` or `anonymous`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Actor {
+    User(String),
+    /// Background jobs, migrations, admin tooling
+    System(String),
+    #[default]
+    Anonymous,
+}
+
+impl Actor {
+    pub fn user(id: impl Into<String>) -> Self {
+        Actor::User(id.into())
+    }
+
+    pub fn system(name: impl Into<String>) -> Self {
+        Actor::System(name.into())
+    }
+}
+
+impl std::fmt::Display for Actor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Actor::User(id) => write!(f, "user:{}", id),
+            Actor::System(name) => write!(f, "system:{}", name),
+            Actor::Anonymous => write!(f, "anonymous"),
+        }
+    }
+}
+
+impl std::str::FromStr for Actor {
+    type Err = UnknownActor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("user", id)) if !id.is_empty() => Ok(Actor::user(id)),
+            Some(("system", name)) if !name.is_empty() => Ok(Actor::system(name)),
+            None if s == "anonymous" => Ok(Actor::Anonymous),
+            _ => Err(UnknownActor(s.to_string())),
+        }
+    }
+}
+
+impl From<Actor> for String {
+    fn from(actor: Actor) -> Self {
+        actor.to_string()
+    }
+}
+
+impl TryFrom<String> for Actor {
+    type Error = UnknownActor;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown actor: {0}")]
+pub struct UnknownActor(pub String);
+
+/// One command applied to an order: who, what, when and the resulting events
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub order_id: OrderId,
+    #[schema(value_type = String, example = "user:support-42")]
+    pub actor: Actor,
+    /// Command name, e.g. `CancelOrder`
+    pub command: String,
+    /// `None` when the command created the order
+    pub status_before: Option<OrderStatus>,
+    pub status_after: OrderStatus,
+    pub reason: Option<String>,
+    /// Domain events raised by the command
+    #[schema(value_type = Vec<Object>)]
+    pub events: Vec<OrderEvent>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        order_id: OrderId,
+        actor: Actor,
+        command: impl Into<String>,
+        status_before: Option<OrderStatus>,
+        status_after: OrderStatus,
+        events: Vec<OrderEvent>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            order_id,
+            actor,
+            command: command.into(),
+            status_before,
+            status_after,
+            reason: None,
+            occurred_at: events
+                .last()
+                .map(OrderEvent::timestamp)
+                .unwrap_or_else(Utc::now),
+            events,
+        }
+    }
+
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Erase the personal data of `customer_id`: their events go to `pseudonym`, the
+    /// free-text reason is erased and, when the customer acted themselves, the actor too
+    pub fn anonymize(&mut self, customer_id: CustomerId, pseudonym: CustomerId) {
+        if self.actor == Actor::user(customer_id.to_string()) {
+            self.actor = Actor::Anonymous;
+        }
+        if self.reason.is_some() {
+            self.reason = Some(ERASED.to_string());
+        }
+        for event in &mut self.events {
+            event.anonymize(pseudonym);
+        }
+    }
+}
+
+/// A handled erasure request, one per run of the request whatever the number of orders
+///
+/// Names the request, not the customer: the record itself holds no personal data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErasureRecord {
+    pub id: Uuid,
+    pub tenant_id: TenantId,
+    /// Reference of the erasure request
+    pub reference: String,
+    pub actor: Actor,
+    pub orders_anonymized: u32,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ErasureRecord {
+    pub fn new(
+        tenant_id: TenantId,
+        reference: impl Into<String>,
+        actor: Actor,
+        orders_anonymized: u32,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            reference: reference.into(),
+            actor,
+            orders_anonymized,
+            occurred_at,
+        }
+    }
+}
+
+/// Append-only audit trail of the commands applied to orders (Port)
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    /// Append an entry
+    async fn record(&self, entry: AuditEntry) -> Result<(), InfrastructureError>;
+
+    /// Entries of an order, oldest first
+    async fn timeline(&self, order_id: OrderId) -> Result<Vec<AuditEntry>, InfrastructureError>;
+
+    /// Erase the personal data of `customer_id` from the entries of an order
+    /// (see [`AuditEntry::anonymize`]), the only rewrite of the trail
+    async fn anonymize(
+        &self,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError>;
+
+    /// Append the record of a handled erasure request
+    async fn record_erasure(&self, erasure: ErasureRecord) -> Result<(), InfrastructureError>;
+
+    /// Erasure requests handled for a tenant, oldest first
+    async fn erasures(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ErasureRecord>, InfrastructureError>;
+}
+
+/// Record an entry once the order is persisted; a failure is logged, not
+/// returned, since the state change already happened
+pub async fn record(audit_log: &dyn AuditLog, entry: AuditEntry) {
+    let order_id = entry.order_id;
+    if let Err(err) = audit_log.record(entry).await {
+        tracing::error!(
+            "Failed to record audit entry for order {}: {}",
+            order_id,
+            ApplicationError::from(err).report()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actor_round_trip() {
+        for actor in [
+            Actor::user("alice"),
+            Actor::system("scheduler"),
+            Actor::Anonymous,
+        ] {
+            assert_eq!(actor.to_string().parse::<Actor>().unwrap(), actor);
+        }
+        assert!("root".parse::<Actor>().is_err());
+        assert_eq!(
+            serde_json::to_value(Actor::user("alice")).unwrap(),
+            "user:alice"
+        );
+    }
+}
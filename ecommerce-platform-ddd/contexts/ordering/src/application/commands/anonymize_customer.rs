@@ -0,0 +1,465 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog, ErasureRecord};
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    clock::Clock,
+    errors::DomainError,
+    id_generator::IdGenerator,
+    repositories::{CartRepository, InvoiceRepository, OrderRepository, WishlistRepository},
+    value_objects::{CustomerId, OrderId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: erase the personal data of a customer from their orders (GDPR erasure request)
+#[derive(Debug)]
+pub struct AnonymizeCustomerCommand {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+    /// Reference of the erasure request (kept in the audit trail)
+    pub reference: String,
+    pub actor: Actor,
+}
+
+/// Scrubs the orders and their audit trail, and deletes the cart and the wishlist; amounts
+/// stay for the accounts
+///
+/// Invoices and credit notes are kept for their legal retention period: only their customer
+/// becomes the pseudonym of the order they bill.
+pub struct AnonymizeCustomerHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    invoice_repository: Arc<dyn InvoiceRepository>,
+    cart_repository: Arc<dyn CartRepository>,
+    wishlist_repository: Arc<dyn WishlistRepository>,
+    audit_log: Arc<dyn AuditLog>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AnonymizeCustomerHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        invoice_repository: Arc<dyn InvoiceRepository>,
+        cart_repository: Arc<dyn CartRepository>,
+        wishlist_repository: Arc<dyn WishlistRepository>,
+        audit_log: Arc<dyn AuditLog>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            invoice_repository,
+            cart_repository,
+            wishlist_repository,
+            audit_log,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the anonymized orders
+    ///
+    /// Nothing is erased while one of the orders is in progress. Running it again
+    /// finishes an interrupted request: anonymized orders no longer match the customer.
+    pub async fn handle(
+        &self,
+        command: AnonymizeCustomerCommand,
+    ) -> Result<Vec<OrderId>, ApplicationError> {
+        let orders = self
+            .order_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?;
+        if orders.iter().any(|order| !order.status().is_terminal()) {
+            return Err(DomainError::CannotAnonymizeOrderInProgress.into());
+        }
+
+        let mut anonymized = Vec::with_capacity(orders.len());
+        for mut order in orders {
+            // Business rule: each order gets its own random customer, so that the
+            // anonymized orders cannot be linked back together
+            let pseudonym = CustomerId::from_uuid(self.ids.next_id());
+            order.anonymize(pseudonym, &*self.clock)?;
+
+            // The trail and invoices first: once saved, the order no longer leads to the
+            // customer
+            self.audit_log
+                .anonymize(order.id(), command.customer_id, pseudonym)
+                .await?;
+            self.invoice_repository
+                .anonymize(&command.tenant_id, order.id(), pseudonym)
+                .await?;
+            self.order_repository.save(&mut order).await?;
+
+            let entry = AuditEntry::new(
+                order.id(),
+                command.actor.clone(),
+                "AnonymizeCustomer",
+                Some(order.status()),
+                order.status(),
+                Vec::new(),
+            )
+            .with_reason(command.reference.clone());
+            audit::record(&*self.audit_log, entry).await;
+            anonymized.push(order.id());
+        }
+
+        // Carts, saved products and their notes are not needed by anyone once the customer
+        // is gone
+        if let Some(cart) = self
+            .cart_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?
+        {
+            self.cart_repository
+                .delete(&command.tenant_id, cart.id())
+                .await?;
+        }
+        self.wishlist_repository
+            .delete(&command.tenant_id, command.customer_id)
+            .await?;
+
+        // The request itself is on record, even for a customer without orders; a failure is
+        // logged, not returned, since the data is already erased
+        let erasure = ErasureRecord::new(
+            command.tenant_id,
+            command.reference.clone(),
+            command.actor,
+            anonymized.len() as u32,
+            self.clock.now(),
+        );
+        if let Err(err) = self.audit_log.record_erasure(erasure).await {
+            tracing::error!(
+                "Failed to record erasure request {}: {}",
+                command.reference,
+                ApplicationError::from(err).report()
+            );
+        }
+
+        tracing::info!(
+            "Erasure request {} anonymized {} orders",
+            command.reference,
+            anonymized.len()
+        );
+        Ok(anonymized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        aggregates::{Cart, Invoice, Order, Wishlist},
+        clock::SystemClock,
+        entities::{CartLine, OrderItem, WishlistItem},
+        events::{OrderEvent, ERASED},
+        id_generator::UuidV4Generator,
+        tenant::Tenant,
+        value_objects::{Money, OrderStatus, ProductId, WishlistPriority},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::persistence::repositories::{
+        InMemoryCartRepository, InMemoryInvoiceRepository, InMemoryOrderRepository,
+        InMemoryWishlistRepository,
+    };
+    use rust_decimal::Decimal;
+
+    async fn place_order(
+        repo: &InMemoryOrderRepository,
+        audit_log: &InMemoryAuditLog,
+        customer_id: CustomerId,
+    ) -> Order {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            1,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            customer_id,
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order
+            .cancel("Wrong delivery address".to_string(), &SystemClock)
+            .unwrap();
+        repo.save(&mut order).await.unwrap();
+        audit_log
+            .record(AuditEntry::new(
+                order.id(),
+                Actor::user(customer_id.to_string()),
+                "CreateOrder",
+                None,
+                order.status(),
+                order.take_events(),
+            ))
+            .await
+            .unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn test_orders_and_trail_are_anonymized() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let wishlists = Arc::new(InMemoryWishlistRepository::new());
+        let customer_id = CustomerId::new();
+        let first = place_order(&repo, &audit_log, customer_id).await;
+        let second = place_order(&repo, &audit_log, customer_id).await;
+        let other = place_order(&repo, &audit_log, CustomerId::new()).await;
+        let mut wishlist = Wishlist::create(TenantId::default(), customer_id, &SystemClock);
+        let saved = WishlistItem::new(
+            ProductId::new(),
+            "Mouse".to_string(),
+            Some("for the office".to_string()),
+            WishlistPriority::Normal,
+            SystemClock.now(),
+        )
+        .unwrap();
+        wishlist.save_item(saved, &SystemClock).unwrap();
+        wishlists.save(&wishlist).await.unwrap();
+
+        let handler = AnonymizeCustomerHandler::new(
+            repo.clone(),
+            Arc::new(InMemoryInvoiceRepository::new()),
+            Arc::new(InMemoryCartRepository::new()),
+            wishlists.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let command = || AnonymizeCustomerCommand {
+            tenant_id: TenantId::default(),
+            customer_id,
+            reference: "DSR-2025-017".to_string(),
+            actor: Actor::user("dpo"),
+        };
+
+        let anonymized = handler.handle(command()).await.unwrap();
+        assert_eq!(anonymized.len(), 2);
+        assert!(handler.handle(command()).await.unwrap().is_empty());
+        assert!(wishlists
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let tenant_id = TenantId::default();
+        let (first, second) = (
+            repo.find_by_id(&tenant_id, first.id())
+                .await
+                .unwrap()
+                .unwrap(),
+            repo.find_by_id(&tenant_id, second.id())
+                .await
+                .unwrap()
+                .unwrap(),
+        );
+        assert_ne!(first.customer_id(), customer_id);
+        assert_ne!(first.customer_id(), second.customer_id());
+        assert_eq!(first.total(), Money::eur(Decimal::new(4990, 2)).unwrap());
+
+        let timeline = audit_log.timeline(first.id()).await.unwrap();
+        assert_eq!(timeline[0].actor, Actor::Anonymous);
+        assert!(timeline[0].events.iter().all(|event| match event {
+            OrderEvent::OrderCreated { customer_id, .. } => *customer_id == first.customer_id(),
+            OrderEvent::OrderCancelled { reason, .. } => reason == ERASED,
+            _ => true,
+        }));
+        assert_eq!(timeline[1].command, "AnonymizeCustomer");
+        assert_eq!(timeline[1].actor, Actor::user("dpo"));
+        assert_eq!(timeline[1].reason.as_deref(), Some("DSR-2025-017"));
+
+        let untouched = audit_log.timeline(other.id()).await.unwrap();
+        assert_eq!(untouched.len(), 1);
+
+        // One record per run of the request
+        let erasures = audit_log.erasures(&TenantId::default()).await.unwrap();
+        let counts: Vec<_> = erasures.iter().map(|e| e.orders_anonymized).collect();
+        assert_eq!(counts, [2, 0]);
+        assert_eq!(erasures[0].reference, "DSR-2025-017");
+    }
+
+    #[tokio::test]
+    async fn test_request_is_on_record_for_a_customer_without_orders() {
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let wishlists = Arc::new(InMemoryWishlistRepository::new());
+        let customer_id = CustomerId::new();
+        let wishlist = Wishlist::create(TenantId::default(), customer_id, &SystemClock);
+        wishlists.save(&wishlist).await.unwrap();
+
+        let handler = AnonymizeCustomerHandler::new(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryInvoiceRepository::new()),
+            Arc::new(InMemoryCartRepository::new()),
+            wishlists.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let anonymized = handler
+            .handle(AnonymizeCustomerCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                reference: "DSR-2025-020".to_string(),
+                actor: Actor::user("dpo"),
+            })
+            .await
+            .unwrap();
+
+        assert!(anonymized.is_empty());
+        assert!(wishlists
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .is_none());
+        let erasures = audit_log.erasures(&TenantId::default()).await.unwrap();
+        assert_eq!(erasures.len(), 1);
+        assert_eq!(erasures[0].reference, "DSR-2025-020");
+        assert_eq!(erasures[0].actor, Actor::user("dpo"));
+        assert_eq!(erasures[0].orders_anonymized, 0);
+    }
+
+    #[tokio::test]
+    async fn test_invoices_are_kept_and_the_cart_is_deleted() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let invoices = Arc::new(InMemoryInvoiceRepository::new());
+        let carts = Arc::new(InMemoryCartRepository::new());
+        let customer_id = CustomerId::new();
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            1,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            customer_id,
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order
+            .mark_as_paid(uuid::Uuid::new_v4(), &SystemClock)
+            .unwrap();
+        let invoice = invoices
+            .issue(Invoice::draft_for_order(&order, &UuidV4Generator, &SystemClock).unwrap())
+            .await
+            .unwrap();
+        order.ship("TRACK-1".to_string(), &SystemClock).unwrap();
+        order.deliver(&SystemClock).unwrap();
+        repo.save(&mut order).await.unwrap();
+        let mut cart = Cart::create(
+            TenantId::default(),
+            Some(customer_id),
+            &UuidV4Generator,
+            &SystemClock,
+        );
+        let line = CartLine::new(
+            ProductId::new(),
+            "Mouse".to_string(),
+            1,
+            Money::eur(Decimal::new(1999, 2)).unwrap(),
+        )
+        .unwrap();
+        cart.add_line(line, &SystemClock).unwrap();
+        carts.save(&cart).await.unwrap();
+
+        let handler = AnonymizeCustomerHandler::new(
+            repo.clone(),
+            invoices.clone(),
+            carts.clone(),
+            Arc::new(InMemoryWishlistRepository::new()),
+            Arc::new(InMemoryAuditLog::new()),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        handler
+            .handle(AnonymizeCustomerCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                reference: "DSR-2025-019".to_string(),
+                actor: Actor::user("dpo"),
+            })
+            .await
+            .unwrap();
+
+        // Kept for the legal retention period, under the pseudonym of the order
+        let order = repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        let kept = invoices
+            .find_by_id(&TenantId::default(), invoice.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(kept.customer_id(), customer_id);
+        assert_eq!(kept.customer_id(), order.customer_id());
+        assert_eq!(kept.number(), invoice.number());
+        assert_eq!(kept.total(), invoice.total());
+
+        assert!(carts
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_nothing_is_erased_while_an_order_is_in_progress() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let wishlists = Arc::new(InMemoryWishlistRepository::new());
+        let customer_id = CustomerId::new();
+        let cancelled = place_order(&repo, &audit_log, customer_id).await;
+        let mut pending = Order::create(
+            &Tenant::default(),
+            customer_id,
+            cancelled.items().to_vec(),
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        repo.save(&mut pending).await.unwrap();
+
+        let handler = AnonymizeCustomerHandler::new(
+            repo.clone(),
+            Arc::new(InMemoryInvoiceRepository::new()),
+            Arc::new(InMemoryCartRepository::new()),
+            wishlists.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let result = handler
+            .handle(AnonymizeCustomerCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                reference: "DSR-2025-018".to_string(),
+                actor: Actor::user("dpo"),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::CannotAnonymizeOrderInProgress
+            ))
+        ));
+        let cancelled = repo
+            .find_by_id(&TenantId::default(), cancelled.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cancelled.customer_id(), customer_id);
+        assert_eq!(cancelled.status(), OrderStatus::Cancelled);
+    }
+}
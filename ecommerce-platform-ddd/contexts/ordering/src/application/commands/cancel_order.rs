@@ -0,0 +1,269 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    tenant::TenantDirectory,
+    value_objects::{OrderId, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Cancel Order (Pending or Confirmed -> Cancelled)
+#[derive(Debug)]
+pub struct CancelOrderCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub reason: String,
+    pub actor: Actor,
+}
+
+pub struct CancelOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CancelOrderHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(&self, command: CancelOrderCommand) -> Result<(), ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let status_before = order.status();
+
+        order.cancel(command.reason.clone(), &*self.clock)?;
+        tenant.ensure_transition(status_before, order.status())?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "CancelOrder",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        )
+        .with_reason(command.reason);
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::{
+        ConfirmOrderCommand, ConfirmOrderHandler, CreateOrderCommand, CreateOrderHandler,
+        CreateOrderItemDto,
+    };
+    use crate::domain::errors::DomainError;
+    use crate::domain::{
+        clock::SystemClock,
+        id_generator::UuidV4Generator,
+        risk::RiskPolicy,
+        tenant::{StatusTransitions, Tenant},
+        value_objects::{CustomerId, OrderStatus, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::infrastructure::risk::RuleBasedRiskAssessor;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_timeline_records_status_changes_and_reason() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let publisher = Arc::new(NoOpEventPublisher);
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+
+        let order_id = CreateOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(InMemoryPriceLists::default()),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        )
+        .handle(CreateOrderCommand {
+            tenant_id: TenantId::default(),
+            customer_id: CustomerId::new(),
+            items: vec![CreateOrderItemDto {
+                product_id: ProductId::new(),
+                product_name: "Test Product".to_string(),
+                quantity: 1,
+                unit_price: Decimal::new(1000, 2),
+            }],
+            billing_address: None,
+            shipping_address: None,
+            actor: Actor::user("alice"),
+        })
+        .await
+        .unwrap();
+        ConfirmOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(RuleBasedRiskAssessor::new(
+                RiskPolicy::default(),
+                repo.clone(),
+                Arc::new(SystemClock),
+            )),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        )
+        .handle(ConfirmOrderCommand {
+            tenant_id: TenantId::default(),
+            order_id,
+            actor: Actor::user("alice"),
+        })
+        .await
+        .unwrap();
+        CancelOrderHandler::new(
+            repo,
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            publisher,
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        )
+        .handle(CancelOrderCommand {
+            tenant_id: TenantId::default(),
+            order_id,
+            reason: "Out of stock".to_string(),
+            actor: Actor::user("support-42"),
+        })
+        .await
+        .unwrap();
+
+        let timeline = audit_log.timeline(order_id).await.unwrap();
+        let commands: Vec<_> = timeline.iter().map(|e| e.command.as_str()).collect();
+        assert_eq!(commands, ["CreateOrder", "ConfirmOrder", "CancelOrder"]);
+
+        let cancel = &timeline[2];
+        assert_eq!(cancel.actor, Actor::user("support-42"));
+        assert_eq!(cancel.status_before, Some(OrderStatus::Confirmed));
+        assert_eq!(cancel.status_after, OrderStatus::Cancelled);
+        assert_eq!(cancel.reason.as_deref(), Some("Out of stock"));
+        assert_eq!(cancel.events[0].event_name(), "ORDER_CANCELLED");
+    }
+
+    #[tokio::test]
+    async fn test_transition_disabled_for_the_tenant_is_refused() {
+        let acme: TenantId = "acme".parse().unwrap();
+        let tenant = Tenant {
+            transitions: StatusTransitions::only(vec![
+                (OrderStatus::Pending, OrderStatus::Confirmed),
+                (OrderStatus::Pending, OrderStatus::Cancelled),
+            ])
+            .unwrap(),
+            ..Tenant::new(acme.clone())
+        };
+        let tenants = Arc::new(TenantRegistry::new(TenantId::default()).with_tenant(tenant));
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let publisher = Arc::new(NoOpEventPublisher);
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+
+        let order_id = CreateOrderHandler::new(
+            repo.clone(),
+            tenants.clone(),
+            Arc::new(InMemoryPriceLists::default()),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        )
+        .handle(CreateOrderCommand {
+            tenant_id: acme.clone(),
+            customer_id: CustomerId::new(),
+            items: vec![CreateOrderItemDto {
+                product_id: ProductId::new(),
+                product_name: "Test Product".to_string(),
+                quantity: 1,
+                unit_price: Decimal::new(1000, 2),
+            }],
+            billing_address: None,
+            shipping_address: None,
+            actor: Actor::user("alice"),
+        })
+        .await
+        .unwrap();
+        ConfirmOrderHandler::new(
+            repo.clone(),
+            tenants.clone(),
+            Arc::new(RuleBasedRiskAssessor::new(
+                RiskPolicy::default(),
+                repo.clone(),
+                Arc::new(SystemClock),
+            )),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        )
+        .handle(ConfirmOrderCommand {
+            tenant_id: acme.clone(),
+            order_id,
+            actor: Actor::user("alice"),
+        })
+        .await
+        .unwrap();
+
+        let result = CancelOrderHandler::new(
+            repo.clone(),
+            tenants,
+            publisher,
+            audit_log,
+            Arc::new(SystemClock),
+        )
+        .handle(CancelOrderCommand {
+            tenant_id: acme.clone(),
+            order_id,
+            reason: "Changed my mind".to_string(),
+            actor: Actor::user("alice"),
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::InvalidStatusTransition {
+                    from: OrderStatus::Confirmed,
+                    to: OrderStatus::Cancelled,
+                }
+            ))
+        ));
+        let order = repo.find_by_id(&acme, order_id).await.unwrap().unwrap();
+        assert_eq!(order.status(), OrderStatus::Confirmed);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    catalog::{PriceCatalog, StockChecker},
+    clock::Clock,
+    errors::DomainError,
+    id_generator::IdGenerator,
+    pricing::{PriceLists, Pricing},
+    repositories::{CartRepository, OrderRepository},
+    tenant::TenantDirectory,
+    value_objects::{CartId, OrderId, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: turn a customer's cart into a pending order
+#[derive(Debug)]
+pub struct CheckoutCartCommand {
+    /// Storefront the order is placed on
+    pub tenant_id: TenantId,
+    pub cart_id: CartId,
+    pub actor: Actor,
+}
+
+/// Checks the cart against the catalog, then places the order and drops the cart
+pub struct CheckoutCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    price_catalog: Arc<dyn PriceCatalog>,
+    price_lists: Arc<dyn PriceLists>,
+    stock_checker: Arc<dyn StockChecker>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CheckoutCartHandler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        price_catalog: Arc<dyn PriceCatalog>,
+        price_lists: Arc<dyn PriceLists>,
+        stock_checker: Arc<dyn StockChecker>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            order_repository,
+            tenants,
+            price_catalog,
+            price_lists,
+            stock_checker,
+            event_publisher,
+            audit_log,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    ///
+    /// When a price changed since it was added, the cart is repriced and the checkout
+    /// refused (`PricesChanged`), so that the customer confirms the new total.
+    /// Stock is only checked: it is reserved by the inventory once the order is placed.
+    pub async fn handle(&self, command: CheckoutCartCommand) -> Result<OrderId, ApplicationError> {
+        let mut cart = self
+            .cart_repository
+            .find_by_id(&command.tenant_id, command.cart_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Cart))?;
+
+        // 1. Business rules of the cart itself (expiry, owner, lines) and of the tenant,
+        //    lines priced with the price rules of the customer
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let pricing = match cart.customer_id() {
+            Some(customer_id) => {
+                self.price_lists
+                    .pricing_for(customer_id, self.clock.now())
+                    .await?
+            }
+            None => Pricing::list_prices(),
+        };
+        let mut order = cart.check_out(&tenant, &pricing, &*self.ids, &*self.clock)?;
+
+        // 2. Prices and stock, as currently known by the catalog
+        let mut repriced = false;
+        for line in cart.lines().to_vec() {
+            let product_id = line.product_id();
+            let price = self
+                .price_catalog
+                .current_price(product_id)
+                .await?
+                .ok_or(DomainError::ProductUnavailable { product_id })?;
+            if price != line.unit_price() {
+                cart.reprice_line(product_id, price, &*self.clock)?;
+                repriced = true;
+            }
+        }
+        if repriced {
+            self.cart_repository.save(&cart).await?;
+            return Err(DomainError::PricesChanged.into());
+        }
+        for line in cart.lines() {
+            let product_id = line.product_id();
+            let available = self.stock_checker.available_quantity(product_id).await?;
+            if available < line.quantity() {
+                return Err(DomainError::InsufficientStock {
+                    product_id,
+                    available,
+                }
+                .into());
+            }
+        }
+
+        // 3. Persist the order, then drop the cart it came from
+        self.order_repository.save(&mut order).await?;
+        self.cart_repository
+            .delete(cart.tenant_id(), cart.id())
+            .await?;
+
+        // 4. Record in the audit trail, then publish domain events
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "CheckoutCart",
+            None,
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(order.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        aggregates::Cart,
+        clock::SystemClock,
+        entities::CartLine,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, OrderStatus, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::catalog::InMemoryCatalog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::{
+        InMemoryCartRepository, InMemoryOrderRepository,
+    };
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    struct Fixture {
+        carts: Arc<InMemoryCartRepository>,
+        orders: Arc<InMemoryOrderRepository>,
+        catalog: Arc<InMemoryCatalog>,
+        audit_log: Arc<InMemoryAuditLog>,
+        handler: CheckoutCartHandler,
+    }
+
+    fn fixture() -> Fixture {
+        let carts = Arc::new(InMemoryCartRepository::new());
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        let catalog = Arc::new(InMemoryCatalog::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let handler = CheckoutCartHandler::new(
+            carts.clone(),
+            orders.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            catalog.clone(),
+            Arc::new(InMemoryPriceLists::default()),
+            catalog.clone(),
+            Arc::new(NoOpEventPublisher),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        Fixture {
+            carts,
+            orders,
+            catalog,
+            audit_log,
+            handler,
+        }
+    }
+
+    fn eur(cents: i64) -> Money {
+        Money::eur(Decimal::new(cents, 2)).unwrap()
+    }
+
+    /// Customer cart holding 2 units of a product seen at 49.90
+    async fn saved_cart(fixture: &Fixture, product_id: ProductId) -> Cart {
+        let mut cart = Cart::create(
+            TenantId::default(),
+            Some(CustomerId::new()),
+            &UuidV4Generator,
+            &SystemClock,
+        );
+        cart.add_line(
+            CartLine::new(product_id, "Keyboard".to_string(), 2, eur(4990)).unwrap(),
+            &SystemClock,
+        )
+        .unwrap();
+        fixture.carts.save(&cart).await.unwrap();
+        cart
+    }
+
+    fn checkout(cart: &Cart) -> CheckoutCartCommand {
+        CheckoutCartCommand {
+            tenant_id: TenantId::default(),
+            cart_id: cart.id(),
+            actor: Actor::user("alice"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkout_places_the_order_and_drops_the_cart() {
+        let fixture = fixture();
+        let product_id = ProductId::new();
+        fixture.catalog.set_product(product_id, eur(4990), 10);
+        let cart = saved_cart(&fixture, product_id).await;
+
+        let order_id = fixture.handler.handle(checkout(&cart)).await.unwrap();
+
+        let order = fixture
+            .orders
+            .find_by_id(&TenantId::default(), order_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.customer_id(), cart.customer_id().unwrap());
+        assert_eq!(order.status(), OrderStatus::Pending);
+        assert_eq!(order.total(), eur(9980));
+        assert!(fixture
+            .carts
+            .find_by_id(&TenantId::default(), cart.id())
+            .await
+            .unwrap()
+            .is_none());
+
+        let timeline = fixture.audit_log.timeline(order_id).await.unwrap();
+        assert_eq!(timeline[0].command, "CheckoutCart");
+    }
+
+    #[tokio::test]
+    async fn test_changed_price_reprices_the_cart_and_refuses_checkout() {
+        let fixture = fixture();
+        let product_id = ProductId::new();
+        fixture.catalog.set_product(product_id, eur(5490), 10);
+        let cart = saved_cart(&fixture, product_id).await;
+
+        let result = fixture.handler.handle(checkout(&cart)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::PricesChanged))
+        ));
+        let repriced = fixture
+            .carts
+            .find_by_id(&TenantId::default(), cart.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(repriced.lines()[0].unit_price(), eur(5490));
+
+        // Checking out again accepts the new price
+        let order_id = fixture.handler.handle(checkout(&cart)).await.unwrap();
+        let order = fixture
+            .orders
+            .find_by_id(&TenantId::default(), order_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.total(), eur(10980));
+    }
+
+    #[tokio::test]
+    async fn test_checkout_requires_stock_and_listed_products() {
+        let fixture = fixture();
+        let product_id = ProductId::new();
+        let cart = saved_cart(&fixture, product_id).await;
+
+        let result = fixture.handler.handle(checkout(&cart)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::ProductUnavailable { product_id: id }))
+                if id == product_id
+        ));
+
+        fixture.catalog.set_product(product_id, eur(4990), 1);
+        let result = fixture.handler.handle(checkout(&cart)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::InsufficientStock {
+                available: 1,
+                ..
+            }))
+        ));
+        assert!(fixture
+            .carts
+            .find_by_id(&TenantId::default(), cart.id())
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
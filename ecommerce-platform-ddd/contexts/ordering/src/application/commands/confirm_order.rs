@@ -0,0 +1,230 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    aggregates::Order,
+    clock::Clock,
+    repositories::OrderRepository,
+    risk::RiskAssessor,
+    tenant::TenantDirectory,
+    value_objects::{OrderId, OrderStatus, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Confirm Order (Pending -> Confirmed, or OnHold when the order looks fraudulent)
+#[derive(Debug)]
+pub struct ConfirmOrderCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub actor: Actor,
+}
+
+pub struct ConfirmOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    risk_assessor: Arc<dyn RiskAssessor>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ConfirmOrderHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        risk_assessor: Arc<dyn RiskAssessor>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            risk_assessor,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the new status of the order (Confirmed or OnHold)
+    pub async fn handle(
+        &self,
+        command: ConfirmOrderCommand,
+    ) -> Result<OrderStatus, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let status_before = order.status();
+
+        let reason = confirm_or_hold(&mut order, &*self.risk_assessor, &*self.clock).await?;
+        tenant.ensure_transition(status_before, order.status())?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let mut entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "ConfirmOrder",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        if let Some(reason) = reason {
+            entry = entry.with_reason(reason);
+        }
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(order.status())
+    }
+}
+
+/// Confirm `order`, or hold it for review when the risk assessment requires one;
+/// returns why it was held
+///
+/// Only an order that can still be confirmed (Pending) is screened, as placed.
+pub(crate) async fn confirm_or_hold(
+    order: &mut Order,
+    risk_assessor: &dyn RiskAssessor,
+    clock: &dyn Clock,
+) -> Result<Option<String>, ApplicationError> {
+    let assessment = if order.status() == OrderStatus::Pending {
+        Some(risk_assessor.assess(order).await?)
+    } else {
+        None
+    };
+    match assessment {
+        Some(assessment) if assessment.requires_review() => {
+            let why = format!(
+                "Risk score {}: {}",
+                assessment.score,
+                assessment.reasons.join(", ")
+            );
+            tracing::warn!("Order {} held for review ({})", order.id(), why);
+            order.hold_for_review(assessment.score, assessment.reasons, clock)?;
+            Ok(Some(why))
+        }
+        _ => {
+            order.confirm(clock)?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        errors::DomainError,
+        id_generator::UuidV4Generator,
+        risk::{RiskFactor, RiskPolicy, RiskRule},
+        tenant::Tenant,
+        value_objects::{CustomerId, Money, PostalAddress, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::risk::RuleBasedRiskAssessor;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    fn address(postal_code: &str, country: &str) -> PostalAddress {
+        PostalAddress {
+            line1: "1 Main Street".to_string(),
+            postal_code: postal_code.to_string(),
+            city: "Springfield".to_string(),
+            country: country.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_risky_order_is_held_instead_of_confirmed() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let policy = RiskPolicy::new(
+            vec![RiskRule::new(
+                "address-mismatch",
+                RiskFactor::AddressMismatch,
+                100,
+            )],
+            100,
+        )
+        .unwrap();
+        let handler = ConfirmOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(RuleBasedRiskAssessor::new(
+                policy,
+                repo.clone(),
+                Arc::new(SystemClock),
+            )),
+            Arc::new(NoOpEventPublisher),
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        );
+        let place_order = |shipping_country: &'static str| {
+            let repo = &repo;
+            async move {
+                let item = OrderItem::new(
+                    ProductId::new(),
+                    "Gift card".to_string(),
+                    1,
+                    Money::eur(Decimal::new(5000, 2)).unwrap(),
+                    &UuidV4Generator,
+                )
+                .unwrap();
+                let mut order = Order::create(
+                    &Tenant::default(),
+                    CustomerId::new(),
+                    vec![item],
+                    &UuidV4Generator,
+                    &SystemClock,
+                )
+                .unwrap()
+                .with_addresses(
+                    Some(address("75002", "FR")),
+                    Some(address("75002", shipping_country)),
+                );
+                repo.save(&mut order).await.unwrap();
+                order.id()
+            }
+        };
+        let confirm = |order_id| ConfirmOrderCommand {
+            tenant_id: TenantId::default(),
+            order_id,
+            actor: Actor::user("alice"),
+        };
+
+        let order_id = place_order("fr").await;
+        let status = handler.handle(confirm(order_id)).await.unwrap();
+        assert_eq!(status, OrderStatus::Confirmed);
+
+        let order_id = place_order("NG").await;
+        let status = handler.handle(confirm(order_id)).await.unwrap();
+        assert_eq!(status, OrderStatus::OnHold);
+        let timeline = audit_log.timeline(order_id).await.unwrap();
+        assert_eq!(
+            timeline[0].reason.as_deref(),
+            Some("Risk score 100: address-mismatch")
+        );
+        assert_eq!(timeline[0].events[0].event_name(), "ORDER_HELD_FOR_REVIEW");
+
+        let result = handler.handle(confirm(order_id)).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::OrderAwaitingReview))
+        ));
+    }
+}
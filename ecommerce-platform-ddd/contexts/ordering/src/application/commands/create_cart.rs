@@ -0,0 +1,61 @@
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    aggregates::Cart,
+    clock::Clock,
+    id_generator::IdGenerator,
+    repositories::CartRepository,
+    value_objects::{CartId, CustomerId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: open a cart, anonymous or for a customer
+#[derive(Debug)]
+pub struct CreateCartCommand {
+    /// Storefront the cart is opened on
+    pub tenant_id: TenantId,
+    pub customer_id: Option<CustomerId>,
+}
+
+pub struct CreateCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CreateCartHandler {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    ///
+    /// A customer has at most one cart per tenant: when they already have one, it is returned.
+    pub async fn handle(&self, command: CreateCartCommand) -> Result<CartId, ApplicationError> {
+        if let Some(customer_id) = command.customer_id {
+            if let Some(cart) = self
+                .cart_repository
+                .find_by_customer(&command.tenant_id, customer_id)
+                .await?
+            {
+                return Ok(cart.id());
+            }
+        }
+
+        let cart = Cart::create(
+            command.tenant_id,
+            command.customer_id,
+            &*self.ids,
+            &*self.clock,
+        );
+        self.cart_repository.save(&cart).await?;
+        Ok(cart.id())
+    }
+}
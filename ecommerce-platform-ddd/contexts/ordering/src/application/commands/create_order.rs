@@ -1,9 +1,15 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::ApplicationError;
 use crate::domain::{
     aggregates::Order,
+    clock::Clock,
     entities::OrderItem,
     errors::DomainError,
+    id_generator::IdGenerator,
+    pricing::PriceLists,
     repositories::OrderRepository,
-    value_objects::{CustomerId, Money, OrderId, ProductId},
+    tenant::TenantDirectory,
+    value_objects::{CustomerId, Money, OrderId, PostalAddress, ProductId, TenantId},
 };
 use crate::infrastructure::messaging::EventPublisher;
 use rust_decimal::Decimal;
@@ -12,8 +18,13 @@ use std::sync::Arc;
 /// Command: Create Order (CQRS Pattern)
 #[derive(Debug)]
 pub struct CreateOrderCommand {
+    pub tenant_id: TenantId,
     pub customer_id: CustomerId,
     pub items: Vec<CreateOrderItemDto>,
+    /// Where the order is billed and delivered, screened when it is confirmed
+    pub billing_address: Option<PostalAddress>,
+    pub shipping_address: Option<PostalAddress>,
+    pub actor: Actor,
 }
 
 #[derive(Debug)]
@@ -28,46 +39,81 @@ pub struct CreateOrderItemDto {
 /// Orchestrates the use case
 pub struct CreateOrderHandler {
     order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    price_lists: Arc<dyn PriceLists>,
     event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
 }
 
 impl CreateOrderHandler {
     pub fn new(
         order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        price_lists: Arc<dyn PriceLists>,
         event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             order_repository,
+            tenants,
+            price_lists,
             event_publisher,
+            audit_log,
+            ids,
+            clock,
         }
     }
 
     /// Handle the command
-    pub async fn handle(&self, command: CreateOrderCommand) -> Result<OrderId, DomainError> {
-        // 1. Convert DTOs to domain entities
+    pub async fn handle(&self, command: CreateOrderCommand) -> Result<OrderId, ApplicationError> {
+        // 1. Convert DTOs to domain entities, priced in the currency of the tenant: the
+        //    requested unit price is the list price of the price rules of the customer
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let pricing = self
+            .price_lists
+            .pricing_for(command.customer_id, self.clock.now())
+            .await?;
         let items: Vec<OrderItem> = command
             .items
             .into_iter()
             .map(|dto| {
-                OrderItem::new(
+                let mut item = OrderItem::new(
                     dto.product_id,
                     dto.product_name,
                     dto.quantity,
-                    Money::eur(dto.unit_price)?,
-                )
+                    Money::new(dto.unit_price, tenant.currency)?,
+                    &*self.ids,
+                )?;
+                item.apply_pricing(&pricing);
+                Ok(item)
             })
-            .collect::<Result<Vec<_>, _>>()?;
+            .collect::<Result<Vec<_>, DomainError>>()?;
 
         // 2. Create aggregate (business logic in domain)
-        let mut order = Order::create(command.customer_id, items)?;
+        let mut order = Order::create(&tenant, command.customer_id, items, &*self.ids, &*self.clock)?
+            .with_addresses(command.billing_address, command.shipping_address);
 
         // 3. Persist
         self.order_repository.save(&mut order).await?;
 
-        // 4. Publish domain events
+        // 4. Record in the audit trail, then publish domain events
         let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "CreateOrder",
+            None,
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
         for event in events {
-            self.event_publisher.publish(event).await?;
+            self.event_publisher.publish(order.tenant_id(), event).await?;
         }
 
         Ok(order.id())
@@ -78,15 +124,34 @@ impl CreateOrderHandler {
 mod tests {
     use super::*;
     use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::id_generator::SequentialIdGenerator;
+    use chrono::Utc;
+    use uuid::Uuid;
     use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::domain::pricing::{PriceList, PriceRule};
 
     #[tokio::test]
     async fn test_create_order_command() {
         let repo = Arc::new(InMemoryOrderRepository::new());
         let publisher = Arc::new(NoOpEventPublisher);
-        let handler = CreateOrderHandler::new(repo, publisher);
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let clock = Arc::new(FixedClock::new(Utc::now()));
+        let handler = CreateOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(InMemoryPriceLists::default()),
+            publisher,
+            audit_log.clone(),
+            Arc::new(SequentialIdGenerator::new()),
+            clock.clone(),
+        );
 
         let command = CreateOrderCommand {
+            tenant_id: TenantId::default(),
             customer_id: CustomerId::new(),
             items: vec![CreateOrderItemDto {
                 product_id: ProductId::new(),
@@ -94,9 +159,69 @@ mod tests {
                 quantity: 2,
                 unit_price: Decimal::new(1000, 2),
             }],
+            billing_address: None,
+            shipping_address: None,
+            actor: Actor::user("alice"),
         };
 
-        let result = handler.handle(command).await;
-        assert!(result.is_ok());
+        let order_id = handler.handle(command).await.unwrap();
+        assert_eq!(order_id.value(), Uuid::from_u128(2));
+
+        let order = repo.find_by_id(&TenantId::default(), order_id).await.unwrap().unwrap();
+        assert_eq!(order.items()[0].id().value(), Uuid::from_u128(1));
+        assert_eq!(order.created_at(), clock.now());
+
+        let timeline = audit_log.timeline(order_id).await.unwrap();
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline[0].command, "CreateOrder");
+        assert_eq!(timeline[0].actor, Actor::user("alice"));
+        assert_eq!(timeline[0].events[0].event_name(), "ORDER_CREATED");
+    }
+
+    #[tokio::test]
+    async fn test_customer_group_price_is_applied_and_recorded() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let customer_id = CustomerId::new();
+        let product_id = ProductId::new();
+        let prices = PriceList::new(vec![PriceRule::new(
+            "wholesale",
+            product_id,
+            Money::eur(Decimal::new(800, 2)).unwrap(),
+        )
+        .for_group("wholesale")])
+        .unwrap();
+        let handler = CreateOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(InMemoryPriceLists::new(prices).with_customer_group(customer_id, "wholesale")),
+            Arc::new(NoOpEventPublisher),
+            Arc::new(InMemoryAuditLog::new()),
+            Arc::new(SequentialIdGenerator::new()),
+            Arc::new(FixedClock::new(Utc::now())),
+        );
+
+        let order_id = handler
+            .handle(CreateOrderCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                items: vec![CreateOrderItemDto {
+                    product_id,
+                    product_name: "Test Product".to_string(),
+                    quantity: 2,
+                    unit_price: Decimal::new(1000, 2),
+                }],
+                billing_address: None,
+                shipping_address: None,
+                actor: Actor::user("alice"),
+            })
+            .await
+            .unwrap();
+
+        let order = repo.find_by_id(&TenantId::default(), order_id).await.unwrap().unwrap();
+        let item = &order.items()[0];
+        assert_eq!(item.unit_price().amount(), Decimal::new(800, 2));
+        assert_eq!(item.list_price().amount(), Decimal::new(1000, 2));
+        assert_eq!(item.price_rule(), Some("wholesale"));
+        assert_eq!(order.total().amount(), Decimal::new(1600, 2));
     }
 }
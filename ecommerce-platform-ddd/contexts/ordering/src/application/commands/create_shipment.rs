@@ -0,0 +1,81 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    entities::ShipmentLine,
+    id_generator::IdGenerator,
+    repositories::OrderRepository,
+    value_objects::{OrderId, ShipmentId, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Create Shipment (pack part of a paid order in a parcel)
+#[derive(Debug)]
+pub struct CreateShipmentCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub lines: Vec<ShipmentLine>,
+    pub actor: Actor,
+}
+
+pub struct CreateShipmentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CreateShipmentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_publisher,
+            audit_log,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(
+        &self,
+        command: CreateShipmentCommand,
+    ) -> Result<ShipmentId, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let status_before = order.status();
+
+        let shipment_id = order.create_shipment(command.lines, &*self.ids, &*self.clock)?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "CreateShipment",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(shipment_id)
+    }
+}
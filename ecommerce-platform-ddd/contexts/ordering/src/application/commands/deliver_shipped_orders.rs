@@ -0,0 +1,188 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    aggregates::Order, clock::Clock, errors::InfrastructureErrorKind,
+    repositories::OrderRepository, tenant::TenantDirectory, value_objects::OrderStatus,
+};
+use crate::infrastructure::messaging::EventPublisher;
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Command: mark as delivered the orders shipped for longer than `delay`
+///
+/// `delay` is the delivery time confirmed by the carrier.
+#[derive(Debug)]
+pub struct DeliverShippedOrdersCommand {
+    pub delay: Duration,
+}
+
+/// Run periodically by the scheduler, as `system:scheduler`
+pub struct DeliverShippedOrdersHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl DeliverShippedOrdersHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the number of delivered orders
+    ///
+    /// An order failing to update is logged and skipped, the next run retries it.
+    /// Orders of a tenant that does not allow `Shipped -> Delivered` are left shipped.
+    pub async fn handle(
+        &self,
+        command: DeliverShippedOrdersCommand,
+    ) -> Result<usize, ApplicationError> {
+        let cutoff = self.clock.now() - command.delay;
+        let shipped = self
+            .order_repository
+            .find_stale(OrderStatus::Shipped, cutoff)
+            .await?;
+
+        let mut delivered = 0;
+        for order in shipped {
+            let order_id = order.id();
+            match self.deliver(order).await {
+                Ok(true) => delivered += 1,
+                Ok(false) => {}
+                Err(err) => tracing::warn!("Failed to deliver order {}: {}", order_id, err),
+            }
+        }
+        Ok(delivered)
+    }
+
+    async fn deliver(&self, mut order: Order) -> Result<bool, ApplicationError> {
+        let tenant = self.tenants.tenant(order.tenant_id())?;
+        let status_before = order.status();
+        if !tenant
+            .transitions
+            .allows(status_before, OrderStatus::Delivered)
+        {
+            return Ok(false);
+        }
+
+        order.deliver(&*self.clock)?;
+        match self
+            .order_repository
+            .save_if_status(&mut order, status_before)
+            .await
+        {
+            Ok(()) => {}
+            // Delivered meanwhile, e.g. by an operator: nothing left to do
+            Err(err) if err.kind() == InfrastructureErrorKind::Conflict => {
+                tracing::debug!("Order {} changed before delivery: {}", order.id(), err);
+                return Ok(false);
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            Actor::system("scheduler"),
+            "DeliverShippedOrder",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        clock::FixedClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, ProductId, TenantId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_shipped_orders_are_delivered_after_delay() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Test Product".to_string(),
+            1,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let clock = Arc::new(FixedClock::new(chrono::Utc::now()));
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &*clock,
+        )
+        .unwrap();
+        order.confirm(&*clock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), &*clock).unwrap();
+        order.ship("TRACK123".to_string(), &*clock).unwrap();
+        repo.save(&mut order).await.unwrap();
+
+        let handler = DeliverShippedOrdersHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            publisher.clone(),
+            Arc::new(InMemoryAuditLog::new()),
+            clock.clone(),
+        );
+        let command = || DeliverShippedOrdersCommand {
+            delay: Duration::days(2),
+        };
+
+        clock.advance(Duration::days(1));
+        assert_eq!(handler.handle(command()).await.unwrap(), 0);
+
+        clock.advance(Duration::days(1));
+        assert_eq!(handler.handle(command()).await.unwrap(), 1);
+
+        let order = repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Delivered);
+        let published = publisher.published().await;
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].event.event_name(), "ORDER_DELIVERED");
+    }
+}
@@ -0,0 +1,31 @@
+use crate::application::error::ApplicationError;
+use crate::domain::{clock::Clock, repositories::CartRepository};
+use std::sync::Arc;
+
+/// Command: delete the carts past their expiry
+#[derive(Debug)]
+pub struct ExpireCartsCommand;
+
+/// Run periodically by the scheduler
+pub struct ExpireCartsHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ExpireCartsHandler {
+    pub fn new(cart_repository: Arc<dyn CartRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            cart_repository,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the number of deleted carts
+    pub async fn handle(&self, _command: ExpireCartsCommand) -> Result<usize, ApplicationError> {
+        let deleted = self
+            .cart_repository
+            .delete_expired(self.clock.now())
+            .await?;
+        Ok(deleted as usize)
+    }
+}
@@ -0,0 +1,338 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    aggregates::Order, clock::Clock, errors::InfrastructureErrorKind,
+    repositories::OrderRepository, tenant::TenantDirectory, value_objects::OrderStatus,
+};
+use crate::infrastructure::messaging::EventPublisher;
+use chrono::Duration;
+use std::sync::Arc;
+
+/// Command: cancel the orders left `Pending` for longer than `ttl`
+#[derive(Debug)]
+pub struct ExpirePendingOrdersCommand {
+    pub ttl: Duration,
+}
+
+/// Run periodically by the scheduler, as `system:scheduler`
+pub struct ExpirePendingOrdersHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ExpirePendingOrdersHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the number of cancelled orders
+    ///
+    /// An order failing to cancel is logged and skipped, the next run retries it.
+    /// Orders of a tenant that does not allow `Pending -> Cancelled` are left pending.
+    pub async fn handle(
+        &self,
+        command: ExpirePendingOrdersCommand,
+    ) -> Result<usize, ApplicationError> {
+        let cutoff = self.clock.now() - command.ttl;
+        let stale = self
+            .order_repository
+            .find_stale(OrderStatus::Pending, cutoff)
+            .await?;
+
+        let mut cancelled = 0;
+        for order in stale {
+            let order_id = order.id();
+            match self.expire(order, command.ttl).await {
+                Ok(true) => cancelled += 1,
+                Ok(false) => {}
+                Err(err) => tracing::warn!("Failed to expire order {}: {}", order_id, err),
+            }
+        }
+        Ok(cancelled)
+    }
+
+    async fn expire(&self, mut order: Order, ttl: Duration) -> Result<bool, ApplicationError> {
+        let tenant = self.tenants.tenant(order.tenant_id())?;
+        if !tenant
+            .transitions
+            .allows(order.status(), OrderStatus::Cancelled)
+        {
+            return Ok(false);
+        }
+
+        let reason = format!(
+            "Automatically cancelled: still pending after {}",
+            describe(ttl)
+        );
+        let status_before = order.status();
+
+        order.cancel(reason.clone(), &*self.clock)?;
+        match self
+            .order_repository
+            .save_if_status(&mut order, status_before)
+            .await
+        {
+            Ok(()) => {}
+            // Confirmed or cancelled since it was read: no longer ours to expire
+            Err(err) if err.kind() == InfrastructureErrorKind::Conflict => {
+                tracing::debug!("Order {} changed before expiring: {}", order.id(), err);
+                return Ok(false);
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            Actor::system("scheduler"),
+            "ExpirePendingOrder",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        )
+        .with_reason(reason);
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(true)
+    }
+}
+
+/// The TTL in the largest unit it is a whole number of, e.g. `24 hours` or `90 minutes`
+fn describe(ttl: Duration) -> String {
+    let seconds = ttl.num_seconds();
+    let (count, unit) = if seconds % 3600 == 0 {
+        (seconds / 3600, "hour")
+    } else if seconds % 60 == 0 {
+        (seconds / 60, "minute")
+    } else {
+        (seconds, "second")
+    };
+    format!("{count} {unit}{}", if count == 1 { "" } else { "s" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        clock::{FixedClock, SystemClock},
+        entities::OrderItem,
+        errors::InfrastructureError,
+        id_generator::UuidV4Generator,
+        repositories::{OrderCriteria, OrderCursor},
+        value_objects::{CustomerId, Money, OrderId, ProductId, TenantId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+
+    /// Lets the customer confirm every order right after the job read it
+    struct ConfirmedMeanwhile(InMemoryOrderRepository);
+
+    #[async_trait]
+    impl OrderRepository for ConfirmedMeanwhile {
+        async fn save(&self, order: &mut Order) -> Result<(), InfrastructureError> {
+            self.0.save(order).await
+        }
+
+        async fn save_if_status(
+            &self,
+            order: &mut Order,
+            expected: OrderStatus,
+        ) -> Result<(), InfrastructureError> {
+            self.0.save_if_status(order, expected).await
+        }
+
+        async fn find_by_id(
+            &self,
+            tenant_id: &TenantId,
+            id: OrderId,
+        ) -> Result<Option<Order>, InfrastructureError> {
+            self.0.find_by_id(tenant_id, id).await
+        }
+
+        async fn find_by_customer(
+            &self,
+            tenant_id: &TenantId,
+            customer_id: CustomerId,
+        ) -> Result<Vec<Order>, InfrastructureError> {
+            self.0.find_by_customer(tenant_id, customer_id).await
+        }
+
+        async fn find_stale(
+            &self,
+            status: OrderStatus,
+            updated_before: DateTime<Utc>,
+        ) -> Result<Vec<Order>, InfrastructureError> {
+            let stale = self.0.find_stale(status, updated_before).await?;
+            for order in &stale {
+                let mut confirmed = order.clone();
+                confirmed.confirm(&SystemClock).unwrap();
+                self.0.save(&mut confirmed).await?;
+            }
+            Ok(stale)
+        }
+
+        async fn find_page(
+            &self,
+            criteria: &OrderCriteria,
+            after: Option<OrderCursor>,
+            limit: u64,
+        ) -> Result<Vec<Order>, InfrastructureError> {
+            self.0.find_page(criteria, after, limit).await
+        }
+
+        async fn delete(
+            &self,
+            tenant_id: &TenantId,
+            id: OrderId,
+        ) -> Result<(), InfrastructureError> {
+            self.0.delete(tenant_id, id).await
+        }
+    }
+
+    fn item() -> OrderItem {
+        OrderItem::new(
+            ProductId::new(),
+            "Test Product".to_string(),
+            1,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_only_stale_pending_orders_are_cancelled() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+
+        let create = |clock: &dyn Clock| {
+            Order::create(
+                &Tenant::default(),
+                CustomerId::new(),
+                vec![item()],
+                &UuidV4Generator,
+                clock,
+            )
+            .unwrap()
+        };
+
+        let mut stale = create(&SystemClock);
+        repo.save(&mut stale).await.unwrap();
+        let mut confirmed = create(&SystemClock);
+        confirmed.confirm(&SystemClock).unwrap();
+        repo.save(&mut confirmed).await.unwrap();
+
+        let clock = Arc::new(FixedClock::new(stale.created_at() + Duration::hours(25)));
+        let mut recent = create(&*clock);
+        repo.save(&mut recent).await.unwrap();
+
+        let handler = ExpirePendingOrdersHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(NoOpEventPublisher),
+            audit_log.clone(),
+            clock.clone(),
+        );
+        let command = || ExpirePendingOrdersCommand {
+            ttl: Duration::hours(24),
+        };
+
+        assert_eq!(handler.handle(command()).await.unwrap(), 1);
+        assert_eq!(handler.handle(command()).await.unwrap(), 0);
+
+        let stale = repo
+            .find_by_id(&TenantId::default(), stale.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stale.status(), OrderStatus::Cancelled);
+        assert_eq!(stale.updated_at(), clock.now());
+        let recent = repo
+            .find_by_id(&TenantId::default(), recent.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(recent.status(), OrderStatus::Pending);
+
+        let timeline = audit_log.timeline(stale.id()).await.unwrap();
+        assert_eq!(timeline[0].actor, Actor::system("scheduler"));
+        assert_eq!(
+            timeline[0].reason.as_deref(),
+            Some("Automatically cancelled: still pending after 24 hours")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_orders_confirmed_after_the_read_are_left_alone() {
+        let repo = Arc::new(ConfirmedMeanwhile(InMemoryOrderRepository::new()));
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item()],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        repo.save(&mut order).await.unwrap();
+
+        let handler = ExpirePendingOrdersHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(NoOpEventPublisher),
+            audit_log.clone(),
+            Arc::new(FixedClock::new(order.created_at() + Duration::hours(25))),
+        );
+        let cancelled = handler
+            .handle(ExpirePendingOrdersCommand {
+                ttl: Duration::hours(24),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(cancelled, 0);
+        let found = repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.status(), OrderStatus::Confirmed);
+        assert!(audit_log.timeline(order.id()).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ttl_is_described_in_its_largest_whole_unit() {
+        assert_eq!(describe(Duration::hours(24)), "24 hours");
+        assert_eq!(describe(Duration::hours(1)), "1 hour");
+        assert_eq!(describe(Duration::minutes(30)), "30 minutes");
+        assert_eq!(describe(Duration::minutes(90)), "90 minutes");
+        assert_eq!(describe(Duration::seconds(45)), "45 seconds");
+    }
+}
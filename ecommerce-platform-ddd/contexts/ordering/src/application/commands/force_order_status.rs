@@ -0,0 +1,298 @@
+use super::confirm_order::confirm_or_hold;
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    risk::RiskAssessor,
+    tenant::TenantDirectory,
+    value_objects::{OrderId, OrderStatus, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Transition an operator can apply by hand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusTransition {
+    /// Screened like any confirmation (a risky order goes `OnHold`) unless `skip_risk`,
+    /// which the audit entry records
+    Confirm {
+        skip_risk: bool,
+    },
+    MarkAsPaid {
+        payment_id: Uuid,
+    },
+    Ship {
+        tracking_number: String,
+    },
+    Deliver,
+    Cancel,
+}
+
+impl StatusTransition {
+    pub fn target(&self) -> OrderStatus {
+        match self {
+            StatusTransition::Confirm { .. } => OrderStatus::Confirmed,
+            StatusTransition::MarkAsPaid { .. } => OrderStatus::Paid,
+            StatusTransition::Ship { .. } => OrderStatus::Shipped,
+            StatusTransition::Deliver => OrderStatus::Delivered,
+            StatusTransition::Cancel => OrderStatus::Cancelled,
+        }
+    }
+}
+
+/// Command: Force Order Status (operator correction, e.g. a payment confirmed out of band)
+///
+/// Goes through the aggregate, so the status machine still applies; the
+/// justification is mandatory and kept in the audit trail.
+#[derive(Debug)]
+pub struct ForceOrderStatusCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub transition: StatusTransition,
+    pub justification: String,
+    pub actor: Actor,
+}
+
+pub struct ForceOrderStatusHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    risk_assessor: Arc<dyn RiskAssessor>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ForceOrderStatusHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        risk_assessor: Arc<dyn RiskAssessor>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            risk_assessor,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the new status (`OnHold` for a confirmation the risk
+    /// assessment holds)
+    pub async fn handle(
+        &self,
+        command: ForceOrderStatusCommand,
+    ) -> Result<OrderStatus, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let status_before = order.status();
+
+        let clock = &*self.clock;
+        let mut reason = command.justification.clone();
+        match command.transition {
+            StatusTransition::Confirm { skip_risk: false } => {
+                let held = confirm_or_hold(&mut order, &*self.risk_assessor, clock).await?;
+                if let Some(held) = held {
+                    reason = format!("{reason} (held: {held})");
+                }
+            }
+            StatusTransition::Confirm { skip_risk: true } => {
+                order.confirm(clock)?;
+                reason = format!("{reason} (risk assessment skipped)");
+            }
+            StatusTransition::MarkAsPaid { payment_id } => order.mark_as_paid(payment_id, clock)?,
+            StatusTransition::Ship { tracking_number } => order.ship(tracking_number, clock)?,
+            StatusTransition::Deliver => order.deliver(clock)?,
+            StatusTransition::Cancel => order.cancel(command.justification.clone(), clock)?,
+        }
+        tenant.ensure_transition(status_before, order.status())?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "ForceOrderStatus",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        )
+        .with_reason(reason);
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(order.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::DomainError;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        risk::{RiskFactor, RiskPolicy, RiskRule},
+        value_objects::{CustomerId, Money, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::risk::RuleBasedRiskAssessor;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    /// Holds the orders of more than 5 units
+    fn handler(
+        repo: Arc<InMemoryOrderRepository>,
+        audit_log: Arc<InMemoryAuditLog>,
+    ) -> ForceOrderStatusHandler {
+        let policy = RiskPolicy::new(
+            vec![RiskRule::new("bulk", RiskFactor::ItemCountAbove(5), 100)],
+            100,
+        )
+        .unwrap();
+        ForceOrderStatusHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(RuleBasedRiskAssessor::new(
+                policy,
+                repo,
+                Arc::new(SystemClock),
+            )),
+            Arc::new(NoOpEventPublisher),
+            audit_log,
+            Arc::new(SystemClock),
+        )
+    }
+
+    async fn place(repo: &InMemoryOrderRepository, quantity: u32) -> Order {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Test Product".to_string(),
+            quantity,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        repo.save(&mut order).await.unwrap();
+        order
+    }
+
+    fn force(order: &Order, transition: StatusTransition) -> ForceOrderStatusCommand {
+        ForceOrderStatusCommand {
+            tenant_id: TenantId::default(),
+            order_id: order.id(),
+            transition,
+            justification: "Bank transfer received, ticket OPS-12".to_string(),
+            actor: Actor::user("ops"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forced_transition_is_audited_with_justification() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let mut order = place(&repo, 1).await;
+        order.confirm(&SystemClock).unwrap();
+        repo.save(&mut order).await.unwrap();
+        let handler = handler(repo.clone(), audit_log.clone());
+
+        let status = handler
+            .handle(force(
+                &order,
+                StatusTransition::MarkAsPaid {
+                    payment_id: Uuid::new_v4(),
+                },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(status, OrderStatus::Paid);
+
+        let timeline = audit_log.timeline(order.id()).await.unwrap();
+        assert_eq!(timeline[0].command, "ForceOrderStatus");
+        assert_eq!(timeline[0].status_before, Some(OrderStatus::Confirmed));
+        assert_eq!(
+            timeline[0].reason.as_deref(),
+            Some("Bank transfer received, ticket OPS-12")
+        );
+
+        // The status machine still applies
+        let result = handler
+            .handle(force(
+                &order,
+                StatusTransition::Confirm { skip_risk: false },
+            ))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::InvalidStatusTransition { .. }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_forced_confirmation_is_screened_unless_skipped() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let handler = handler(repo.clone(), audit_log.clone());
+
+        let risky = place(&repo, 6).await;
+        let status = handler
+            .handle(force(
+                &risky,
+                StatusTransition::Confirm { skip_risk: false },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(status, OrderStatus::OnHold);
+        let timeline = audit_log.timeline(risky.id()).await.unwrap();
+        assert_eq!(
+            timeline[0].reason.as_deref(),
+            Some("Bank transfer received, ticket OPS-12 (held: Risk score 100: bulk)")
+        );
+
+        let skipped = place(&repo, 6).await;
+        let status = handler
+            .handle(force(
+                &skipped,
+                StatusTransition::Confirm { skip_risk: true },
+            ))
+            .await
+            .unwrap();
+        assert_eq!(status, OrderStatus::Confirmed);
+        let timeline = audit_log.timeline(skipped.id()).await.unwrap();
+        assert_eq!(timeline[0].actor, Actor::user("ops"));
+        assert_eq!(
+            timeline[0].reason.as_deref(),
+            Some("Bank transfer received, ticket OPS-12 (risk assessment skipped)")
+        );
+    }
+}
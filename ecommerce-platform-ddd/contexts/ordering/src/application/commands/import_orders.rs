@@ -0,0 +1,167 @@
+use crate::application::audit::Actor;
+use crate::application::commands::CreateOrderHandler;
+use crate::application::dto::{CreateOrderRequest, ImportReport, ImportRowError};
+use crate::application::error::ApplicationError;
+use crate::domain::value_objects::TenantId;
+use std::sync::Arc;
+
+/// One order read from an import file, or the reason it could not be read
+#[derive(Debug)]
+pub struct ImportRow {
+    /// 1-based line in the source file
+    pub line: usize,
+    pub order: Result<CreateOrderRequest, String>,
+}
+
+/// Command: Import orders in bulk (legacy migration)
+#[derive(Debug)]
+pub struct ImportOrdersCommand {
+    /// Storefront the orders are imported into
+    pub tenant_id: TenantId,
+    pub rows: Vec<ImportRow>,
+    pub actor: Actor,
+}
+
+/// Creates each order through `CreateOrderHandler`, so imported orders follow
+/// the same business rules, audit trail and events as the API
+pub struct ImportOrdersHandler {
+    create_order: Arc<CreateOrderHandler>,
+}
+
+impl ImportOrdersHandler {
+    pub fn new(create_order: Arc<CreateOrderHandler>) -> Self {
+        Self { create_order }
+    }
+
+    /// Invalid rows are reported and skipped; a storage failure aborts the import
+    pub async fn handle(
+        &self,
+        command: ImportOrdersCommand,
+    ) -> Result<ImportReport, ApplicationError> {
+        let mut report = ImportReport::default();
+
+        for row in command.rows {
+            let result = match row.order {
+                Ok(request) => {
+                    match self
+                        .create_order
+                        .handle(
+                            request.into_command(command.tenant_id.clone(), command.actor.clone()),
+                        )
+                        .await
+                    {
+                        Ok(order_id) => Ok(order_id),
+                        Err(err @ ApplicationError::Infrastructure(_)) => return Err(err),
+                        Err(err) => Err(err.to_string()),
+                    }
+                }
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(order_id) => {
+                    report.imported += 1;
+                    report.order_ids.push(order_id);
+                }
+                Err(error) => {
+                    report.failed += 1;
+                    report.errors.push(ImportRowError {
+                        line: row.line,
+                        error,
+                    });
+                }
+            }
+        }
+
+        tracing::info!(
+            imported = report.imported,
+            failed = report.failed,
+            "Order import finished"
+        );
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::CreateOrderItemRequest;
+    use crate::domain::errors::DomainError;
+    use crate::domain::{
+        clock::SystemClock,
+        id_generator::UuidV4Generator,
+        repositories::OrderRepository,
+        value_objects::{CustomerId, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    fn request(quantity: u32) -> CreateOrderRequest {
+        CreateOrderRequest {
+            customer_id: CustomerId::new(),
+            items: vec![CreateOrderItemRequest {
+                product_id: ProductId::new(),
+                product_name: "Test Product".to_string(),
+                quantity,
+                unit_price: Decimal::new(1000, 2),
+            }],
+            billing_address: None,
+            shipping_address: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rows_are_reported_and_skipped() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let create_order = Arc::new(CreateOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            Arc::new(InMemoryPriceLists::default()),
+            Arc::new(NoOpEventPublisher),
+            Arc::new(InMemoryAuditLog::new()),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        ));
+        let handler = ImportOrdersHandler::new(create_order);
+
+        let report = handler
+            .handle(ImportOrdersCommand {
+                tenant_id: TenantId::default(),
+                rows: vec![
+                    ImportRow {
+                        line: 1,
+                        order: Ok(request(2)),
+                    },
+                    ImportRow {
+                        line: 2,
+                        order: Ok(request(0)),
+                    },
+                    ImportRow {
+                        line: 3,
+                        order: Err("missing field `customer_id`".to_string()),
+                    },
+                ],
+                actor: Actor::system("import"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.imported, 1);
+        assert_eq!(report.failed, 2);
+        assert_eq!(report.errors[0].line, 2);
+        assert_eq!(
+            report.errors[0].error,
+            DomainError::InvalidQuantity.to_string()
+        );
+        assert_eq!(report.errors[1].line, 3);
+        assert!(repo
+            .find_by_id(&TenantId::default(), report.order_ids[0])
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
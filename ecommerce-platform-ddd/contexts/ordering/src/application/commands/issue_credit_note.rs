@@ -0,0 +1,204 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    aggregates::Invoice,
+    clock::Clock,
+    id_generator::IdGenerator,
+    repositories::InvoiceRepository,
+    value_objects::{InvoiceId, OrderItemId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: refund (part of) an invoice
+#[derive(Debug)]
+pub struct IssueCreditNoteCommand {
+    pub tenant_id: TenantId,
+    pub invoice_id: InvoiceId,
+    /// Units refunded per invoiced item; `None` refunds everything not credited yet
+    pub lines: Option<Vec<(OrderItemId, u32)>>,
+    pub reason: String,
+}
+
+pub struct IssueCreditNoteHandler {
+    invoice_repository: Arc<dyn InvoiceRepository>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl IssueCreditNoteHandler {
+    pub fn new(
+        invoice_repository: Arc<dyn InvoiceRepository>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            invoice_repository,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the issued credit note
+    pub async fn handle(
+        &self,
+        command: IssueCreditNoteCommand,
+    ) -> Result<Invoice, ApplicationError> {
+        let invoice = self
+            .invoice_repository
+            .find_by_id(&command.tenant_id, command.invoice_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Invoice))?;
+        let credit_notes = self
+            .invoice_repository
+            .find_by_order(&command.tenant_id, invoice.order_id())
+            .await?;
+
+        let draft = invoice.draft_credit_note(
+            command.lines,
+            command.reason,
+            &credit_notes,
+            &*self.ids,
+            &*self.clock,
+        )?;
+        let credit_note = self.invoice_repository.issue(draft).await?;
+
+        tracing::info!(
+            "Credit note {} issued against invoice {}",
+            credit_note.number(),
+            invoice.number()
+        );
+        Ok(credit_note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::DomainError;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, InvoiceKind, Money, ProductId},
+    };
+    use crate::infrastructure::persistence::repositories::InMemoryInvoiceRepository;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    async fn issued_invoice(repo: &InMemoryInvoiceRepository) -> Invoice {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            3,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), &SystemClock).unwrap();
+        let draft = Invoice::draft_for_order(&order, &UuidV4Generator, &SystemClock).unwrap();
+        repo.issue(draft).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_credit_notes_never_refund_more_than_invoiced() {
+        let repo = Arc::new(InMemoryInvoiceRepository::new());
+        let handler = IssueCreditNoteHandler::new(
+            repo.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let invoice = issued_invoice(&repo).await;
+        let item = invoice.lines()[0].order_item_id();
+        let refund = |quantity| IssueCreditNoteCommand {
+            tenant_id: TenantId::default(),
+            invoice_id: invoice.id(),
+            lines: Some(vec![(item, quantity)]),
+            reason: "Damaged".to_string(),
+        };
+
+        let credit_note = handler.handle(refund(2)).await.unwrap();
+        let exceeding = handler.handle(refund(2)).await;
+        handler.handle(refund(1)).await.unwrap();
+
+        assert_eq!(credit_note.kind(), InvoiceKind::CreditNote);
+        assert_eq!(credit_note.total().amount(), Decimal::new(9980, 2));
+        assert!(matches!(
+            exceeding,
+            Err(ApplicationError::Domain(
+                DomainError::CreditNoteExceedsInvoice
+            ))
+        ));
+        assert_eq!(
+            repo.find_by_order(&TenantId::default(), invoice.order_id())
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_invoice_is_rejected() {
+        let handler = IssueCreditNoteHandler::new(
+            Arc::new(InMemoryInvoiceRepository::new()),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+
+        let result = handler
+            .handle(IssueCreditNoteCommand {
+                tenant_id: TenantId::default(),
+                invoice_id: InvoiceId::new(),
+                lines: None,
+                reason: "Returned".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::NotFound(Resource::Invoice))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invoice_of_another_tenant_is_not_found() {
+        let repo = Arc::new(InMemoryInvoiceRepository::new());
+        let handler = IssueCreditNoteHandler::new(
+            repo.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let invoice = issued_invoice(&repo).await;
+
+        let result = handler
+            .handle(IssueCreditNoteCommand {
+                tenant_id: "acme".parse().unwrap(),
+                invoice_id: invoice.id(),
+                lines: None,
+                reason: "Returned".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::NotFound(Resource::Invoice))
+        ));
+        assert_eq!(
+            repo.find_by_order(&TenantId::default(), invoice.order_id())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
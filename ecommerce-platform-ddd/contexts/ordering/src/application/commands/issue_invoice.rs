@@ -0,0 +1,281 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    aggregates::Invoice,
+    clock::Clock,
+    errors::InfrastructureErrorKind,
+    id_generator::IdGenerator,
+    repositories::{InvoiceRepository, OrderRepository},
+    value_objects::{InvoiceId, InvoiceKind, OrderId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: invoice a paid order
+#[derive(Debug)]
+pub struct IssueInvoiceCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+}
+
+/// Run when an order is paid (`OrderPaid`)
+pub struct IssueInvoiceHandler {
+    invoice_repository: Arc<dyn InvoiceRepository>,
+    order_repository: Arc<dyn OrderRepository>,
+    ids: Arc<dyn IdGenerator>,
+    clock: Arc<dyn Clock>,
+}
+
+impl IssueInvoiceHandler {
+    pub fn new(
+        invoice_repository: Arc<dyn InvoiceRepository>,
+        order_repository: Arc<dyn OrderRepository>,
+        ids: Arc<dyn IdGenerator>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            invoice_repository,
+            order_repository,
+            ids,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the invoice of the order
+    ///
+    /// Idempotent: an order is invoiced once, however many times `OrderPaid` is delivered,
+    /// even concurrently.
+    pub async fn handle(
+        &self,
+        command: IssueInvoiceCommand,
+    ) -> Result<InvoiceId, ApplicationError> {
+        if let Some(invoice_id) = self.issued(&command).await? {
+            return Ok(invoice_id);
+        }
+
+        let order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let draft = Invoice::draft_for_order(&order, &*self.ids, &*self.clock)?;
+        let invoice = match self.invoice_repository.issue(draft).await {
+            Ok(invoice) => invoice,
+            // Issued meanwhile by another delivery; the refused one consumed no number
+            Err(err) if err.kind() == InfrastructureErrorKind::Conflict => {
+                return self.issued(&command).await?.ok_or_else(|| err.into());
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        tracing::info!(
+            "Invoice {} issued for order {}",
+            invoice.number(),
+            order.id()
+        );
+        Ok(invoice.id())
+    }
+
+    /// Invoice already issued for the order, if any
+    async fn issued(
+        &self,
+        command: &IssueInvoiceCommand,
+    ) -> Result<Option<InvoiceId>, ApplicationError> {
+        let issued = self
+            .invoice_repository
+            .find_by_order(&command.tenant_id, command.order_id)
+            .await?;
+        Ok(issued
+            .iter()
+            .find(|i| i.kind() == InvoiceKind::Invoice)
+            .map(|invoice| invoice.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::DomainError;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, ProductId},
+    };
+    use crate::infrastructure::persistence::repositories::{
+        InMemoryInvoiceRepository, InMemoryOrderRepository,
+    };
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    struct Fixture {
+        invoices: Arc<InMemoryInvoiceRepository>,
+        orders: Arc<InMemoryOrderRepository>,
+        handler: IssueInvoiceHandler,
+    }
+
+    fn fixture() -> Fixture {
+        let invoices = Arc::new(InMemoryInvoiceRepository::new());
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        let handler = IssueInvoiceHandler::new(
+            invoices.clone(),
+            orders.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        Fixture {
+            invoices,
+            orders,
+            handler,
+        }
+    }
+
+    async fn saved_order(fixture: &Fixture, paid: bool) -> Order {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            2,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        if paid {
+            order.confirm(&SystemClock).unwrap();
+            order.mark_as_paid(Uuid::new_v4(), &SystemClock).unwrap();
+        }
+        fixture.orders.save(&mut order).await.unwrap();
+        order
+    }
+
+    #[tokio::test]
+    async fn test_paid_order_is_invoiced_once() {
+        let fixture = fixture();
+        let order = saved_order(&fixture, true).await;
+        let command = || IssueInvoiceCommand {
+            tenant_id: TenantId::default(),
+            order_id: order.id(),
+        };
+
+        let first = fixture.handler.handle(command()).await.unwrap();
+        let second = fixture.handler.handle(command()).await.unwrap();
+
+        assert_eq!(first, second);
+        let invoices = fixture
+            .invoices
+            .find_by_order(&TenantId::default(), order.id())
+            .await
+            .unwrap();
+        assert_eq!(invoices.len(), 1);
+        assert_eq!(invoices[0].total(), order.total());
+    }
+
+    /// Invoices whose first read comes before a concurrent delivery of `OrderPaid` issued one
+    struct StaleInvoices(
+        Arc<InMemoryInvoiceRepository>,
+        std::sync::atomic::AtomicBool,
+    );
+
+    #[async_trait::async_trait]
+    impl InvoiceRepository for StaleInvoices {
+        async fn issue(
+            &self,
+            draft: crate::domain::aggregates::InvoiceDraft,
+        ) -> Result<Invoice, crate::domain::errors::InfrastructureError> {
+            self.0.issue(draft).await
+        }
+
+        async fn find_by_id(
+            &self,
+            tenant_id: &TenantId,
+            id: InvoiceId,
+        ) -> Result<Option<Invoice>, crate::domain::errors::InfrastructureError> {
+            self.0.find_by_id(tenant_id, id).await
+        }
+
+        async fn find_by_order(
+            &self,
+            tenant_id: &TenantId,
+            order_id: OrderId,
+        ) -> Result<Vec<Invoice>, crate::domain::errors::InfrastructureError> {
+            if self.1.swap(false, std::sync::atomic::Ordering::SeqCst) {
+                return Ok(Vec::new());
+            }
+            self.0.find_by_order(tenant_id, order_id).await
+        }
+
+        async fn anonymize(
+            &self,
+            tenant_id: &TenantId,
+            order_id: OrderId,
+            pseudonym: crate::domain::value_objects::CustomerId,
+        ) -> Result<(), crate::domain::errors::InfrastructureError> {
+            self.0.anonymize(tenant_id, order_id, pseudonym).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrently_paid_order_is_invoiced_once() {
+        let fixture = fixture();
+        let order = saved_order(&fixture, true).await;
+        let concurrent = fixture
+            .invoices
+            .issue(Invoice::draft_for_order(&order, &UuidV4Generator, &SystemClock).unwrap())
+            .await
+            .unwrap();
+        let handler = IssueInvoiceHandler::new(
+            Arc::new(StaleInvoices(fixture.invoices.clone(), true.into())),
+            fixture.orders.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+
+        let invoice_id = handler
+            .handle(IssueInvoiceCommand {
+                tenant_id: TenantId::default(),
+                order_id: order.id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(invoice_id, concurrent.id());
+        let invoices = fixture
+            .invoices
+            .find_by_order(&TenantId::default(), order.id())
+            .await
+            .unwrap();
+        assert_eq!(invoices.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unpaid_order_is_not_invoiced() {
+        let fixture = fixture();
+        let order = saved_order(&fixture, false).await;
+
+        let result = fixture
+            .handler
+            .handle(IssueInvoiceCommand {
+                tenant_id: TenantId::default(),
+                order_id: order.id(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::OrderNotPaid))
+        ));
+        assert!(fixture
+            .invoices
+            .find_by_order(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
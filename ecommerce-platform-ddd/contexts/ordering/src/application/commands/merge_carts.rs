@@ -0,0 +1,185 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::CartRepository,
+    value_objects::{CartId, CustomerId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: fold the cart filled before logging in into the customer's cart
+#[derive(Debug)]
+pub struct MergeCartsCommand {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+    pub anonymous_cart_id: CartId,
+}
+
+pub struct MergeCartsHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MergeCartsHandler {
+    pub fn new(cart_repository: Arc<dyn CartRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            cart_repository,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the cart of the customer
+    ///
+    /// Without a cart yet, the customer simply takes over the anonymous one.
+    pub async fn handle(&self, command: MergeCartsCommand) -> Result<CartId, ApplicationError> {
+        let mut anonymous = self
+            .cart_repository
+            .find_by_id(&command.tenant_id, command.anonymous_cart_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Cart))?;
+
+        match self
+            .cart_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?
+        {
+            None => {
+                anonymous.assign_to(command.customer_id, &*self.clock)?;
+                self.cart_repository.save(&anonymous).await?;
+                Ok(anonymous.id())
+            }
+            Some(mut cart) => {
+                cart.merge(anonymous, &*self.clock)?;
+                self.cart_repository.save(&cart).await?;
+                self.cart_repository
+                    .delete(&command.tenant_id, command.anonymous_cart_id)
+                    .await?;
+                Ok(cart.id())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::{
+        CartChange, CreateCartCommand, CreateCartHandler, UpdateCartCommand, UpdateCartHandler,
+    };
+    use crate::domain::{
+        clock::SystemClock, id_generator::UuidV4Generator, value_objects::ProductId,
+    };
+    use crate::infrastructure::persistence::repositories::InMemoryCartRepository;
+    use rust_decimal::Decimal;
+
+    struct Fixture {
+        repo: Arc<InMemoryCartRepository>,
+        create: CreateCartHandler,
+        update: UpdateCartHandler,
+        merge: MergeCartsHandler,
+    }
+
+    fn fixture() -> Fixture {
+        let repo = Arc::new(InMemoryCartRepository::new());
+        Fixture {
+            create: CreateCartHandler::new(
+                repo.clone(),
+                Arc::new(UuidV4Generator),
+                Arc::new(SystemClock),
+            ),
+            update: UpdateCartHandler::new(repo.clone(), Arc::new(SystemClock)),
+            merge: MergeCartsHandler::new(repo.clone(), Arc::new(SystemClock)),
+            repo,
+        }
+    }
+
+    async fn cart_with(
+        fixture: &Fixture,
+        customer_id: Option<CustomerId>,
+        product: ProductId,
+    ) -> CartId {
+        let cart_id = fixture
+            .create
+            .handle(CreateCartCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+            })
+            .await
+            .unwrap();
+        fixture
+            .update
+            .handle(UpdateCartCommand {
+                tenant_id: TenantId::default(),
+                cart_id,
+                change: CartChange::AddLine {
+                    product_id: product,
+                    product_name: "Keyboard".to_string(),
+                    quantity: 1,
+                    unit_price: Decimal::new(4990, 2),
+                },
+            })
+            .await
+            .unwrap();
+        cart_id
+    }
+
+    #[tokio::test]
+    async fn test_customer_without_cart_takes_over_the_anonymous_one() {
+        let fixture = fixture();
+        let customer_id = CustomerId::new();
+        let anonymous_cart_id = cart_with(&fixture, None, ProductId::new()).await;
+
+        let cart_id = fixture
+            .merge
+            .handle(MergeCartsCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                anonymous_cart_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(cart_id, anonymous_cart_id);
+        let cart = fixture
+            .repo
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cart.id(), anonymous_cart_id);
+        assert_eq!(cart.lines().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_cart_is_merged_then_deleted() {
+        let fixture = fixture();
+        let customer_id = CustomerId::new();
+        let product = ProductId::new();
+        let customer_cart_id = cart_with(&fixture, Some(customer_id), product).await;
+        let anonymous_cart_id = cart_with(&fixture, None, product).await;
+
+        let cart_id = fixture
+            .merge
+            .handle(MergeCartsCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                anonymous_cart_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(cart_id, customer_cart_id);
+        let cart = fixture
+            .repo
+            .find_by_id(&TenantId::default(), cart_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cart.lines()[0].quantity(), 2);
+        assert!(fixture
+            .repo
+            .find_by_id(&TenantId::default(), anonymous_cart_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
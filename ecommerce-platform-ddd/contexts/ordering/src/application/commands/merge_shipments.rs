@@ -0,0 +1,80 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    value_objects::{OrderId, ShipmentId, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Merge Shipments (two pending parcels become one)
+#[derive(Debug)]
+pub struct MergeShipmentsCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    /// Parcel kept
+    pub shipment_id: ShipmentId,
+    /// Parcel whose lines move to `shipment_id`
+    pub merged_shipment_id: ShipmentId,
+    pub actor: Actor,
+}
+
+pub struct MergeShipmentsHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MergeShipmentsHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(&self, command: MergeShipmentsCommand) -> Result<(), ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let status_before = order.status();
+
+        order.merge_shipments(
+            command.shipment_id,
+            command.merged_shipment_id,
+            &*self.clock,
+        )?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "MergeShipments",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,49 @@
+pub mod anonymize_customer;
+pub mod cancel_order;
+pub mod checkout_cart;
+pub mod confirm_order;
+pub mod create_cart;
 pub mod create_order;
+pub mod create_shipment;
+pub mod deliver_shipped_orders;
+pub mod expire_carts;
+pub mod expire_pending_orders;
+pub mod force_order_status;
+pub mod import_orders;
+pub mod issue_credit_note;
+pub mod issue_invoice;
+pub mod merge_carts;
+pub mod merge_shipments;
+pub mod order_wishlist_items;
+pub mod replay_order_events;
+pub mod review_order;
+pub mod share_wishlist;
+pub mod ship_shipment;
+pub mod update_cart;
+pub mod update_wishlist;
 
+pub use anonymize_customer::{AnonymizeCustomerCommand, AnonymizeCustomerHandler};
+pub use cancel_order::{CancelOrderCommand, CancelOrderHandler};
+pub use checkout_cart::{CheckoutCartCommand, CheckoutCartHandler};
+pub use confirm_order::{ConfirmOrderCommand, ConfirmOrderHandler};
+pub use create_cart::{CreateCartCommand, CreateCartHandler};
 pub use create_order::{CreateOrderCommand, CreateOrderHandler, CreateOrderItemDto};
+pub use create_shipment::{CreateShipmentCommand, CreateShipmentHandler};
+pub use deliver_shipped_orders::{DeliverShippedOrdersCommand, DeliverShippedOrdersHandler};
+pub use expire_carts::{ExpireCartsCommand, ExpireCartsHandler};
+pub use expire_pending_orders::{ExpirePendingOrdersCommand, ExpirePendingOrdersHandler};
+pub use force_order_status::{ForceOrderStatusCommand, ForceOrderStatusHandler, StatusTransition};
+pub use import_orders::{ImportOrdersCommand, ImportOrdersHandler, ImportRow};
+pub use issue_credit_note::{IssueCreditNoteCommand, IssueCreditNoteHandler};
+pub use issue_invoice::{IssueInvoiceCommand, IssueInvoiceHandler};
+pub use merge_carts::{MergeCartsCommand, MergeCartsHandler};
+pub use merge_shipments::{MergeShipmentsCommand, MergeShipmentsHandler};
+pub use order_wishlist_items::{
+    OrderWishlistItemsCommand, OrderWishlistItemsHandler, WishlistSelection,
+};
+pub use replay_order_events::{ReplayOrderEventsCommand, ReplayOrderEventsHandler};
+pub use review_order::{ReviewDecision, ReviewOrderCommand, ReviewOrderHandler};
+pub use share_wishlist::{ShareWishlistCommand, ShareWishlistHandler};
+pub use ship_shipment::{ShipShipmentCommand, ShipShipmentHandler};
+pub use update_cart::{CartChange, UpdateCartCommand, UpdateCartHandler};
+pub use update_wishlist::{UpdateWishlistCommand, UpdateWishlistHandler, WishlistChange};
@@ -0,0 +1,357 @@
+use crate::application::audit::Actor;
+use crate::application::commands::{CreateOrderCommand, CreateOrderHandler, CreateOrderItemDto};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    aggregates::Wishlist,
+    catalog::PriceCatalog,
+    clock::Clock,
+    errors::DomainError,
+    repositories::WishlistRepository,
+    tenant::TenantDirectory,
+    value_objects::{CustomerId, OrderId, ProductId, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: order products saved in a customer's wishlist
+#[derive(Debug)]
+pub struct OrderWishlistItemsCommand {
+    /// Storefront the order is placed on
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+    pub items: Vec<WishlistSelection>,
+    /// Keep the ordered products in the wishlist instead of removing them
+    pub keep_in_wishlist: bool,
+    pub actor: Actor,
+}
+
+/// Saved product to order, and how many units
+#[derive(Debug, Clone, Copy)]
+pub struct WishlistSelection {
+    pub product_id: ProductId,
+    pub quantity: u32,
+}
+
+/// Turns the selected wishlist items into order lines priced by the catalog, then places
+/// the order through `CreateOrderHandler`, so that it follows the same business rules,
+/// audit trail and events as the API
+pub struct OrderWishlistItemsHandler {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    price_catalog: Arc<dyn PriceCatalog>,
+    create_order: Arc<CreateOrderHandler>,
+    clock: Arc<dyn Clock>,
+}
+
+impl OrderWishlistItemsHandler {
+    pub fn new(
+        wishlist_repository: Arc<dyn WishlistRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        price_catalog: Arc<dyn PriceCatalog>,
+        create_order: Arc<CreateOrderHandler>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            wishlist_repository,
+            tenants,
+            price_catalog,
+            create_order,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(
+        &self,
+        command: OrderWishlistItemsCommand,
+    ) -> Result<OrderId, ApplicationError> {
+        let mut wishlist = self
+            .wishlist_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Wishlist))?;
+
+        // 1. Selected items, at the current catalog prices
+        let items = self
+            .order_items(&command.tenant_id, &wishlist, &command.items)
+            .await?;
+
+        // 2. Place the order
+        let order_id = self
+            .create_order
+            .handle(CreateOrderCommand {
+                tenant_id: command.tenant_id,
+                customer_id: wishlist.customer_id(),
+                items,
+                billing_address: None,
+                shipping_address: None,
+                actor: command.actor,
+            })
+            .await?;
+
+        // 3. The ordered products leave the wishlist
+        if !command.keep_in_wishlist {
+            let ordered: Vec<ProductId> = command.items.iter().map(|s| s.product_id).collect();
+            wishlist.remove_items(&ordered, &*self.clock);
+            self.wishlist_repository.save(&wishlist).await?;
+        }
+
+        Ok(order_id)
+    }
+
+    /// Order lines of the `selection` of `wishlist`, in the currency of the tenant
+    ///
+    /// The wishlist keeps no price: each product is priced by the catalog, and refused
+    /// when it is no longer sold or not sold in the currency of the tenant.
+    pub async fn order_items(
+        &self,
+        tenant_id: &TenantId,
+        wishlist: &Wishlist,
+        selection: &[WishlistSelection],
+    ) -> Result<Vec<CreateOrderItemDto>, ApplicationError> {
+        let tenant = self.tenants.tenant(tenant_id)?;
+        let product_ids: Vec<ProductId> = selection.iter().map(|s| s.product_id).collect();
+        let saved = wishlist.select(&product_ids)?;
+
+        let mut items = Vec::with_capacity(saved.len());
+        for (item, selected) in saved.into_iter().zip(selection) {
+            let product_id = item.product_id();
+            let price = self
+                .price_catalog
+                .current_price(product_id)
+                .await?
+                .ok_or(DomainError::ProductUnavailable { product_id })?;
+            if price.currency() != tenant.currency {
+                return Err(DomainError::CurrencyNotAccepted {
+                    expected: tenant.currency,
+                    actual: price.currency(),
+                }
+                .into());
+            }
+            items.push(CreateOrderItemDto {
+                product_id,
+                product_name: item.product_name().to_string(),
+                quantity: selected.quantity,
+                unit_price: price.amount(),
+            });
+        }
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        clock::SystemClock,
+        entities::WishlistItem,
+        id_generator::UuidV4Generator,
+        repositories::OrderRepository,
+        value_objects::{Currency, Money, WishlistPriority},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::catalog::InMemoryCatalog;
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::repositories::{
+        InMemoryOrderRepository, InMemoryWishlistRepository,
+    };
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    struct Fixture {
+        wishlists: Arc<InMemoryWishlistRepository>,
+        orders: Arc<InMemoryOrderRepository>,
+        catalog: Arc<InMemoryCatalog>,
+        handler: OrderWishlistItemsHandler,
+    }
+
+    fn fixture() -> Fixture {
+        let wishlists = Arc::new(InMemoryWishlistRepository::new());
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        let catalog = Arc::new(InMemoryCatalog::new());
+        let tenants = Arc::new(TenantRegistry::new(TenantId::default()));
+        let create_order = Arc::new(CreateOrderHandler::new(
+            orders.clone(),
+            tenants.clone(),
+            Arc::new(InMemoryPriceLists::default()),
+            Arc::new(NoOpEventPublisher),
+            Arc::new(InMemoryAuditLog::new()),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        ));
+        let handler = OrderWishlistItemsHandler::new(
+            wishlists.clone(),
+            tenants,
+            catalog.clone(),
+            create_order,
+            Arc::new(SystemClock),
+        );
+        Fixture {
+            wishlists,
+            orders,
+            catalog,
+            handler,
+        }
+    }
+
+    fn eur(cents: i64) -> Money {
+        Money::eur(Decimal::new(cents, 2)).unwrap()
+    }
+
+    /// Wishlist of a new customer holding `products`
+    async fn saved_wishlist(fixture: &Fixture, products: &[ProductId]) -> Wishlist {
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &SystemClock);
+        for (index, product_id) in products.iter().enumerate() {
+            let item = WishlistItem::new(
+                *product_id,
+                format!("Product {index}"),
+                None,
+                WishlistPriority::Normal,
+                SystemClock.now(),
+            )
+            .unwrap();
+            wishlist.save_item(item, &SystemClock).unwrap();
+        }
+        fixture.wishlists.save(&wishlist).await.unwrap();
+        wishlist
+    }
+
+    fn command(wishlist: &Wishlist, items: &[(ProductId, u32)]) -> OrderWishlistItemsCommand {
+        OrderWishlistItemsCommand {
+            tenant_id: TenantId::default(),
+            customer_id: wishlist.customer_id(),
+            items: items
+                .iter()
+                .map(|&(product_id, quantity)| WishlistSelection {
+                    product_id,
+                    quantity,
+                })
+                .collect(),
+            keep_in_wishlist: false,
+            actor: Actor::user("alice"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_selected_items_become_an_order_at_catalog_prices() {
+        let fixture = fixture();
+        let (ordered, kept) = (ProductId::new(), ProductId::new());
+        fixture.catalog.set_product(ordered, eur(4990), 10);
+        let wishlist = saved_wishlist(&fixture, &[ordered, kept]).await;
+
+        let items = fixture
+            .handler
+            .order_items(
+                &TenantId::default(),
+                &wishlist,
+                &[WishlistSelection {
+                    product_id: ordered,
+                    quantity: 2,
+                }],
+            )
+            .await
+            .unwrap();
+        assert_eq!(items[0].product_name, "Product 0");
+        assert_eq!(items[0].unit_price, Decimal::new(4990, 2));
+
+        let order_id = fixture
+            .handler
+            .handle(command(&wishlist, &[(ordered, 2)]))
+            .await
+            .unwrap();
+
+        let order = fixture
+            .orders
+            .find_by_id(&TenantId::default(), order_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.customer_id(), wishlist.customer_id());
+        assert_eq!(order.total(), eur(9980));
+        let wishlist = fixture
+            .wishlists
+            .find_by_customer(wishlist.tenant_id(), wishlist.customer_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(wishlist.items().len(), 1);
+        assert_eq!(wishlist.items()[0].product_id(), kept);
+    }
+
+    #[tokio::test]
+    async fn test_ordered_items_can_stay_in_the_wishlist() {
+        let fixture = fixture();
+        let product_id = ProductId::new();
+        fixture.catalog.set_product(product_id, eur(1999), 10);
+        let wishlist = saved_wishlist(&fixture, &[product_id]).await;
+
+        let mut command = command(&wishlist, &[(product_id, 1)]);
+        command.keep_in_wishlist = true;
+        fixture.handler.handle(command).await.unwrap();
+
+        let wishlist = fixture
+            .wishlists
+            .find_by_customer(wishlist.tenant_id(), wishlist.customer_id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(wishlist.items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_selection_must_be_saved_and_sold_in_the_tenant_currency() {
+        let fixture = fixture();
+        let (unlisted, in_usd) = (ProductId::new(), ProductId::new());
+        fixture.catalog.set_product(
+            in_usd,
+            Money::new(Decimal::new(1999, 2), Currency::USD).unwrap(),
+            10,
+        );
+        let wishlist = saved_wishlist(&fixture, &[unlisted, in_usd]).await;
+
+        let result = fixture.handler.handle(command(&wishlist, &[])).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::EmptyOrder))
+        ));
+        let result = fixture
+            .handler
+            .handle(command(&wishlist, &[(ProductId::new(), 1)]))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::WishlistItemNotFound))
+        ));
+        let result = fixture
+            .handler
+            .handle(command(&wishlist, &[(unlisted, 1)]))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::ProductUnavailable { product_id }))
+                if product_id == unlisted
+        ));
+        let result = fixture
+            .handler
+            .handle(command(&wishlist, &[(in_usd, 1)]))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::CurrencyNotAccepted { .. }
+            ))
+        ));
+
+        let result = fixture
+            .handler
+            .handle(command(
+                &Wishlist::create(TenantId::default(), CustomerId::new(), &SystemClock),
+                &[],
+            ))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::NotFound(Resource::Wishlist))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::application::audit::AuditLog;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::value_objects::{OrderId, TenantId};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Replay Order Events (re-publish after a broker outage or for a new consumer)
+///
+/// Events are read back from the audit trail, in the order they were raised.
+/// They get new envelopes, so consumers must deduplicate on the event payload.
+#[derive(Debug)]
+pub struct ReplayOrderEventsCommand {
+    /// Tenant of the order, stamped on the new envelopes
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+}
+
+pub struct ReplayOrderEventsHandler {
+    audit_log: Arc<dyn AuditLog>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ReplayOrderEventsHandler {
+    pub fn new(audit_log: Arc<dyn AuditLog>, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        Self {
+            audit_log,
+            event_publisher,
+        }
+    }
+
+    /// Handle the command, returning the number of events published
+    pub async fn handle(
+        &self,
+        command: ReplayOrderEventsCommand,
+    ) -> Result<usize, ApplicationError> {
+        let timeline = self.audit_log.timeline(command.order_id).await?;
+        if timeline.is_empty() {
+            return Err(ApplicationError::NotFound(Resource::Order));
+        }
+
+        let mut published = 0;
+        for event in timeline.into_iter().flat_map(|entry| entry.events) {
+            self.event_publisher
+                .publish(&command.tenant_id, event)
+                .await?;
+            published += 1;
+        }
+        Ok(published)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::audit::{Actor, AuditEntry};
+    use crate::domain::{
+        events::OrderEvent,
+        value_objects::{CustomerId, Money, OrderStatus},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_events_are_republished_in_order() {
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+        let order_id = OrderId::new();
+        let created = OrderEvent::OrderCreated {
+            order_id,
+            customer_id: CustomerId::new(),
+            total: Money::eur(Decimal::new(1000, 2)).unwrap(),
+            timestamp: Utc::now(),
+        };
+        let confirmed = OrderEvent::OrderConfirmed {
+            order_id,
+            timestamp: Utc::now(),
+        };
+        for (command, before, after, event) in [
+            ("CreateOrder", None, OrderStatus::Pending, created),
+            (
+                "ConfirmOrder",
+                Some(OrderStatus::Pending),
+                OrderStatus::Confirmed,
+                confirmed,
+            ),
+        ] {
+            audit_log
+                .record(AuditEntry::new(
+                    order_id,
+                    Actor::default(),
+                    command,
+                    before,
+                    after,
+                    vec![event],
+                ))
+                .await
+                .unwrap();
+        }
+
+        let handler = ReplayOrderEventsHandler::new(audit_log, publisher.clone());
+        let count = handler
+            .handle(ReplayOrderEventsCommand {
+                tenant_id: TenantId::default(),
+                order_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+        let names: Vec<_> = publisher
+            .published()
+            .await
+            .iter()
+            .map(|envelope| envelope.event.event_name())
+            .collect();
+        assert_eq!(names, ["ORDER_CREATED", "ORDER_CONFIRMED"]);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_order_is_reported() {
+        let handler = ReplayOrderEventsHandler::new(
+            Arc::new(InMemoryAuditLog::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+        );
+        let result = handler
+            .handle(ReplayOrderEventsCommand {
+                tenant_id: TenantId::default(),
+                order_id: OrderId::new(),
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::NotFound(Resource::Order))
+        ));
+    }
+}
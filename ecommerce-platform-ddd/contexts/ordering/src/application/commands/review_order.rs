@@ -0,0 +1,205 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    tenant::TenantDirectory,
+    value_objects::{OrderId, OrderStatus, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: settle the review of an order held by the risk assessment
+/// (OnHold -> Confirmed or Cancelled)
+#[derive(Debug)]
+pub struct ReviewOrderCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub decision: ReviewDecision,
+    pub actor: Actor,
+}
+
+/// Outcome of the manual review
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewDecision {
+    /// The order is legitimate: it is confirmed
+    Approve,
+    /// The order is fraudulent: it is cancelled, `reason` going to the customer
+    Reject { reason: String },
+}
+
+pub struct ReviewOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ReviewOrderHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the new status of the order
+    pub async fn handle(
+        &self,
+        command: ReviewOrderCommand,
+    ) -> Result<OrderStatus, ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let status_before = order.status();
+
+        let reason = match command.decision {
+            ReviewDecision::Approve => {
+                order.approve_review(&*self.clock)?;
+                None
+            }
+            ReviewDecision::Reject { reason } => {
+                order.reject_review(reason.clone(), &*self.clock)?;
+                Some(reason)
+            }
+        };
+        tenant.ensure_transition(status_before, order.status())?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let mut entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "ReviewOrder",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        if let Some(reason) = reason {
+            entry = entry.with_reason(reason);
+        }
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(order.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        errors::DomainError,
+        id_generator::UuidV4Generator,
+        tenant::Tenant,
+        value_objects::{CustomerId, Money, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    async fn held_order(repo: &InMemoryOrderRepository) -> OrderId {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Gift card".to_string(),
+            1,
+            Money::eur(Decimal::new(5000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order
+            .hold_for_review(100, vec!["velocity".to_string()], &SystemClock)
+            .unwrap();
+        repo.save(&mut order).await.unwrap();
+        order.id()
+    }
+
+    #[tokio::test]
+    async fn test_review_releases_or_cancels_the_held_order() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let handler = ReviewOrderHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        );
+        let review = |order_id, decision| ReviewOrderCommand {
+            tenant_id: TenantId::default(),
+            order_id,
+            decision,
+            actor: Actor::user("risk-analyst"),
+        };
+
+        let approved = held_order(&repo).await;
+        let status = handler
+            .handle(review(approved, ReviewDecision::Approve))
+            .await
+            .unwrap();
+        assert_eq!(status, OrderStatus::Confirmed);
+        let result = handler
+            .handle(review(approved, ReviewDecision::Approve))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(
+                DomainError::InvalidStatusTransition { .. }
+            ))
+        ));
+
+        let rejected = held_order(&repo).await;
+        let decision = ReviewDecision::Reject {
+            reason: "Card reported stolen".to_string(),
+        };
+        let status = handler.handle(review(rejected, decision)).await.unwrap();
+        assert_eq!(status, OrderStatus::Cancelled);
+        let timeline = audit_log.timeline(rejected).await.unwrap();
+        assert_eq!(timeline[0].command, "ReviewOrder");
+        assert_eq!(timeline[0].status_before, Some(OrderStatus::OnHold));
+        assert_eq!(timeline[0].reason.as_deref(), Some("Card reported stolen"));
+
+        let published: Vec<_> = publisher
+            .published()
+            .await
+            .iter()
+            .map(|envelope| envelope.event.event_name())
+            .collect();
+        assert_eq!(
+            published,
+            ["ORDER_REVIEW_APPROVED", "ORDER_REVIEW_REJECTED"]
+        );
+    }
+}
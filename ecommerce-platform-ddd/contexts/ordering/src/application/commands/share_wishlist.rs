@@ -0,0 +1,45 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, ShareToken, TenantId},
+};
+use std::sync::Arc;
+
+/// Command: give read access to a customer's wishlist through a link
+#[derive(Debug)]
+pub struct ShareWishlistCommand {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+}
+
+pub struct ShareWishlistHandler {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ShareWishlistHandler {
+    pub fn new(wishlist_repository: Arc<dyn WishlistRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            wishlist_repository,
+            clock,
+        }
+    }
+
+    /// Handle the command, returning the token of the link (the current one when
+    /// the wishlist is already shared)
+    pub async fn handle(
+        &self,
+        command: ShareWishlistCommand,
+    ) -> Result<ShareToken, ApplicationError> {
+        let mut wishlist = self
+            .wishlist_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Wishlist))?;
+
+        let token = wishlist.share(&*self.clock);
+        self.wishlist_repository.save(&wishlist).await?;
+        Ok(token)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::application::audit::{self, Actor, AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    tenant::TenantDirectory,
+    value_objects::{OrderId, ShipmentId, TenantId},
+};
+use crate::infrastructure::messaging::EventPublisher;
+use std::sync::Arc;
+
+/// Command: Ship Shipment (the order becomes Shipped with its last parcel)
+#[derive(Debug)]
+pub struct ShipShipmentCommand {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+    pub shipment_id: ShipmentId,
+    pub tracking_number: String,
+    pub actor: Actor,
+}
+
+pub struct ShipShipmentHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    tenants: Arc<dyn TenantDirectory>,
+    event_publisher: Arc<dyn EventPublisher>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ShipShipmentHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        tenants: Arc<dyn TenantDirectory>,
+        event_publisher: Arc<dyn EventPublisher>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            tenants,
+            event_publisher,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(&self, command: ShipShipmentCommand) -> Result<(), ApplicationError> {
+        let mut order = self
+            .order_repository
+            .find_by_id(&command.tenant_id, command.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        let tenant = self.tenants.tenant(&command.tenant_id)?;
+        let status_before = order.status();
+
+        order.ship_shipment(command.shipment_id, command.tracking_number, &*self.clock)?;
+        tenant.ensure_transition(status_before, order.status())?;
+        self.order_repository.save(&mut order).await?;
+
+        let events = order.take_events();
+        let entry = AuditEntry::new(
+            order.id(),
+            command.actor,
+            "ShipShipment",
+            Some(status_before),
+            order.status(),
+            events.clone(),
+        );
+        audit::record(&*self.audit_log, entry).await;
+
+        for event in events {
+            self.event_publisher
+                .publish(order.tenant_id(), event)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::{CreateShipmentCommand, CreateShipmentHandler};
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::{OrderItem, ShipmentLine},
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, OrderStatus, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use crate::infrastructure::tenancy::TenantRegistry;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_last_parcel_ships_the_order() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Chair".to_string(),
+            2,
+            Money::eur(Decimal::new(4900, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order
+            .mark_as_paid(uuid::Uuid::new_v4(), &SystemClock)
+            .unwrap();
+        order.take_events();
+        repo.save(&mut order).await.unwrap();
+        let line = ShipmentLine {
+            order_item_id: order.items()[0].id(),
+            quantity: 1,
+        };
+
+        let create = CreateShipmentHandler::new(
+            repo.clone(),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        );
+        let ship = ShipShipmentHandler::new(
+            repo.clone(),
+            Arc::new(TenantRegistry::new(TenantId::default())),
+            publisher.clone(),
+            audit_log.clone(),
+            Arc::new(SystemClock),
+        );
+        for tracking_number in ["TRK-1", "TRK-2"] {
+            let shipment_id = create
+                .handle(CreateShipmentCommand {
+                    tenant_id: TenantId::default(),
+                    order_id: order.id(),
+                    lines: vec![line],
+                    actor: Actor::system("warehouse"),
+                })
+                .await
+                .unwrap();
+            ship.handle(ShipShipmentCommand {
+                tenant_id: TenantId::default(),
+                order_id: order.id(),
+                shipment_id,
+                tracking_number: tracking_number.to_string(),
+                actor: Actor::system("warehouse"),
+            })
+            .await
+            .unwrap();
+        }
+
+        let stored = repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status(), OrderStatus::Shipped);
+
+        let timeline = audit_log.timeline(order.id()).await.unwrap();
+        let last = timeline.last().unwrap();
+        assert_eq!(last.command, "ShipShipment");
+        assert_eq!(last.status_before, Some(OrderStatus::Paid));
+        assert_eq!(last.status_after, OrderStatus::Shipped);
+
+        let published: Vec<_> = publisher
+            .published()
+            .await
+            .iter()
+            .map(|envelope| envelope.event.event_name())
+            .collect();
+        assert_eq!(
+            published,
+            [
+                "SHIPMENT_CREATED",
+                "SHIPMENT_SHIPPED",
+                "SHIPMENT_CREATED",
+                "SHIPMENT_SHIPPED",
+                "ORDER_SHIPPED"
+            ]
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    clock::Clock,
+    entities::CartLine,
+    repositories::CartRepository,
+    value_objects::{CartId, Money, ProductId, TenantId},
+};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Command: change the lines of a cart
+#[derive(Debug)]
+pub struct UpdateCartCommand {
+    pub tenant_id: TenantId,
+    pub cart_id: CartId,
+    pub change: CartChange,
+}
+
+/// Edit applied to a cart by `UpdateCartHandler`
+#[derive(Debug)]
+pub enum CartChange {
+    /// Add a product (its quantity adds up when already in the cart)
+    AddLine {
+        product_id: ProductId,
+        product_name: String,
+        quantity: u32,
+        /// Price shown to the customer, in EUR
+        unit_price: Decimal,
+    },
+    SetQuantity {
+        product_id: ProductId,
+        quantity: u32,
+    },
+    RemoveLine {
+        product_id: ProductId,
+    },
+}
+
+pub struct UpdateCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl UpdateCartHandler {
+    pub fn new(cart_repository: Arc<dyn CartRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            cart_repository,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(&self, command: UpdateCartCommand) -> Result<(), ApplicationError> {
+        let mut cart = self
+            .cart_repository
+            .find_by_id(&command.tenant_id, command.cart_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Cart))?;
+
+        match command.change {
+            CartChange::AddLine {
+                product_id,
+                product_name,
+                quantity,
+                unit_price,
+            } => {
+                let line =
+                    CartLine::new(product_id, product_name, quantity, Money::eur(unit_price)?)?;
+                cart.add_line(line, &*self.clock)?;
+            }
+            CartChange::SetQuantity {
+                product_id,
+                quantity,
+            } => cart.update_quantity(product_id, quantity, &*self.clock)?,
+            CartChange::RemoveLine { product_id } => cart.remove_line(product_id, &*self.clock)?,
+        }
+
+        Ok(self.cart_repository.save(&cart).await?)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    aggregates::Wishlist,
+    clock::Clock,
+    entities::WishlistItem,
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, ProductId, TenantId, WishlistPriority},
+};
+use std::sync::Arc;
+
+/// Command: change the products saved in a customer's wishlist
+#[derive(Debug)]
+pub struct UpdateWishlistCommand {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+    pub change: WishlistChange,
+}
+
+/// Edit applied to a wishlist by `UpdateWishlistHandler`
+#[derive(Debug)]
+pub enum WishlistChange {
+    /// Save a product (its note and priority are replaced when already saved)
+    SaveItem {
+        product_id: ProductId,
+        product_name: String,
+        note: Option<String>,
+        priority: WishlistPriority,
+    },
+    RemoveItem {
+        product_id: ProductId,
+    },
+    /// Revoke the share link
+    StopSharing,
+}
+
+/// The wishlist is created with the first saved product
+pub struct UpdateWishlistHandler {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl UpdateWishlistHandler {
+    pub fn new(wishlist_repository: Arc<dyn WishlistRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            wishlist_repository,
+            clock,
+        }
+    }
+
+    /// Handle the command
+    pub async fn handle(&self, command: UpdateWishlistCommand) -> Result<(), ApplicationError> {
+        let wishlist = self
+            .wishlist_repository
+            .find_by_customer(&command.tenant_id, command.customer_id)
+            .await?;
+
+        let wishlist = match (wishlist, command.change) {
+            (
+                wishlist,
+                WishlistChange::SaveItem {
+                    product_id,
+                    product_name,
+                    note,
+                    priority,
+                },
+            ) => {
+                let mut wishlist = wishlist.unwrap_or_else(|| {
+                    Wishlist::create(command.tenant_id, command.customer_id, &*self.clock)
+                });
+                let item =
+                    WishlistItem::new(product_id, product_name, note, priority, self.clock.now())?;
+                wishlist.save_item(item, &*self.clock)?;
+                wishlist
+            }
+            (None, _) => return Err(ApplicationError::NotFound(Resource::Wishlist)),
+            (Some(mut wishlist), WishlistChange::RemoveItem { product_id }) => {
+                wishlist.remove_item(product_id, &*self.clock)?;
+                wishlist
+            }
+            (Some(mut wishlist), WishlistChange::StopSharing) => {
+                wishlist.stop_sharing(&*self.clock);
+                wishlist
+            }
+        };
+
+        Ok(self.wishlist_repository.save(&wishlist).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::SystemClock;
+    use crate::domain::errors::DomainError;
+    use crate::infrastructure::persistence::repositories::InMemoryWishlistRepository;
+
+    fn save(customer_id: CustomerId, product_id: ProductId) -> UpdateWishlistCommand {
+        UpdateWishlistCommand {
+            tenant_id: TenantId::default(),
+            customer_id,
+            change: WishlistChange::SaveItem {
+                product_id,
+                product_name: "Keyboard".to_string(),
+                note: Some("in blue".to_string()),
+                priority: WishlistPriority::High,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_first_saved_product_creates_the_wishlist() {
+        let repo = Arc::new(InMemoryWishlistRepository::new());
+        let handler = UpdateWishlistHandler::new(repo.clone(), Arc::new(SystemClock));
+        let (customer_id, product_id) = (CustomerId::new(), ProductId::new());
+
+        handler.handle(save(customer_id, product_id)).await.unwrap();
+        let wishlist = repo
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(wishlist.items()[0].note(), Some("in blue"));
+
+        let result = handler
+            .handle(UpdateWishlistCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                change: WishlistChange::RemoveItem {
+                    product_id: ProductId::new(),
+                },
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::WishlistItemNotFound))
+        ));
+
+        handler
+            .handle(UpdateWishlistCommand {
+                tenant_id: TenantId::default(),
+                customer_id,
+                change: WishlistChange::RemoveItem { product_id },
+            })
+            .await
+            .unwrap();
+        let wishlist = repo
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(wishlist.items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_wishlist() {
+        let handler = UpdateWishlistHandler::new(
+            Arc::new(InMemoryWishlistRepository::new()),
+            Arc::new(SystemClock),
+        );
+
+        let result = handler
+            .handle(UpdateWishlistCommand {
+                tenant_id: TenantId::default(),
+                customer_id: CustomerId::new(),
+                change: WishlistChange::StopSharing,
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::NotFound(Resource::Wishlist))
+        ));
+    }
+}
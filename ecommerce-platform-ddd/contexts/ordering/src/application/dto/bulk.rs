@@ -0,0 +1,21 @@
+use crate::domain::value_objects::OrderId;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Outcome of a bulk import: created orders and rejected rows
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ImportReport {
+    pub imported: usize,
+    pub failed: usize,
+    pub order_ids: Vec<OrderId>,
+    pub errors: Vec<ImportRowError>,
+}
+
+/// Row that could not be imported, with the parsing or business rule error
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ImportRowError {
+    /// 1-based line of the row (first line of the order in CSV)
+    pub line: usize,
+    #[schema(example = "Quantity must be greater than zero")]
+    pub error: String,
+}
@@ -0,0 +1,103 @@
+use crate::domain::aggregates::Cart;
+use crate::domain::entities::CartLine;
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{CartId, CustomerId, Money, ProductId};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for `POST /api/carts`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct CreateCartRequest {
+    /// Absent for an anonymous shopper
+    pub customer_id: Option<CustomerId>,
+}
+
+/// Response body for `POST /api/carts`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateCartResponse {
+    pub cart_id: CartId,
+}
+
+/// Request body for `POST /api/carts/{cart_id}/lines`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddCartLineRequest {
+    pub product_id: ProductId,
+    pub product_name: String,
+    #[schema(minimum = 1)]
+    pub quantity: u32,
+    /// Unit price in EUR shown to the customer, as a decimal string
+    #[schema(value_type = String, example = "10.00")]
+    pub unit_price: Decimal,
+}
+
+/// Request body for `PUT /api/carts/{cart_id}/lines/{product_id}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCartLineRequest {
+    #[schema(minimum = 1)]
+    pub quantity: u32,
+}
+
+/// Request body for `POST /api/customers/{customer_id}/cart/merge`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeCartsRequest {
+    /// Cart filled before logging in
+    pub anonymous_cart_id: CartId,
+}
+
+/// Response body for `POST /api/customers/{customer_id}/cart/merge`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeCartsResponse {
+    /// Cart of the customer, now holding the lines of the anonymous one
+    pub cart_id: CartId,
+}
+
+/// Read model returned by `GET /api/carts/{cart_id}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CartResponse {
+    pub id: CartId,
+    pub customer_id: Option<CustomerId>,
+    pub lines: Vec<CartLineResponse>,
+    pub total: Money,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CartLineResponse {
+    pub product_id: ProductId,
+    pub product_name: String,
+    pub quantity: u32,
+    pub unit_price: Money,
+    pub subtotal: Money,
+}
+
+impl TryFrom<&Cart> for CartResponse {
+    type Error = DomainError;
+
+    fn try_from(cart: &Cart) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: cart.id(),
+            customer_id: cart.customer_id(),
+            lines: cart.lines().iter().map(CartLineResponse::from).collect(),
+            total: cart.total()?,
+            created_at: cart.created_at(),
+            updated_at: cart.updated_at(),
+            expires_at: cart.expires_at(),
+        })
+    }
+}
+
+impl From<&CartLine> for CartLineResponse {
+    fn from(line: &CartLine) -> Self {
+        Self {
+            product_id: line.product_id(),
+            product_name: line.product_name().to_string(),
+            quantity: line.quantity(),
+            unit_price: line.unit_price(),
+            subtotal: line.subtotal(),
+        }
+    }
+}
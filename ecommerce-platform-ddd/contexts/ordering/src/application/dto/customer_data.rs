@@ -0,0 +1,23 @@
+use crate::application::audit::AuditEntry;
+use crate::application::dto::OrderResponse;
+use crate::domain::value_objects::{CustomerId, TenantId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Everything the ordering context holds about a customer (GDPR access request)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerDataExport {
+    pub customer_id: CustomerId,
+    pub tenant_id: TenantId,
+    pub exported_at: DateTime<Utc>,
+    /// Oldest order first
+    pub orders: Vec<CustomerOrderData>,
+}
+
+/// An order of the customer, its items and the events of its audit trail
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CustomerOrderData {
+    pub order: OrderResponse,
+    pub timeline: Vec<AuditEntry>,
+}
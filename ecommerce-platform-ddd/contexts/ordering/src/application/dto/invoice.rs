@@ -0,0 +1,94 @@
+use crate::domain::aggregates::Invoice;
+use crate::domain::entities::InvoiceLine;
+use crate::domain::value_objects::{
+    CustomerId, InvoiceId, InvoiceKind, Money, OrderId, OrderItemId, ProductId,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for `POST /api/invoices/{invoice_id}/credit-notes`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IssueCreditNoteRequest {
+    /// Units refunded per invoiced item; absent to refund everything not credited yet
+    pub lines: Option<Vec<CreditNoteLineRequest>>,
+    #[schema(example = "Returned by the customer")]
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreditNoteLineRequest {
+    pub order_item_id: OrderItemId,
+    #[schema(minimum = 1)]
+    pub quantity: u32,
+}
+
+/// Response body for `POST /api/invoices/{invoice_id}/credit-notes`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IssueCreditNoteResponse {
+    pub invoice_id: InvoiceId,
+    #[schema(example = "CN-2026-000001")]
+    pub number: String,
+}
+
+/// Read model of an invoice or credit note
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InvoiceResponse {
+    pub id: InvoiceId,
+    #[schema(example = "INV-2026-000042")]
+    pub number: String,
+    pub kind: InvoiceKind,
+    pub order_id: OrderId,
+    pub customer_id: CustomerId,
+    /// Credit notes only: the invoice refunded
+    pub credited_invoice_id: Option<InvoiceId>,
+    /// Credit notes only: why the refund was made
+    pub reason: Option<String>,
+    pub lines: Vec<InvoiceLineResponse>,
+    pub total: Money,
+    pub issued_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InvoiceLineResponse {
+    pub order_item_id: OrderItemId,
+    pub product_id: ProductId,
+    pub description: String,
+    pub quantity: u32,
+    pub unit_price: Money,
+    pub subtotal: Money,
+}
+
+impl From<&Invoice> for InvoiceResponse {
+    fn from(invoice: &Invoice) -> Self {
+        Self {
+            id: invoice.id(),
+            number: invoice.number().to_string(),
+            kind: invoice.kind(),
+            order_id: invoice.order_id(),
+            customer_id: invoice.customer_id(),
+            credited_invoice_id: invoice.credited_invoice_id(),
+            reason: invoice.reason().map(str::to_string),
+            lines: invoice
+                .lines()
+                .iter()
+                .map(InvoiceLineResponse::from)
+                .collect(),
+            total: invoice.total(),
+            issued_at: invoice.issued_at(),
+        }
+    }
+}
+
+impl From<&InvoiceLine> for InvoiceLineResponse {
+    fn from(line: &InvoiceLine) -> Self {
+        Self {
+            order_item_id: line.order_item_id(),
+            product_id: line.product_id(),
+            description: line.description().to_string(),
+            quantity: line.quantity(),
+            unit_price: line.unit_price(),
+            subtotal: line.subtotal(),
+        }
+    }
+}
@@ -1 +1,31 @@
 // Data Transfer Objects for API requests and responses
+pub mod bulk;
+pub mod cart;
+pub mod customer_data;
+pub mod invoice;
+pub mod order;
+pub mod shipment;
+pub mod wishlist;
+
+pub use bulk::{ImportReport, ImportRowError};
+pub use cart::{
+    AddCartLineRequest, CartLineResponse, CartResponse, CreateCartRequest, CreateCartResponse,
+    MergeCartsRequest, MergeCartsResponse, UpdateCartLineRequest,
+};
+pub use customer_data::{CustomerDataExport, CustomerOrderData};
+pub use invoice::{
+    CreditNoteLineRequest, InvoiceLineResponse, InvoiceResponse, IssueCreditNoteRequest,
+    IssueCreditNoteResponse,
+};
+pub use order::{
+    CancelOrderRequest, CreateOrderItemRequest, CreateOrderRequest,
+    CreateOrderResponse, OrderItemResponse, OrderResponse, ReviewOrderRequest,
+};
+pub use shipment::{
+    CreateShipmentRequest, CreateShipmentResponse, MergeShipmentsRequest, ShipShipmentRequest,
+    ShipmentResponse,
+};
+pub use wishlist::{
+    OrderWishlistItemsRequest, SaveWishlistItemRequest, ShareWishlistResponse,
+    SharedWishlistResponse, WishlistItemResponse, WishlistResponse, WishlistSelectionRequest,
+};
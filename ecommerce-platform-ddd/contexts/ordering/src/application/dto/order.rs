@@ -0,0 +1,157 @@
+use crate::application::audit::Actor;
+use crate::application::commands::{CreateOrderCommand, CreateOrderItemDto};
+use crate::application::dto::ShipmentResponse;
+use crate::domain::aggregates::Order;
+use crate::domain::entities::OrderItem;
+use crate::domain::value_objects::{
+    CustomerId, Money, OrderId, OrderItemId, OrderStatus, PostalAddress, ProductId, TenantId,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for `POST /api/orders`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateOrderRequest {
+    pub customer_id: CustomerId,
+    pub items: Vec<CreateOrderItemRequest>,
+    /// Where the order is billed; screened against the shipping address on confirmation
+    #[serde(default)]
+    pub billing_address: Option<PostalAddress>,
+    /// Where the order is delivered
+    #[serde(default)]
+    pub shipping_address: Option<PostalAddress>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateOrderItemRequest {
+    pub product_id: ProductId,
+    pub product_name: String,
+    #[schema(minimum = 1)]
+    pub quantity: u32,
+    /// Unit price in the currency of the tenant, as a decimal string; the list price
+    /// when a price rule of the customer applies
+    #[schema(value_type = String, example = "10.00")]
+    pub unit_price: Decimal,
+}
+
+/// Response body for `POST /api/orders`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateOrderResponse {
+    pub order_id: OrderId,
+}
+
+/// Request body for `POST /api/admin/orders/{order_id}/review`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "decision", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewOrderRequest {
+    /// Confirm the held order
+    Approve,
+    /// Cancel the held order
+    Reject {
+        #[schema(example = "Card reported stolen")]
+        reason: String,
+    },
+}
+
+/// Request body for `POST /api/orders/{order_id}/cancel`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CancelOrderRequest {
+    #[schema(example = "Customer request")]
+    pub reason: String,
+}
+
+/// Read model returned by `GET /api/orders/{order_id}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderResponse {
+    pub id: OrderId,
+    pub customer_id: CustomerId,
+    pub status: OrderStatus,
+    pub items: Vec<OrderItemResponse>,
+    pub total: Money,
+    /// Tax included in `total`, or due on top of it (rule of the tenant when ordered)
+    pub tax: Money,
+    /// What the customer pays
+    pub total_including_tax: Money,
+    /// Parcels of a multi-parcel fulfilment
+    pub shipments: Vec<ShipmentResponse>,
+    /// Addresses given when ordering; `null` when not given or erased
+    pub billing_address: Option<PostalAddress>,
+    pub shipping_address: Option<PostalAddress>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderItemResponse {
+    pub id: OrderItemId,
+    pub product_id: ProductId,
+    pub product_name: String,
+    pub quantity: u32,
+    /// Price the line is sold at
+    pub unit_price: Money,
+    /// Price before the price rules
+    pub list_price: Money,
+    /// Price rule giving `unit_price`; `null` when sold at the list price
+    #[schema(example = "wholesale-10")]
+    pub price_rule: Option<String>,
+    pub subtotal: Money,
+}
+
+impl From<&Order> for OrderResponse {
+    fn from(order: &Order) -> Self {
+        Self {
+            id: order.id(),
+            customer_id: order.customer_id(),
+            status: order.status(),
+            items: order.items().iter().map(OrderItemResponse::from).collect(),
+            total: order.total(),
+            tax: order.tax(),
+            total_including_tax: order.total_including_tax(),
+            shipments: order.shipments().iter().map(ShipmentResponse::from).collect(),
+            billing_address: order.billing_address().cloned(),
+            shipping_address: order.shipping_address().cloned(),
+            created_at: order.created_at(),
+            updated_at: order.updated_at(),
+        }
+    }
+}
+
+impl From<&OrderItem> for OrderItemResponse {
+    fn from(item: &OrderItem) -> Self {
+        Self {
+            id: item.id(),
+            product_id: item.product_id(),
+            product_name: item.product_name().to_string(),
+            quantity: item.quantity(),
+            unit_price: item.unit_price(),
+            list_price: item.list_price(),
+            price_rule: item.price_rule().map(str::to_string),
+            subtotal: item.subtotal(),
+        }
+    }
+}
+
+impl CreateOrderRequest {
+    /// Command issued by `actor` on the `tenant_id` storefront
+    pub fn into_command(self, tenant_id: TenantId, actor: Actor) -> CreateOrderCommand {
+        CreateOrderCommand {
+            tenant_id,
+            customer_id: self.customer_id,
+            items: self
+                .items
+                .into_iter()
+                .map(|item| CreateOrderItemDto {
+                    product_id: item.product_id,
+                    product_name: item.product_name,
+                    quantity: item.quantity,
+                    unit_price: item.unit_price,
+                })
+                .collect(),
+            billing_address: self.billing_address,
+            shipping_address: self.shipping_address,
+            actor,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::domain::entities::{Shipment, ShipmentLine};
+use crate::domain::value_objects::{ShipmentId, ShipmentStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for `POST /api/orders/{order_id}/shipments`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateShipmentRequest {
+    pub lines: Vec<ShipmentLine>,
+}
+
+/// Response body for `POST /api/orders/{order_id}/shipments`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateShipmentResponse {
+    pub shipment_id: ShipmentId,
+}
+
+/// Request body for `POST /api/orders/{order_id}/shipments/{shipment_id}/ship`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShipShipmentRequest {
+    #[schema(example = "1Z999AA10123456784")]
+    pub tracking_number: String,
+}
+
+/// Request body for `POST /api/orders/{order_id}/shipments/{shipment_id}/merge`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeShipmentsRequest {
+    /// Pending parcel whose lines move into the one of the path
+    pub merged_shipment_id: ShipmentId,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShipmentResponse {
+    pub id: ShipmentId,
+    pub status: ShipmentStatus,
+    pub lines: Vec<ShipmentLine>,
+    pub tracking_number: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub shipped_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Shipment> for ShipmentResponse {
+    fn from(shipment: &Shipment) -> Self {
+        Self {
+            id: shipment.id(),
+            status: shipment.status(),
+            lines: shipment.lines().to_vec(),
+            tracking_number: shipment.tracking_number().map(str::to_string),
+            created_at: shipment.created_at(),
+            shipped_at: shipment.shipped_at(),
+        }
+    }
+}
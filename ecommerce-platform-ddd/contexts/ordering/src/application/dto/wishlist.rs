@@ -0,0 +1,117 @@
+use crate::domain::aggregates::Wishlist;
+use crate::domain::entities::WishlistItem;
+use crate::domain::value_objects::{CustomerId, ProductId, ShareToken, WishlistPriority};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request body for `PUT /api/wishlists/{customer_id}/items/{product_id}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SaveWishlistItemRequest {
+    pub product_name: String,
+    /// Reminder of the customer, e.g. the size or color wanted
+    #[schema(example = "Size M, in blue")]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub priority: WishlistPriority,
+}
+
+/// Response body for `POST /api/wishlists/{customer_id}/share`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShareWishlistResponse {
+    /// Read by `GET /api/shared-wishlists/{share_token}`
+    pub share_token: ShareToken,
+}
+
+/// Request body for `POST /api/wishlists/{customer_id}/orders`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OrderWishlistItemsRequest {
+    /// Saved products to order, priced by the catalog
+    pub items: Vec<WishlistSelectionRequest>,
+    /// Keep the ordered products in the wishlist (they are removed by default)
+    #[serde(default)]
+    pub keep_in_wishlist: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WishlistSelectionRequest {
+    pub product_id: ProductId,
+    #[serde(default = "one")]
+    #[schema(minimum = 1, default = 1)]
+    pub quantity: u32,
+}
+
+fn one() -> u32 {
+    1
+}
+
+/// Read model returned by `GET /api/wishlists/{customer_id}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WishlistResponse {
+    pub customer_id: CustomerId,
+    /// Highest priority first, then oldest first
+    pub items: Vec<WishlistItemResponse>,
+    /// `null` while the wishlist is private
+    pub share_token: Option<ShareToken>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Read model returned by `GET /api/shared-wishlists/{share_token}`, without the
+/// identity of the customer
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SharedWishlistResponse {
+    /// Highest priority first, then oldest first
+    pub items: Vec<WishlistItemResponse>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WishlistItemResponse {
+    pub product_id: ProductId,
+    pub product_name: String,
+    pub note: Option<String>,
+    pub priority: WishlistPriority,
+    pub added_at: DateTime<Utc>,
+}
+
+impl From<&Wishlist> for WishlistResponse {
+    fn from(wishlist: &Wishlist) -> Self {
+        Self {
+            customer_id: wishlist.customer_id(),
+            items: items(wishlist),
+            share_token: wishlist.share_token().cloned(),
+            created_at: wishlist.created_at(),
+            updated_at: wishlist.updated_at(),
+        }
+    }
+}
+
+impl From<&Wishlist> for SharedWishlistResponse {
+    fn from(wishlist: &Wishlist) -> Self {
+        Self {
+            items: items(wishlist),
+            updated_at: wishlist.updated_at(),
+        }
+    }
+}
+
+fn items(wishlist: &Wishlist) -> Vec<WishlistItemResponse> {
+    wishlist
+        .items_by_priority()
+        .into_iter()
+        .map(WishlistItemResponse::from)
+        .collect()
+}
+
+impl From<&WishlistItem> for WishlistItemResponse {
+    fn from(item: &WishlistItem) -> Self {
+        Self {
+            product_id: item.product_id(),
+            product_name: item.product_name().to_string(),
+            note: item.note().map(str::to_string),
+            priority: item.priority(),
+            added_at: item.added_at(),
+        }
+    }
+}
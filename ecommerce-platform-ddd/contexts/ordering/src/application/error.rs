@@ -0,0 +1,117 @@
+use crate::domain::errors::{DomainError, InfrastructureError};
+use crate::domain::value_objects::MoneyError;
+use thiserror::Error;
+
+/// Resource looked up by a command or a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Order,
+    Cart,
+    Invoice,
+    Webhook,
+    Wishlist,
+}
+
+impl std::fmt::Display for Resource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Resource::Order => write!(f, "Order"),
+            Resource::Cart => write!(f, "Cart"),
+            Resource::Invoice => write!(f, "Invoice"),
+            Resource::Webhook => write!(f, "Webhook"),
+            Resource::Wishlist => write!(f, "Wishlist"),
+        }
+    }
+}
+
+/// Error of a command or query handler
+///
+/// Business rules and adapter failures are wrapped as they are, so that their
+/// code and source chain reach the transport layer.
+#[derive(Debug, Error)]
+pub enum ApplicationError {
+    /// A business rule refused the request
+    #[error(transparent)]
+    Domain(#[from] DomainError),
+
+    /// Unknown resource, or one of another tenant
+    #[error("{0} not found")]
+    NotFound(Resource),
+
+    /// The request clashes with a previous one
+    #[error("{0}")]
+    Conflict(String),
+
+    /// The caller may not act on the resource
+    #[error("{0}")]
+    Unauthorized(String),
+
+    #[error(transparent)]
+    Infrastructure(#[from] InfrastructureError),
+}
+
+impl From<MoneyError> for ApplicationError {
+    fn from(err: MoneyError) -> Self {
+        ApplicationError::Domain(err.into())
+    }
+}
+
+impl ApplicationError {
+    /// Stable machine-readable code, e.g. `ORDER_NOT_FOUND` or `EMPTY_ORDER`
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApplicationError::Domain(err) => err.code(),
+            ApplicationError::NotFound(resource) => match resource {
+                Resource::Order => "ORDER_NOT_FOUND",
+                Resource::Cart => "CART_NOT_FOUND",
+                Resource::Invoice => "INVOICE_NOT_FOUND",
+                Resource::Webhook => "WEBHOOK_NOT_FOUND",
+                Resource::Wishlist => "WISHLIST_NOT_FOUND",
+            },
+            ApplicationError::Conflict(_) => "CONFLICT",
+            ApplicationError::Unauthorized(_) => "UNAUTHORIZED",
+            ApplicationError::Infrastructure(err) => err.code(),
+        }
+    }
+
+    /// The message followed by its causes, for the logs
+    pub fn report(&self) -> String {
+        let mut report = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(err) = source {
+            report.push_str(": ");
+            report.push_str(&err.to_string());
+            source = err.source();
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_and_source_chain() {
+        assert_eq!(
+            ApplicationError::NotFound(Resource::Order).to_string(),
+            "Order not found"
+        );
+        assert_eq!(
+            ApplicationError::NotFound(Resource::Cart).code(),
+            "CART_NOT_FOUND"
+        );
+        assert_eq!(
+            ApplicationError::from(DomainError::EmptyOrder).code(),
+            "EMPTY_ORDER"
+        );
+
+        let io = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused");
+        let err = ApplicationError::from(
+            InfrastructureError::storage("cannot save order").with_source(io),
+        );
+        assert_eq!(err.code(), "STORAGE_FAILURE");
+        assert_eq!(err.to_string(), "cannot save order");
+        assert_eq!(err.report(), "cannot save order: connection refused");
+    }
+}
@@ -1,5 +1,7 @@
+pub mod audit;
 pub mod commands;
 pub mod dto;
+pub mod error;
 pub mod queries;
 
 pub use commands::*;
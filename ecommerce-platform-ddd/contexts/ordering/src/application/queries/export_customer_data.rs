@@ -0,0 +1,147 @@
+use crate::application::audit::AuditLog;
+use crate::application::dto::{CustomerDataExport, CustomerOrderData, OrderResponse};
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    clock::Clock,
+    repositories::OrderRepository,
+    value_objects::{CustomerId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: everything linked to a customer, for a GDPR access request
+#[derive(Debug)]
+pub struct ExportCustomerDataQuery {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+}
+
+pub struct ExportCustomerDataHandler {
+    order_repository: Arc<dyn OrderRepository>,
+    audit_log: Arc<dyn AuditLog>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ExportCustomerDataHandler {
+    pub fn new(
+        order_repository: Arc<dyn OrderRepository>,
+        audit_log: Arc<dyn AuditLog>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            order_repository,
+            audit_log,
+            clock,
+        }
+    }
+
+    /// Handle the query (an unknown customer exports no order)
+    pub async fn handle(
+        &self,
+        query: ExportCustomerDataQuery,
+    ) -> Result<CustomerDataExport, ApplicationError> {
+        let mut orders = self
+            .order_repository
+            .find_by_customer(&query.tenant_id, query.customer_id)
+            .await?;
+        orders.sort_by_key(|order| (order.created_at(), order.id().value()));
+
+        let mut exported = Vec::with_capacity(orders.len());
+        for order in &orders {
+            exported.push(CustomerOrderData {
+                order: OrderResponse::from(order),
+                timeline: self.audit_log.timeline(order.id()).await?,
+            });
+        }
+
+        Ok(CustomerDataExport {
+            customer_id: query.customer_id,
+            tenant_id: query.tenant_id,
+            exported_at: self.clock.now(),
+            orders: exported,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::audit::{Actor, AuditEntry};
+    use crate::domain::{
+        aggregates::Order,
+        clock::FixedClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        tenant::Tenant,
+        value_objects::{Money, ProductId},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_orders_items_and_events_of_the_customer_are_exported() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let clock = Arc::new(FixedClock::new(Utc::now()));
+        let customer_id = CustomerId::new();
+
+        let mut order_ids = Vec::new();
+        for customer_id in [customer_id, customer_id, CustomerId::new()] {
+            let item = OrderItem::new(
+                ProductId::new(),
+                "Keyboard".to_string(),
+                2,
+                Money::eur(Decimal::new(4990, 2)).unwrap(),
+                &UuidV4Generator,
+            )
+            .unwrap();
+            let mut order = Order::create(
+                &Tenant::default(),
+                customer_id,
+                vec![item],
+                &UuidV4Generator,
+                &*clock,
+            )
+            .unwrap();
+            repo.save(&mut order).await.unwrap();
+            audit_log
+                .record(AuditEntry::new(
+                    order.id(),
+                    Actor::Anonymous,
+                    "CreateOrder",
+                    None,
+                    order.status(),
+                    order.take_events(),
+                ))
+                .await
+                .unwrap();
+            order_ids.push(order.id());
+            clock.advance(Duration::minutes(1));
+        }
+
+        let handler = ExportCustomerDataHandler::new(repo, audit_log, clock.clone());
+        let export = handler
+            .handle(ExportCustomerDataQuery {
+                tenant_id: TenantId::default(),
+                customer_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(export.customer_id, customer_id);
+        assert_eq!(export.exported_at, clock.now());
+        let exported: Vec<_> = export.orders.iter().map(|data| data.order.id).collect();
+        assert_eq!(exported, order_ids[..2]);
+        assert_eq!(export.orders[0].order.items[0].quantity, 2);
+        assert_eq!(
+            export.orders[0].timeline[0].events[0].event_name(),
+            "ORDER_CREATED"
+        );
+        let json = serde_json::to_value(&export).unwrap();
+        assert_eq!(
+            json["orders"][1]["order"]["customer_id"],
+            customer_id.to_string()
+        );
+    }
+}
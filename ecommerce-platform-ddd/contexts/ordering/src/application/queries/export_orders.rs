@@ -0,0 +1,125 @@
+use crate::application::dto::OrderResponse;
+use crate::application::error::ApplicationError;
+use crate::domain::repositories::{OrderCriteria, OrderCursor, OrderRepository};
+use std::sync::Arc;
+
+/// Query: Export the orders matching `criteria`, `batch_size` at a time
+#[derive(Debug, Clone)]
+pub struct ExportOrdersQuery {
+    pub criteria: OrderCriteria,
+    pub batch_size: u64,
+}
+
+/// Query Handler (read side, no events)
+pub struct ExportOrdersHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl ExportOrdersHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// Handle the query: batches are fetched lazily by the returned export
+    pub fn handle(&self, query: ExportOrdersQuery) -> OrderExport {
+        OrderExport {
+            order_repository: self.order_repository.clone(),
+            query,
+            after: None,
+            done: false,
+        }
+    }
+}
+
+/// Export in progress: only one batch is held in memory at a time
+pub struct OrderExport {
+    order_repository: Arc<dyn OrderRepository>,
+    query: ExportOrdersQuery,
+    after: Option<OrderCursor>,
+    done: bool,
+}
+
+impl OrderExport {
+    /// Next batch, oldest orders first; `None` once every order was returned
+    pub async fn next_batch(&mut self) -> Result<Option<Vec<OrderResponse>>, ApplicationError> {
+        if self.done {
+            return Ok(None);
+        }
+        let batch_size = self.query.batch_size.max(1);
+        let orders = self
+            .order_repository
+            .find_page(&self.query.criteria, self.after, batch_size)
+            .await?;
+
+        self.done = (orders.len() as u64) < batch_size;
+        let Some(last) = orders.last() else {
+            return Ok(None);
+        };
+        self.after = Some((last.created_at(), last.id()));
+        Ok(Some(orders.iter().map(OrderResponse::from).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::FixedClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, OrderStatus, ProductId},
+    };
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_export_returns_matching_orders_in_batches() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let clock = FixedClock::new(Utc::now());
+        for i in 0..5 {
+            let item = OrderItem::new(
+                ProductId::new(),
+                "Test Product".to_string(),
+                1,
+                Money::eur(Decimal::new(1000, 2)).unwrap(),
+                &UuidV4Generator,
+            )
+            .unwrap();
+            let mut order = Order::create(
+                &Tenant::default(),
+                CustomerId::new(),
+                vec![item],
+                &UuidV4Generator,
+                &clock,
+            )
+            .unwrap();
+            if i % 2 == 1 {
+                order.confirm(&clock).unwrap();
+            }
+            repo.save(&mut order).await.unwrap();
+            clock.advance(Duration::minutes(1));
+        }
+
+        let mut export = ExportOrdersHandler::new(repo).handle(ExportOrdersQuery {
+            criteria: OrderCriteria {
+                status: Some(OrderStatus::Pending),
+                ..Default::default()
+            },
+            batch_size: 2,
+        });
+
+        let mut batches = Vec::new();
+        while let Some(batch) = export.next_batch().await.unwrap() {
+            batches.push(batch);
+        }
+        assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), [2, 1]);
+        assert!(batches[0][0].created_at < batches[0][1].created_at);
+        assert!(batches
+            .iter()
+            .flatten()
+            .all(|order| order.status == OrderStatus::Pending));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::application::dto::CartResponse;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::CartRepository,
+    value_objects::{CartId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: Get Cart by id
+#[derive(Debug)]
+pub struct GetCartQuery {
+    pub tenant_id: TenantId,
+    pub cart_id: CartId,
+}
+
+/// Query Handler (read side)
+pub struct GetCartHandler {
+    cart_repository: Arc<dyn CartRepository>,
+}
+
+impl GetCartHandler {
+    pub fn new(cart_repository: Arc<dyn CartRepository>) -> Self {
+        Self { cart_repository }
+    }
+
+    /// Handle the query
+    pub async fn handle(&self, query: GetCartQuery) -> Result<CartResponse, ApplicationError> {
+        let cart = self
+            .cart_repository
+            .find_by_id(&query.tenant_id, query.cart_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Cart))?;
+        Ok(CartResponse::try_from(&cart)?)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::application::dto::InvoiceResponse;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::InvoiceRepository,
+    value_objects::{InvoiceId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: Get Invoice (or credit note) by id
+#[derive(Debug)]
+pub struct GetInvoiceQuery {
+    pub tenant_id: TenantId,
+    pub invoice_id: InvoiceId,
+}
+
+/// Query Handler (read side)
+pub struct GetInvoiceHandler {
+    invoice_repository: Arc<dyn InvoiceRepository>,
+}
+
+impl GetInvoiceHandler {
+    pub fn new(invoice_repository: Arc<dyn InvoiceRepository>) -> Self {
+        Self { invoice_repository }
+    }
+
+    /// Handle the query
+    pub async fn handle(
+        &self,
+        query: GetInvoiceQuery,
+    ) -> Result<InvoiceResponse, ApplicationError> {
+        let invoice = self
+            .invoice_repository
+            .find_by_id(&query.tenant_id, query.invoice_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Invoice))?;
+        Ok(InvoiceResponse::from(&invoice))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::application::dto::OrderResponse;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::OrderRepository,
+    value_objects::{OrderId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: Get Order by id (CQRS Pattern)
+#[derive(Debug)]
+pub struct GetOrderQuery {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+}
+
+/// Query Handler (read side, no events)
+pub struct GetOrderHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl GetOrderHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// Handle the query
+    pub async fn handle(&self, query: GetOrderQuery) -> Result<OrderResponse, ApplicationError> {
+        let order = self
+            .order_repository
+            .find_by_id(&query.tenant_id, query.order_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Order))?;
+        Ok(OrderResponse::from(&order))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, OrderStatus, ProductId},
+    };
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_get_order_query() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Test Product".to_string(),
+            2,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order =
+            Order::create(&Tenant::default(), CustomerId::new(), vec![item], &UuidV4Generator, &SystemClock).unwrap();
+        repo.save(&mut order).await.unwrap();
+
+        let handler = GetOrderHandler::new(repo);
+        let response = handler
+            .handle(GetOrderQuery {
+                tenant_id: TenantId::default(),
+                order_id: order.id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, order.id());
+        assert_eq!(response.status, OrderStatus::Pending);
+        assert_eq!(response.total.amount(), Decimal::new(2000, 2));
+        assert_eq!(response.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_unknown_order() {
+        let handler = GetOrderHandler::new(Arc::new(InMemoryOrderRepository::new()));
+        let result = handler
+            .handle(GetOrderQuery {
+                tenant_id: TenantId::default(),
+                order_id: OrderId::new(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(Resource::Order))));
+    }
+}
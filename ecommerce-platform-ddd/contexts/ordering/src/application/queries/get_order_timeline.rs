@@ -0,0 +1,45 @@
+use crate::application::audit::{AuditEntry, AuditLog};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::OrderRepository,
+    value_objects::{OrderId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: audit trail of an order, oldest entry first
+#[derive(Debug)]
+pub struct GetOrderTimelineQuery {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+}
+
+pub struct GetOrderTimelineHandler {
+    audit_log: Arc<dyn AuditLog>,
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl GetOrderTimelineHandler {
+    pub fn new(audit_log: Arc<dyn AuditLog>, order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self {
+            audit_log,
+            order_repository,
+        }
+    }
+
+    /// Handle the query (`NotFound` for an unknown order, or one of another tenant)
+    pub async fn handle(
+        &self,
+        query: GetOrderTimelineQuery,
+    ) -> Result<Vec<AuditEntry>, ApplicationError> {
+        // The audit trail is shared by the tenants: the order tells who may read it
+        if self
+            .order_repository
+            .find_by_id(&query.tenant_id, query.order_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::NotFound(Resource::Order));
+        }
+        Ok(self.audit_log.timeline(query.order_id).await?)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::application::dto::SharedWishlistResponse;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::WishlistRepository,
+    value_objects::{ShareToken, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: Get a wishlist through its share link
+#[derive(Debug)]
+pub struct GetSharedWishlistQuery {
+    /// Storefront the link was shared from
+    pub tenant_id: TenantId,
+    pub share_token: ShareToken,
+}
+
+/// Query Handler (read side)
+pub struct GetSharedWishlistHandler {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+}
+
+impl GetSharedWishlistHandler {
+    pub fn new(wishlist_repository: Arc<dyn WishlistRepository>) -> Self {
+        Self {
+            wishlist_repository,
+        }
+    }
+
+    /// Handle the query; a revoked token is not found
+    pub async fn handle(
+        &self,
+        query: GetSharedWishlistQuery,
+    ) -> Result<SharedWishlistResponse, ApplicationError> {
+        let wishlist = self
+            .wishlist_repository
+            .find_by_share_token(&query.tenant_id, &query.share_token)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Wishlist))?;
+        Ok(SharedWishlistResponse::from(&wishlist))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::application::dto::WishlistResponse;
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::{
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: Get the wishlist of a customer
+#[derive(Debug)]
+pub struct GetWishlistQuery {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+}
+
+/// Query Handler (read side)
+pub struct GetWishlistHandler {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+}
+
+impl GetWishlistHandler {
+    pub fn new(wishlist_repository: Arc<dyn WishlistRepository>) -> Self {
+        Self {
+            wishlist_repository,
+        }
+    }
+
+    /// Handle the query
+    pub async fn handle(
+        &self,
+        query: GetWishlistQuery,
+    ) -> Result<WishlistResponse, ApplicationError> {
+        let wishlist = self
+            .wishlist_repository
+            .find_by_customer(&query.tenant_id, query.customer_id)
+            .await?
+            .ok_or(ApplicationError::NotFound(Resource::Wishlist))?;
+        Ok(WishlistResponse::from(&wishlist))
+    }
+}
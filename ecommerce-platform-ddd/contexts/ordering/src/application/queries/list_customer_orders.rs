@@ -0,0 +1,37 @@
+use crate::application::dto::OrderResponse;
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    repositories::OrderRepository,
+    value_objects::{CustomerId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: List the orders of a customer
+#[derive(Debug)]
+pub struct ListCustomerOrdersQuery {
+    pub tenant_id: TenantId,
+    pub customer_id: CustomerId,
+}
+
+pub struct ListCustomerOrdersHandler {
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl ListCustomerOrdersHandler {
+    pub fn new(order_repository: Arc<dyn OrderRepository>) -> Self {
+        Self { order_repository }
+    }
+
+    /// Handle the query (most recent orders first)
+    pub async fn handle(
+        &self,
+        query: ListCustomerOrdersQuery,
+    ) -> Result<Vec<OrderResponse>, ApplicationError> {
+        let mut orders = self
+            .order_repository
+            .find_by_customer(&query.tenant_id, query.customer_id)
+            .await?;
+        orders.sort_by_key(|order| std::cmp::Reverse(order.created_at()));
+        Ok(orders.iter().map(OrderResponse::from).collect())
+    }
+}
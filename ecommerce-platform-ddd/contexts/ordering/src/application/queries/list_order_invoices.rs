@@ -0,0 +1,37 @@
+use crate::application::dto::InvoiceResponse;
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    repositories::InvoiceRepository,
+    value_objects::{OrderId, TenantId},
+};
+use std::sync::Arc;
+
+/// Query: invoice and credit notes of an order
+#[derive(Debug)]
+pub struct ListOrderInvoicesQuery {
+    pub tenant_id: TenantId,
+    pub order_id: OrderId,
+}
+
+/// Query Handler (read side)
+pub struct ListOrderInvoicesHandler {
+    invoice_repository: Arc<dyn InvoiceRepository>,
+}
+
+impl ListOrderInvoicesHandler {
+    pub fn new(invoice_repository: Arc<dyn InvoiceRepository>) -> Self {
+        Self { invoice_repository }
+    }
+
+    /// Handle the query, oldest document first
+    pub async fn handle(
+        &self,
+        query: ListOrderInvoicesQuery,
+    ) -> Result<Vec<InvoiceResponse>, ApplicationError> {
+        let invoices = self
+            .invoice_repository
+            .find_by_order(&query.tenant_id, query.order_id)
+            .await?;
+        Ok(invoices.iter().map(InvoiceResponse::from).collect())
+    }
+}
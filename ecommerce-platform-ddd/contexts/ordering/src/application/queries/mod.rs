@@ -1,2 +1,22 @@
-// Query handlers will go here (CQRS Read Side)
-// Example: GetOrderQuery, ListOrdersQuery
+// Query handlers (CQRS Read Side)
+pub mod export_customer_data;
+pub mod export_orders;
+pub mod get_cart;
+pub mod get_invoice;
+pub mod get_order;
+pub mod get_order_timeline;
+pub mod get_shared_wishlist;
+pub mod get_wishlist;
+pub mod list_customer_orders;
+pub mod list_order_invoices;
+
+pub use export_customer_data::{ExportCustomerDataHandler, ExportCustomerDataQuery};
+pub use export_orders::{ExportOrdersHandler, ExportOrdersQuery, OrderExport};
+pub use get_cart::{GetCartHandler, GetCartQuery};
+pub use get_invoice::{GetInvoiceHandler, GetInvoiceQuery};
+pub use get_order::{GetOrderHandler, GetOrderQuery};
+pub use get_order_timeline::{GetOrderTimelineHandler, GetOrderTimelineQuery};
+pub use get_shared_wishlist::{GetSharedWishlistHandler, GetSharedWishlistQuery};
+pub use get_wishlist::{GetWishlistHandler, GetWishlistQuery};
+pub use list_customer_orders::{ListCustomerOrdersHandler, ListCustomerOrdersQuery};
+pub use list_order_invoices::{ListOrderInvoicesHandler, ListOrderInvoicesQuery};
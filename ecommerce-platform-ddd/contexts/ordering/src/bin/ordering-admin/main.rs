@@ -0,0 +1,569 @@
+//! Operator tool for the ordering context, wired like the server
+//! (same configuration, repository, audit log and event publisher).
+
+mod output;
+
+use anyhow::{bail, Context};
+use chrono::{DateTime, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use ordering_context::application::audit::Actor;
+use ordering_context::application::commands::{
+    AnonymizeCustomerCommand, AnonymizeCustomerHandler, ForceOrderStatusCommand, ForceOrderStatusHandler, ImportOrdersCommand,
+    ReplayOrderEventsCommand, ReplayOrderEventsHandler, ReviewDecision, ReviewOrderCommand,
+    StatusTransition,
+};
+use ordering_context::application::queries::{
+    ExportCustomerDataHandler, ExportCustomerDataQuery, ExportOrdersQuery, GetOrderQuery,
+    GetOrderTimelineQuery,
+};
+use ordering_context::domain::{
+    repositories::{OrderCriteria, OrderCursor},
+    value_objects::{CustomerId, OrderId, OrderStatus, TenantId},
+};
+use ordering_context::infrastructure::{
+    bootstrap::{self, Adapters, Handlers},
+    bulk::BulkFormat,
+    config::{RepositoryBackend, Settings},
+    persistence::Migrator,
+};
+use output::{OutputFormat, Table};
+use sea_orm_migration::MigratorTrait;
+use serde_json::json;
+use std::io::Write;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "ordering-admin",
+    version,
+    about = "Operator tool for the ordering context"
+)]
+struct Cli {
+    /// Output format
+    #[arg(long, short, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    /// Operator recorded in the audit trail (`user:<id>` or `system:<name>`)
+    #[arg(long, global = true, default_value = "system:ordering-admin")]
+    actor: Actor,
+
+    /// Storefront of the orders (default: `tenancy.default_tenant`)
+    #[arg(long, global = true)]
+    tenant: Option<TenantId>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Show an order, its items and its audit trail
+    Inspect { order_id: OrderId },
+
+    /// Apply a status transition by hand (the status machine still applies)
+    ForceStatus {
+        order_id: OrderId,
+        /// Target status (CONFIRMED, PAID, SHIPPED, DELIVERED or CANCELLED)
+        #[arg(value_parser = parse_status)]
+        status: OrderStatus,
+        /// Why the transition is forced (kept in the audit trail)
+        #[arg(long)]
+        reason: String,
+        /// Payment reference, required for PAID
+        #[arg(long)]
+        payment_id: Option<Uuid>,
+        /// Carrier tracking number, required for SHIPPED
+        #[arg(long)]
+        tracking_number: Option<String>,
+        /// Confirm without the risk assessment (recorded in the audit trail); without it,
+        /// an order the assessment holds goes ON_HOLD
+        #[arg(long)]
+        skip_risk: bool,
+    },
+
+    /// Settle the review of an order held ON_HOLD by the risk assessment
+    Review {
+        order_id: OrderId,
+        #[arg(value_enum)]
+        decision: Verdict,
+        /// Why the order is rejected (required for reject, sent to the customer)
+        #[arg(long)]
+        reason: Option<String>,
+    },
+
+    /// Re-publish the events of orders, read back from the audit trail
+    ReplayEvents {
+        #[arg(required = true)]
+        order_ids: Vec<OrderId>,
+    },
+
+    /// Re-save every order, of every tenant, to recompute the denormalized columns of `orders` (totals, currency)
+    RebuildProjections {
+        #[arg(long, default_value_t = 500)]
+        batch_size: u64,
+    },
+
+    /// Apply, roll back or list database migrations
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+
+    /// Create orders from a CSV or JSON Lines file
+    Import {
+        file: PathBuf,
+        #[arg(long, default_value = "ndjson")]
+        format: BulkFormat,
+    },
+
+    /// Write orders to a CSV or JSON Lines file (stdout by default)
+    Export {
+        #[arg(long, default_value = "ndjson")]
+        format: BulkFormat,
+        #[arg(long, value_parser = parse_status)]
+        status: Option<OrderStatus>,
+        /// Created at or after (RFC 3339)
+        #[arg(long)]
+        from: Option<DateTime<Utc>>,
+        /// Created strictly before (RFC 3339)
+        #[arg(long)]
+        to: Option<DateTime<Utc>>,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// Export or erase the personal data of a customer (GDPR requests)
+    CustomerData {
+        #[command(subcommand)]
+        action: CustomerDataAction,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Verdict {
+    /// Confirm the order
+    Approve,
+    /// Cancel the order
+    Reject,
+}
+
+#[derive(Debug, Subcommand)]
+enum CustomerDataAction {
+    /// Write the orders, items and events of the customer as JSON (stdout by default)
+    Export {
+        customer_id: CustomerId,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Erase the customer from their orders and audit trail, keeping the amounts
+    /// (refused while one of their orders is in progress)
+    Anonymize {
+        customer_id: CustomerId,
+        /// Reference of the erasure request (kept in the audit trail)
+        #[arg(long)]
+        reference: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum MigrateAction {
+    /// Apply pending migrations
+    Up {
+        #[arg(long)]
+        steps: Option<u32>,
+    },
+    /// Roll back the last migration(s)
+    Down {
+        #[arg(long, default_value_t = 1)]
+        steps: u32,
+    },
+    /// List migrations and whether they are applied
+    Status,
+}
+
+fn parse_status(value: &str) -> Result<OrderStatus, String> {
+    value
+        .to_ascii_uppercase()
+        .parse()
+        .map_err(|err| format!("{err}"))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    // Logs go to stderr so that stdout stays parseable
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn")),
+        )
+        .init();
+
+    let mut settings = Settings::load()?;
+    if settings.repository.backend != RepositoryBackend::Sql {
+        bail!("ordering-admin works on the SQL backend (set ORDERING_REPOSITORY_BACKEND=sql)");
+    }
+
+    if let Command::Migrate { action } = cli.command {
+        return migrate(&settings, action, cli.output).await;
+    }
+
+    // Schema changes are explicit here (`migrate up`), never a side effect
+    settings.database.run_migrations = false;
+    let adapters = Adapters::connect(&settings).await?;
+    let handlers = Handlers::new(&adapters);
+    let tenant_id = cli
+        .tenant
+        .unwrap_or_else(|| settings.tenancy.default_tenant.clone());
+
+    match cli.command {
+        Command::Inspect { order_id } => {
+            let order = handlers
+                .get_order
+                .handle(GetOrderQuery {
+                    tenant_id: tenant_id.clone(),
+                    order_id,
+                })
+                .await?;
+            let timeline = handlers
+                .get_order_timeline
+                .handle(GetOrderTimelineQuery {
+                    tenant_id,
+                    order_id,
+                })
+                .await?;
+            cli.output
+                .print(&json!({ "order": order, "timeline": timeline }), || {
+                    let summary =
+                        Table::new(["ORDER", "CUSTOMER", "STATUS", "TOTAL", "CREATED", "UPDATED"])
+                            .row([
+                                order.id.to_string(),
+                                order.customer_id.to_string(),
+                                order.status.to_string(),
+                                order.total.to_string(),
+                                order.created_at.to_rfc3339(),
+                                order.updated_at.to_rfc3339(),
+                            ]);
+                    let items = order.items.iter().fold(
+                        Table::new(["ITEM", "PRODUCT", "QUANTITY", "UNIT PRICE", "SUBTOTAL"]),
+                        |table, item| {
+                            table.row([
+                                item.id.to_string(),
+                                item.product_name.clone(),
+                                item.quantity.to_string(),
+                                item.unit_price.to_string(),
+                                item.subtotal.to_string(),
+                            ])
+                        },
+                    );
+                    let history = timeline.iter().fold(
+                        Table::new(["AT", "ACTOR", "COMMAND", "TRANSITION", "REASON"]),
+                        |table, entry| {
+                            let before = entry
+                                .status_before
+                                .map_or_else(|| "-".to_string(), |status| status.to_string());
+                            table.row([
+                                entry.occurred_at.to_rfc3339(),
+                                entry.actor.to_string(),
+                                entry.command.clone(),
+                                format!("{} -> {}", before, entry.status_after),
+                                entry.reason.clone().unwrap_or_default(),
+                            ])
+                        },
+                    );
+                    vec![summary, items, history]
+                });
+        }
+
+        Command::ForceStatus {
+            order_id,
+            status,
+            reason,
+            payment_id,
+            tracking_number,
+            skip_risk,
+        } => {
+            if reason.trim().is_empty() {
+                bail!("--reason must explain why the transition is forced");
+            }
+            if skip_risk && status != OrderStatus::Confirmed {
+                bail!("--skip-risk only applies to CONFIRMED");
+            }
+            let transition = match status {
+                OrderStatus::Confirmed => StatusTransition::Confirm { skip_risk },
+                OrderStatus::Paid => StatusTransition::MarkAsPaid {
+                    payment_id: payment_id.context("--payment-id is required for PAID")?,
+                },
+                OrderStatus::Shipped => StatusTransition::Ship {
+                    tracking_number: tracking_number
+                        .context("--tracking-number is required for SHIPPED")?,
+                },
+                OrderStatus::Delivered => StatusTransition::Deliver,
+                OrderStatus::Cancelled => StatusTransition::Cancel,
+                OrderStatus::Pending => bail!("an order cannot be moved back to PENDING"),
+                OrderStatus::OnHold => {
+                    bail!("only the risk assessment puts an order ON_HOLD")
+                }
+            };
+            let handler = ForceOrderStatusHandler::new(
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.risk_assessor.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            );
+            let status = handler
+                .handle(ForceOrderStatusCommand {
+                    tenant_id,
+                    order_id,
+                    transition,
+                    justification: reason,
+                    actor: cli.actor,
+                })
+                .await?;
+            cli.output
+                .print(&json!({ "order_id": order_id, "status": status }), || {
+                    vec![Table::new(["ORDER", "STATUS"])
+                        .row([order_id.to_string(), status.to_string()])]
+                });
+        }
+
+        Command::Review {
+            order_id,
+            decision,
+            reason,
+        } => {
+            let decision = match decision {
+                Verdict::Approve => ReviewDecision::Approve,
+                Verdict::Reject => ReviewDecision::Reject {
+                    reason: reason.context("--reason is required to reject an order")?,
+                },
+            };
+            let status = handlers
+                .review_order
+                .handle(ReviewOrderCommand {
+                    tenant_id,
+                    order_id,
+                    decision,
+                    actor: cli.actor,
+                })
+                .await?;
+            cli.output
+                .print(&json!({ "order_id": order_id, "status": status }), || {
+                    vec![Table::new(["ORDER", "STATUS"])
+                        .row([order_id.to_string(), status.to_string()])]
+                });
+        }
+
+        Command::ReplayEvents { order_ids } => {
+            let handler = ReplayOrderEventsHandler::new(
+                adapters.audit_log.clone(),
+                adapters.event_publisher.clone(),
+            );
+            let mut replayed = Vec::new();
+            for order_id in order_ids {
+                let events = handler
+                    .handle(ReplayOrderEventsCommand {
+                        tenant_id: tenant_id.clone(),
+                        order_id,
+                    })
+                    .await
+                    .with_context(|| format!("order {order_id}"))?;
+                replayed.push(json!({ "order_id": order_id, "events": events }));
+            }
+            cli.output.print(&replayed, || {
+                vec![replayed
+                    .iter()
+                    .fold(Table::new(["ORDER", "EVENTS"]), |table, row| {
+                        table.row([
+                            row["order_id"].as_str().unwrap_or_default().to_string(),
+                            row["events"].to_string(),
+                        ])
+                    })]
+            });
+        }
+
+        Command::RebuildProjections { batch_size } => {
+            let repository = adapters.order_repository.clone();
+            let criteria = OrderCriteria::default();
+            let mut after: Option<OrderCursor> = None;
+            let mut rebuilt = 0usize;
+            loop {
+                let mut page = repository
+                    .find_page(&criteria, after, batch_size.max(1))
+                    .await?;
+                let Some(last) = page.last() else { break };
+                after = Some((last.created_at(), last.id()));
+                for order in &mut page {
+                    repository.save(order).await?;
+                }
+                rebuilt += page.len();
+            }
+            cli.output.print(&json!({ "rebuilt": rebuilt }), || {
+                vec![Table::new(["REBUILT"]).row([rebuilt.to_string()])]
+            });
+        }
+
+        Command::Import { file, format } => {
+            let content = std::fs::read_to_string(&file)
+                .with_context(|| format!("cannot read {}", file.display()))?;
+            let report = handlers
+                .import_orders
+                .handle(ImportOrdersCommand {
+                    tenant_id,
+                    rows: format.read_orders(&content),
+                    actor: cli.actor,
+                })
+                .await?;
+            cli.output.print(&report, || {
+                let summary = Table::new(["IMPORTED", "FAILED"])
+                    .row([report.imported.to_string(), report.failed.to_string()]);
+                let errors = report
+                    .errors
+                    .iter()
+                    .fold(Table::new(["LINE", "ERROR"]), |table, error| {
+                        table.row([error.line.to_string(), error.error.clone()])
+                    });
+                vec![summary, errors]
+            });
+        }
+
+        Command::Export {
+            format,
+            status,
+            from,
+            to,
+            out,
+        } => {
+            let mut writer: Box<dyn Write> = match &out {
+                Some(path) => Box::new(std::io::BufWriter::new(
+                    std::fs::File::create(path)
+                        .with_context(|| format!("cannot create {}", path.display()))?,
+                )),
+                None => Box::new(std::io::BufWriter::new(std::io::stdout().lock())),
+            };
+            let mut export = handlers.export_orders.handle(ExportOrdersQuery {
+                criteria: OrderCriteria {
+                    tenant_id: Some(tenant_id),
+                    status,
+                    created_from: from,
+                    created_until: to,
+                },
+                batch_size: 500,
+            });
+            writer.write_all(&format.export_header())?;
+            let mut exported = 0usize;
+            while let Some(batch) = export.next_batch().await? {
+                writer.write_all(&format.write_orders(&batch)?)?;
+                exported += batch.len();
+            }
+            writer.flush()?;
+            eprintln!("{exported} orders exported");
+        }
+
+        Command::CustomerData {
+            action: CustomerDataAction::Export { customer_id, out },
+        } => {
+            let handler = ExportCustomerDataHandler::new(
+                adapters.order_repository.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            );
+            let export = handler
+                .handle(ExportCustomerDataQuery {
+                    tenant_id,
+                    customer_id,
+                })
+                .await?;
+            let json = serde_json::to_vec_pretty(&export)?;
+            match &out {
+                Some(path) => std::fs::write(path, json)
+                    .with_context(|| format!("cannot write {}", path.display()))?,
+                None => std::io::stdout().lock().write_all(&json)?,
+            }
+            eprintln!(
+                "{} orders of customer {} exported",
+                export.orders.len(),
+                customer_id
+            );
+        }
+
+        Command::CustomerData {
+            action:
+                CustomerDataAction::Anonymize {
+                    customer_id,
+                    reference,
+                },
+        } => {
+            if reference.trim().is_empty() {
+                bail!("--reference must identify the erasure request");
+            }
+            let handler = AnonymizeCustomerHandler::new(
+                adapters.order_repository.clone(),
+                adapters.invoice_repository.clone(),
+                adapters.cart_repository.clone(),
+                adapters.wishlist_repository.clone(),
+                adapters.audit_log.clone(),
+                adapters.ids.clone(),
+                adapters.clock.clone(),
+            );
+            let order_ids = handler
+                .handle(AnonymizeCustomerCommand {
+                    tenant_id,
+                    customer_id,
+                    reference,
+                    actor: cli.actor,
+                })
+                .await?;
+            cli.output.print(
+                &json!({ "customer_id": customer_id, "anonymized": order_ids }),
+                || {
+                    vec![order_ids
+                        .iter()
+                        .fold(Table::new(["ANONYMIZED ORDER"]), |table, order_id| {
+                            table.row([order_id.to_string()])
+                        })]
+                },
+            );
+        }
+
+        Command::Migrate { .. } => unreachable!("handled before connecting the adapters"),
+    }
+
+    adapters.event_publisher.flush().await?;
+    Ok(())
+}
+
+async fn migrate(
+    settings: &Settings,
+    action: MigrateAction,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let db = bootstrap::connect_database(&settings.database).await?;
+    match action {
+        MigrateAction::Up { steps } => Migrator::up(&db, steps).await?,
+        MigrateAction::Down { steps } => Migrator::down(&db, Some(steps)).await?,
+        MigrateAction::Status => {}
+    }
+
+    let migrations: Vec<_> = Migrator::get_migration_with_status(&db)
+        .await?
+        .iter()
+        .map(|migration| json!({ "name": migration.name(), "status": migration.status().to_string() }))
+        .collect();
+    output.print(&migrations, || {
+        vec![migrations
+            .iter()
+            .fold(Table::new(["MIGRATION", "STATUS"]), |table, migration| {
+                table.row([
+                    migration["name"].as_str().unwrap_or_default().to_string(),
+                    migration["status"].as_str().unwrap_or_default().to_string(),
+                ])
+            })]
+    });
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+
+/// How results are printed on stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for humans
+    Table,
+    /// Pretty JSON for scripts
+    Json,
+}
+
+impl OutputFormat {
+    /// Print `value` as JSON, or the table built by `table` otherwise
+    pub fn print<T: Serialize>(&self, value: &T, table: impl FnOnce() -> Vec<Table>) {
+        match self {
+            OutputFormat::Json => match serde_json::to_string_pretty(value) {
+                Ok(json) => println!("{json}"),
+                Err(err) => eprintln!("error: cannot serialize output: {err}"),
+            },
+            OutputFormat::Table => {
+                let tables = table();
+                for (index, table) in tables.iter().enumerate() {
+                    if index > 0 {
+                        println!();
+                    }
+                    print!("{table}");
+                }
+            }
+        }
+    }
+}
+
+/// Plain text table with left-aligned, space-padded columns
+#[derive(Debug, Default)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<const N: usize>(headers: [&str; N]) -> Self {
+        Self {
+            headers: headers.iter().map(ToString::to_string).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row<const N: usize>(mut self, cells: [String; N]) -> Self {
+        self.rows.push(cells.into());
+        self
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_are_aligned_on_the_widest_cell() {
+        let table = Table::new(["NAME", "STATUS"])
+            .row(["m20251120_000001".to_string(), "Applied".to_string()])
+            .row(["m2".to_string(), "Pending".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            "NAME              STATUS\n\
+             m20251120_000001  Applied\n\
+             m2                Pending\n"
+        );
+    }
+}
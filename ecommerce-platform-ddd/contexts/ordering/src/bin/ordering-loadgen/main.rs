@@ -0,0 +1,250 @@
+//! Load generator for the ordering HTTP API: concurrent clients create orders through
+//! `POST /api/orders`, then the throughput and latency percentiles are reported.
+//!
+//! Without `--target`, the service is started in-process once per `--backend`, wired like
+//! the server, so that the repositories can be compared on the same machine.
+
+mod report;
+
+use anyhow::{bail, Context};
+use clap::{Parser, ValueEnum};
+use ordering_context::application::dto::{CreateOrderItemRequest, CreateOrderRequest};
+use ordering_context::domain::{CustomerId, Order, ProductId};
+use ordering_context::infrastructure::{
+    api,
+    bootstrap::{self, Adapters, Handlers},
+    config::{RepositoryBackend, Settings},
+    tenancy::TENANT_HEADER,
+};
+use report::{Report, Reports, Sample};
+use reqwest::StatusCode;
+use rust_decimal::Decimal;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::oneshot;
+use tokio::task::{JoinHandle, JoinSet};
+use uuid::Uuid;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "ordering-loadgen",
+    version,
+    about = "Load generator for the ordering HTTP API"
+)]
+struct Cli {
+    /// Base URL of a running server (e.g. `http://localhost:3000`) instead of in-process services
+    #[arg(long)]
+    target: Option<String>,
+
+    /// Repositories of the in-process services, one run each
+    #[arg(long = "backend", value_enum, default_values_t = [Backend::InMemory, Backend::Sqlite])]
+    backends: Vec<Backend>,
+
+    /// Orders created by a run
+    #[arg(long, default_value_t = 2000)]
+    requests: usize,
+
+    /// Clients sending requests at the same time
+    #[arg(long, default_value_t = 32)]
+    concurrency: usize,
+
+    /// Lines of each order
+    #[arg(long, default_value_t = 5)]
+    items: usize,
+
+    /// Orders created before measuring, to fill connection pools
+    #[arg(long, default_value_t = 100)]
+    warmup: usize,
+
+    /// Storefront of the orders (`X-Tenant-Id`), priced in its currency by `--target` servers
+    #[arg(long)]
+    tenant: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Backend {
+    /// `InMemory*` repositories
+    InMemory,
+    /// SQL repositories on a SQLite file, migrated at startup
+    Sqlite,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    if cli.items == 0 || cli.items > Order::MAX_ITEMS {
+        bail!("--items must be between 1 and {}", Order::MAX_ITEMS);
+    }
+    if cli.concurrency == 0 {
+        bail!("--concurrency must be greater than 0");
+    }
+
+    let mut reports = Vec::new();
+    match &cli.target {
+        Some(target) => reports.push(run(&cli, "target", target).await?),
+        None => {
+            for backend in &cli.backends {
+                let service = Service::start(*backend).await?;
+                let label = backend.to_possible_value().unwrap();
+                let report = run(&cli, label.get_name(), &service.url).await;
+                service.stop().await;
+                reports.push(report?);
+            }
+        }
+    }
+
+    print!("{}", Reports(&reports));
+    Ok(())
+}
+
+/// Warm up, then measure `--requests` orders
+async fn run(cli: &Cli, label: &str, base_url: &str) -> anyhow::Result<Report> {
+    let client = reqwest::Client::builder()
+        .pool_max_idle_per_host(cli.concurrency)
+        .build()?;
+    let url = format!("{}/api/orders", base_url.trim_end_matches('/'));
+    eprintln!(
+        "{label}: {} orders of {} lines, {} clients",
+        cli.requests, cli.items, cli.concurrency
+    );
+
+    let warmup = drive(&client, cli, &url, cli.warmup).await;
+    if !warmup.is_empty() && warmup.iter().all(|sample| !sample.ok) {
+        bail!("{label}: no order could be created on {url}");
+    }
+    let started = Instant::now();
+    let samples = drive(&client, cli, &url, cli.requests).await;
+    Ok(Report::new(label, &samples, started.elapsed()))
+}
+
+/// Create `requests` orders with `--concurrency` clients
+async fn drive(client: &reqwest::Client, cli: &Cli, url: &str, requests: usize) -> Vec<Sample> {
+    let remaining = Arc::new(AtomicUsize::new(requests));
+    let mut clients = JoinSet::new();
+    for _ in 0..cli.concurrency {
+        let (client, url, remaining) = (client.clone(), url.to_string(), remaining.clone());
+        let (items, tenant) = (cli.items, cli.tenant.clone());
+        clients.spawn(async move {
+            let mut samples = Vec::new();
+            while remaining
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                let mut request = client.post(&url).json(&order_request(items));
+                if let Some(tenant) = &tenant {
+                    request = request.header(TENANT_HEADER, tenant);
+                }
+                let started = Instant::now();
+                let ok = match request.send().await {
+                    // The body is read so that the connection goes back to the pool
+                    Ok(response) => {
+                        let status = response.status();
+                        response.bytes().await.is_ok() && status == StatusCode::CREATED
+                    }
+                    Err(_) => false,
+                };
+                samples.push(Sample {
+                    latency: started.elapsed(),
+                    ok,
+                });
+            }
+            samples
+        });
+    }
+    clients.join_all().await.into_iter().flatten().collect()
+}
+
+/// Order of a new customer, with `items` distinct products
+fn order_request(items: usize) -> CreateOrderRequest {
+    CreateOrderRequest {
+        customer_id: CustomerId::new(),
+        items: (1..=items)
+            .map(|index| CreateOrderItemRequest {
+                product_id: ProductId::new(),
+                product_name: format!("Product {index}"),
+                quantity: 1,
+                unit_price: Decimal::new(1999, 2),
+            })
+            .collect(),
+        billing_address: None,
+        shipping_address: None,
+    }
+}
+
+/// Ordering service started in-process on a free local port
+struct Service {
+    url: String,
+    shutdown: oneshot::Sender<()>,
+    server: JoinHandle<std::io::Result<()>>,
+    /// SQLite file removed on stop
+    database: Option<PathBuf>,
+}
+
+impl Service {
+    /// Default settings with `backend`, no broker, webhooks, jobs nor rate limit
+    async fn start(backend: Backend) -> anyhow::Result<Self> {
+        let mut settings = Settings::default();
+        settings.features.publish_events = false;
+        settings.webhooks.enabled = false;
+        settings.jobs.enabled = false;
+        settings.rate_limit.enabled = false;
+        let database = match backend {
+            Backend::InMemory => {
+                settings.repository.backend = RepositoryBackend::InMemory;
+                None
+            }
+            Backend::Sqlite => {
+                let path =
+                    std::env::temp_dir().join(format!("ordering-loadgen-{}.db", Uuid::new_v4()));
+                settings.repository.backend = RepositoryBackend::Sql;
+                settings.database.url = Some(format!("sqlite://{}?mode=rwc", path.display()));
+                settings.database.run_migrations = true;
+                Some(path)
+            }
+        };
+
+        let adapters = Adapters::connect(&settings)
+            .await
+            .with_context(|| format!("cannot start the {backend:?} service"))?;
+        let handlers = Handlers::new(&adapters);
+        let app = api::router(bootstrap::app_state(
+            &settings,
+            &adapters,
+            &handlers,
+            bootstrap::tenant_resolver(&settings, &adapters),
+        ));
+
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let (shutdown, stopped) = oneshot::channel();
+        let server = tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(async move {
+                stopped.await.ok();
+            })
+            .await
+        });
+        Ok(Self {
+            url,
+            shutdown,
+            server,
+            database,
+        })
+    }
+
+    async fn stop(self) {
+        self.shutdown.send(()).ok();
+        self.server.await.ok();
+        if let Some(path) = self.database {
+            for suffix in ["", "-wal", "-shm"] {
+                std::fs::remove_file(format!("{}{suffix}", path.display())).ok();
+            }
+        }
+    }
+}
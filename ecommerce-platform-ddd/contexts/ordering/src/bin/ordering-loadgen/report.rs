@@ -0,0 +1,129 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Outcome of one request
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub latency: Duration,
+    /// The order was created (`201 Created`)
+    pub ok: bool,
+}
+
+/// Throughput and latency of a run, latencies of the created orders only
+#[derive(Debug)]
+pub struct Report {
+    pub label: String,
+    pub requests: usize,
+    pub errors: usize,
+    pub elapsed: Duration,
+    latencies: Vec<Duration>,
+}
+
+impl Report {
+    pub fn new(label: impl Into<String>, samples: &[Sample], elapsed: Duration) -> Self {
+        let mut latencies: Vec<Duration> = samples
+            .iter()
+            .filter(|sample| sample.ok)
+            .map(|sample| sample.latency)
+            .collect();
+        latencies.sort_unstable();
+        Self {
+            label: label.into(),
+            requests: samples.len(),
+            errors: samples.len() - latencies.len(),
+            elapsed,
+            latencies,
+        }
+    }
+
+    /// Orders created per second of the run
+    pub fn throughput(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds == 0.0 {
+            return 0.0;
+        }
+        self.latencies.len() as f64 / seconds
+    }
+
+    /// Latency under which `percent` % of the orders were created (nearest rank)
+    pub fn percentile(&self, percent: f64) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let rank = (percent / 100.0 * self.latencies.len() as f64).ceil() as usize;
+        Some(self.latencies[rank.clamp(1, self.latencies.len()) - 1])
+    }
+}
+
+fn millis(latency: Option<Duration>) -> String {
+    latency
+        .map(|latency| format!("{:.2}ms", latency.as_secs_f64() * 1000.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// One line per run, aligned under a header
+pub struct Reports<'a>(pub &'a [Report]);
+
+impl fmt::Display for Reports<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12} {:>9} {:>7} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "BACKEND", "REQUESTS", "ERRORS", "ORDERS/S", "P50", "P90", "P99", "MAX"
+        )?;
+        for report in self.0 {
+            writeln!(
+                f,
+                "{:<12} {:>9} {:>7} {:>10.1} {:>10} {:>10} {:>10} {:>10}",
+                report.label,
+                report.requests,
+                report.errors,
+                report.throughput(),
+                millis(report.percentile(50.0)),
+                millis(report.percentile(90.0)),
+                millis(report.percentile(99.0)),
+                millis(report.percentile(100.0)),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(millis: impl IntoIterator<Item = u64>, ok: bool) -> Vec<Sample> {
+        millis
+            .into_iter()
+            .map(|millis| Sample {
+                latency: Duration::from_millis(millis),
+                ok,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_percentiles_of_the_created_orders() {
+        let mut all = samples((1..=100).rev(), true);
+        all.extend(samples([5000, 6000], false));
+        let report = Report::new("in-memory", &all, Duration::from_secs(4));
+
+        assert_eq!(report.requests, 102);
+        assert_eq!(report.errors, 2);
+        assert_eq!(report.throughput(), 25.0);
+        assert_eq!(report.percentile(50.0), Some(Duration::from_millis(50)));
+        assert_eq!(report.percentile(99.0), Some(Duration::from_millis(99)));
+        assert_eq!(report.percentile(100.0), Some(Duration::from_millis(100)));
+        assert_eq!(report.percentile(0.0), Some(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn test_run_without_created_order() {
+        let report = Report::new("sqlite", &samples([10], false), Duration::ZERO);
+
+        assert_eq!(report.percentile(50.0), None);
+        assert_eq!(report.throughput(), 0.0);
+        assert!(Reports(&[report]).to_string().contains("sqlite"));
+    }
+}
@@ -0,0 +1,484 @@
+use crate::domain::{
+    aggregates::Order,
+    clock::Clock,
+    entities::{CartLine, OrderItem},
+    errors::DomainError,
+    id_generator::IdGenerator,
+    pricing::Pricing,
+    tenant::Tenant,
+    value_objects::{CartId, CustomerId, Money, ProductId, TenantId},
+};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+
+/// Cart Aggregate Root
+/// Products a customer intends to buy, turned into an Order at checkout
+#[derive(Debug, Clone)]
+pub struct Cart {
+    // Identity
+    id: CartId,
+    // Storefront the cart was opened on
+    tenant_id: TenantId,
+    // None while the shopper is anonymous
+    customer_id: Option<CustomerId>,
+
+    // State
+    lines: Vec<CartLine>,
+
+    // Audit
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl Cart {
+    /// Business rule: a cart cannot hold more lines than an order
+    pub const MAX_LINES: usize = Order::MAX_ITEMS;
+
+    /// Business rule: a cart left untouched for this many days expires
+    pub const LIFETIME_DAYS: i64 = 30;
+
+    /// Factory method: an empty cart of a tenant, anonymous or owned by a customer
+    pub fn create(
+        tenant_id: TenantId,
+        customer_id: Option<CustomerId>,
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Self {
+        let now = clock.now();
+        Self {
+            id: CartId::generate(ids),
+            tenant_id,
+            customer_id,
+            lines: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            expires_at: now + Duration::days(Self::LIFETIME_DAYS),
+        }
+    }
+
+    /// Rebuild a Cart from persistence
+    pub fn reconstitute(
+        id: CartId,
+        tenant_id: TenantId,
+        customer_id: Option<CustomerId>,
+        lines: Vec<CartLine>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            customer_id,
+            lines,
+            created_at,
+            updated_at,
+            expires_at,
+        }
+    }
+
+    /// Business logic: add a product, or more units of a product already in the cart
+    ///
+    /// The latest price seen by the customer replaces the previous one.
+    pub fn add_line(&mut self, line: CartLine, clock: &dyn Clock) -> Result<(), DomainError> {
+        self.ensure_active(clock)?;
+
+        let mut lines = self.lines.clone();
+        Self::put_line(&mut lines, line)?;
+        self.replace_lines(lines, clock);
+        Ok(())
+    }
+
+    /// Business logic: set the quantity of a product in the cart
+    pub fn update_quantity(
+        &mut self,
+        product_id: ProductId,
+        quantity: u32,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.ensure_active(clock)?;
+
+        self.line_mut(product_id)?.set_quantity(quantity)?;
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: remove a product from the cart
+    pub fn remove_line(
+        &mut self,
+        product_id: ProductId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.ensure_active(clock)?;
+
+        let position = self
+            .lines
+            .iter()
+            .position(|line| line.product_id() == product_id)
+            .ok_or(DomainError::CartLineNotFound)?;
+        self.lines.remove(position);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: align the price of a product on the catalog
+    pub fn reprice_line(
+        &mut self,
+        product_id: ProductId,
+        unit_price: Money,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.line_mut(product_id)?.set_unit_price(unit_price);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: give an anonymous cart to the customer who just logged in
+    pub fn assign_to(
+        &mut self,
+        customer_id: CustomerId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.ensure_active(clock)?;
+        if self.customer_id.is_some() {
+            return Err(DomainError::CartAlreadyAssigned);
+        }
+
+        self.customer_id = Some(customer_id);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: move the lines of an anonymous cart into this one
+    ///
+    /// Quantities of a product present in both carts add up.
+    pub fn merge(&mut self, anonymous: Cart, clock: &dyn Clock) -> Result<(), DomainError> {
+        if anonymous.id == self.id {
+            return Err(DomainError::CannotMergeCartWithItself);
+        }
+        if anonymous.customer_id.is_some() {
+            return Err(DomainError::CartAlreadyAssigned);
+        }
+        self.ensure_active(clock)?;
+        anonymous.ensure_active(clock)?;
+
+        let mut lines = self.lines.clone();
+        for line in anonymous.lines {
+            Self::put_line(&mut lines, line)?;
+        }
+        self.replace_lines(lines, clock);
+        Ok(())
+    }
+
+    /// Business logic: turn the cart into a pending order of `tenant` for its customer,
+    /// the catalog prices of the lines being the list prices of the `pricing` rules
+    ///
+    /// Prices and stock are checked by the caller, against the catalog.
+    pub fn check_out(
+        &self,
+        tenant: &Tenant,
+        pricing: &Pricing,
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Result<Order, DomainError> {
+        self.ensure_active(clock)?;
+        let customer_id = self.customer_id.ok_or(DomainError::AnonymousCart)?;
+        if self.lines.is_empty() {
+            return Err(DomainError::EmptyCart);
+        }
+
+        let items = self
+            .lines
+            .iter()
+            .map(|line| {
+                let mut item = OrderItem::new(
+                    line.product_id(),
+                    line.product_name().to_string(),
+                    line.quantity(),
+                    line.unit_price(),
+                    ids,
+                )?;
+                item.apply_pricing(pricing);
+                Ok(item)
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+        Order::create(tenant, customer_id, items, ids, clock)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Calculate total from lines (business logic)
+    pub fn total(&self) -> Result<Money, DomainError> {
+        self.lines
+            .iter()
+            .try_fold(Money::eur(Decimal::ZERO)?, |acc, line| {
+                (acc + line.subtotal()).map_err(DomainError::from)
+            })
+    }
+
+    fn ensure_active(&self, clock: &dyn Clock) -> Result<(), DomainError> {
+        if self.is_expired(clock.now()) {
+            return Err(DomainError::CartExpired);
+        }
+        Ok(())
+    }
+
+    fn line_mut(&mut self, product_id: ProductId) -> Result<&mut CartLine, DomainError> {
+        self.lines
+            .iter_mut()
+            .find(|line| line.product_id() == product_id)
+            .ok_or(DomainError::CartLineNotFound)
+    }
+
+    /// Add `line` to `lines`, combining it with the line of the same product
+    fn put_line(lines: &mut Vec<CartLine>, line: CartLine) -> Result<(), DomainError> {
+        let existing = lines
+            .iter_mut()
+            .find(|existing| existing.product_id() == line.product_id());
+        if let Some(existing) = existing {
+            let quantity = existing
+                .quantity()
+                .checked_add(line.quantity())
+                .ok_or(DomainError::InvalidQuantity)?;
+            existing.set_quantity(quantity)?;
+            existing.set_unit_price(line.unit_price());
+        } else if lines.len() >= Self::MAX_LINES {
+            return Err(DomainError::TooManyItems {
+                max: Self::MAX_LINES,
+            });
+        } else {
+            lines.push(line);
+        }
+        Ok(())
+    }
+
+    /// Install lines computed on a copy, so that a failure leaves the cart untouched
+    fn replace_lines(&mut self, lines: Vec<CartLine>, clock: &dyn Clock) {
+        self.lines = lines;
+        self.touch(clock);
+    }
+
+    /// Any change pushes the expiry back
+    fn touch(&mut self, clock: &dyn Clock) {
+        self.updated_at = clock.now();
+        self.expires_at = self.updated_at + Duration::days(Self::LIFETIME_DAYS);
+    }
+
+    // Getters (encapsulation)
+    pub fn id(&self) -> CartId {
+        self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn customer_id(&self) -> Option<CustomerId> {
+        self.customer_id
+    }
+
+    pub fn lines(&self) -> &[CartLine] {
+        &self.lines
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::id_generator::SequentialIdGenerator;
+    use crate::domain::value_objects::OrderStatus;
+
+    fn line(product_id: ProductId, quantity: u32, cents: i64) -> CartLine {
+        CartLine::new(
+            product_id,
+            "Test Product".to_string(),
+            quantity,
+            Money::eur(Decimal::new(cents, 2)).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_adding_a_product_twice_adds_up_quantities() {
+        let clock = FixedClock::new(Utc::now());
+        let mut cart = Cart::create(
+            TenantId::default(),
+            None,
+            &SequentialIdGenerator::new(),
+            &clock,
+        );
+        let product = ProductId::new();
+
+        cart.add_line(line(product, 1, 1000), &clock).unwrap();
+        cart.add_line(line(product, 2, 900), &clock).unwrap();
+
+        assert_eq!(cart.lines().len(), 1);
+        assert_eq!(cart.lines()[0].quantity(), 3);
+        assert_eq!(cart.lines()[0].unit_price().amount(), Decimal::new(900, 2));
+        assert_eq!(cart.total().unwrap().amount(), Decimal::new(2700, 2));
+    }
+
+    #[test]
+    fn test_update_and_remove_lines() {
+        let clock = FixedClock::new(Utc::now());
+        let mut cart = Cart::create(
+            TenantId::default(),
+            None,
+            &SequentialIdGenerator::new(),
+            &clock,
+        );
+        let product = ProductId::new();
+        cart.add_line(line(product, 1, 1000), &clock).unwrap();
+
+        cart.update_quantity(product, 4, &clock).unwrap();
+        assert_eq!(cart.lines()[0].quantity(), 4);
+        assert!(matches!(
+            cart.update_quantity(product, 0, &clock),
+            Err(DomainError::InvalidQuantity)
+        ));
+        assert!(matches!(
+            cart.remove_line(ProductId::new(), &clock),
+            Err(DomainError::CartLineNotFound)
+        ));
+
+        cart.remove_line(product, &clock).unwrap();
+        assert!(cart.lines().is_empty());
+    }
+
+    #[test]
+    fn test_line_count_is_capped() {
+        let clock = FixedClock::new(Utc::now());
+        let mut cart = Cart::create(
+            TenantId::default(),
+            None,
+            &SequentialIdGenerator::new(),
+            &clock,
+        );
+        for _ in 0..Cart::MAX_LINES {
+            cart.add_line(line(ProductId::new(), 1, 100), &clock)
+                .unwrap();
+        }
+
+        let result = cart.add_line(line(ProductId::new(), 1, 100), &clock);
+        assert!(matches!(result, Err(DomainError::TooManyItems { .. })));
+        assert_eq!(cart.lines().len(), Cart::MAX_LINES);
+    }
+
+    #[test]
+    fn test_merge_anonymous_cart_into_customer_cart() {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+        let shared = ProductId::new();
+        let mut customer_cart =
+            Cart::create(TenantId::default(), Some(CustomerId::new()), &ids, &clock);
+        customer_cart
+            .add_line(line(shared, 1, 1000), &clock)
+            .unwrap();
+        let mut anonymous = Cart::create(TenantId::default(), None, &ids, &clock);
+        anonymous.add_line(line(shared, 2, 1000), &clock).unwrap();
+        anonymous
+            .add_line(line(ProductId::new(), 1, 500), &clock)
+            .unwrap();
+
+        customer_cart.merge(anonymous, &clock).unwrap();
+
+        assert_eq!(customer_cart.lines().len(), 2);
+        assert_eq!(customer_cart.lines()[0].quantity(), 3);
+        assert_eq!(
+            customer_cart.total().unwrap().amount(),
+            Decimal::new(3500, 2)
+        );
+    }
+
+    #[test]
+    fn test_cannot_merge_another_customer_cart() {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+        let mut cart = Cart::create(TenantId::default(), Some(CustomerId::new()), &ids, &clock);
+        let other = Cart::create(TenantId::default(), Some(CustomerId::new()), &ids, &clock);
+
+        assert!(matches!(
+            cart.merge(other, &clock),
+            Err(DomainError::CartAlreadyAssigned)
+        ));
+        assert!(matches!(
+            cart.merge(cart.clone(), &clock),
+            Err(DomainError::CannotMergeCartWithItself)
+        ));
+    }
+
+    #[test]
+    fn test_cart_expires_unless_touched() {
+        let clock = FixedClock::new(Utc::now());
+        let mut cart = Cart::create(
+            TenantId::default(),
+            None,
+            &SequentialIdGenerator::new(),
+            &clock,
+        );
+        let product = ProductId::new();
+
+        clock.advance(Duration::days(Cart::LIFETIME_DAYS - 1));
+        cart.add_line(line(product, 1, 1000), &clock).unwrap();
+        clock.advance(Duration::days(Cart::LIFETIME_DAYS - 1));
+        assert!(!cart.is_expired(clock.now()));
+
+        clock.advance(Duration::days(1));
+        assert!(cart.is_expired(clock.now()));
+        assert!(matches!(
+            cart.update_quantity(product, 2, &clock),
+            Err(DomainError::CartExpired)
+        ));
+    }
+
+    #[test]
+    fn test_check_out_creates_a_pending_order() {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+        let customer_id = CustomerId::new();
+        let mut cart = Cart::create(TenantId::default(), None, &ids, &clock);
+        cart.add_line(line(ProductId::new(), 2, 1000), &clock)
+            .unwrap();
+
+        assert!(matches!(
+            cart.check_out(&Tenant::default(), &Pricing::list_prices(), &ids, &clock),
+            Err(DomainError::AnonymousCart)
+        ));
+
+        cart.assign_to(customer_id, &clock).unwrap();
+        let order = cart
+            .check_out(&Tenant::default(), &Pricing::list_prices(), &ids, &clock)
+            .unwrap();
+        assert_eq!(order.customer_id(), customer_id);
+        assert_eq!(order.status(), OrderStatus::Pending);
+        assert_eq!(order.total().amount(), Decimal::new(2000, 2));
+    }
+
+    #[test]
+    fn test_cannot_check_out_an_empty_cart() {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+        let cart = Cart::create(TenantId::default(), Some(CustomerId::new()), &ids, &clock);
+
+        assert!(matches!(
+            cart.check_out(&Tenant::default(), &Pricing::list_prices(), &ids, &clock),
+            Err(DomainError::EmptyCart)
+        ));
+    }
+}
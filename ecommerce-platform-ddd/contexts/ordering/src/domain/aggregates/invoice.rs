@@ -0,0 +1,551 @@
+use crate::domain::{
+    aggregates::Order,
+    clock::Clock,
+    entities::InvoiceLine,
+    errors::DomainError,
+    id_generator::IdGenerator,
+    value_objects::{
+        Currency, CustomerId, InvoiceId, InvoiceKind, InvoiceNumber, Money, OrderId, OrderItemId,
+        OrderStatus, TenantId,
+    },
+};
+use chrono::{DateTime, Datelike, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+/// Invoice Aggregate Root
+/// Legal record of a paid order (invoice) or of a refund (credit note)
+///
+/// Immutable once issued: corrections are made by issuing credit notes.
+#[derive(Debug, Clone)]
+pub struct Invoice {
+    // Identity
+    id: InvoiceId,
+    number: InvoiceNumber,
+    // Tenant of the order billed; each tenant numbers its own invoices
+    tenant_id: TenantId,
+
+    // What is billed, to whom
+    order_id: OrderId,
+    customer_id: CustomerId,
+    lines: Vec<InvoiceLine>,
+    total: Money,
+
+    // Credit notes only: the invoice refunded and why
+    credited_invoice_id: Option<InvoiceId>,
+    reason: Option<String>,
+
+    issued_at: DateTime<Utc>,
+}
+
+/// Invoice waiting for its number
+///
+/// Numbers are allocated by the repository when the invoice is stored, so that
+/// no number is consumed by an invoice that is never issued.
+#[derive(Debug, Clone)]
+pub struct InvoiceDraft {
+    id: InvoiceId,
+    kind: InvoiceKind,
+    tenant_id: TenantId,
+    order_id: OrderId,
+    customer_id: CustomerId,
+    lines: Vec<InvoiceLine>,
+    total: Money,
+    credited_invoice_id: Option<InvoiceId>,
+    reason: Option<String>,
+    issued_at: DateTime<Utc>,
+}
+
+impl InvoiceDraft {
+    pub fn id(&self) -> InvoiceId {
+        self.id
+    }
+
+    pub fn kind(&self) -> InvoiceKind {
+        self.kind
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn order_id(&self) -> OrderId {
+        self.order_id
+    }
+
+    /// Credit notes only: the invoice refunded
+    pub fn credited_invoice_id(&self) -> Option<InvoiceId> {
+        self.credited_invoice_id
+    }
+
+    /// Year of the numbering series the invoice belongs to
+    pub fn year(&self) -> i32 {
+        self.issued_at.year()
+    }
+
+    /// Seal the draft with the next number of its series
+    pub fn into_invoice(self, sequence: u32) -> Invoice {
+        Invoice {
+            id: self.id,
+            number: InvoiceNumber::new(self.kind, self.year(), sequence),
+            tenant_id: self.tenant_id,
+            order_id: self.order_id,
+            customer_id: self.customer_id,
+            lines: self.lines,
+            total: self.total,
+            credited_invoice_id: self.credited_invoice_id,
+            reason: self.reason,
+            issued_at: self.issued_at,
+        }
+    }
+}
+
+impl Invoice {
+    /// Factory method: invoice every item of a paid order
+    pub fn draft_for_order(
+        order: &Order,
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Result<InvoiceDraft, DomainError> {
+        // Business rule: only orders that have been paid are invoiced
+        if !matches!(
+            order.status(),
+            OrderStatus::Paid | OrderStatus::Shipped | OrderStatus::Delivered
+        ) {
+            return Err(DomainError::OrderNotPaid);
+        }
+
+        let lines = order
+            .items()
+            .iter()
+            .map(|item| {
+                InvoiceLine::new(
+                    item.id(),
+                    item.product_id(),
+                    item.product_name().to_string(),
+                    item.quantity(),
+                    item.unit_price(),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(InvoiceDraft {
+            id: InvoiceId::generate(ids),
+            kind: InvoiceKind::Invoice,
+            tenant_id: order.tenant_id().clone(),
+            order_id: order.id(),
+            customer_id: order.customer_id(),
+            total: Self::sum(&lines, order.total().currency())?,
+            lines,
+            credited_invoice_id: None,
+            reason: None,
+            issued_at: clock.now(),
+        })
+    }
+
+    /// Business logic: refund some units of this invoice
+    ///
+    /// `quantities` lists the units refunded per order item; `None` refunds
+    /// everything not credited yet. `credit_notes` are the credit notes already
+    /// issued for the order: an invoice is never credited beyond what it billed.
+    pub fn draft_credit_note(
+        &self,
+        quantities: Option<Vec<(OrderItemId, u32)>>,
+        reason: String,
+        credit_notes: &[Invoice],
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Result<InvoiceDraft, DomainError> {
+        // Business rule: a credit note cannot itself be refunded
+        if self.kind() != InvoiceKind::Invoice {
+            return Err(DomainError::CannotCreditCreditNote);
+        }
+
+        // Business rule: a refund must be justified
+        if reason.trim().is_empty() {
+            return Err(DomainError::InvalidCreditNoteReason);
+        }
+
+        let mut remaining = self.remaining_quantities(credit_notes);
+        let lines = match quantities {
+            None => {
+                let lines = self
+                    .lines
+                    .iter()
+                    .filter_map(|line| match remaining[&line.order_item_id()] {
+                        0 => None,
+                        quantity => Some(line.with_quantity(quantity)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if lines.is_empty() {
+                    return Err(DomainError::InvoiceFullyCredited);
+                }
+                lines
+            }
+            Some(quantities) => {
+                if quantities.is_empty() {
+                    return Err(DomainError::EmptyCreditNote);
+                }
+
+                let mut lines: Vec<InvoiceLine> = Vec::new();
+                for (order_item_id, quantity) in quantities {
+                    if quantity == 0 {
+                        return Err(DomainError::InvalidQuantity);
+                    }
+                    let available = remaining
+                        .get_mut(&order_item_id)
+                        .ok_or(DomainError::InvoiceLineNotFound)?;
+                    // Business rule: never refund more units than were billed
+                    if quantity > *available {
+                        return Err(DomainError::CreditNoteExceedsInvoice);
+                    }
+                    *available -= quantity;
+
+                    match lines
+                        .iter_mut()
+                        .find(|line| line.order_item_id() == order_item_id)
+                    {
+                        Some(line) => *line = line.with_quantity(line.quantity() + quantity)?,
+                        None => {
+                            let invoiced = self
+                                .lines
+                                .iter()
+                                .find(|line| line.order_item_id() == order_item_id)
+                                .ok_or(DomainError::InvoiceLineNotFound)?;
+                            lines.push(invoiced.with_quantity(quantity)?);
+                        }
+                    }
+                }
+                lines
+            }
+        };
+
+        Ok(InvoiceDraft {
+            id: InvoiceId::generate(ids),
+            kind: InvoiceKind::CreditNote,
+            tenant_id: self.tenant_id.clone(),
+            order_id: self.order_id,
+            customer_id: self.customer_id,
+            total: Self::sum(&lines, self.total.currency())?,
+            lines,
+            credited_invoice_id: Some(self.id),
+            reason: Some(reason),
+            issued_at: clock.now(),
+        })
+    }
+
+    /// Business rule, checked again when the credit note is stored: `draft` refunds
+    /// no more than `credit_notes` left of this invoice
+    ///
+    /// Two refunds drafted at the same time both saw the same credit notes; only the
+    /// first one stored still fits.
+    pub fn ensure_creditable(
+        &self,
+        draft: &InvoiceDraft,
+        credit_notes: &[Invoice],
+    ) -> Result<(), DomainError> {
+        let mut remaining = self.remaining_quantities(credit_notes);
+        for line in &draft.lines {
+            let available = remaining
+                .get_mut(&line.order_item_id())
+                .ok_or(DomainError::InvoiceLineNotFound)?;
+            if line.quantity() > *available {
+                return Err(DomainError::CreditNoteExceedsInvoice);
+            }
+            *available -= line.quantity();
+        }
+        Ok(())
+    }
+
+    /// Units of each invoiced item not refunded yet by `credit_notes`
+    fn remaining_quantities(&self, credit_notes: &[Invoice]) -> HashMap<OrderItemId, u32> {
+        let mut remaining: HashMap<OrderItemId, u32> = self
+            .lines
+            .iter()
+            .map(|line| (line.order_item_id(), line.quantity()))
+            .collect();
+        for line in credit_notes
+            .iter()
+            .filter(|note| note.credited_invoice_id == Some(self.id))
+            .flat_map(|note| note.lines.iter())
+        {
+            if let Some(quantity) = remaining.get_mut(&line.order_item_id()) {
+                *quantity = quantity.saturating_sub(line.quantity());
+            }
+        }
+        remaining
+    }
+
+    /// Rebuild an Invoice from persistence
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: InvoiceId,
+        number: InvoiceNumber,
+        tenant_id: TenantId,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        lines: Vec<InvoiceLine>,
+        currency: Currency,
+        credited_invoice_id: Option<InvoiceId>,
+        reason: Option<String>,
+        issued_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Ok(Self {
+            id,
+            number,
+            tenant_id,
+            order_id,
+            customer_id,
+            total: Self::sum(&lines, currency)?,
+            lines,
+            credited_invoice_id,
+            reason,
+            issued_at,
+        })
+    }
+
+    /// Business logic: replace the customer at their erasure request
+    ///
+    /// The invoice itself is kept for its legal retention period; `pseudonym` is the
+    /// one given to the order it bills.
+    pub fn anonymize(&mut self, pseudonym: CustomerId) {
+        self.customer_id = pseudonym;
+    }
+
+    fn sum(lines: &[InvoiceLine], currency: Currency) -> Result<Money, DomainError> {
+        lines
+            .iter()
+            .try_fold(Money::new(Decimal::ZERO, currency)?, |total, line| {
+                total + line.subtotal()
+            })
+            .map_err(DomainError::from)
+    }
+
+    // Getters
+    pub fn id(&self) -> InvoiceId {
+        self.id
+    }
+
+    pub fn number(&self) -> InvoiceNumber {
+        self.number
+    }
+
+    pub fn kind(&self) -> InvoiceKind {
+        self.number.kind()
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn order_id(&self) -> OrderId {
+        self.order_id
+    }
+
+    pub fn customer_id(&self) -> CustomerId {
+        self.customer_id
+    }
+
+    pub fn lines(&self) -> &[InvoiceLine] {
+        &self.lines
+    }
+
+    pub fn total(&self) -> Money {
+        self.total
+    }
+
+    pub fn credited_invoice_id(&self) -> Option<InvoiceId> {
+        self.credited_invoice_id
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.issued_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::entities::OrderItem;
+    use crate::domain::id_generator::SequentialIdGenerator;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::value_objects::ProductId;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn clock() -> FixedClock {
+        FixedClock::new(Utc.with_ymd_and_hms(2026, 3, 14, 10, 0, 0).unwrap())
+    }
+
+    fn paid_order(ids: &SequentialIdGenerator, clock: &FixedClock) -> Order {
+        let items = vec![
+            OrderItem::new(
+                ProductId::new(),
+                "Keyboard".to_string(),
+                2,
+                Money::eur(Decimal::new(4950, 2)).unwrap(),
+                ids,
+            )
+            .unwrap(),
+            OrderItem::new(
+                ProductId::new(),
+                "Mouse".to_string(),
+                1,
+                Money::eur(Decimal::new(1999, 2)).unwrap(),
+                ids,
+            )
+            .unwrap(),
+        ];
+        let mut order =
+            Order::create(&Tenant::default(), CustomerId::new(), items, ids, clock).unwrap();
+        order.confirm(clock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), clock).unwrap();
+        order
+    }
+
+    fn invoice(order: &Order, ids: &SequentialIdGenerator, clock: &FixedClock) -> Invoice {
+        Invoice::draft_for_order(order, ids, clock)
+            .unwrap()
+            .into_invoice(1)
+    }
+
+    #[test]
+    fn test_invoice_lines_and_total_come_from_the_order() {
+        let ids = SequentialIdGenerator::new();
+        let clock = clock();
+        let order = paid_order(&ids, &clock);
+
+        let invoice = invoice(&order, &ids, &clock);
+
+        assert_eq!(invoice.number().to_string(), "INV-2026-000001");
+        assert_eq!(invoice.kind(), InvoiceKind::Invoice);
+        assert_eq!(invoice.order_id(), order.id());
+        assert_eq!(invoice.lines().len(), 2);
+        assert_eq!(invoice.lines()[0].quantity(), 2);
+        assert_eq!(
+            invoice.lines()[0].subtotal().amount(),
+            Decimal::new(9900, 2)
+        );
+        assert_eq!(invoice.total(), order.total());
+        assert_eq!(invoice.issued_at(), clock.now());
+    }
+
+    #[test]
+    fn test_unpaid_order_cannot_be_invoiced() {
+        let ids = SequentialIdGenerator::new();
+        let clock = clock();
+        let order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![OrderItem::new(
+                ProductId::new(),
+                "Keyboard".to_string(),
+                1,
+                Money::eur(Decimal::new(4950, 2)).unwrap(),
+                &ids,
+            )
+            .unwrap()],
+            &ids,
+            &clock,
+        )
+        .unwrap();
+
+        let result = Invoice::draft_for_order(&order, &ids, &clock);
+
+        assert!(matches!(result, Err(DomainError::OrderNotPaid)));
+    }
+
+    #[test]
+    fn test_full_credit_note_refunds_everything_not_credited_yet() {
+        let ids = SequentialIdGenerator::new();
+        let clock = clock();
+        let invoice = invoice(&paid_order(&ids, &clock), &ids, &clock);
+        let keyboard = invoice.lines()[0].order_item_id();
+
+        let partial = invoice
+            .draft_credit_note(
+                Some(vec![(keyboard, 1)]),
+                "Damaged".to_string(),
+                &[],
+                &ids,
+                &clock,
+            )
+            .unwrap()
+            .into_invoice(1);
+        let rest = invoice
+            .draft_credit_note(
+                None,
+                "Returned".to_string(),
+                std::slice::from_ref(&partial),
+                &ids,
+                &clock,
+            )
+            .unwrap()
+            .into_invoice(2);
+
+        assert_eq!(partial.number().to_string(), "CN-2026-000001");
+        assert_eq!(partial.credited_invoice_id(), Some(invoice.id()));
+        assert_eq!(partial.total().amount(), Decimal::new(4950, 2));
+        assert_eq!(rest.lines()[0].quantity(), 1);
+        assert_eq!(rest.lines()[1].quantity(), 1);
+        assert_eq!((partial.total() + rest.total()).unwrap(), invoice.total());
+        assert!(matches!(
+            invoice.draft_credit_note(None, "Again".to_string(), &[partial, rest], &ids, &clock),
+            Err(DomainError::InvoiceFullyCredited)
+        ));
+    }
+
+    #[test]
+    fn test_credit_note_cannot_exceed_invoiced_quantities() {
+        let ids = SequentialIdGenerator::new();
+        let clock = clock();
+        let invoice = invoice(&paid_order(&ids, &clock), &ids, &clock);
+        let keyboard = invoice.lines()[0].order_item_id();
+
+        let result = invoice.draft_credit_note(
+            Some(vec![(keyboard, 1), (keyboard, 2)]),
+            "Damaged".to_string(),
+            &[],
+            &ids,
+            &clock,
+        );
+
+        assert!(matches!(result, Err(DomainError::CreditNoteExceedsInvoice)));
+        assert!(matches!(
+            invoice.draft_credit_note(
+                Some(vec![(OrderItemId::new(), 1)]),
+                "Damaged".to_string(),
+                &[],
+                &ids,
+                &clock,
+            ),
+            Err(DomainError::InvoiceLineNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_credit_note_needs_a_reason_and_cannot_be_credited() {
+        let ids = SequentialIdGenerator::new();
+        let clock = clock();
+        let invoice = invoice(&paid_order(&ids, &clock), &ids, &clock);
+
+        assert!(matches!(
+            invoice.draft_credit_note(None, "  ".to_string(), &[], &ids, &clock),
+            Err(DomainError::InvalidCreditNoteReason)
+        ));
+
+        let credit_note = invoice
+            .draft_credit_note(None, "Returned".to_string(), &[], &ids, &clock)
+            .unwrap()
+            .into_invoice(1);
+        assert!(matches!(
+            credit_note.draft_credit_note(None, "Returned".to_string(), &[], &ids, &clock),
+            Err(DomainError::CannotCreditCreditNote)
+        ));
+    }
+}
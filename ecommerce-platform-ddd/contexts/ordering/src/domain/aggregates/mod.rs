@@ -1,3 +1,12 @@
+pub mod cart;
+pub mod invoice;
 pub mod order;
+pub mod wishlist;
 
+#[cfg(test)]
+mod order_properties;
+
+pub use cart::Cart;
+pub use invoice::{Invoice, InvoiceDraft};
 pub use order::Order;
+pub use wishlist::Wishlist;
@@ -1,8 +1,15 @@
 use crate::domain::{
-    entities::OrderItem,
-    events::OrderEvent,
-    value_objects::{CustomerId, Money, OrderId, OrderStatus},
+    clock::Clock,
+    entities::{OrderItem, Shipment, ShipmentLine},
     errors::DomainError,
+    events::OrderEvent,
+    id_generator::IdGenerator,
+    pricing::Pricing,
+    tenant::{TaxRule, Tenant},
+    value_objects::{
+        CustomerId, Money, OrderId, OrderItemId, OrderStatus, PostalAddress, ShipmentId,
+        ShipmentStatus, TenantId,
+    },
 };
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -15,12 +22,20 @@ use uuid::Uuid;
 pub struct Order {
     // Identity
     id: OrderId,
+    tenant_id: TenantId,
     customer_id: CustomerId,
 
     // State
     items: Vec<OrderItem>,
     status: OrderStatus,
     total: Money,
+    // Tax rule of the tenant when the order was placed
+    tax: TaxRule,
+    // Parcels of a multi-parcel fulfilment (empty when shipped in one go)
+    shipments: Vec<Shipment>,
+    // Where the order is billed and delivered, when given by the customer
+    billing_address: Option<PostalAddress>,
+    shipping_address: Option<PostalAddress>,
 
     // Audit
     created_at: DateTime<Utc>,
@@ -31,25 +46,45 @@ pub struct Order {
 }
 
 impl Order {
+    /// Business rule: upper bound on the number of lines of an order
+    pub const MAX_ITEMS: usize = 50;
+
     /// Factory method - only way to create a valid Order
-    pub fn create(customer_id: CustomerId, items: Vec<OrderItem>) -> Result<Self, DomainError> {
+    pub fn create(
+        tenant: &Tenant,
+        customer_id: CustomerId,
+        items: Vec<OrderItem>,
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Result<Self, DomainError> {
         // Business rule: order must have at least one item
         if items.is_empty() {
             return Err(DomainError::EmptyOrder);
         }
+        if items.len() > Self::MAX_ITEMS {
+            return Err(DomainError::TooManyItems {
+                max: Self::MAX_ITEMS,
+            });
+        }
 
         // Calculate total (business logic in aggregate)
         let total = Self::calculate_total(&items)?;
+        tenant.ensure_currency(total)?;
 
-        let order_id = OrderId::new();
-        let now = Utc::now();
+        let order_id = OrderId::generate(ids);
+        let now = clock.now();
 
         let mut order = Self {
             id: order_id,
+            tenant_id: tenant.id.clone(),
             customer_id,
             items,
             status: OrderStatus::Pending,
             total,
+            tax: tenant.tax,
+            shipments: Vec::new(),
+            billing_address: None,
+            shipping_address: None,
             created_at: now,
             updated_at: now,
             domain_events: Vec::new(),
@@ -66,8 +101,62 @@ impl Order {
         Ok(order)
     }
 
+    /// Rebuild an Order from persistence (no validation of transitions, no events)
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstitute(
+        id: OrderId,
+        tenant_id: TenantId,
+        customer_id: CustomerId,
+        items: Vec<OrderItem>,
+        status: OrderStatus,
+        tax: TaxRule,
+        shipments: Vec<Shipment>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if items.is_empty() {
+            return Err(DomainError::EmptyOrder);
+        }
+
+        let total = Self::calculate_total(&items)?;
+
+        Ok(Self {
+            id,
+            tenant_id,
+            customer_id,
+            items,
+            status,
+            total,
+            tax,
+            shipments,
+            billing_address: None,
+            shipping_address: None,
+            created_at,
+            updated_at,
+            domain_events: Vec::new(),
+        })
+    }
+
+    /// Set the addresses the order is billed and delivered to, on creation or from
+    /// persistence
+    ///
+    /// Fraud screening compares these, never addresses given when confirming.
+    pub fn with_addresses(
+        mut self,
+        billing_address: Option<PostalAddress>,
+        shipping_address: Option<PostalAddress>,
+    ) -> Self {
+        self.billing_address = billing_address;
+        self.shipping_address = shipping_address;
+        self
+    }
+
     /// Business logic: confirm the order
-    pub fn confirm(&mut self) -> Result<(), DomainError> {
+    pub fn confirm(&mut self, clock: &dyn Clock) -> Result<(), DomainError> {
+        // Business rule: a held order is only confirmed by approving its review
+        if self.status == OrderStatus::OnHold {
+            return Err(DomainError::OrderAwaitingReview);
+        }
         if !self.status.can_transition_to(OrderStatus::Confirmed) {
             return Err(DomainError::InvalidStatusTransition {
                 from: self.status,
@@ -76,7 +165,7 @@ impl Order {
         }
 
         self.status = OrderStatus::Confirmed;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         self.add_event(OrderEvent::OrderConfirmed {
             order_id: self.id,
@@ -86,8 +175,71 @@ impl Order {
         Ok(())
     }
 
+    /// Business logic: suspend the confirmation until an operator reviews the order
+    ///
+    /// `reasons` are the risk rules matched by the order, adding up to `score`.
+    pub fn hold_for_review(
+        &mut self,
+        score: u32,
+        reasons: Vec<String>,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        if !self.status.can_transition_to(OrderStatus::OnHold) {
+            return Err(DomainError::InvalidStatusTransition {
+                from: self.status,
+                to: OrderStatus::OnHold,
+            });
+        }
+
+        self.status = OrderStatus::OnHold;
+        self.updated_at = clock.now();
+
+        self.add_event(OrderEvent::OrderHeldForReview {
+            order_id: self.id,
+            score,
+            reasons,
+            timestamp: self.updated_at,
+        });
+
+        Ok(())
+    }
+
+    /// Business logic: release a held order, which becomes confirmed
+    pub fn approve_review(&mut self, clock: &dyn Clock) -> Result<(), DomainError> {
+        self.ensure_on_hold(OrderStatus::Confirmed)?;
+
+        self.status = OrderStatus::Confirmed;
+        self.updated_at = clock.now();
+
+        self.add_event(OrderEvent::OrderReviewApproved {
+            order_id: self.id,
+            timestamp: self.updated_at,
+        });
+
+        Ok(())
+    }
+
+    /// Business logic: refuse a held order, which becomes cancelled
+    pub fn reject_review(&mut self, reason: String, clock: &dyn Clock) -> Result<(), DomainError> {
+        self.ensure_on_hold(OrderStatus::Cancelled)?;
+        if reason.trim().is_empty() {
+            return Err(DomainError::EmptyRejectionReason);
+        }
+
+        self.status = OrderStatus::Cancelled;
+        self.updated_at = clock.now();
+
+        self.add_event(OrderEvent::OrderReviewRejected {
+            order_id: self.id,
+            reason,
+            timestamp: self.updated_at,
+        });
+
+        Ok(())
+    }
+
     /// Business logic: mark as paid
-    pub fn mark_as_paid(&mut self, payment_id: Uuid) -> Result<(), DomainError> {
+    pub fn mark_as_paid(&mut self, payment_id: Uuid, clock: &dyn Clock) -> Result<(), DomainError> {
         if !self.status.can_transition_to(OrderStatus::Paid) {
             return Err(DomainError::InvalidStatusTransition {
                 from: self.status,
@@ -96,7 +248,7 @@ impl Order {
         }
 
         self.status = OrderStatus::Paid;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         self.add_event(OrderEvent::OrderPaid {
             order_id: self.id,
@@ -107,17 +259,21 @@ impl Order {
         Ok(())
     }
 
-    /// Business logic: ship the order
-    pub fn ship(&mut self, tracking_number: String) -> Result<(), DomainError> {
+    /// Business logic: ship the order in a single parcel
+    pub fn ship(&mut self, tracking_number: String, clock: &dyn Clock) -> Result<(), DomainError> {
         if !self.status.can_transition_to(OrderStatus::Shipped) {
             return Err(DomainError::InvalidStatusTransition {
                 from: self.status,
                 to: OrderStatus::Shipped,
             });
         }
+        // Multi-parcel orders are shipped by `ship_shipment`
+        if !self.shipments.is_empty() {
+            return Err(DomainError::OrderHasShipments);
+        }
 
         self.status = OrderStatus::Shipped;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         self.add_event(OrderEvent::OrderShipped {
             order_id: self.id,
@@ -128,8 +284,28 @@ impl Order {
         Ok(())
     }
 
+    /// Business logic: confirm delivery by the carrier
+    pub fn deliver(&mut self, clock: &dyn Clock) -> Result<(), DomainError> {
+        if !self.status.can_transition_to(OrderStatus::Delivered) {
+            return Err(DomainError::InvalidStatusTransition {
+                from: self.status,
+                to: OrderStatus::Delivered,
+            });
+        }
+
+        self.status = OrderStatus::Delivered;
+        self.updated_at = clock.now();
+
+        self.add_event(OrderEvent::OrderDelivered {
+            order_id: self.id,
+            timestamp: self.updated_at,
+        });
+
+        Ok(())
+    }
+
     /// Business logic: cancel the order
-    pub fn cancel(&mut self, reason: String) -> Result<(), DomainError> {
+    pub fn cancel(&mut self, reason: String, clock: &dyn Clock) -> Result<(), DomainError> {
         // Business rule: cannot cancel terminal orders
         if self.status.is_terminal() {
             return Err(DomainError::CannotCancelTerminalOrder);
@@ -143,7 +319,7 @@ impl Order {
         }
 
         self.status = OrderStatus::Cancelled;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         self.add_event(OrderEvent::OrderCancelled {
             order_id: self.id,
@@ -154,47 +330,269 @@ impl Order {
         Ok(())
     }
 
+    /// Business logic: erase the personal data of the order at the customer's request
+    ///
+    /// The customer becomes `pseudonym`, addresses and tracking numbers are erased; items,
+    /// prices, totals and taxes are kept for the accounts. No event is raised.
+    pub fn anonymize(
+        &mut self,
+        pseudonym: CustomerId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        // Business rule: orders in progress still need the customer to be fulfilled
+        if !self.status.is_terminal() {
+            return Err(DomainError::CannotAnonymizeOrderInProgress);
+        }
+
+        self.customer_id = pseudonym;
+        self.billing_address = None;
+        self.shipping_address = None;
+        for shipment in &mut self.shipments {
+            shipment.anonymize();
+        }
+        self.updated_at = clock.now();
+
+        Ok(())
+    }
+
     /// Business logic: add item (only in Pending status)
-    pub fn add_item(&mut self, item: OrderItem) -> Result<(), DomainError> {
+    pub fn add_item(&mut self, item: OrderItem, clock: &dyn Clock) -> Result<(), DomainError> {
         if !self.status.can_be_modified() {
             return Err(DomainError::CannotModifyNonPendingOrder);
         }
+        if self.items.len() >= Self::MAX_ITEMS {
+            return Err(DomainError::TooManyItems {
+                max: Self::MAX_ITEMS,
+            });
+        }
 
+        // Compute the new total first: a failure (currency mismatch) leaves the order untouched
+        self.total = (self.total + item.subtotal())?;
         self.items.push(item);
-        self.total = Self::calculate_total(&self.items)?;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
 
         Ok(())
     }
 
     /// Business logic: remove item (only in Pending status)
-    pub fn remove_item(&mut self, item_id: crate::domain::value_objects::OrderItemId) -> Result<(), DomainError> {
+    pub fn remove_item(
+        &mut self,
+        item_id: crate::domain::value_objects::OrderItemId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
         if !self.status.can_be_modified() {
             return Err(DomainError::CannotModifyNonPendingOrder);
         }
 
-        let initial_len = self.items.len();
-        self.items.retain(|item| item.id() != item_id);
+        let position = self
+            .items
+            .iter()
+            .position(|item| item.id() == item_id)
+            .ok_or(DomainError::OrderItemNotFound)?;
 
-        if self.items.len() == initial_len {
-            return Err(DomainError::OrderItemNotFound);
+        if self.items.len() == 1 {
+            return Err(DomainError::CannotRemoveLastItem);
         }
 
-        if self.items.is_empty() {
-            return Err(DomainError::CannotRemoveLastItem);
+        self.items.remove(position);
+        self.total = Self::calculate_total(&self.items)?;
+        self.updated_at = clock.now();
+
+        Ok(())
+    }
+
+    /// Business logic: change the quantity of an item (only in Pending status), repriced
+    /// with the prices of the customer
+    pub fn change_item_quantity(
+        &mut self,
+        item_id: OrderItemId,
+        quantity: u32,
+        pricing: &Pricing,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        if !self.status.can_be_modified() {
+            return Err(DomainError::CannotModifyNonPendingOrder);
         }
 
+        let item = self
+            .items
+            .iter_mut()
+            .find(|item| item.id() == item_id)
+            .ok_or(DomainError::OrderItemNotFound)?;
+        item.change_quantity(quantity, pricing)?;
         self.total = Self::calculate_total(&self.items)?;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
+
+        Ok(())
+    }
+
+    /// Fulfilment: pack some items (and quantities) in a new parcel (only in Paid status)
+    pub fn create_shipment(
+        &mut self,
+        lines: Vec<ShipmentLine>,
+        ids: &dyn IdGenerator,
+        clock: &dyn Clock,
+    ) -> Result<ShipmentId, DomainError> {
+        self.ensure_awaiting_shipment()?;
+
+        let now = clock.now();
+        let shipment = Shipment::new(ShipmentId::generate(ids), lines, now)?;
+
+        // Business rule: a unit is packed in at most one parcel
+        for line in shipment.lines() {
+            let item = self
+                .items
+                .iter()
+                .find(|item| item.id() == line.order_item_id)
+                .ok_or(DomainError::OrderItemNotFound)?;
+            if self.packed_quantity(item.id()) + line.quantity > item.quantity() {
+                return Err(DomainError::ShipmentExceedsOrderedQuantity);
+            }
+        }
+
+        let shipment_id = shipment.id();
+        self.add_event(OrderEvent::ShipmentCreated {
+            order_id: self.id,
+            shipment_id,
+            lines: shipment.lines().to_vec(),
+            timestamp: now,
+        });
+        self.shipments.push(shipment);
+        self.updated_at = now;
+
+        Ok(shipment_id)
+    }
+
+    /// Fulfilment: hand a parcel over to the carrier
+    ///
+    /// The order becomes Shipped once every unit has left in a shipped parcel.
+    pub fn ship_shipment(
+        &mut self,
+        shipment_id: ShipmentId,
+        tracking_number: String,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.ensure_awaiting_shipment()?;
+
+        let now = clock.now();
+        self.shipments
+            .iter_mut()
+            .find(|shipment| shipment.id() == shipment_id)
+            .ok_or(DomainError::ShipmentNotFound)?
+            .ship(tracking_number.clone(), now)?;
+        self.updated_at = now;
+
+        self.add_event(OrderEvent::ShipmentShipped {
+            order_id: self.id,
+            shipment_id,
+            tracking_number,
+            timestamp: now,
+        });
+
+        if self.is_fully_shipped() {
+            self.status = OrderStatus::Shipped;
+            let tracking_numbers: Vec<_> = self
+                .shipments
+                .iter()
+                .filter_map(Shipment::tracking_number)
+                .collect();
+            self.add_event(OrderEvent::OrderShipped {
+                order_id: self.id,
+                tracking_number: tracking_numbers.join(", "),
+                timestamp: now,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Fulfilment: move the lines of `merged_shipment_id` into `shipment_id` (both pending)
+    pub fn merge_shipments(
+        &mut self,
+        shipment_id: ShipmentId,
+        merged_shipment_id: ShipmentId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        self.ensure_awaiting_shipment()?;
+        if shipment_id == merged_shipment_id {
+            return Err(DomainError::CannotMergeShipmentWithItself);
+        }
+
+        let position = |id: ShipmentId| {
+            self.shipments
+                .iter()
+                .position(|shipment| shipment.id() == id)
+                .ok_or(DomainError::ShipmentNotFound)
+        };
+        let target = position(shipment_id)?;
+        let source = position(merged_shipment_id)?;
+        if self.shipments[target].status() == ShipmentStatus::Shipped
+            || self.shipments[source].status() == ShipmentStatus::Shipped
+        {
+            return Err(DomainError::ShipmentAlreadyShipped);
+        }
+
+        let merged = self.shipments.remove(source);
+        let target = if source < target { target - 1 } else { target };
+        self.shipments[target].absorb(merged)?;
+        self.updated_at = clock.now();
+
+        self.add_event(OrderEvent::ShipmentsMerged {
+            order_id: self.id,
+            shipment_id,
+            merged_shipment_id,
+            timestamp: self.updated_at,
+        });
+
+        Ok(())
+    }
+
+    fn ensure_on_hold(&self, to: OrderStatus) -> Result<(), DomainError> {
+        if self.status != OrderStatus::OnHold {
+            return Err(DomainError::InvalidStatusTransition {
+                from: self.status,
+                to,
+            });
+        }
+        Ok(())
+    }
 
+    fn ensure_awaiting_shipment(&self) -> Result<(), DomainError> {
+        if self.status != OrderStatus::Paid {
+            return Err(DomainError::OrderNotAwaitingShipment);
+        }
         Ok(())
     }
 
+    /// Units of an item already packed in a parcel
+    fn packed_quantity(&self, item_id: OrderItemId) -> u32 {
+        self.shipments
+            .iter()
+            .map(|shipment| shipment.quantity_of(item_id))
+            .sum()
+    }
+
+    fn is_fully_shipped(&self) -> bool {
+        self.shipments
+            .iter()
+            .all(|shipment| shipment.status() == ShipmentStatus::Shipped)
+            && self
+                .items
+                .iter()
+                .all(|item| self.packed_quantity(item.id()) == item.quantity())
+    }
+
     /// Calculate total from items (business logic)
+    /// Every item must be priced in the currency of the first one
     fn calculate_total(items: &[OrderItem]) -> Result<Money, DomainError> {
+        let currency = items
+            .first()
+            .ok_or(DomainError::EmptyOrder)?
+            .unit_price()
+            .currency();
         items
             .iter()
-            .try_fold(Money::eur(Decimal::ZERO)?, |acc, item| {
+            .try_fold(Money::new(Decimal::ZERO, currency)?, |acc, item| {
                 (acc + item.subtotal()).map_err(DomainError::from)
             })
     }
@@ -204,6 +602,10 @@ impl Order {
         self.id
     }
 
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
     pub fn customer_id(&self) -> CustomerId {
         self.customer_id
     }
@@ -216,10 +618,36 @@ impl Order {
         self.total
     }
 
+    pub fn tax_rule(&self) -> TaxRule {
+        self.tax
+    }
+
+    /// Tax included in the total, or due on top of it
+    pub fn tax(&self) -> Money {
+        self.tax.tax_on(self.total)
+    }
+
+    /// What the customer pays
+    pub fn total_including_tax(&self) -> Money {
+        self.tax.total_including_tax(self.total)
+    }
+
     pub fn items(&self) -> &[OrderItem] {
         &self.items
     }
 
+    pub fn shipments(&self) -> &[Shipment] {
+        &self.shipments
+    }
+
+    pub fn billing_address(&self) -> Option<&PostalAddress> {
+        self.billing_address.as_ref()
+    }
+
+    pub fn shipping_address(&self) -> Option<&PostalAddress> {
+        self.shipping_address.as_ref()
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -245,7 +673,12 @@ impl Order {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::value_objects::{Currency, ProductId};
+    use crate::domain::clock::{FixedClock, SystemClock};
+    use crate::domain::events::ERASED;
+    use crate::domain::id_generator::{SequentialIdGenerator, UuidV4Generator};
+    use crate::domain::pricing::{PriceList, PriceRule};
+    use crate::domain::value_objects::ProductId;
+    use chrono::Duration;
 
     fn create_test_item() -> OrderItem {
         OrderItem::new(
@@ -253,6 +686,7 @@ mod tests {
             "Test Product".to_string(),
             1,
             Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
         )
         .unwrap()
     }
@@ -260,45 +694,511 @@ mod tests {
     #[test]
     fn test_order_creation() {
         let items = vec![create_test_item()];
-        let order = Order::create(CustomerId::new(), items).unwrap();
+        let order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
 
         assert_eq!(order.status(), OrderStatus::Pending);
         assert_eq!(order.items().len(), 1);
         assert!(!order.events().is_empty());
     }
 
+    #[test]
+    fn test_identity_and_creation_time_are_injected() {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+        let order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![create_test_item()],
+            &ids,
+            &clock,
+        )
+        .unwrap();
+
+        assert_eq!(order.id().value(), Uuid::from_u128(1));
+        assert_eq!(order.created_at(), clock.now());
+        assert_eq!(order.updated_at(), clock.now());
+        assert_eq!(order.events()[0].timestamp(), clock.now());
+    }
+
     #[test]
     fn test_empty_order_fails() {
-        let result = Order::create(CustomerId::new(), vec![]);
+        let result = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![],
+            &UuidV4Generator,
+            &SystemClock,
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_order_confirmation() {
         let items = vec![create_test_item()];
-        let mut order = Order::create(CustomerId::new(), items).unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
 
-        order.confirm().unwrap();
+        order.confirm(&SystemClock).unwrap();
         assert_eq!(order.status(), OrderStatus::Confirmed);
     }
 
+    #[test]
+    fn test_held_order_is_confirmed_or_cancelled_by_its_review() {
+        let clock = FixedClock::new(Utc::now());
+        let create = || {
+            Order::create(
+                &Tenant::default(),
+                CustomerId::new(),
+                vec![create_test_item()],
+                &UuidV4Generator,
+                &clock,
+            )
+            .unwrap()
+        };
+
+        let mut approved = create();
+        assert!(approved.approve_review(&clock).is_err());
+        approved
+            .hold_for_review(80, vec!["velocity".to_string()], &clock)
+            .unwrap();
+        assert_eq!(approved.status(), OrderStatus::OnHold);
+        assert!(matches!(
+            approved.confirm(&clock),
+            Err(DomainError::OrderAwaitingReview)
+        ));
+        assert!(approved.add_item(create_test_item(), &clock).is_err());
+        approved.approve_review(&clock).unwrap();
+        assert_eq!(approved.status(), OrderStatus::Confirmed);
+        assert!(matches!(
+            approved.events().last(),
+            Some(OrderEvent::OrderReviewApproved { .. })
+        ));
+
+        let mut rejected = create();
+        rejected.hold_for_review(80, Vec::new(), &clock).unwrap();
+        assert!(matches!(
+            rejected.reject_review(" ".to_string(), &clock),
+            Err(DomainError::EmptyRejectionReason)
+        ));
+        rejected
+            .reject_review("Stolen card".to_string(), &clock)
+            .unwrap();
+        assert_eq!(rejected.status(), OrderStatus::Cancelled);
+        assert!(rejected.hold_for_review(80, Vec::new(), &clock).is_err());
+    }
+
     #[test]
     fn test_invalid_state_transition() {
         let items = vec![create_test_item()];
-        let mut order = Order::create(CustomerId::new(), items).unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
 
         // Cannot go directly from Pending to Shipped
-        let result = order.ship("TRACK123".to_string());
+        let result = order.ship("TRACK123".to_string(), &SystemClock);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_cannot_modify_confirmed_order() {
         let items = vec![create_test_item()];
-        let mut order = Order::create(CustomerId::new(), items).unwrap();
-        order.confirm().unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order.confirm(&SystemClock).unwrap();
 
-        let result = order.add_item(create_test_item());
+        let result = order.add_item(create_test_item(), &SystemClock);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_item_count_is_capped() {
+        let items = (0..Order::MAX_ITEMS + 1)
+            .map(|_| create_test_item())
+            .collect();
+        let result = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        );
+        assert!(matches!(result, Err(DomainError::TooManyItems { .. })));
+
+        let items = (0..Order::MAX_ITEMS).map(|_| create_test_item()).collect();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        let total = order.total();
+        let result = order.add_item(create_test_item(), &SystemClock);
+        assert!(matches!(
+            result,
+            Err(DomainError::TooManyItems {
+                max: Order::MAX_ITEMS
+            })
+        ));
+        assert_eq!(order.items().len(), Order::MAX_ITEMS);
+        assert_eq!(order.total(), total);
+    }
+
+    #[test]
+    fn test_cannot_remove_last_item() {
+        let items = vec![create_test_item()];
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        let item_id = order.items()[0].id();
+
+        let result = order.remove_item(item_id, &SystemClock);
+        assert!(matches!(result, Err(DomainError::CannotRemoveLastItem)));
+        assert_eq!(order.items().len(), 1);
+    }
+
+    #[test]
+    fn test_add_item_in_other_currency_leaves_order_untouched() {
+        let items = vec![create_test_item()];
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Imported Product".to_string(),
+            1,
+            Money::usd(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+
+        let result = order.add_item(item, &SystemClock);
+        assert!(matches!(result, Err(DomainError::MoneyError(_))));
+        assert_eq!(order.items().len(), 1);
+        assert_eq!(order.total(), Money::eur(Decimal::new(1000, 2)).unwrap());
+    }
+
+    #[test]
+    fn test_changed_quantity_reprices_the_item_and_the_total() {
+        let item = create_test_item();
+        let pricing = PriceList::new(vec![PriceRule::new(
+            "tier-10",
+            item.product_id(),
+            Money::eur(Decimal::new(900, 2)).unwrap(),
+        )
+        .from_quantity(10)])
+        .unwrap()
+        .pricing(None, Utc::now());
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        let item_id = order.items()[0].id();
+
+        order
+            .change_item_quantity(item_id, 10, &pricing, &SystemClock)
+            .unwrap();
+        assert_eq!(order.items()[0].price_rule(), Some("tier-10"));
+        assert_eq!(order.total(), Money::eur(Decimal::new(9000, 2)).unwrap());
+
+        order.confirm(&SystemClock).unwrap();
+        let result = order.change_item_quantity(item_id, 1, &pricing, &SystemClock);
+        assert!(matches!(
+            result,
+            Err(DomainError::CannotModifyNonPendingOrder)
+        ));
+    }
+
+    #[test]
+    fn test_transitions_are_stamped_by_the_clock() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![create_test_item()],
+            &UuidV4Generator,
+            &clock,
+        )
+        .unwrap();
+        assert_eq!(order.created_at(), clock.now());
+        clock.advance(Duration::days(1));
+
+        order.confirm(&clock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), &clock).unwrap();
+        clock.advance(Duration::hours(3));
+        order.ship("TRACK123".to_string(), &clock).unwrap();
+        clock.advance(Duration::days(2));
+        order.deliver(&clock).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Delivered);
+        assert_eq!(order.updated_at(), clock.now());
+        assert_eq!(order.events().last().unwrap().timestamp(), clock.now());
+    }
+
+    fn paid_order(clock: &dyn Clock) -> Order {
+        let items = vec![
+            OrderItem::new(
+                ProductId::new(),
+                "Keyboard".to_string(),
+                2,
+                Money::eur(Decimal::new(4990, 2)).unwrap(),
+                &UuidV4Generator,
+            )
+            .unwrap(),
+            create_test_item(),
+        ];
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            clock,
+        )
+        .unwrap();
+        order.confirm(clock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), clock).unwrap();
+        order.take_events();
+        order
+    }
+
+    fn line(item: &OrderItem, quantity: u32) -> ShipmentLine {
+        ShipmentLine {
+            order_item_id: item.id(),
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_order_ships_when_every_shipment_has_shipped() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = paid_order(&clock);
+        let (keyboard, other) = (order.items()[0].clone(), order.items()[1].clone());
+
+        let first = order
+            .create_shipment(vec![line(&keyboard, 1)], &UuidV4Generator, &clock)
+            .unwrap();
+        let second = order
+            .create_shipment(
+                vec![line(&keyboard, 1), line(&other, 1)],
+                &UuidV4Generator,
+                &clock,
+            )
+            .unwrap();
+        assert_eq!(order.shipments().len(), 2);
+
+        order
+            .ship_shipment(first, "TRK-1".to_string(), &clock)
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Paid);
+
+        clock.advance(Duration::hours(2));
+        order
+            .ship_shipment(second, "TRK-2".to_string(), &clock)
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Shipped);
+        assert_eq!(order.updated_at(), clock.now());
+
+        let names: Vec<_> = order.events().iter().map(OrderEvent::event_name).collect();
+        assert_eq!(
+            names,
+            [
+                "SHIPMENT_CREATED",
+                "SHIPMENT_CREATED",
+                "SHIPMENT_SHIPPED",
+                "SHIPMENT_SHIPPED",
+                "ORDER_SHIPPED"
+            ]
+        );
+        assert!(matches!(
+            order.events().last(),
+            Some(OrderEvent::OrderShipped { tracking_number, .. }) if tracking_number == "TRK-1, TRK-2"
+        ));
+    }
+
+    #[test]
+    fn test_order_waits_for_unpacked_quantities() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = paid_order(&clock);
+        let keyboard = order.items()[0].clone();
+
+        let shipment = order
+            .create_shipment(vec![line(&keyboard, 2)], &UuidV4Generator, &clock)
+            .unwrap();
+        order
+            .ship_shipment(shipment, "TRK-1".to_string(), &clock)
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Paid);
+        assert!(matches!(
+            order.ship_shipment(shipment, "TRK-1".to_string(), &clock),
+            Err(DomainError::ShipmentAlreadyShipped)
+        ));
+    }
+
+    #[test]
+    fn test_shipments_cannot_exceed_ordered_quantities() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = paid_order(&clock);
+        let keyboard = order.items()[0].clone();
+        order
+            .create_shipment(vec![line(&keyboard, 1)], &UuidV4Generator, &clock)
+            .unwrap();
+
+        let result = order.create_shipment(vec![line(&keyboard, 2)], &UuidV4Generator, &clock);
+        assert!(matches!(
+            result,
+            Err(DomainError::ShipmentExceedsOrderedQuantity)
+        ));
+
+        let unknown = ShipmentLine {
+            order_item_id: OrderItemId::new(),
+            quantity: 1,
+        };
+        let result = order.create_shipment(vec![unknown], &UuidV4Generator, &clock);
+        assert!(matches!(result, Err(DomainError::OrderItemNotFound)));
+        assert_eq!(order.shipments().len(), 1);
+    }
+
+    #[test]
+    fn test_shipments_require_a_paid_order() {
+        let clock = FixedClock::new(Utc::now());
+        let items = vec![create_test_item()];
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            items,
+            &UuidV4Generator,
+            &clock,
+        )
+        .unwrap();
+        let item = order.items()[0].clone();
+
+        let result = order.create_shipment(vec![line(&item, 1)], &UuidV4Generator, &clock);
+        assert!(matches!(result, Err(DomainError::OrderNotAwaitingShipment)));
+    }
+
+    #[test]
+    fn test_merged_shipments_ship_as_one() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = paid_order(&clock);
+        let (keyboard, other) = (order.items()[0].clone(), order.items()[1].clone());
+        let first = order
+            .create_shipment(vec![line(&keyboard, 2)], &UuidV4Generator, &clock)
+            .unwrap();
+        let second = order
+            .create_shipment(vec![line(&other, 1)], &UuidV4Generator, &clock)
+            .unwrap();
+
+        assert!(matches!(
+            order.merge_shipments(first, first, &clock),
+            Err(DomainError::CannotMergeShipmentWithItself)
+        ));
+        order.merge_shipments(second, first, &clock).unwrap();
+        assert_eq!(order.shipments().len(), 1);
+        assert_eq!(order.shipments()[0].id(), second);
+        assert_eq!(order.shipments()[0].quantity_of(keyboard.id()), 2);
+
+        order
+            .ship_shipment(second, "TRK-1".to_string(), &clock)
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::Shipped);
+    }
+
+    #[test]
+    fn test_single_parcel_ship_is_refused_once_split() {
+        let clock = FixedClock::new(Utc::now());
+        let mut order = paid_order(&clock);
+        let keyboard = order.items()[0].clone();
+        order
+            .create_shipment(vec![line(&keyboard, 1)], &UuidV4Generator, &clock)
+            .unwrap();
+
+        let result = order.ship("TRK-1".to_string(), &clock);
+        assert!(matches!(result, Err(DomainError::OrderHasShipments)));
+        assert_eq!(order.status(), OrderStatus::Paid);
+    }
+
+    #[test]
+    fn test_anonymized_order_keeps_its_totals() {
+        let clock = FixedClock::new(Utc::now());
+        let home = PostalAddress {
+            line1: "1 Main Street".to_string(),
+            postal_code: "75002".to_string(),
+            city: "Paris".to_string(),
+            country: "FR".to_string(),
+        };
+        let mut order = paid_order(&clock).with_addresses(Some(home.clone()), Some(home));
+        let (keyboard, other) = (order.items()[0].clone(), order.items()[1].clone());
+        let pseudonym = CustomerId::new();
+
+        let result = order.anonymize(pseudonym, &clock);
+        assert!(matches!(
+            result,
+            Err(DomainError::CannotAnonymizeOrderInProgress)
+        ));
+
+        for item in [&keyboard, &other] {
+            let shipment = order
+                .create_shipment(vec![line(item, item.quantity())], &UuidV4Generator, &clock)
+                .unwrap();
+            order
+                .ship_shipment(shipment, "TRK-1".to_string(), &clock)
+                .unwrap();
+        }
+        order.deliver(&clock).unwrap();
+        let (total, tax) = (order.total_including_tax(), order.tax());
+
+        clock.advance(Duration::days(30));
+        order.anonymize(pseudonym, &clock).unwrap();
+        assert_eq!(order.customer_id(), pseudonym);
+        assert!(order.billing_address().is_none());
+        assert!(order.shipping_address().is_none());
+        assert!(order
+            .shipments()
+            .iter()
+            .all(|shipment| shipment.tracking_number() == Some(ERASED)));
+        assert_eq!(order.total_including_tax(), total);
+        assert_eq!(order.tax(), tax);
+        assert_eq!(order.items().len(), 2);
+        assert_eq!(order.updated_at(), clock.now());
+    }
 }
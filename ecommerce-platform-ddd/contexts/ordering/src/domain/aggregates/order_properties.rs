@@ -0,0 +1,206 @@
+//! Property tests: random command sequences must never break the `Order` invariants
+
+use super::Order;
+use crate::domain::{
+    clock::{Clock, FixedClock},
+    entities::OrderItem,
+    errors::DomainError,
+    id_generator::SequentialIdGenerator,
+    tenant::Tenant,
+    value_objects::{Currency, CustomerId, Money, OrderItemId, OrderStatus, ProductId},
+};
+use chrono::{Duration, Utc};
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+enum Command {
+    AddItem(ItemSpec),
+    /// Remove the item at `index % len`
+    RemoveItem(usize),
+    RemoveUnknownItem,
+    Confirm,
+    HoldForReview,
+    ApproveReview,
+    RejectReview,
+    MarkAsPaid,
+    Ship,
+    Deliver,
+    Cancel,
+}
+
+#[derive(Debug, Clone)]
+struct ItemSpec {
+    quantity: u32,
+    cents: i64,
+    currency: Currency,
+}
+
+fn item_spec() -> impl Strategy<Value = ItemSpec> {
+    (
+        0u32..5,
+        0i64..100_000,
+        prop_oneof![8 => Just(Currency::EUR), 1 => Just(Currency::USD)],
+    )
+        .prop_map(|(quantity, cents, currency)| ItemSpec {
+            quantity,
+            cents,
+            currency,
+        })
+}
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        4 => item_spec().prop_map(Command::AddItem),
+        3 => any::<usize>().prop_map(Command::RemoveItem),
+        1 => Just(Command::RemoveUnknownItem),
+        2 => Just(Command::Confirm),
+        1 => Just(Command::HoldForReview),
+        1 => Just(Command::ApproveReview),
+        1 => Just(Command::RejectReview),
+        2 => Just(Command::MarkAsPaid),
+        2 => Just(Command::Ship),
+        1 => Just(Command::Deliver),
+        1 => Just(Command::Cancel),
+    ]
+}
+
+fn build_item(spec: &ItemSpec, ids: &SequentialIdGenerator) -> Result<OrderItem, DomainError> {
+    OrderItem::new(
+        ProductId::new(),
+        "Product".to_string(),
+        spec.quantity,
+        Money::new(Decimal::new(spec.cents, 2), spec.currency)?,
+        ids,
+    )
+}
+
+/// Observable state that a failed command must leave unchanged
+fn snapshot(order: &Order) -> (OrderStatus, Vec<OrderItemId>, Money, usize) {
+    let item_ids = order.items().iter().map(OrderItem::id).collect();
+    (
+        order.status(),
+        item_ids,
+        order.total(),
+        order.events().len(),
+    )
+}
+
+fn assert_invariants(order: &Order) -> Result<(), TestCaseError> {
+    prop_assert!(!order.items().is_empty(), "an order always keeps an item");
+    prop_assert!(order.items().len() <= Order::MAX_ITEMS);
+
+    let sum = order
+        .items()
+        .iter()
+        .map(|item| item.subtotal().amount())
+        .sum::<Decimal>();
+    prop_assert_eq!(order.total().amount(), sum);
+    prop_assert_eq!(order.total().currency(), Currency::EUR);
+    prop_assert!(order
+        .items()
+        .iter()
+        .all(|item| item.unit_price().currency() == Currency::EUR));
+    prop_assert!(order.updated_at() >= order.created_at());
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn test_random_command_sequences_keep_invariants(
+        initial in prop::collection::vec(item_spec(), 1..4),
+        commands in prop::collection::vec(command(), 0..40),
+    ) {
+        let ids = SequentialIdGenerator::new();
+        let clock = FixedClock::new(Utc::now());
+
+        let items: Result<Vec<_>, _> = initial.iter().map(|spec| build_item(spec, &ids)).collect();
+        let created = items.and_then(|items| Order::create(&Tenant::default(), CustomerId::new(), items, &ids, &clock));
+        let Ok(mut order) = created else {
+            // Invalid initial items (zero quantity, mixed currencies) are rejected up front
+            prop_assert!(initial.iter().any(|spec| spec.quantity == 0)
+                || initial.iter().any(|spec| spec.currency != Currency::EUR));
+            return Ok(());
+        };
+        prop_assert_eq!(order.status(), OrderStatus::Pending);
+        prop_assert_eq!(order.events().len(), 1);
+        assert_invariants(&order)?;
+
+        for command in commands {
+            clock.advance(Duration::minutes(1));
+            let before = snapshot(&order);
+            let status_before = order.status();
+
+            let result = match &command {
+                Command::AddItem(spec) => build_item(spec, &ids)
+                    .and_then(|item| order.add_item(item, &clock)),
+                Command::RemoveItem(index) => {
+                    let item_id = order.items()[index % order.items().len()].id();
+                    order.remove_item(item_id, &clock)
+                }
+                Command::RemoveUnknownItem => {
+                    order.remove_item(OrderItemId::from_uuid(Uuid::new_v4()), &clock)
+                }
+                Command::Confirm => order.confirm(&clock),
+                Command::HoldForReview => {
+                    order.hold_for_review(50, vec!["velocity".to_string()], &clock)
+                }
+                Command::ApproveReview => order.approve_review(&clock),
+                Command::RejectReview => {
+                    order.reject_review("Stolen card".to_string(), &clock)
+                }
+                Command::MarkAsPaid => order.mark_as_paid(Uuid::new_v4(), &clock),
+                Command::Ship => order.ship("TRACK".to_string(), &clock),
+                Command::Deliver => order.deliver(&clock),
+                Command::Cancel => order.cancel("Changed my mind".to_string(), &clock),
+            };
+
+            match result {
+                Err(err) => {
+                    // A rejected command changes nothing
+                    prop_assert_eq!(snapshot(&order), before);
+                    match (&command, &err) {
+                        (Command::RemoveItem(_), DomainError::CannotRemoveLastItem) => {
+                            prop_assert_eq!(order.items().len(), 1);
+                        }
+                        (Command::RemoveItem(_), DomainError::CannotModifyNonPendingOrder)
+                        | (Command::AddItem(_), DomainError::CannotModifyNonPendingOrder) => {
+                            prop_assert_ne!(status_before, OrderStatus::Pending);
+                        }
+                        (Command::AddItem(_), DomainError::TooManyItems { max }) => {
+                            prop_assert_eq!(order.items().len(), *max);
+                        }
+                        (Command::Cancel, DomainError::CannotCancelTerminalOrder) => {
+                            prop_assert!(status_before.is_terminal());
+                        }
+                        (Command::Confirm, DomainError::OrderAwaitingReview) => {
+                            prop_assert_eq!(status_before, OrderStatus::OnHold);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(()) => {
+                    prop_assert_eq!(order.updated_at(), clock.now());
+                    let new_events = &order.events()[before.3..];
+                    if order.status() == status_before {
+                        // Item changes are only allowed while pending and raise no event
+                        prop_assert_eq!(status_before, OrderStatus::Pending);
+                        prop_assert!(new_events.is_empty());
+                    } else {
+                        prop_assert!(status_before.can_transition_to(order.status()));
+                        prop_assert_eq!(new_events.len(), 1);
+                        prop_assert_eq!(new_events[0].status(), Some(order.status()));
+                        prop_assert_eq!(new_events[0].timestamp(), clock.now());
+                    }
+                    if matches!(command, Command::RemoveItem(_)) {
+                        prop_assert_eq!(order.items().len(), before.1.len() - 1);
+                    }
+                }
+            }
+            assert_invariants(&order)?;
+        }
+    }
+}
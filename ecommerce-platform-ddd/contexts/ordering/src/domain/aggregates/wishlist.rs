@@ -0,0 +1,319 @@
+use crate::domain::{
+    clock::Clock,
+    entities::WishlistItem,
+    errors::DomainError,
+    value_objects::{CustomerId, ProductId, ShareToken, TenantId},
+};
+use chrono::{DateTime, Utc};
+
+/// Wishlist Aggregate Root
+/// Products a customer saved for later, moved into orders when they decide to buy
+///
+/// A customer has one wishlist per tenant, identified by the tenant and the customer. It can
+/// be shared read-only through a link holding its share token.
+#[derive(Debug, Clone)]
+pub struct Wishlist {
+    // Identity
+    tenant_id: TenantId,
+    customer_id: CustomerId,
+
+    // State
+    items: Vec<WishlistItem>,
+    // None while the wishlist is private
+    share_token: Option<ShareToken>,
+
+    // Audit
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Wishlist {
+    /// Business rule: a wishlist holds at most this many products
+    pub const MAX_ITEMS: usize = 100;
+
+    /// Factory method: the empty, private wishlist of a customer on a tenant
+    pub fn create(tenant_id: TenantId, customer_id: CustomerId, clock: &dyn Clock) -> Self {
+        let now = clock.now();
+        Self {
+            tenant_id,
+            customer_id,
+            items: Vec::new(),
+            share_token: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Rebuild a Wishlist from persistence
+    pub fn reconstitute(
+        tenant_id: TenantId,
+        customer_id: CustomerId,
+        items: Vec<WishlistItem>,
+        share_token: Option<ShareToken>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            tenant_id,
+            customer_id,
+            items,
+            share_token,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Business logic: save a product, or change the note and priority of a saved one
+    pub fn save_item(&mut self, item: WishlistItem, clock: &dyn Clock) -> Result<(), DomainError> {
+        let existing = self
+            .items
+            .iter_mut()
+            .find(|existing| existing.product_id() == item.product_id());
+        if let Some(existing) = existing {
+            existing.update_from(item);
+        } else if self.items.len() >= Self::MAX_ITEMS {
+            return Err(DomainError::WishlistFull {
+                max: Self::MAX_ITEMS,
+            });
+        } else {
+            self.items.push(item);
+        }
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: remove a saved product
+    pub fn remove_item(
+        &mut self,
+        product_id: ProductId,
+        clock: &dyn Clock,
+    ) -> Result<(), DomainError> {
+        let position = self
+            .items
+            .iter()
+            .position(|item| item.product_id() == product_id)
+            .ok_or(DomainError::WishlistItemNotFound)?;
+        self.items.remove(position);
+        self.touch(clock);
+        Ok(())
+    }
+
+    /// Business logic: the saved products about to be ordered, in the order requested
+    ///
+    /// Nothing selected is an empty order; every selected product must be saved.
+    pub fn select(&self, product_ids: &[ProductId]) -> Result<Vec<&WishlistItem>, DomainError> {
+        if product_ids.is_empty() {
+            return Err(DomainError::EmptyOrder);
+        }
+        product_ids
+            .iter()
+            .map(|product_id| {
+                self.items
+                    .iter()
+                    .find(|item| item.product_id() == *product_id)
+                    .ok_or(DomainError::WishlistItemNotFound)
+            })
+            .collect()
+    }
+
+    /// Business logic: drop the products moved into an order (unknown ones are ignored)
+    pub fn remove_items(&mut self, product_ids: &[ProductId], clock: &dyn Clock) {
+        self.items
+            .retain(|item| !product_ids.contains(&item.product_id()));
+        self.touch(clock);
+    }
+
+    /// Business logic: give read access through a link, returning its token
+    ///
+    /// Sharing again keeps the same link; a new token is only drawn once the
+    /// previous one was revoked by `stop_sharing`.
+    pub fn share(&mut self, clock: &dyn Clock) -> ShareToken {
+        if let Some(token) = &self.share_token {
+            return token.clone();
+        }
+        let token = ShareToken::generate();
+        self.share_token = Some(token.clone());
+        self.touch(clock);
+        token
+    }
+
+    /// Business logic: revoke the link, the wishlist becomes private again
+    pub fn stop_sharing(&mut self, clock: &dyn Clock) {
+        if self.share_token.take().is_some() {
+            self.touch(clock);
+        }
+    }
+
+    /// Saved products, highest priority first, then oldest first
+    pub fn items_by_priority(&self) -> Vec<&WishlistItem> {
+        let mut items: Vec<&WishlistItem> = self.items.iter().collect();
+        items.sort_by(|a, b| {
+            b.priority()
+                .cmp(&a.priority())
+                .then(a.added_at().cmp(&b.added_at()))
+        });
+        items
+    }
+
+    fn touch(&mut self, clock: &dyn Clock) {
+        self.updated_at = clock.now();
+    }
+
+    // Getters (encapsulation)
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn customer_id(&self) -> CustomerId {
+        self.customer_id
+    }
+
+    pub fn items(&self) -> &[WishlistItem] {
+        &self.items
+    }
+
+    pub fn share_token(&self) -> Option<&ShareToken> {
+        self.share_token.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::FixedClock;
+    use crate::domain::value_objects::WishlistPriority;
+    use chrono::Duration;
+
+    fn item(product_id: ProductId, priority: WishlistPriority, clock: &FixedClock) -> WishlistItem {
+        WishlistItem::new(
+            product_id,
+            "Test Product".to_string(),
+            None,
+            priority,
+            clock.now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_saving_a_product_twice_updates_it() {
+        let clock = FixedClock::new(Utc::now());
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &clock);
+        let product = ProductId::new();
+        wishlist
+            .save_item(item(product, WishlistPriority::Low, &clock), &clock)
+            .unwrap();
+        let added_at = clock.now();
+
+        clock.advance(Duration::days(1));
+        let again = WishlistItem::new(
+            product,
+            "Test Product".to_string(),
+            Some("in blue".to_string()),
+            WishlistPriority::High,
+            clock.now(),
+        )
+        .unwrap();
+        wishlist.save_item(again, &clock).unwrap();
+
+        assert_eq!(wishlist.items().len(), 1);
+        assert_eq!(wishlist.items()[0].priority(), WishlistPriority::High);
+        assert_eq!(wishlist.items()[0].note(), Some("in blue"));
+        assert_eq!(wishlist.items()[0].added_at(), added_at);
+        assert_eq!(wishlist.updated_at(), clock.now());
+    }
+
+    #[test]
+    fn test_item_count_is_capped() {
+        let clock = FixedClock::new(Utc::now());
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &clock);
+        for _ in 0..Wishlist::MAX_ITEMS {
+            wishlist
+                .save_item(
+                    item(ProductId::new(), WishlistPriority::Normal, &clock),
+                    &clock,
+                )
+                .unwrap();
+        }
+
+        let result = wishlist.save_item(
+            item(ProductId::new(), WishlistPriority::Normal, &clock),
+            &clock,
+        );
+        assert!(matches!(result, Err(DomainError::WishlistFull { .. })));
+        assert!(matches!(
+            wishlist.remove_item(ProductId::new(), &clock),
+            Err(DomainError::WishlistItemNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_items_by_priority() {
+        let clock = FixedClock::new(Utc::now());
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &clock);
+        let (first, urgent, later) = (ProductId::new(), ProductId::new(), ProductId::new());
+        for (product, priority) in [
+            (first, WishlistPriority::Normal),
+            (later, WishlistPriority::Low),
+            (urgent, WishlistPriority::High),
+        ] {
+            wishlist
+                .save_item(item(product, priority, &clock), &clock)
+                .unwrap();
+            clock.advance(Duration::minutes(1));
+        }
+
+        let order: Vec<ProductId> = wishlist
+            .items_by_priority()
+            .iter()
+            .map(|item| item.product_id())
+            .collect();
+        assert_eq!(order, vec![urgent, first, later]);
+    }
+
+    #[test]
+    fn test_select_and_remove_ordered_items() {
+        let clock = FixedClock::new(Utc::now());
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &clock);
+        let (kept, ordered) = (ProductId::new(), ProductId::new());
+        for product in [kept, ordered] {
+            wishlist
+                .save_item(item(product, WishlistPriority::Normal, &clock), &clock)
+                .unwrap();
+        }
+
+        assert!(matches!(wishlist.select(&[]), Err(DomainError::EmptyOrder)));
+        assert!(matches!(
+            wishlist.select(&[ordered, ProductId::new()]),
+            Err(DomainError::WishlistItemNotFound)
+        ));
+        let selected = wishlist.select(&[ordered]).unwrap();
+        assert_eq!(selected[0].product_id(), ordered);
+
+        wishlist.remove_items(&[ordered], &clock);
+        assert_eq!(wishlist.items().len(), 1);
+        assert_eq!(wishlist.items()[0].product_id(), kept);
+    }
+
+    #[test]
+    fn test_share_until_revoked() {
+        let clock = FixedClock::new(Utc::now());
+        let mut wishlist = Wishlist::create(TenantId::default(), CustomerId::new(), &clock);
+
+        let token = wishlist.share(&clock);
+        assert_eq!(wishlist.share(&clock), token);
+        assert_eq!(wishlist.share_token(), Some(&token));
+
+        wishlist.stop_sharing(&clock);
+        assert_eq!(wishlist.share_token(), None);
+        assert_ne!(wishlist.share(&clock), token);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::{
+    errors::InfrastructureError,
+    value_objects::{Money, ProductId},
+};
+use async_trait::async_trait;
+
+/// Current selling prices, owned by the catalog (Port)
+/// Checked at checkout: the cart keeps the price the customer saw
+#[async_trait]
+pub trait PriceCatalog: Send + Sync {
+    /// Price of a product, `None` when it is no longer sold
+    async fn current_price(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Option<Money>, InfrastructureError>;
+}
+
+/// Units available for sale, owned by the inventory (Port)
+#[async_trait]
+pub trait StockChecker: Send + Sync {
+    async fn available_quantity(&self, product_id: ProductId) -> Result<u32, InfrastructureError>;
+}
@@ -0,0 +1,77 @@
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{Money, ProductId};
+use rust_decimal::Decimal;
+
+/// CartLine Entity
+/// Part of the Cart aggregate, identified by its product (one line per product)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartLine {
+    product_id: ProductId,
+    product_name: String,
+    quantity: u32,
+    /// Price seen by the customer when the product was added
+    unit_price: Money,
+}
+
+impl CartLine {
+    /// Factory method with validation
+    pub fn new(
+        product_id: ProductId,
+        product_name: String,
+        quantity: u32,
+        unit_price: Money,
+    ) -> Result<Self, DomainError> {
+        // Business rule: quantity must be positive
+        if quantity == 0 {
+            return Err(DomainError::InvalidQuantity);
+        }
+
+        // Business rule: product name cannot be empty
+        if product_name.trim().is_empty() {
+            return Err(DomainError::InvalidProductName);
+        }
+
+        Ok(Self {
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+        })
+    }
+
+    /// Business logic: calculate subtotal
+    pub fn subtotal(&self) -> Money {
+        let amount = self.unit_price.amount() * Decimal::from(self.quantity);
+        Money::new(amount, self.unit_price.currency())
+            .expect("Subtotal calculation should always produce valid money")
+    }
+
+    pub(crate) fn set_quantity(&mut self, quantity: u32) -> Result<(), DomainError> {
+        if quantity == 0 {
+            return Err(DomainError::InvalidQuantity);
+        }
+        self.quantity = quantity;
+        Ok(())
+    }
+
+    pub(crate) fn set_unit_price(&mut self, unit_price: Money) {
+        self.unit_price = unit_price;
+    }
+
+    // Getters
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    pub fn unit_price(&self) -> Money {
+        self.unit_price
+    }
+}
@@ -0,0 +1,82 @@
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{Money, OrderItemId, ProductId};
+use rust_decimal::Decimal;
+
+/// InvoiceLine Entity
+/// Part of the Invoice aggregate, copied from the order item it bills
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceLine {
+    order_item_id: OrderItemId,
+    product_id: ProductId,
+    description: String,
+    quantity: u32,
+    unit_price: Money,
+}
+
+impl InvoiceLine {
+    /// Factory method with validation
+    pub fn new(
+        order_item_id: OrderItemId,
+        product_id: ProductId,
+        description: String,
+        quantity: u32,
+        unit_price: Money,
+    ) -> Result<Self, DomainError> {
+        // Business rule: quantity must be positive
+        if quantity == 0 {
+            return Err(DomainError::InvalidQuantity);
+        }
+
+        // Business rule: description cannot be empty
+        if description.trim().is_empty() {
+            return Err(DomainError::InvalidProductName);
+        }
+
+        Ok(Self {
+            order_item_id,
+            product_id,
+            description,
+            quantity,
+            unit_price,
+        })
+    }
+
+    /// Business logic: calculate subtotal
+    pub fn subtotal(&self) -> Money {
+        let amount = self.unit_price.amount() * Decimal::from(self.quantity);
+        Money::new(amount, self.unit_price.currency())
+            .expect("Subtotal calculation should always produce valid money")
+    }
+
+    /// Same line, for another quantity
+    pub(crate) fn with_quantity(&self, quantity: u32) -> Result<Self, DomainError> {
+        Self::new(
+            self.order_item_id,
+            self.product_id,
+            self.description.clone(),
+            quantity,
+            self.unit_price,
+        )
+    }
+
+    // Getters
+    pub fn order_item_id(&self) -> OrderItemId {
+        self.order_item_id
+    }
+
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn quantity(&self) -> u32 {
+        self.quantity
+    }
+
+    pub fn unit_price(&self) -> Money {
+        self.unit_price
+    }
+}
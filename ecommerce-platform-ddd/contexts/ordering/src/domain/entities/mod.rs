@@ -1,3 +1,11 @@
+pub mod cart_line;
+pub mod invoice_line;
 pub mod order_item;
+pub mod shipment;
+pub mod wishlist_item;
 
+pub use cart_line::CartLine;
+pub use invoice_line::InvoiceLine;
 pub use order_item::OrderItem;
+pub use shipment::{Shipment, ShipmentLine};
+pub use wishlist_item::WishlistItem;
@@ -1,5 +1,7 @@
 use crate::domain::value_objects::{Money, OrderItemId, ProductId};
 use crate::domain::errors::DomainError;
+use crate::domain::id_generator::IdGenerator;
+use crate::domain::pricing::Pricing;
 use rust_decimal::Decimal;
 
 /// OrderItem Entity
@@ -11,15 +13,41 @@ pub struct OrderItem {
     product_name: String,
     quantity: u32,
     unit_price: Money,
+    // Price before the price rules (catalog or caller price), kept to reprice the line
+    list_price: Money,
+    // Rule giving the unit price, `None` when sold at the list price
+    price_rule: Option<String>,
 }
 
 impl OrderItem {
-    /// Factory method with validation
+    /// Factory method with validation, sold at `unit_price` until priced
     pub fn new(
         product_id: ProductId,
         product_name: String,
         quantity: u32,
         unit_price: Money,
+        ids: &dyn IdGenerator,
+    ) -> Result<Self, DomainError> {
+        Self::reconstitute(
+            OrderItemId::generate(ids),
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+            unit_price,
+            None,
+        )
+    }
+
+    /// Rebuild an OrderItem from persistence, keeping its identity
+    pub fn reconstitute(
+        id: OrderItemId,
+        product_id: ProductId,
+        product_name: String,
+        quantity: u32,
+        unit_price: Money,
+        list_price: Money,
+        price_rule: Option<String>,
     ) -> Result<Self, DomainError> {
         // Business rule: quantity must be positive
         if quantity == 0 {
@@ -32,14 +60,31 @@ impl OrderItem {
         }
 
         Ok(Self {
-            id: OrderItemId::new(),
+            id,
             product_id,
             product_name,
             quantity,
             unit_price,
+            list_price,
+            price_rule,
         })
     }
 
+    /// Business rule: the unit price is the best price rule for the quantity, else the
+    /// list price
+    pub fn apply_pricing(&mut self, pricing: &Pricing) {
+        match pricing.best_rule(self.product_id, self.list_price, self.quantity) {
+            Some(rule) => {
+                self.unit_price = rule.unit_price;
+                self.price_rule = Some(rule.name.clone());
+            }
+            None => {
+                self.unit_price = self.list_price;
+                self.price_rule = None;
+            }
+        }
+    }
+
     /// Business logic: calculate subtotal
     pub fn subtotal(&self) -> Money {
         let amount = self.unit_price.amount() * Decimal::from(self.quantity);
@@ -47,12 +92,18 @@ impl OrderItem {
             .expect("Subtotal calculation should always produce valid money")
     }
 
-    /// Change quantity (business rule: must remain positive)
-    pub fn change_quantity(&mut self, new_quantity: u32) -> Result<(), DomainError> {
+    /// Change quantity (business rule: must remain positive), repricing the line since
+    /// the quantity tier may change
+    pub fn change_quantity(
+        &mut self,
+        new_quantity: u32,
+        pricing: &Pricing,
+    ) -> Result<(), DomainError> {
         if new_quantity == 0 {
             return Err(DomainError::InvalidQuantity);
         }
         self.quantity = new_quantity;
+        self.apply_pricing(pricing);
         Ok(())
     }
 
@@ -76,12 +127,21 @@ impl OrderItem {
     pub fn unit_price(&self) -> Money {
         self.unit_price
     }
+
+    pub fn list_price(&self) -> Money {
+        self.list_price
+    }
+
+    pub fn price_rule(&self) -> Option<&str> {
+        self.price_rule.as_deref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::value_objects::Currency;
+    use crate::domain::id_generator::{SequentialIdGenerator, UuidV4Generator};
+    use crate::domain::pricing::{PriceList, PriceRule};
 
     #[test]
     fn test_order_item_creation() {
@@ -90,6 +150,7 @@ mod tests {
             "Product A".to_string(),
             2,
             Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
         )
         .unwrap();
 
@@ -97,6 +158,21 @@ mod tests {
         assert_eq!(item.product_name(), "Product A");
     }
 
+    #[test]
+    fn test_identity_comes_from_the_generator() {
+        let ids = SequentialIdGenerator::starting_at(7);
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Product A".to_string(),
+            1,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &ids,
+        )
+        .unwrap();
+
+        assert_eq!(item.id().value(), uuid::Uuid::from_u128(7));
+    }
+
     #[test]
     fn test_zero_quantity_fails() {
         let result = OrderItem::new(
@@ -104,6 +180,7 @@ mod tests {
             "Product A".to_string(),
             0,
             Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
         );
         assert!(result.is_err());
     }
@@ -115,10 +192,44 @@ mod tests {
             "Product A".to_string(),
             3,
             Money::eur(Decimal::new(1000, 2)).unwrap(), // 10.00 EUR
+            &UuidV4Generator,
         )
         .unwrap();
 
         let subtotal = item.subtotal();
         assert_eq!(subtotal.amount(), Decimal::new(3000, 2)); // 30.00 EUR
     }
+
+    #[test]
+    fn test_changed_quantity_is_repriced() {
+        let product_id = ProductId::new();
+        let pricing = PriceList::new(vec![PriceRule::new(
+            "tier-10",
+            product_id,
+            Money::eur(Decimal::new(800, 2)).unwrap(),
+        )
+        .from_quantity(10)])
+        .unwrap()
+        .pricing(None, chrono::Utc::now());
+        let mut item = OrderItem::new(
+            product_id,
+            "Product A".to_string(),
+            1,
+            Money::eur(Decimal::new(1000, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+
+        item.change_quantity(10, &pricing).unwrap();
+        assert_eq!(item.unit_price().amount(), Decimal::new(800, 2));
+        assert_eq!(item.price_rule(), Some("tier-10"));
+
+        item.change_quantity(9, &pricing).unwrap();
+        assert_eq!(item.unit_price().amount(), Decimal::new(1000, 2));
+        assert_eq!(item.price_rule(), None);
+        assert!(matches!(
+            item.change_quantity(0, &pricing),
+            Err(DomainError::InvalidQuantity)
+        ));
+    }
 }
@@ -0,0 +1,212 @@
+use crate::domain::errors::DomainError;
+use crate::domain::events::ERASED;
+use crate::domain::value_objects::{OrderItemId, ShipmentId, ShipmentStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Quantity of one order item packed in a shipment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ShipmentLine {
+    pub order_item_id: OrderItemId,
+    #[schema(minimum = 1)]
+    pub quantity: u32,
+}
+
+/// Shipment Entity
+/// One parcel of the Order aggregate: part of its items, shipped on its own
+#[derive(Debug, Clone)]
+pub struct Shipment {
+    id: ShipmentId,
+    lines: Vec<ShipmentLine>,
+    status: ShipmentStatus,
+    tracking_number: Option<String>,
+    created_at: DateTime<Utc>,
+    shipped_at: Option<DateTime<Utc>>,
+}
+
+impl Shipment {
+    /// Factory method: a pending parcel (lines of the same item are combined)
+    pub fn new(
+        id: ShipmentId,
+        lines: Vec<ShipmentLine>,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        Self::reconstitute(id, lines, ShipmentStatus::Pending, None, created_at, None)
+    }
+
+    /// Rebuild a Shipment from persistence, keeping its identity
+    pub fn reconstitute(
+        id: ShipmentId,
+        lines: Vec<ShipmentLine>,
+        status: ShipmentStatus,
+        tracking_number: Option<String>,
+        created_at: DateTime<Utc>,
+        shipped_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, DomainError> {
+        // Business rule: a parcel holds at least one unit of each of its items
+        if lines.is_empty() {
+            return Err(DomainError::EmptyShipment);
+        }
+        if lines.iter().any(|line| line.quantity == 0) {
+            return Err(DomainError::InvalidQuantity);
+        }
+
+        let mut shipment = Self {
+            id,
+            lines: Vec::with_capacity(lines.len()),
+            status,
+            tracking_number,
+            created_at,
+            shipped_at,
+        };
+        shipment.add_lines(lines);
+        Ok(shipment)
+    }
+
+    /// Hand the parcel over to the carrier
+    pub fn ship(&mut self, tracking_number: String, at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.status == ShipmentStatus::Shipped {
+            return Err(DomainError::ShipmentAlreadyShipped);
+        }
+        if tracking_number.trim().is_empty() {
+            return Err(DomainError::InvalidTrackingNumber);
+        }
+
+        self.status = ShipmentStatus::Shipped;
+        self.tracking_number = Some(tracking_number);
+        self.shipped_at = Some(at);
+        Ok(())
+    }
+
+    /// Erase the tracking number, which leads to the delivery address at the carrier
+    pub fn anonymize(&mut self) {
+        if self.tracking_number.is_some() {
+            self.tracking_number = Some(ERASED.to_string());
+        }
+    }
+
+    /// Move the lines of another pending parcel into this one
+    pub fn absorb(&mut self, other: Shipment) -> Result<(), DomainError> {
+        if self.status == ShipmentStatus::Shipped || other.status == ShipmentStatus::Shipped {
+            return Err(DomainError::ShipmentAlreadyShipped);
+        }
+        self.add_lines(other.lines);
+        Ok(())
+    }
+
+    /// Units of `item_id` packed in this parcel
+    pub fn quantity_of(&self, item_id: OrderItemId) -> u32 {
+        self.lines
+            .iter()
+            .filter(|line| line.order_item_id == item_id)
+            .map(|line| line.quantity)
+            .sum()
+    }
+
+    fn add_lines(&mut self, lines: Vec<ShipmentLine>) {
+        for line in lines {
+            match self
+                .lines
+                .iter_mut()
+                .find(|existing| existing.order_item_id == line.order_item_id)
+            {
+                Some(existing) => existing.quantity += line.quantity,
+                None => self.lines.push(line),
+            }
+        }
+    }
+
+    // Getters
+    pub fn id(&self) -> ShipmentId {
+        self.id
+    }
+
+    pub fn lines(&self) -> &[ShipmentLine] {
+        &self.lines
+    }
+
+    pub fn status(&self) -> ShipmentStatus {
+        self.status
+    }
+
+    pub fn tracking_number(&self) -> Option<&str> {
+        self.tracking_number.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn shipped_at(&self) -> Option<DateTime<Utc>> {
+        self.shipped_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(item_id: OrderItemId, quantity: u32) -> ShipmentLine {
+        ShipmentLine {
+            order_item_id: item_id,
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_lines_of_the_same_item_are_combined() {
+        let item_id = OrderItemId::new();
+        let shipment = Shipment::new(
+            ShipmentId::new(),
+            vec![
+                line(item_id, 1),
+                line(OrderItemId::new(), 1),
+                line(item_id, 2),
+            ],
+            Utc::now(),
+        )
+        .unwrap();
+
+        assert_eq!(shipment.lines().len(), 2);
+        assert_eq!(shipment.quantity_of(item_id), 3);
+    }
+
+    #[test]
+    fn test_empty_or_zero_quantity_shipment_fails() {
+        assert!(matches!(
+            Shipment::new(ShipmentId::new(), vec![], Utc::now()),
+            Err(DomainError::EmptyShipment)
+        ));
+        assert!(matches!(
+            Shipment::new(
+                ShipmentId::new(),
+                vec![line(OrderItemId::new(), 0)],
+                Utc::now()
+            ),
+            Err(DomainError::InvalidQuantity)
+        ));
+    }
+
+    #[test]
+    fn test_shipment_ships_once() {
+        let mut shipment = Shipment::new(
+            ShipmentId::new(),
+            vec![line(OrderItemId::new(), 1)],
+            Utc::now(),
+        )
+        .unwrap();
+        assert!(matches!(
+            shipment.ship(" ".to_string(), Utc::now()),
+            Err(DomainError::InvalidTrackingNumber)
+        ));
+
+        shipment.ship("TRK-1".to_string(), Utc::now()).unwrap();
+        assert_eq!(shipment.status(), ShipmentStatus::Shipped);
+        assert_eq!(shipment.tracking_number(), Some("TRK-1"));
+        assert!(matches!(
+            shipment.ship("TRK-2".to_string(), Utc::now()),
+            Err(DomainError::ShipmentAlreadyShipped)
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::domain::errors::DomainError;
+use crate::domain::value_objects::{ProductId, WishlistPriority};
+use chrono::{DateTime, Utc};
+
+/// WishlistItem Entity
+/// Part of the Wishlist aggregate, identified by its product (saved once per wishlist)
+///
+/// No price is kept: the product is priced by the catalog when it is ordered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WishlistItem {
+    product_id: ProductId,
+    product_name: String,
+    /// Reminder of the customer, e.g. the size or color wanted
+    note: Option<String>,
+    priority: WishlistPriority,
+    added_at: DateTime<Utc>,
+}
+
+impl WishlistItem {
+    /// Business rule: notes are short reminders
+    pub const MAX_NOTE_LENGTH: usize = 500;
+
+    /// Factory method with validation
+    pub fn new(
+        product_id: ProductId,
+        product_name: String,
+        note: Option<String>,
+        priority: WishlistPriority,
+        added_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        // Business rule: product name cannot be empty
+        if product_name.trim().is_empty() {
+            return Err(DomainError::InvalidProductName);
+        }
+
+        Ok(Self {
+            product_id,
+            product_name,
+            note: Self::validate_note(note)?,
+            priority,
+            added_at,
+        })
+    }
+
+    /// A blank note is no note
+    fn validate_note(note: Option<String>) -> Result<Option<String>, DomainError> {
+        let note = note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        if note
+            .as_ref()
+            .is_some_and(|note| note.chars().count() > Self::MAX_NOTE_LENGTH)
+        {
+            return Err(DomainError::WishlistNoteTooLong {
+                max: Self::MAX_NOTE_LENGTH,
+            });
+        }
+        Ok(note)
+    }
+
+    /// Take the name, note and priority of `other`, saved again for the same product;
+    /// the item keeps the date it was first added
+    pub(crate) fn update_from(&mut self, other: WishlistItem) {
+        self.product_name = other.product_name;
+        self.note = other.note;
+        self.priority = other.priority;
+    }
+
+    // Getters
+    pub fn product_id(&self) -> ProductId {
+        self.product_id
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    pub fn priority(&self) -> WishlistPriority {
+        self.priority
+    }
+
+    pub fn added_at(&self) -> DateTime<Utc> {
+        self.added_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(note: Option<&str>) -> Result<WishlistItem, DomainError> {
+        WishlistItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            note.map(str::to_string),
+            WishlistPriority::High,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_notes_are_trimmed_and_capped() {
+        assert_eq!(item(Some("  in blue ")).unwrap().note(), Some("in blue"));
+        assert_eq!(item(Some("   ")).unwrap().note(), None);
+        assert!(item(Some(&"é".repeat(WishlistItem::MAX_NOTE_LENGTH))).is_ok());
+        assert!(matches!(
+            item(Some(&"é".repeat(WishlistItem::MAX_NOTE_LENGTH + 1))),
+            Err(DomainError::WishlistNoteTooLong { .. })
+        ));
+    }
+}
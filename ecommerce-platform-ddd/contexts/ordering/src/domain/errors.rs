@@ -1,8 +1,9 @@
-use crate::domain::value_objects::{MoneyError, OrderStatus};
+use crate::domain::value_objects::{Currency, MoneyError, OrderStatus, ProductId, TenantId};
 use thiserror::Error;
 
 /// Domain-specific errors
-/// These represent business rule violations
+/// These represent business rule violations, and nothing else: lookups are application
+/// errors, adapter failures are [`InfrastructureError`]s
 #[derive(Debug, Error)]
 pub enum DomainError {
     // Order errors
@@ -10,10 +11,7 @@ pub enum DomainError {
     EmptyOrder,
 
     #[error("Cannot transition from {from:?} to {to:?}")]
-    InvalidStatusTransition {
-        from: OrderStatus,
-        to: OrderStatus,
-    },
+    InvalidStatusTransition { from: OrderStatus, to: OrderStatus },
 
     #[error("Cannot cancel an order in terminal state")]
     CannotCancelTerminalOrder,
@@ -27,6 +25,107 @@ pub enum DomainError {
     #[error("Cannot remove the last item from an order")]
     CannotRemoveLastItem,
 
+    #[error("Order cannot contain more than {max} items")]
+    TooManyItems { max: usize },
+
+    #[error("Order is shipped through its shipments")]
+    OrderHasShipments,
+
+    #[error("Personal data of an order in progress cannot be erased")]
+    CannotAnonymizeOrderInProgress,
+
+    // Risk review errors
+    #[error("Order is held for review: it must be approved or rejected")]
+    OrderAwaitingReview,
+
+    #[error("Rejection reason cannot be empty")]
+    EmptyRejectionReason,
+
+    // Shipment errors
+    #[error("Shipments can only be managed on a paid order")]
+    OrderNotAwaitingShipment,
+
+    #[error("Shipment not found")]
+    ShipmentNotFound,
+
+    #[error("Shipment cannot be empty")]
+    EmptyShipment,
+
+    #[error("Shipment quantities exceed the ordered quantities")]
+    ShipmentExceedsOrderedQuantity,
+
+    #[error("Shipment has already been shipped")]
+    ShipmentAlreadyShipped,
+
+    #[error("Cannot merge a shipment with itself")]
+    CannotMergeShipmentWithItself,
+
+    #[error("Tracking number cannot be empty")]
+    InvalidTrackingNumber,
+
+    // Cart errors
+    #[error("Cart has expired")]
+    CartExpired,
+
+    #[error("Product is not in the cart")]
+    CartLineNotFound,
+
+    #[error("Cart is empty")]
+    EmptyCart,
+
+    #[error("Cart must belong to a customer to be checked out")]
+    AnonymousCart,
+
+    #[error("Cart already belongs to a customer")]
+    CartAlreadyAssigned,
+
+    #[error("Cannot merge a cart with itself")]
+    CannotMergeCartWithItself,
+
+    #[error("Product {product_id} is no longer available")]
+    ProductUnavailable { product_id: ProductId },
+
+    #[error("Prices changed since the products were added to the cart")]
+    PricesChanged,
+
+    #[error("Only {available} units of product {product_id} in stock")]
+    InsufficientStock {
+        product_id: ProductId,
+        available: u32,
+    },
+
+    // Invoice errors
+    #[error("Only paid orders can be invoiced")]
+    OrderNotPaid,
+
+    #[error("A credit note cannot be credited")]
+    CannotCreditCreditNote,
+
+    #[error("Credit note reason cannot be empty")]
+    InvalidCreditNoteReason,
+
+    #[error("Credit note cannot be empty")]
+    EmptyCreditNote,
+
+    #[error("Order item is not on the invoice")]
+    InvoiceLineNotFound,
+
+    #[error("Credited quantities exceed the invoiced quantities")]
+    CreditNoteExceedsInvoice,
+
+    #[error("Invoice has already been fully credited")]
+    InvoiceFullyCredited,
+
+    // Wishlist errors
+    #[error("Product is not in the wishlist")]
+    WishlistItemNotFound,
+
+    #[error("Wishlist cannot hold more than {max} products")]
+    WishlistFull { max: usize },
+
+    #[error("Wishlist note cannot exceed {max} characters")]
+    WishlistNoteTooLong { max: usize },
+
     // Order item errors
     #[error("Quantity must be greater than zero")]
     InvalidQuantity,
@@ -34,20 +133,160 @@ pub enum DomainError {
     #[error("Product name cannot be empty")]
     InvalidProductName,
 
+    // Tenant errors
+    #[error("Unknown tenant: {0}")]
+    UnknownTenant(TenantId),
+
+    #[error("Prices must be in {expected}, not {actual}")]
+    CurrencyNotAccepted {
+        expected: Currency,
+        actual: Currency,
+    },
+
+    #[error("Tax rate must be between 0 and 1")]
+    InvalidTaxRate,
+
+    // Pricing errors
+    #[error("Invalid price rule {name}: {reason}")]
+    InvalidPriceRule { name: String, reason: String },
+
+    // Risk assessment errors
+    #[error("Invalid risk rule {name}: {reason}")]
+    InvalidRiskRule { name: String, reason: String },
+
     // Money errors
     #[error("Money error: {0}")]
     MoneyError(#[from] MoneyError),
+}
 
-    // Repository errors
-    #[error("Order not found")]
-    OrderNotFound,
+impl DomainError {
+    /// Stable machine-readable code of the rule, e.g. `EMPTY_ORDER` (part of the API contract)
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::EmptyOrder => "EMPTY_ORDER",
+            DomainError::InvalidStatusTransition { .. } => "INVALID_STATUS_TRANSITION",
+            DomainError::CannotCancelTerminalOrder => "CANNOT_CANCEL_TERMINAL_ORDER",
+            DomainError::CannotModifyNonPendingOrder => "CANNOT_MODIFY_NON_PENDING_ORDER",
+            DomainError::OrderItemNotFound => "ORDER_ITEM_NOT_FOUND",
+            DomainError::CannotRemoveLastItem => "CANNOT_REMOVE_LAST_ITEM",
+            DomainError::TooManyItems { .. } => "TOO_MANY_ITEMS",
+            DomainError::OrderHasShipments => "ORDER_HAS_SHIPMENTS",
+            DomainError::CannotAnonymizeOrderInProgress => "CANNOT_ANONYMIZE_ORDER_IN_PROGRESS",
+            DomainError::OrderAwaitingReview => "ORDER_AWAITING_REVIEW",
+            DomainError::EmptyRejectionReason => "EMPTY_REJECTION_REASON",
+            DomainError::OrderNotAwaitingShipment => "ORDER_NOT_AWAITING_SHIPMENT",
+            DomainError::ShipmentNotFound => "SHIPMENT_NOT_FOUND",
+            DomainError::EmptyShipment => "EMPTY_SHIPMENT",
+            DomainError::ShipmentExceedsOrderedQuantity => "SHIPMENT_EXCEEDS_ORDERED_QUANTITY",
+            DomainError::ShipmentAlreadyShipped => "SHIPMENT_ALREADY_SHIPPED",
+            DomainError::CannotMergeShipmentWithItself => "CANNOT_MERGE_SHIPMENT_WITH_ITSELF",
+            DomainError::InvalidTrackingNumber => "INVALID_TRACKING_NUMBER",
+            DomainError::CartExpired => "CART_EXPIRED",
+            DomainError::CartLineNotFound => "CART_LINE_NOT_FOUND",
+            DomainError::EmptyCart => "EMPTY_CART",
+            DomainError::AnonymousCart => "ANONYMOUS_CART",
+            DomainError::CartAlreadyAssigned => "CART_ALREADY_ASSIGNED",
+            DomainError::CannotMergeCartWithItself => "CANNOT_MERGE_CART_WITH_ITSELF",
+            DomainError::ProductUnavailable { .. } => "PRODUCT_UNAVAILABLE",
+            DomainError::PricesChanged => "PRICES_CHANGED",
+            DomainError::InsufficientStock { .. } => "INSUFFICIENT_STOCK",
+            DomainError::OrderNotPaid => "ORDER_NOT_PAID",
+            DomainError::CannotCreditCreditNote => "CANNOT_CREDIT_CREDIT_NOTE",
+            DomainError::InvalidCreditNoteReason => "INVALID_CREDIT_NOTE_REASON",
+            DomainError::EmptyCreditNote => "EMPTY_CREDIT_NOTE",
+            DomainError::InvoiceLineNotFound => "INVOICE_LINE_NOT_FOUND",
+            DomainError::CreditNoteExceedsInvoice => "CREDIT_NOTE_EXCEEDS_INVOICE",
+            DomainError::InvoiceFullyCredited => "INVOICE_FULLY_CREDITED",
+            DomainError::WishlistItemNotFound => "WISHLIST_ITEM_NOT_FOUND",
+            DomainError::WishlistFull { .. } => "WISHLIST_FULL",
+            DomainError::WishlistNoteTooLong { .. } => "WISHLIST_NOTE_TOO_LONG",
+            DomainError::InvalidQuantity => "INVALID_QUANTITY",
+            DomainError::InvalidProductName => "INVALID_PRODUCT_NAME",
+            DomainError::UnknownTenant(_) => "UNKNOWN_TENANT",
+            DomainError::CurrencyNotAccepted { .. } => "CURRENCY_NOT_ACCEPTED",
+            DomainError::InvalidTaxRate => "INVALID_TAX_RATE",
+            DomainError::InvalidPriceRule { .. } => "INVALID_PRICE_RULE",
+            DomainError::InvalidRiskRule { .. } => "INVALID_RISK_RULE",
+            DomainError::MoneyError(err) => match err {
+                MoneyError::NegativeAmount => "NEGATIVE_AMOUNT",
+                MoneyError::CurrencyMismatch => "CURRENCY_MISMATCH",
+                MoneyError::UnknownCurrency(_) => "UNKNOWN_CURRENCY",
+            },
+        }
+    }
+}
 
-    #[error("Database error: {0}")]
-    DatabaseError(String),
+/// What failed behind a port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfrastructureErrorKind {
+    /// Database or store unreachable, or a write refused
+    Storage,
+    /// Write refused because it clashes with stored data (a unique key, or a change
+    /// made since the data was read)
+    Conflict,
+    /// Stored data no longer reads back as a valid aggregate
+    CorruptedData,
+    /// Event broker or subscription failure
+    Messaging,
+}
+
+/// Failure of an adapter behind a port (database, broker, remote service)
+///
+/// The domain does not know the adapters: their error is kept as the source of this one.
+#[derive(Debug, Error)]
+#[error("{context}")]
+pub struct InfrastructureError {
+    kind: InfrastructureErrorKind,
+    context: String,
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
 
-impl From<sea_orm::DbErr> for DomainError {
-    fn from(err: sea_orm::DbErr) -> Self {
-        DomainError::DatabaseError(err.to_string())
+impl InfrastructureError {
+    pub fn new(kind: InfrastructureErrorKind, context: impl Into<String>) -> Self {
+        Self {
+            kind,
+            context: context.into(),
+            source: None,
+        }
+    }
+
+    pub fn storage(context: impl Into<String>) -> Self {
+        Self::new(InfrastructureErrorKind::Storage, context)
+    }
+
+    pub fn conflict(context: impl Into<String>) -> Self {
+        Self::new(InfrastructureErrorKind::Conflict, context)
+    }
+
+    pub fn corrupted(context: impl Into<String>) -> Self {
+        Self::new(InfrastructureErrorKind::CorruptedData, context)
+    }
+
+    pub fn messaging(context: impl Into<String>) -> Self {
+        Self::new(InfrastructureErrorKind::Messaging, context)
+    }
+
+    /// Keep the adapter error that caused this one
+    pub fn with_source(
+        mut self,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn kind(&self) -> InfrastructureErrorKind {
+        self.kind
+    }
+
+    /// Stable machine-readable code, e.g. `STORAGE_FAILURE`
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            InfrastructureErrorKind::Storage => "STORAGE_FAILURE",
+            InfrastructureErrorKind::Conflict => "STORAGE_CONFLICT",
+            InfrastructureErrorKind::CorruptedData => "CORRUPTED_DATA",
+            InfrastructureErrorKind::Messaging => "MESSAGING_FAILURE",
+        }
     }
 }
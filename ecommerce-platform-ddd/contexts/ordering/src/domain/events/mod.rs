@@ -1,8 +1,12 @@
-use crate::domain::value_objects::{CustomerId, Money, OrderId};
+use crate::domain::entities::ShipmentLine;
+use crate::domain::value_objects::{CustomerId, Money, OrderId, OrderStatus, ShipmentId};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Stands in for personal data erased at the customer's request
+pub const ERASED: &str = "[erased]";
+
 /// Domain Events - Immutable records of things that happened
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
@@ -36,9 +40,62 @@ pub enum OrderEvent {
         reason: String,
         timestamp: DateTime<Utc>,
     },
+    /// Confirmation suspended: `reasons` are the risk rules matched, adding up to `score`
+    OrderHeldForReview {
+        order_id: OrderId,
+        score: u32,
+        reasons: Vec<String>,
+        timestamp: DateTime<Utc>,
+    },
+    /// The held order was released by an operator: it is confirmed
+    OrderReviewApproved {
+        order_id: OrderId,
+        timestamp: DateTime<Utc>,
+    },
+    /// The held order was refused by an operator: it is cancelled
+    OrderReviewRejected {
+        order_id: OrderId,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
+    ShipmentCreated {
+        order_id: OrderId,
+        shipment_id: ShipmentId,
+        lines: Vec<ShipmentLine>,
+        timestamp: DateTime<Utc>,
+    },
+    ShipmentShipped {
+        order_id: OrderId,
+        shipment_id: ShipmentId,
+        tracking_number: String,
+        timestamp: DateTime<Utc>,
+    },
+    /// `merged_shipment_id` no longer exists: its lines moved to `shipment_id`
+    ShipmentsMerged {
+        order_id: OrderId,
+        shipment_id: ShipmentId,
+        merged_shipment_id: ShipmentId,
+        timestamp: DateTime<Utc>,
+    },
 }
 
 impl OrderEvent {
+    /// Every value returned by [`OrderEvent::event_name`]
+    pub const EVENT_NAMES: [&'static str; 12] = [
+        "ORDER_CREATED",
+        "ORDER_CONFIRMED",
+        "ORDER_PAID",
+        "ORDER_SHIPPED",
+        "ORDER_DELIVERED",
+        "ORDER_CANCELLED",
+        "ORDER_HELD_FOR_REVIEW",
+        "ORDER_REVIEW_APPROVED",
+        "ORDER_REVIEW_REJECTED",
+        "SHIPMENT_CREATED",
+        "SHIPMENT_SHIPPED",
+        "SHIPMENTS_MERGED",
+    ];
+
     /// Get the order ID for any event type
     pub fn order_id(&self) -> OrderId {
         match self {
@@ -47,7 +104,13 @@ impl OrderEvent {
             | OrderEvent::OrderPaid { order_id, .. }
             | OrderEvent::OrderShipped { order_id, .. }
             | OrderEvent::OrderDelivered { order_id, .. }
-            | OrderEvent::OrderCancelled { order_id, .. } => *order_id,
+            | OrderEvent::OrderCancelled { order_id, .. }
+            | OrderEvent::OrderHeldForReview { order_id, .. }
+            | OrderEvent::OrderReviewApproved { order_id, .. }
+            | OrderEvent::OrderReviewRejected { order_id, .. }
+            | OrderEvent::ShipmentCreated { order_id, .. }
+            | OrderEvent::ShipmentShipped { order_id, .. }
+            | OrderEvent::ShipmentsMerged { order_id, .. } => *order_id,
         }
     }
 
@@ -59,7 +122,54 @@ impl OrderEvent {
             | OrderEvent::OrderPaid { timestamp, .. }
             | OrderEvent::OrderShipped { timestamp, .. }
             | OrderEvent::OrderDelivered { timestamp, .. }
-            | OrderEvent::OrderCancelled { timestamp, .. } => *timestamp,
+            | OrderEvent::OrderCancelled { timestamp, .. }
+            | OrderEvent::OrderHeldForReview { timestamp, .. }
+            | OrderEvent::OrderReviewApproved { timestamp, .. }
+            | OrderEvent::OrderReviewRejected { timestamp, .. }
+            | OrderEvent::ShipmentCreated { timestamp, .. }
+            | OrderEvent::ShipmentShipped { timestamp, .. }
+            | OrderEvent::ShipmentsMerged { timestamp, .. } => *timestamp,
+        }
+    }
+
+    /// Get the order status entered with this event (`None` for shipment events)
+    pub fn status(&self) -> Option<OrderStatus> {
+        match self {
+            OrderEvent::OrderCreated { .. } => Some(OrderStatus::Pending),
+            OrderEvent::OrderConfirmed { .. } => Some(OrderStatus::Confirmed),
+            OrderEvent::OrderPaid { .. } => Some(OrderStatus::Paid),
+            OrderEvent::OrderShipped { .. } => Some(OrderStatus::Shipped),
+            OrderEvent::OrderDelivered { .. } => Some(OrderStatus::Delivered),
+            OrderEvent::OrderCancelled { .. } => Some(OrderStatus::Cancelled),
+            OrderEvent::OrderHeldForReview { .. } => Some(OrderStatus::OnHold),
+            OrderEvent::OrderReviewApproved { .. } => Some(OrderStatus::Confirmed),
+            OrderEvent::OrderReviewRejected { .. } => Some(OrderStatus::Cancelled),
+            OrderEvent::ShipmentCreated { .. }
+            | OrderEvent::ShipmentShipped { .. }
+            | OrderEvent::ShipmentsMerged { .. } => None,
+        }
+    }
+
+    /// Erase the personal data of the event: the customer becomes `pseudonym`, free-text
+    /// reasons and tracking numbers are replaced by [`ERASED`]; amounts are kept
+    pub fn anonymize(&mut self, pseudonym: CustomerId) {
+        match self {
+            OrderEvent::OrderCreated { customer_id, .. } => *customer_id = pseudonym,
+            OrderEvent::OrderCancelled { reason, .. }
+            | OrderEvent::OrderReviewRejected { reason, .. } => *reason = ERASED.to_string(),
+            OrderEvent::OrderShipped {
+                tracking_number, ..
+            }
+            | OrderEvent::ShipmentShipped {
+                tracking_number, ..
+            } => *tracking_number = ERASED.to_string(),
+            OrderEvent::OrderConfirmed { .. }
+            | OrderEvent::OrderPaid { .. }
+            | OrderEvent::OrderDelivered { .. }
+            | OrderEvent::OrderHeldForReview { .. }
+            | OrderEvent::OrderReviewApproved { .. }
+            | OrderEvent::ShipmentCreated { .. }
+            | OrderEvent::ShipmentsMerged { .. } => {}
         }
     }
 
@@ -72,6 +182,12 @@ impl OrderEvent {
             OrderEvent::OrderShipped { .. } => "ORDER_SHIPPED",
             OrderEvent::OrderDelivered { .. } => "ORDER_DELIVERED",
             OrderEvent::OrderCancelled { .. } => "ORDER_CANCELLED",
+            OrderEvent::OrderHeldForReview { .. } => "ORDER_HELD_FOR_REVIEW",
+            OrderEvent::OrderReviewApproved { .. } => "ORDER_REVIEW_APPROVED",
+            OrderEvent::OrderReviewRejected { .. } => "ORDER_REVIEW_REJECTED",
+            OrderEvent::ShipmentCreated { .. } => "SHIPMENT_CREATED",
+            OrderEvent::ShipmentShipped { .. } => "SHIPMENT_SHIPPED",
+            OrderEvent::ShipmentsMerged { .. } => "SHIPMENTS_MERGED",
         }
     }
 }
@@ -0,0 +1,79 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use uuid::Uuid;
+
+/// Source of new entity identities (Port)
+/// Injected so that IDs are predictable in tests and replays
+pub trait IdGenerator: Send + Sync {
+    fn next_id(&self) -> Uuid;
+}
+
+/// Random UUIDv4 identities
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UuidV4Generator;
+
+impl IdGenerator for UuidV4Generator {
+    fn next_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Time-ordered UUIDv7 identities (monotonic within the process, index friendly)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UuidV7Generator;
+
+impl IdGenerator for UuidV7Generator {
+    fn next_id(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// Deterministic identities 1, 2, 3... for tests
+#[derive(Debug)]
+pub struct SequentialIdGenerator {
+    next: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    pub fn new() -> Self {
+        Self::starting_at(1)
+    }
+
+    pub fn starting_at(first: u64) -> Self {
+        Self {
+            next: AtomicU64::new(first),
+        }
+    }
+}
+
+impl Default for SequentialIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> Uuid {
+        Uuid::from_u64_pair(0, self.next.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids_are_predictable() {
+        let ids = SequentialIdGenerator::new();
+        assert_eq!(ids.next_id(), Uuid::from_u128(1));
+        assert_eq!(ids.next_id(), Uuid::from_u128(2));
+    }
+
+    #[test]
+    fn test_uuid_v7_ids_are_time_ordered() {
+        let ids = UuidV7Generator;
+        let first = ids.next_id();
+        let second = ids.next_id();
+        assert_eq!(first.get_version_num(), 7);
+        assert!(first < second);
+    }
+}
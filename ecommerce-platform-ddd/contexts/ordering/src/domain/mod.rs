@@ -1,14 +1,27 @@
 pub mod aggregates;
+pub mod catalog;
+pub mod clock;
 pub mod entities;
 pub mod errors;
 pub mod events;
+pub mod id_generator;
+pub mod pricing;
 pub mod repositories;
+pub mod risk;
+pub mod tenant;
 pub mod value_objects;
 
 // Re-exports for convenience
-pub use aggregates::Order;
+pub use aggregates::{Cart, Invoice, Order, Wishlist};
+pub use clock::{Clock, SystemClock};
 pub use entities::OrderItem;
 pub use errors::DomainError;
 pub use events::OrderEvent;
-pub use repositories::OrderRepository;
-pub use value_objects::{CustomerId, Money, OrderId, OrderItemId, OrderStatus, ProductId};
+pub use id_generator::{IdGenerator, UuidV7Generator};
+pub use repositories::{
+    CartRepository, InvoiceRepository, OrderCriteria, OrderRepository, WishlistRepository,
+};
+pub use tenant::{Tenant, TenantDirectory};
+pub use value_objects::{
+    CustomerId, Money, OrderId, OrderItemId, OrderStatus, ProductId, TenantId,
+};
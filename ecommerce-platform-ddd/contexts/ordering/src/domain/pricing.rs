@@ -0,0 +1,292 @@
+use crate::domain::{
+    errors::{DomainError, InfrastructureError},
+    value_objects::{CustomerId, Money, ProductId},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/// Unit price of a product for a customer group, a quantity tier and a sale period
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceRule {
+    /// Recorded on the order lines it prices, e.g. `wholesale-10`
+    pub name: String,
+    pub product_id: ProductId,
+    pub unit_price: Money,
+    /// Customers of this group only; every customer when `None`
+    pub customer_group: Option<String>,
+    /// First quantity of the tier (tiers 1-9, 10-49, 50+ are three rules from 1, 10 and 50)
+    pub min_quantity: u32,
+    /// Start of the sale period, included
+    pub valid_from: Option<DateTime<Utc>>,
+    /// End of the sale period, excluded
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+impl PriceRule {
+    /// Rule for every customer, from the first unit, without time limit
+    pub fn new(name: impl Into<String>, product_id: ProductId, unit_price: Money) -> Self {
+        Self {
+            name: name.into(),
+            product_id,
+            unit_price,
+            customer_group: None,
+            min_quantity: 1,
+            valid_from: None,
+            valid_until: None,
+        }
+    }
+
+    pub fn for_group(mut self, customer_group: impl Into<String>) -> Self {
+        self.customer_group = Some(customer_group.into());
+        self
+    }
+
+    pub fn from_quantity(mut self, min_quantity: u32) -> Self {
+        self.min_quantity = min_quantity;
+        self
+    }
+
+    pub fn valid_between(
+        mut self,
+        valid_from: Option<DateTime<Utc>>,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.valid_from = valid_from;
+        self.valid_until = valid_until;
+        self
+    }
+
+    fn validate(&self) -> Result<(), DomainError> {
+        let invalid = |reason: &str| DomainError::InvalidPriceRule {
+            name: self.name.clone(),
+            reason: reason.to_string(),
+        };
+        if self.name.trim().is_empty() {
+            return Err(invalid("name cannot be empty"));
+        }
+        if self.min_quantity == 0 {
+            return Err(invalid("min_quantity must be greater than zero"));
+        }
+        if let (Some(from), Some(until)) = (self.valid_from, self.valid_until) {
+            if from >= until {
+                return Err(invalid("valid_from must be before valid_until"));
+            }
+        }
+        Ok(())
+    }
+
+    fn applies(
+        &self,
+        product_id: ProductId,
+        list_price: Money,
+        customer_group: Option<&str>,
+        quantity: u32,
+        at: DateTime<Utc>,
+    ) -> bool {
+        self.product_id == product_id
+            && self.unit_price.currency() == list_price.currency()
+            && self
+                .customer_group
+                .as_deref()
+                .is_none_or(|group| Some(group) == customer_group)
+            && quantity >= self.min_quantity
+            && self.valid_from.is_none_or(|from| at >= from)
+            && self.valid_until.is_none_or(|until| at < until)
+    }
+}
+
+/// Price rules in force
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriceList {
+    rules: Vec<PriceRule>,
+}
+
+impl PriceList {
+    /// Business rule: rule names are unique, since order lines record them
+    pub fn new(rules: Vec<PriceRule>) -> Result<Self, DomainError> {
+        let mut names = HashSet::new();
+        for rule in &rules {
+            rule.validate()?;
+            if !names.insert(rule.name.as_str()) {
+                return Err(DomainError::InvalidPriceRule {
+                    name: rule.name.clone(),
+                    reason: "name is used by another rule".to_string(),
+                });
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn rules(&self) -> &[PriceRule] {
+        &self.rules
+    }
+
+    /// Prices of a customer of `customer_group` ordering at `at`
+    pub fn pricing(&self, customer_group: Option<String>, at: DateTime<Utc>) -> Pricing {
+        Pricing {
+            list: self.clone(),
+            customer_group,
+            at,
+        }
+    }
+}
+
+/// Prices of one customer at one moment, applied to order lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pricing {
+    list: PriceList,
+    customer_group: Option<String>,
+    at: DateTime<Utc>,
+}
+
+impl Pricing {
+    /// No rule: every line is sold at its list price
+    pub fn list_prices() -> Self {
+        PriceList::default().pricing(None, DateTime::UNIX_EPOCH)
+    }
+
+    pub fn customer_group(&self) -> Option<&str> {
+        self.customer_group.as_deref()
+    }
+
+    /// Business rule: the customer gets the lowest unit price among the rules that apply,
+    /// the first listed winning a tie; `None` leaves the list price
+    pub fn best_rule(
+        &self,
+        product_id: ProductId,
+        list_price: Money,
+        quantity: u32,
+    ) -> Option<&PriceRule> {
+        self.list
+            .rules
+            .iter()
+            .filter(|rule| {
+                rule.applies(
+                    product_id,
+                    list_price,
+                    self.customer_group.as_deref(),
+                    quantity,
+                    self.at,
+                )
+            })
+            .fold(None, |best: Option<&PriceRule>, rule| match best {
+                Some(best) if best.unit_price.amount() <= rule.unit_price.amount() => Some(best),
+                _ => Some(rule),
+            })
+    }
+}
+
+/// Price lists and customer groups, owned by the pricing team (Port)
+#[async_trait]
+pub trait PriceLists: Send + Sync {
+    /// Prices of the orders a customer places at `at`
+    async fn pricing_for(
+        &self,
+        customer_id: CustomerId,
+        at: DateTime<Utc>,
+    ) -> Result<Pricing, InfrastructureError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    fn eur(cents: i64) -> Money {
+        Money::eur(Decimal::new(cents, 2)).unwrap()
+    }
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 11, day, 0, 0, 0).unwrap()
+    }
+
+    fn keyboard_prices(product_id: ProductId) -> PriceList {
+        PriceList::new(vec![
+            PriceRule::new("tier-10", product_id, eur(4500)).from_quantity(10),
+            PriceRule::new("tier-50", product_id, eur(3990)).from_quantity(50),
+            PriceRule::new("wholesale", product_id, eur(4200)).for_group("wholesale"),
+            PriceRule::new("black-friday", product_id, eur(3500))
+                .valid_between(Some(day(28)), Some(day(29))),
+        ])
+        .unwrap()
+    }
+
+    fn rule_name(pricing: &Pricing, product_id: ProductId, quantity: u32) -> Option<&str> {
+        pricing
+            .best_rule(product_id, eur(4990), quantity)
+            .map(|rule| rule.name.as_str())
+    }
+
+    #[test]
+    fn test_quantity_tiers() {
+        let product_id = ProductId::new();
+        let pricing = keyboard_prices(product_id).pricing(None, day(1));
+
+        assert_eq!(rule_name(&pricing, product_id, 9), None);
+        assert_eq!(rule_name(&pricing, product_id, 10), Some("tier-10"));
+        assert_eq!(rule_name(&pricing, product_id, 49), Some("tier-10"));
+        assert_eq!(rule_name(&pricing, product_id, 50), Some("tier-50"));
+        assert_eq!(rule_name(&pricing, ProductId::new(), 50), None);
+    }
+
+    #[test]
+    fn test_customer_group_gets_the_lowest_price() {
+        let product_id = ProductId::new();
+        let pricing = keyboard_prices(product_id).pricing(Some("wholesale".to_string()), day(1));
+
+        assert_eq!(rule_name(&pricing, product_id, 1), Some("wholesale"));
+        assert_eq!(rule_name(&pricing, product_id, 50), Some("tier-50"));
+    }
+
+    #[test]
+    fn test_sale_price_only_during_the_sale() {
+        let product_id = ProductId::new();
+        let prices = keyboard_prices(product_id);
+
+        assert_eq!(
+            rule_name(&prices.pricing(None, day(27)), product_id, 1),
+            None
+        );
+        assert_eq!(
+            rule_name(&prices.pricing(None, day(28)), product_id, 1),
+            Some("black-friday")
+        );
+        assert_eq!(
+            rule_name(&prices.pricing(None, day(29)), product_id, 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rules_in_another_currency_are_ignored() {
+        let product_id = ProductId::new();
+        let usd = Money::usd(Decimal::new(3000, 2)).unwrap();
+        let pricing = PriceList::new(vec![PriceRule::new("us", product_id, usd)])
+            .unwrap()
+            .pricing(None, day(1));
+
+        assert_eq!(rule_name(&pricing, product_id, 1), None);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_refused() {
+        let product_id = ProductId::new();
+
+        assert!(PriceList::new(vec![
+            PriceRule::new("tier", product_id, eur(100)),
+            PriceRule::new("tier", product_id, eur(90)).from_quantity(10),
+        ])
+        .is_err());
+        assert!(PriceList::new(vec![
+            PriceRule::new("zero", product_id, eur(100)).from_quantity(0)
+        ])
+        .is_err());
+        assert!(
+            PriceList::new(vec![PriceRule::new("sale", product_id, eur(100))
+                .valid_between(Some(day(29)), Some(day(28)))])
+            .is_err()
+        );
+    }
+}
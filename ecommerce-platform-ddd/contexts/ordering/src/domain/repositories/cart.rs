@@ -0,0 +1,37 @@
+use crate::domain::{
+    aggregates::Cart,
+    errors::InfrastructureError,
+    value_objects::{CartId, CustomerId, TenantId},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Repository trait for carts (Port)
+///
+/// Carts are isolated per tenant: a cart is only visible to the tenant it was opened on.
+#[async_trait]
+pub trait CartRepository: Send + Sync {
+    /// Save or update a cart
+    /// Fails if the ID is already used by a cart of another tenant
+    async fn save(&self, cart: &Cart) -> Result<(), InfrastructureError>;
+
+    /// Find cart by ID
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: CartId,
+    ) -> Result<Option<Cart>, InfrastructureError>;
+
+    /// Find the cart of a customer (a customer has at most one per tenant)
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Cart>, InfrastructureError>;
+
+    /// Delete the carts of every tenant expired at `now`, returning how many were deleted
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, InfrastructureError>;
+
+    /// Delete a cart
+    async fn delete(&self, tenant_id: &TenantId, id: CartId) -> Result<(), InfrastructureError>;
+}
@@ -0,0 +1,49 @@
+use crate::domain::{
+    aggregates::{Invoice, InvoiceDraft},
+    errors::InfrastructureError,
+    value_objects::{CustomerId, InvoiceId, OrderId, TenantId},
+};
+use async_trait::async_trait;
+
+/// Repository trait for invoices and credit notes (Port)
+///
+/// Invoices are legal documents: once issued they are never deleted, and only an
+/// erasure request updates them (see `anonymize`). They are isolated per tenant,
+/// like the orders they bill.
+#[async_trait]
+pub trait InvoiceRepository: Send + Sync {
+    /// Number the draft and store it, atomically
+    ///
+    /// Numbers are sequential and gap-free within a series (tenant, kind and year of
+    /// issue): a number is only consumed by an invoice that is actually stored.
+    /// An order has a single invoice: issuing another one fails with a `Conflict` error.
+    /// A credit note is checked again against the credit notes already stored for its
+    /// invoice, under a lock: one that would refund more than remains fails with a
+    /// `Conflict` error.
+    async fn issue(&self, draft: InvoiceDraft) -> Result<Invoice, InfrastructureError>;
+
+    /// Find invoice or credit note by ID
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: InvoiceId,
+    ) -> Result<Option<Invoice>, InfrastructureError>;
+
+    /// Invoices and credit notes of an order, oldest first
+    async fn find_by_order(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+    ) -> Result<Vec<Invoice>, InfrastructureError>;
+
+    /// Replace the customer of the invoices and credit notes of an order
+    ///
+    /// Erasure requests only: numbers, lines and totals stay untouched for their
+    /// legal retention period.
+    async fn anonymize(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError>;
+}
@@ -1,25 +1,96 @@
+pub mod cart;
+pub mod invoice;
+pub mod wishlist;
+
+pub use cart::CartRepository;
+pub use invoice::InvoiceRepository;
+pub use wishlist::WishlistRepository;
+
 use crate::domain::{
     aggregates::Order,
-    errors::DomainError,
-    value_objects::{CustomerId, OrderId},
+    errors::InfrastructureError,
+    value_objects::{CustomerId, OrderId, OrderStatus, TenantId},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Filter for bulk reads such as exports
+#[derive(Debug, Clone, Default)]
+pub struct OrderCriteria {
+    /// `None` reads the orders of every tenant (operators only)
+    pub tenant_id: Option<TenantId>,
+    pub status: Option<OrderStatus>,
+    /// Created at or after this instant
+    pub created_from: Option<DateTime<Utc>>,
+    /// Created strictly before this instant
+    pub created_until: Option<DateTime<Utc>>,
+}
+
+impl OrderCriteria {
+    pub fn matches(&self, order: &Order) -> bool {
+        self.tenant_id
+            .as_ref()
+            .is_none_or(|tenant_id| order.tenant_id() == tenant_id)
+            && self.status.is_none_or(|status| order.status() == status)
+            && self.created_from.is_none_or(|from| order.created_at() >= from)
+            && self.created_until.is_none_or(|until| order.created_at() < until)
+    }
+}
+
+/// Position of the last order of a page (keyset pagination on creation date, then ID)
+pub type OrderCursor = (DateTime<Utc>, OrderId);
 
 /// Repository trait (Port in Hexagonal Architecture)
 /// The domain defines what it needs, infrastructure implements how
+///
+/// Orders are isolated per tenant: an order is only visible to the tenant owning it.
 #[async_trait]
 pub trait OrderRepository: Send + Sync {
     /// Save or update an order
-    async fn save(&self, order: &mut Order) -> Result<(), DomainError>;
+    /// Fails if the ID is already used by an order of another tenant
+    async fn save(&self, order: &mut Order) -> Result<(), InfrastructureError>;
+
+    /// Save an order read in `expected` status, unless its stored status changed since
+    /// Fails with a conflict if the stored order is no longer in `expected` status
+    /// (background jobs skip an order changed by a customer or an operator meanwhile)
+    async fn save_if_status(
+        &self,
+        order: &mut Order,
+        expected: OrderStatus,
+    ) -> Result<(), InfrastructureError>;
 
     /// Find order by ID
-    async fn find_by_id(&self, id: OrderId) -> Result<Option<Order>, DomainError>;
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: OrderId,
+    ) -> Result<Option<Order>, InfrastructureError>;
 
     /// Find all orders for a customer
-    async fn find_by_customer(&self, customer_id: CustomerId) -> Result<Vec<Order>, DomainError>;
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, InfrastructureError>;
+
+    /// Find orders in `status` not modified since `updated_before`, of every tenant
+    /// (background jobs apply the rules of each order's tenant)
+    async fn find_stale(
+        &self,
+        status: OrderStatus,
+        updated_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, InfrastructureError>;
+
+    /// Up to `limit` orders matching `criteria`, by creation date then ID, after `after`
+    async fn find_page(
+        &self,
+        criteria: &OrderCriteria,
+        after: Option<OrderCursor>,
+        limit: u64,
+    ) -> Result<Vec<Order>, InfrastructureError>;
 
     /// Delete an order
-    async fn delete(&self, id: OrderId) -> Result<(), DomainError>;
+    async fn delete(&self, tenant_id: &TenantId, id: OrderId) -> Result<(), InfrastructureError>;
 
     /// Get next order ID (for event sourcing scenarios)
     fn next_id(&self) -> OrderId {
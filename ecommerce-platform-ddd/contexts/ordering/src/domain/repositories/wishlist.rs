@@ -0,0 +1,37 @@
+use crate::domain::{
+    aggregates::Wishlist,
+    errors::InfrastructureError,
+    value_objects::{CustomerId, ShareToken, TenantId},
+};
+use async_trait::async_trait;
+
+/// Repository trait for wishlists (Port)
+///
+/// Wishlists are isolated per tenant: a customer has a separate wishlist on each storefront,
+/// and a wishlist is never returned when looked up from another one.
+#[async_trait]
+pub trait WishlistRepository: Send + Sync {
+    /// Save or update the wishlist of a customer
+    async fn save(&self, wishlist: &Wishlist) -> Result<(), InfrastructureError>;
+
+    /// Find the wishlist of a customer on a tenant
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Wishlist>, InfrastructureError>;
+
+    /// Find the wishlist of a tenant currently shared with `token`
+    async fn find_by_share_token(
+        &self,
+        tenant_id: &TenantId,
+        token: &ShareToken,
+    ) -> Result<Option<Wishlist>, InfrastructureError>;
+
+    /// Delete the wishlist of a customer on a tenant
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<(), InfrastructureError>;
+}
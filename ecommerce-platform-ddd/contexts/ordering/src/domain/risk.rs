@@ -0,0 +1,311 @@
+use crate::domain::{
+    aggregates::Order,
+    errors::{DomainError, InfrastructureError},
+    value_objects::Money,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+
+/// What a risk rule looks at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RiskFactor {
+    /// Total of the order strictly above this amount, in the currency of the order
+    OrderTotalAbove(Decimal),
+    /// More units ordered than this, all lines together
+    ItemCountAbove(u32),
+    /// More orders than `max_orders` placed by the customer within `window`, this one
+    /// included (card testing places many small orders in a row)
+    OrdersWithin { max_orders: u32, window: Duration },
+    /// Billing and shipping addresses of the order given and in different areas
+    AddressMismatch,
+}
+
+/// Points added to the score of an order when its factor matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskRule {
+    /// Recorded in the `OrderHeldForReview` event of the orders it matches
+    pub name: String,
+    pub factor: RiskFactor,
+    pub score: u32,
+}
+
+impl RiskRule {
+    pub fn new(name: impl Into<String>, factor: RiskFactor, score: u32) -> Self {
+        Self {
+            name: name.into(),
+            factor,
+            score,
+        }
+    }
+
+    fn validate(&self) -> Result<(), DomainError> {
+        let invalid = |reason: &str| DomainError::InvalidRiskRule {
+            name: self.name.clone(),
+            reason: reason.to_string(),
+        };
+        if self.name.trim().is_empty() {
+            return Err(invalid("name cannot be empty"));
+        }
+        if self.score == 0 {
+            return Err(invalid("score must be greater than zero"));
+        }
+        match &self.factor {
+            RiskFactor::OrderTotalAbove(amount) if amount.is_sign_negative() => {
+                Err(invalid("amount cannot be negative"))
+            }
+            RiskFactor::OrdersWithin { window, .. } if *window <= Duration::zero() => {
+                Err(invalid("window must be positive"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn matches(&self, profile: &RiskProfile) -> bool {
+        match &self.factor {
+            RiskFactor::OrderTotalAbove(amount) => profile.total.amount() > *amount,
+            RiskFactor::ItemCountAbove(count) => profile.item_count > *count,
+            RiskFactor::OrdersWithin { max_orders, window } => {
+                let since = profile.at - *window;
+                let recent = profile
+                    .order_times
+                    .iter()
+                    .filter(|created_at| **created_at > since && **created_at <= profile.at)
+                    .count();
+                recent > *max_orders as usize
+            }
+            RiskFactor::AddressMismatch => profile.address_mismatch,
+        }
+    }
+}
+
+/// What the risk rules know about an order being confirmed
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskProfile {
+    pub total: Money,
+    /// Units ordered, all lines together
+    pub item_count: u32,
+    /// Creation time of every order of the customer, this one included
+    pub order_times: Vec<DateTime<Utc>>,
+    pub address_mismatch: bool,
+    /// When the order is assessed
+    pub at: DateTime<Utc>,
+}
+
+impl RiskProfile {
+    /// Profile of `order`, among the `customer_orders` of the same customer and tenant
+    ///
+    /// Addresses only mismatch when both are stored on the order: a missing one proves
+    /// nothing.
+    pub fn of(order: &Order, customer_orders: &[Order], at: DateTime<Utc>) -> Self {
+        let order_times = customer_orders
+            .iter()
+            .filter(|other| other.id() != order.id())
+            .map(Order::created_at)
+            .chain(std::iter::once(order.created_at()))
+            .collect();
+        let address_mismatch = match (order.billing_address(), order.shipping_address()) {
+            (Some(billing), Some(shipping)) => !billing.same_area(shipping),
+            _ => false,
+        };
+        Self {
+            total: order.total(),
+            item_count: order.items().iter().map(|item| item.quantity()).sum(),
+            order_times,
+            address_mismatch,
+            at,
+        }
+    }
+}
+
+/// Score of an order and the rules that made it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskAssessment {
+    pub score: u32,
+    /// Names of the rules matched, in the order of the policy
+    pub reasons: Vec<String>,
+    /// Score from which the order is held for review
+    pub review_score: u32,
+}
+
+impl RiskAssessment {
+    /// Business rule: an order reaching the review score is not confirmed until an
+    /// operator approves it
+    pub fn requires_review(&self) -> bool {
+        self.score >= self.review_score
+    }
+}
+
+/// Risk rules in force, and the score holding an order for review
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskPolicy {
+    rules: Vec<RiskRule>,
+    review_score: u32,
+}
+
+impl Default for RiskPolicy {
+    /// No rule: every order is confirmed
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            review_score: Self::DEFAULT_REVIEW_SCORE,
+        }
+    }
+}
+
+impl RiskPolicy {
+    pub const DEFAULT_REVIEW_SCORE: u32 = 100;
+
+    /// Business rule: rule names are unique, since held orders record them
+    pub fn new(rules: Vec<RiskRule>, review_score: u32) -> Result<Self, DomainError> {
+        if review_score == 0 {
+            return Err(DomainError::InvalidRiskRule {
+                name: "review_score".to_string(),
+                reason: "must be greater than zero".to_string(),
+            });
+        }
+        let mut names = HashSet::new();
+        for rule in &rules {
+            rule.validate()?;
+            if !names.insert(rule.name.as_str()) {
+                return Err(DomainError::InvalidRiskRule {
+                    name: rule.name.clone(),
+                    reason: "name is used by another rule".to_string(),
+                });
+            }
+        }
+        Ok(Self {
+            rules,
+            review_score,
+        })
+    }
+
+    pub fn rules(&self) -> &[RiskRule] {
+        &self.rules
+    }
+
+    pub fn review_score(&self) -> u32 {
+        self.review_score
+    }
+
+    /// Business rule: the score of an order is the sum of the scores of the rules it matches
+    pub fn assess(&self, profile: &RiskProfile) -> RiskAssessment {
+        let matched: Vec<&RiskRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.matches(profile))
+            .collect();
+        RiskAssessment {
+            score: matched
+                .iter()
+                .fold(0u32, |score, rule| score.saturating_add(rule.score)),
+            reasons: matched.iter().map(|rule| rule.name.clone()).collect(),
+            review_score: self.review_score,
+        }
+    }
+}
+
+/// Fraud screening consulted before an order is confirmed (Port)
+#[async_trait]
+pub trait RiskAssessor: Send + Sync {
+    /// Score `order`, with the addresses stored on it
+    async fn assess(&self, order: &Order) -> Result<RiskAssessment, InfrastructureError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(cents: i64, item_count: u32, minutes_ago: &[i64]) -> RiskProfile {
+        let at = Utc::now();
+        RiskProfile {
+            total: Money::eur(Decimal::new(cents, 2)).unwrap(),
+            item_count,
+            order_times: minutes_ago
+                .iter()
+                .map(|minutes| at - Duration::minutes(*minutes))
+                .collect(),
+            address_mismatch: false,
+            at,
+        }
+    }
+
+    fn card_testing_policy() -> RiskPolicy {
+        RiskPolicy::new(
+            vec![
+                RiskRule::new(
+                    "large-order",
+                    RiskFactor::OrderTotalAbove(Decimal::new(1000, 0)),
+                    60,
+                ),
+                RiskRule::new("bulk", RiskFactor::ItemCountAbove(20), 30),
+                RiskRule::new(
+                    "velocity",
+                    RiskFactor::OrdersWithin {
+                        max_orders: 3,
+                        window: Duration::hours(1),
+                    },
+                    100,
+                ),
+                RiskRule::new("address-mismatch", RiskFactor::AddressMismatch, 40),
+            ],
+            100,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_scores_add_up_to_a_review() {
+        let policy = card_testing_policy();
+
+        let usual = policy.assess(&profile(4990, 1, &[0, 60 * 24]));
+        assert_eq!(usual.score, 0);
+        assert!(!usual.requires_review());
+
+        let mut large = profile(120_000, 25, &[0]);
+        let assessment = policy.assess(&large);
+        assert_eq!(assessment.score, 90);
+        assert_eq!(assessment.reasons, vec!["large-order", "bulk"]);
+        assert!(!assessment.requires_review());
+
+        large.address_mismatch = true;
+        let assessment = policy.assess(&large);
+        assert_eq!(assessment.score, 130);
+        assert!(assessment.requires_review());
+    }
+
+    #[test]
+    fn test_velocity_counts_the_orders_of_the_window() {
+        let policy = card_testing_policy();
+
+        assert!(!policy
+            .assess(&profile(100, 1, &[0, 10, 20, 90]))
+            .requires_review());
+        let burst = policy.assess(&profile(100, 1, &[0, 5, 10, 15]));
+        assert_eq!(burst.reasons, vec!["velocity"]);
+        assert!(burst.requires_review());
+    }
+
+    #[test]
+    fn test_invalid_rules_are_refused() {
+        let rule = |name: &str, score| RiskRule::new(name, RiskFactor::AddressMismatch, score);
+
+        assert!(RiskPolicy::new(vec![rule("same", 10), rule("same", 20)], 100).is_err());
+        assert!(RiskPolicy::new(vec![rule("free", 0)], 100).is_err());
+        assert!(RiskPolicy::new(vec![rule(" ", 10)], 100).is_err());
+        assert!(RiskPolicy::new(vec![rule("ok", 10)], 0).is_err());
+        assert!(RiskPolicy::new(
+            vec![RiskRule::new(
+                "instant",
+                RiskFactor::OrdersWithin {
+                    max_orders: 1,
+                    window: Duration::zero(),
+                },
+                10,
+            )],
+            100,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::domain::{
+    errors::DomainError,
+    value_objects::{Currency, Money, OrderStatus, TenantId},
+};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Storefront sharing the deployment, with its own business rules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tenant {
+    pub id: TenantId,
+    /// Currency every order of the tenant is priced in
+    pub currency: Currency,
+    /// Tax applied to new orders (an order keeps the rule it was created with)
+    pub tax: TaxRule,
+    pub transitions: StatusTransitions,
+}
+
+impl Tenant {
+    /// Tenant selling in EUR, without tax, on the standard order lifecycle
+    pub fn new(id: TenantId) -> Self {
+        Self {
+            id,
+            currency: Currency::EUR,
+            tax: TaxRule::none(),
+            transitions: StatusTransitions::standard(),
+        }
+    }
+
+    /// Business rule: prices are in the currency of the tenant
+    pub fn ensure_currency(&self, price: Money) -> Result<(), DomainError> {
+        if price.currency() != self.currency {
+            return Err(DomainError::CurrencyNotAccepted {
+                expected: self.currency,
+                actual: price.currency(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Business rule: an order only moves along the transitions enabled for its tenant
+    ///
+    /// Checked by the use cases after the aggregate changed the status, before saving.
+    pub fn ensure_transition(&self, from: OrderStatus, to: OrderStatus) -> Result<(), DomainError> {
+        if from != to && !self.transitions.allows(from, to) {
+            return Err(DomainError::InvalidStatusTransition { from, to });
+        }
+        Ok(())
+    }
+}
+
+impl Default for Tenant {
+    fn default() -> Self {
+        Self::new(TenantId::default())
+    }
+}
+
+/// Value Added Tax of a tenant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxRule {
+    /// Fraction of the net price, e.g. `0.20`
+    rate: Decimal,
+    /// Whether item prices already include the tax (B2C) or not (B2B)
+    included: bool,
+}
+
+impl TaxRule {
+    pub fn new(rate: Decimal, included: bool) -> Result<Self, DomainError> {
+        if rate < Decimal::ZERO || rate >= Decimal::ONE {
+            return Err(DomainError::InvalidTaxRate);
+        }
+        Ok(Self { rate, included })
+    }
+
+    /// No tax at all
+    pub fn none() -> Self {
+        Self {
+            rate: Decimal::ZERO,
+            included: true,
+        }
+    }
+
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    pub fn included(&self) -> bool {
+        self.included
+    }
+
+    /// Tax contained in `amount` (prices including tax) or due on top of it, to the cent
+    pub fn tax_on(&self, amount: Money) -> Money {
+        let tax = if self.included {
+            amount.amount() - amount.amount() / (Decimal::ONE + self.rate)
+        } else {
+            amount.amount() * self.rate
+        };
+        let tax = tax.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        Money::new(tax, amount.currency()).expect("tax on a positive amount is positive")
+    }
+
+    /// What the customer pays for `amount`
+    pub fn total_including_tax(&self, amount: Money) -> Money {
+        if self.included {
+            return amount;
+        }
+        (amount + self.tax_on(amount)).expect("tax is in the currency of the amount")
+    }
+}
+
+/// Status changes enabled for a tenant, among those of the order lifecycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTransitions(Vec<(OrderStatus, OrderStatus)>);
+
+impl StatusTransitions {
+    const STATUSES: [OrderStatus; 7] = [
+        OrderStatus::Pending,
+        OrderStatus::OnHold,
+        OrderStatus::Confirmed,
+        OrderStatus::Paid,
+        OrderStatus::Shipped,
+        OrderStatus::Delivered,
+        OrderStatus::Cancelled,
+    ];
+
+    /// Every transition of the order lifecycle (`OrderStatus::can_transition_to`)
+    pub fn standard() -> Self {
+        Self(
+            Self::STATUSES
+                .iter()
+                .flat_map(|from| Self::STATUSES.iter().map(move |to| (*from, *to)))
+                .filter(|(from, to)| from.can_transition_to(*to))
+                .collect(),
+        )
+    }
+
+    /// Review of the orders held by fraud screening, which no tenant can disable: without
+    /// it a risky order could neither be held nor settled
+    const REVIEW: [(OrderStatus, OrderStatus); 3] = [
+        (OrderStatus::Pending, OrderStatus::OnHold),
+        (OrderStatus::OnHold, OrderStatus::Confirmed),
+        (OrderStatus::OnHold, OrderStatus::Cancelled),
+    ];
+
+    /// Only these transitions and those of the review; a tenant can disable steps, not
+    /// invent new ones
+    pub fn only(mut transitions: Vec<(OrderStatus, OrderStatus)>) -> Result<Self, DomainError> {
+        if let Some((from, to)) = transitions
+            .iter()
+            .find(|(from, to)| !from.can_transition_to(*to))
+        {
+            return Err(DomainError::InvalidStatusTransition {
+                from: *from,
+                to: *to,
+            });
+        }
+        for review in Self::REVIEW {
+            if !transitions.contains(&review) {
+                transitions.push(review);
+            }
+        }
+        Ok(Self(transitions))
+    }
+
+    pub fn allows(&self, from: OrderStatus, to: OrderStatus) -> bool {
+        self.0.contains(&(from, to))
+    }
+}
+
+/// Tenants served by the deployment (Port)
+pub trait TenantDirectory: Send + Sync {
+    /// Business rules of a tenant
+    fn tenant(&self, id: &TenantId) -> Result<Tenant, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eur(cents: i64) -> Money {
+        Money::eur(Decimal::new(cents, 2)).unwrap()
+    }
+
+    #[test]
+    fn test_tax_included_in_prices() {
+        let vat = TaxRule::new(Decimal::new(20, 2), true).unwrap();
+
+        assert_eq!(vat.tax_on(eur(12000)), eur(2000));
+        assert_eq!(vat.total_including_tax(eur(12000)), eur(12000));
+    }
+
+    #[test]
+    fn test_tax_added_to_prices() {
+        let sales_tax = TaxRule::new(Decimal::new(825, 4), false).unwrap();
+
+        // 8.25% of 19.99 = 1.649175
+        assert_eq!(sales_tax.tax_on(eur(1999)), eur(165));
+        assert_eq!(sales_tax.total_including_tax(eur(1999)), eur(2164));
+        assert!(TaxRule::new(Decimal::ONE, false).is_err());
+        assert!(TaxRule::new(Decimal::new(-1, 2), true).is_err());
+    }
+
+    #[test]
+    fn test_tenant_can_only_disable_transitions() {
+        use OrderStatus::*;
+        let standard = StatusTransitions::standard();
+        assert!(standard.allows(Pending, Confirmed));
+        assert!(standard.allows(Confirmed, Cancelled));
+        assert!(!standard.allows(Pending, Delivered));
+
+        let no_late_cancel = Tenant {
+            transitions: StatusTransitions::only(vec![
+                (Pending, Confirmed),
+                (Confirmed, Paid),
+                (Paid, Shipped),
+                (Shipped, Delivered),
+                (Pending, Cancelled),
+            ])
+            .unwrap(),
+            ..Tenant::default()
+        };
+        assert!(no_late_cancel.ensure_transition(Pending, Cancelled).is_ok());
+        assert!(no_late_cancel
+            .ensure_transition(Confirmed, Cancelled)
+            .is_err());
+        // Staying in the same status is not a transition
+        assert!(no_late_cancel.ensure_transition(Paid, Paid).is_ok());
+        // Risky orders are still held and reviewed
+        assert!(no_late_cancel.ensure_transition(Pending, OnHold).is_ok());
+        assert!(no_late_cancel.ensure_transition(OnHold, Confirmed).is_ok());
+        assert!(no_late_cancel.ensure_transition(OnHold, Cancelled).is_ok());
+
+        assert!(StatusTransitions::only(vec![(Pending, Paid)]).is_err());
+    }
+
+    #[test]
+    fn test_prices_must_be_in_the_tenant_currency() {
+        let tenant = Tenant::default();
+
+        assert!(tenant.ensure_currency(eur(100)).is_ok());
+        assert!(matches!(
+            tenant.ensure_currency(Money::usd(Decimal::ONE).unwrap()),
+            Err(DomainError::CurrencyNotAccepted { .. })
+        ));
+    }
+}
@@ -1,10 +1,12 @@
+use crate::domain::id_generator::IdGenerator;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Type-safe IDs to prevent mixing different entity IDs
 macro_rules! define_id {
     ($name:ident) => {
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
         #[serde(transparent)]
         pub struct $name(Uuid);
 
@@ -13,6 +15,11 @@ macro_rules! define_id {
                 Self(Uuid::new_v4())
             }
 
+            /// New identity drawn from the injected generator
+            pub fn generate(ids: &dyn IdGenerator) -> Self {
+                Self(ids.next_id())
+            }
+
             pub fn from_uuid(uuid: Uuid) -> Self {
                 Self(uuid)
             }
@@ -28,6 +35,14 @@ macro_rules! define_id {
             }
         }
 
+        impl std::str::FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::parse_str(s).map(Self)
+            }
+        }
+
         impl std::fmt::Display for $name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "{}", self.0)
@@ -41,6 +56,9 @@ define_id!(OrderItemId);
 define_id!(CustomerId);
 define_id!(ProductId);
 define_id!(PaymentId);
+define_id!(ShipmentId);
+define_id!(CartId);
+define_id!(InvoiceId);
 
 #[cfg(test)]
 mod tests {
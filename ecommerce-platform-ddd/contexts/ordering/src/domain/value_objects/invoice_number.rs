@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// InvoiceKind Value Object
+/// An invoice bills a paid order, a credit note refunds (part of) an invoice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InvoiceKind {
+    Invoice,
+    CreditNote,
+}
+
+impl InvoiceKind {
+    /// Prefix of the numbers of this series
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            InvoiceKind::Invoice => "INV",
+            InvoiceKind::CreditNote => "CN",
+        }
+    }
+}
+
+impl std::fmt::Display for InvoiceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvoiceKind::Invoice => write!(f, "INVOICE"),
+            InvoiceKind::CreditNote => write!(f, "CREDIT_NOTE"),
+        }
+    }
+}
+
+impl std::str::FromStr for InvoiceKind {
+    type Err = UnknownInvoiceKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "INVOICE" => Ok(InvoiceKind::Invoice),
+            "CREDIT_NOTE" => Ok(InvoiceKind::CreditNote),
+            _ => Err(UnknownInvoiceKind(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown invoice kind: {0}")]
+pub struct UnknownInvoiceKind(pub String);
+
+/// InvoiceNumber Value Object
+/// Legal number of an invoice, e.g. `INV-2026-000042`
+///
+/// Each kind has its own series, restarting at 1 every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvoiceNumber {
+    kind: InvoiceKind,
+    year: i32,
+    sequence: u32,
+}
+
+impl InvoiceNumber {
+    pub fn new(kind: InvoiceKind, year: i32, sequence: u32) -> Self {
+        Self {
+            kind,
+            year,
+            sequence,
+        }
+    }
+
+    pub fn kind(&self) -> InvoiceKind {
+        self.kind
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+}
+
+impl std::fmt::Display for InvoiceNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}-{:06}",
+            self.kind.prefix(),
+            self.year,
+            self.sequence
+        )
+    }
+}
+
+impl std::str::FromStr for InvoiceNumber {
+    type Err = InvalidInvoiceNumber;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidInvoiceNumber(s.to_string());
+        let mut parts = s.splitn(3, '-');
+        let kind = match parts.next() {
+            Some("INV") => InvoiceKind::Invoice,
+            Some("CN") => InvoiceKind::CreditNote,
+            _ => return Err(invalid()),
+        };
+        let year = parts
+            .next()
+            .and_then(|year| year.parse().ok())
+            .ok_or_else(invalid)?;
+        let sequence = parts
+            .next()
+            .and_then(|sequence| sequence.parse().ok())
+            .ok_or_else(invalid)?;
+        Ok(Self::new(kind, year, sequence))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid invoice number: {0}")]
+pub struct InvalidInvoiceNumber(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_is_prefixed_by_kind_and_year() {
+        assert_eq!(
+            InvoiceNumber::new(InvoiceKind::Invoice, 2026, 42).to_string(),
+            "INV-2026-000042"
+        );
+        assert_eq!(
+            InvoiceNumber::new(InvoiceKind::CreditNote, 2026, 7).to_string(),
+            "CN-2026-000007"
+        );
+    }
+
+    #[test]
+    fn test_number_round_trips_through_its_string_form() {
+        let number = InvoiceNumber::new(InvoiceKind::CreditNote, 2027, 1_234_567);
+        assert_eq!(number.to_string().parse::<InvoiceNumber>().unwrap(), number);
+        assert!("XX-2026-000001".parse::<InvoiceNumber>().is_err());
+        assert!("INV-2026".parse::<InvoiceNumber>().is_err());
+    }
+}
@@ -1,7 +1,21 @@
+pub mod invoice_number;
 pub mod money;
 pub mod order_status;
+pub mod postal_address;
+pub mod share_token;
+pub mod shipment_status;
+pub mod tenant_id;
 pub mod ids;
+pub mod wishlist_priority;
 
+pub use invoice_number::{InvalidInvoiceNumber, InvoiceKind, InvoiceNumber, UnknownInvoiceKind};
 pub use money::{Currency, Money, MoneyError};
-pub use order_status::OrderStatus;
-pub use ids::{CustomerId, OrderId, OrderItemId, PaymentId, ProductId};
+pub use order_status::{OrderStatus, UnknownOrderStatus};
+pub use postal_address::PostalAddress;
+pub use ids::{
+    CartId, CustomerId, InvoiceId, OrderId, OrderItemId, PaymentId, ProductId, ShipmentId,
+};
+pub use share_token::{InvalidShareToken, ShareToken};
+pub use shipment_status::{ShipmentStatus, UnknownShipmentStatus};
+pub use tenant_id::{InvalidTenantId, TenantId};
+pub use wishlist_priority::{UnknownWishlistPriority, WishlistPriority};
@@ -1,16 +1,19 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, Sub};
+use utoipa::ToSchema;
 
 /// Money Value Object
 /// Immutable, self-validating
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Serialized as `{"amount": "10.00", "currency": "EUR"}` (decimal as string)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct Money {
+    #[schema(value_type = String, example = "10.00")]
     amount: Decimal,
     currency: Currency,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum Currency {
     EUR,
     USD,
@@ -46,6 +49,35 @@ impl Money {
     }
 }
 
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Currency::EUR => write!(f, "EUR"),
+            Currency::USD => write!(f, "USD"),
+            Currency::GBP => write!(f, "GBP"),
+        }
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "EUR" => Ok(Currency::EUR),
+            "USD" => Ok(Currency::USD),
+            "GBP" => Ok(Currency::GBP),
+            _ => Err(MoneyError::UnknownCurrency(s.to_string())),
+        }
+    }
+}
+
 // Arithmetic operations with currency validation
 impl Add for Money {
     type Output = Result<Money, MoneyError>;
@@ -80,6 +112,9 @@ pub enum MoneyError {
     
     #[error("Currency mismatch in operation")]
     CurrencyMismatch,
+
+    #[error("Unknown currency: {0}")]
+    UnknownCurrency(String),
 }
 
 #[cfg(test)]
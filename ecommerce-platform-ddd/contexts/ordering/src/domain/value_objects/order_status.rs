@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// OrderStatus Value Object
 /// Encapsulates valid status transitions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     Pending,
+    /// Held by the risk assessment until an operator approves or rejects it
+    OnHold,
     Confirmed,
     Paid,
     Shipped,
@@ -20,10 +23,13 @@ impl OrderStatus {
         matches!(
             (self, new_status),
             (Pending, Confirmed)
+                | (Pending, OnHold)
+                | (OnHold, Confirmed)
                 | (Confirmed, Paid)
                 | (Paid, Shipped)
                 | (Shipped, Delivered)
                 | (Pending, Cancelled)
+                | (OnHold, Cancelled)
                 | (Confirmed, Cancelled)
         )
     }
@@ -41,6 +47,7 @@ impl std::fmt::Display for OrderStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             OrderStatus::Pending => write!(f, "PENDING"),
+            OrderStatus::OnHold => write!(f, "ON_HOLD"),
             OrderStatus::Confirmed => write!(f, "CONFIRMED"),
             OrderStatus::Paid => write!(f, "PAID"),
             OrderStatus::Shipped => write!(f, "SHIPPED"),
@@ -50,6 +57,27 @@ impl std::fmt::Display for OrderStatus {
     }
 }
 
+impl std::str::FromStr for OrderStatus {
+    type Err = UnknownOrderStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(OrderStatus::Pending),
+            "ON_HOLD" => Ok(OrderStatus::OnHold),
+            "CONFIRMED" => Ok(OrderStatus::Confirmed),
+            "PAID" => Ok(OrderStatus::Paid),
+            "SHIPPED" => Ok(OrderStatus::Shipped),
+            "DELIVERED" => Ok(OrderStatus::Delivered),
+            "CANCELLED" => Ok(OrderStatus::Cancelled),
+            _ => Err(UnknownOrderStatus(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown order status: {0}")]
+pub struct UnknownOrderStatus(pub String);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,12 +87,16 @@ mod tests {
         assert!(OrderStatus::Pending.can_transition_to(OrderStatus::Confirmed));
         assert!(OrderStatus::Confirmed.can_transition_to(OrderStatus::Paid));
         assert!(OrderStatus::Paid.can_transition_to(OrderStatus::Shipped));
+        assert!(OrderStatus::Pending.can_transition_to(OrderStatus::OnHold));
+        assert!(OrderStatus::OnHold.can_transition_to(OrderStatus::Confirmed));
+        assert!(OrderStatus::OnHold.can_transition_to(OrderStatus::Cancelled));
     }
 
     #[test]
     fn test_invalid_transitions() {
         assert!(!OrderStatus::Pending.can_transition_to(OrderStatus::Delivered));
         assert!(!OrderStatus::Cancelled.can_transition_to(OrderStatus::Paid));
+        assert!(!OrderStatus::OnHold.can_transition_to(OrderStatus::Paid));
     }
 
     #[test]
@@ -73,4 +105,17 @@ mod tests {
         assert!(OrderStatus::Cancelled.is_terminal());
         assert!(!OrderStatus::Pending.is_terminal());
     }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for status in [
+            OrderStatus::Pending,
+            OrderStatus::OnHold,
+            OrderStatus::Shipped,
+            OrderStatus::Cancelled,
+        ] {
+            assert_eq!(status.to_string().parse::<OrderStatus>().unwrap(), status);
+        }
+        assert!("UNKNOWN".parse::<OrderStatus>().is_err());
+    }
 }
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// PostalAddress Value Object
+/// Billing or shipping address given when an order is confirmed
+///
+/// Only used to assess the risk of the order: it is never stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PostalAddress {
+    #[schema(example = "12 rue de la Paix")]
+    pub line1: String,
+    #[schema(example = "75002")]
+    pub postal_code: String,
+    #[schema(example = "Paris")]
+    pub city: String,
+    /// ISO 3166-1 alpha-2 code
+    #[schema(example = "FR")]
+    pub country: String,
+}
+
+impl PostalAddress {
+    /// Business rule: two addresses are in the same place when they share the country
+    /// and the postal code, whatever the case and spacing (street lines are written
+    /// in too many ways to be compared)
+    pub fn same_area(&self, other: &PostalAddress) -> bool {
+        fn normalize(value: &str) -> String {
+            value
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_uppercase)
+                .collect()
+        }
+        normalize(&self.country) == normalize(&other.country)
+            && normalize(&self.postal_code) == normalize(&other.postal_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(postal_code: &str, country: &str) -> PostalAddress {
+        PostalAddress {
+            line1: "221B Baker Street".to_string(),
+            postal_code: postal_code.to_string(),
+            city: "London".to_string(),
+            country: country.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_same_area_ignores_case_and_spacing() {
+        assert!(address("NW1 6XE", "GB").same_area(&address("nw16xe", " gb")));
+        assert!(!address("NW1 6XE", "GB").same_area(&address("NW1 6XE", "IE")));
+        assert!(!address("NW1 6XE", "GB").same_area(&address("EC1A 1BB", "GB")));
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// ShareToken Value Object
+/// Secret part of the link giving read access to a wishlist
+///
+/// 32 lowercase hexadecimal characters. Always random: unlike identities, a token must
+/// not be guessable from the injected `IdGenerator`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "3f6c1e0b9a8d4f2e8b7c6d5e4f3a2b1c")]
+pub struct ShareToken(String);
+
+impl ShareToken {
+    pub const LENGTH: usize = 32;
+
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().simple().to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ShareToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for ShareToken {
+    type Err = InvalidShareToken;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = s.len() == Self::LENGTH
+            && s.bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+        if !valid {
+            return Err(InvalidShareToken);
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for ShareToken {
+    type Error = InvalidShareToken;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ShareToken> for String {
+    fn from(token: ShareToken) -> Self {
+        token.0
+    }
+}
+
+/// The token itself is not echoed back: it may be a mistyped valid one
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid share token")]
+pub struct InvalidShareToken;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_tokens_parse_back() {
+        let token = ShareToken::generate();
+        assert_eq!(token.as_str().parse::<ShareToken>().unwrap(), token);
+        assert_ne!(ShareToken::generate(), token);
+        for invalid in [
+            "",
+            "3F6C1E0B9A8D4F2E8B7C6D5E4F3A2B1C",
+            "3f6c1e0b",
+            &"g".repeat(32),
+        ] {
+            assert!(invalid.parse::<ShareToken>().is_err(), "{}", invalid);
+        }
+    }
+}
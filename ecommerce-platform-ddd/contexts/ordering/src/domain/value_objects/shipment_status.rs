@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// ShipmentStatus Value Object
+/// A parcel is prepared, then handed over to the carrier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShipmentStatus {
+    Pending,
+    Shipped,
+}
+
+impl std::fmt::Display for ShipmentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShipmentStatus::Pending => write!(f, "PENDING"),
+            ShipmentStatus::Shipped => write!(f, "SHIPPED"),
+        }
+    }
+}
+
+impl std::str::FromStr for ShipmentStatus {
+    type Err = UnknownShipmentStatus;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(ShipmentStatus::Pending),
+            "SHIPPED" => Ok(ShipmentStatus::Shipped),
+            _ => Err(UnknownShipmentStatus(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown shipment status: {0}")]
+pub struct UnknownShipmentStatus(pub String);
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// TenantId Value Object
+/// Storefront owning an order, e.g. `acme-fr`
+///
+/// Lowercase ASCII letters, digits, `-` and `_`, at most 64 characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, example = "default")]
+pub struct TenantId(String);
+
+impl TenantId {
+    /// Tenant of the data created before multi-tenancy, and of single-storefront deployments
+    pub const DEFAULT: &'static str = "default";
+    pub const MAX_LENGTH: usize = 64;
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for TenantId {
+    fn default() -> Self {
+        Self(Self::DEFAULT.to_string())
+    }
+}
+
+impl std::fmt::Display for TenantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for TenantId {
+    type Err = InvalidTenantId;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = !s.is_empty()
+            && s.len() <= Self::MAX_LENGTH
+            && s.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_');
+        if !valid {
+            return Err(InvalidTenantId(s.to_string()));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = InvalidTenantId;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<TenantId> for String {
+    fn from(tenant_id: TenantId) -> Self {
+        tenant_id.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid tenant id: {0}")]
+pub struct InvalidTenantId(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("acme-fr".parse::<TenantId>().unwrap().as_str(), "acme-fr");
+        assert_eq!(TenantId::default().as_str(), TenantId::DEFAULT);
+        for invalid in ["", "Acme", "acme fr", "acme/fr", &"a".repeat(65)] {
+            assert!(invalid.parse::<TenantId>().is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// WishlistPriority Value Object
+/// How much the customer wants a saved product, `HIGH` listed first
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WishlistPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl std::fmt::Display for WishlistPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WishlistPriority::Low => write!(f, "LOW"),
+            WishlistPriority::Normal => write!(f, "NORMAL"),
+            WishlistPriority::High => write!(f, "HIGH"),
+        }
+    }
+}
+
+impl std::str::FromStr for WishlistPriority {
+    type Err = UnknownWishlistPriority;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "LOW" => Ok(WishlistPriority::Low),
+            "NORMAL" => Ok(WishlistPriority::Normal),
+            "HIGH" => Ok(WishlistPriority::High),
+            _ => Err(UnknownWishlistPriority(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown wishlist priority: {0}")]
+pub struct UnknownWishlistPriority(pub String);
@@ -0,0 +1,206 @@
+use super::{ApiError, AppState, Operator, ProblemDetails, TenantHeader};
+use crate::application::commands::{ImportOrdersCommand, ReviewDecision, ReviewOrderCommand};
+use crate::application::dto::{ImportReport, ReviewOrderRequest};
+use crate::application::queries::ExportOrdersQuery;
+use crate::domain::{
+    repositories::OrderCriteria,
+    value_objects::{OrderId, OrderStatus, TenantId},
+};
+use crate::infrastructure::bulk::BulkFormat;
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tokio_stream::wrappers::ReceiverStream;
+use utoipa::IntoParams;
+
+/// Largest import accepted in one request (legacy migrations come in big files)
+pub const IMPORT_BODY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Orders read from the repository per round trip while exporting
+const EXPORT_BATCH_SIZE: u64 = 500;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ImportParams {
+    /// File format of the body (default: ndjson)
+    #[serde(default)]
+    pub format: BulkFormat,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportParams {
+    /// File format of the export (default: ndjson)
+    #[serde(default)]
+    pub format: BulkFormat,
+    /// Only orders in this status
+    pub status: Option<OrderStatus>,
+    /// Only orders created at or after this instant (RFC 3339)
+    pub from: Option<DateTime<Utc>>,
+    /// Only orders created strictly before this instant (RFC 3339)
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// POST /api/admin/orders/import
+#[utoipa::path(
+    post,
+    path = "/api/admin/orders/import",
+    tag = "admin",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ImportParams,
+    ),
+    request_body(
+        description = "CSV (one line per item, grouped by `order_ref`) or JSON Lines (one `CreateOrderRequest` per line)",
+        content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )
+    ),
+    responses(
+        (status = 200, description = "Import finished; rejected rows are listed with their error", body = ImportReport),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure, import aborted", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn import_orders(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Operator(operator): Operator,
+    Query(params): Query<ImportParams>,
+    body: String,
+) -> Result<Json<ImportReport>, ApiError> {
+    let report = state
+        .import_orders
+        .handle(ImportOrdersCommand {
+            tenant_id,
+            rows: params.format.read_orders(&body),
+            actor: operator.actor(),
+        })
+        .await?;
+    Ok(Json(report))
+}
+
+/// POST /api/admin/orders/{order_id}/review
+#[utoipa::path(
+    post,
+    path = "/api/admin/orders/{order_id}/review",
+    tag = "admin",
+    security(("bearer_token" = [])),
+    request_body = ReviewOrderRequest,
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+    ),
+    responses(
+        (status = 204, description = "Order confirmed (APPROVE) or cancelled (REJECT)"),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order is not ON_HOLD", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Missing rejection reason", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn review_order(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Operator(reviewer): Operator,
+    Path(order_id): Path<OrderId>,
+    Json(request): Json<ReviewOrderRequest>,
+) -> Result<StatusCode, ApiError> {
+    let decision = match request {
+        ReviewOrderRequest::Approve => ReviewDecision::Approve,
+        ReviewOrderRequest::Reject { reason } => ReviewDecision::Reject { reason },
+    };
+    state
+        .review_order
+        .handle(ReviewOrderCommand {
+            tenant_id,
+            order_id,
+            decision,
+            actor: reviewer.actor(),
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/admin/orders/export
+#[utoipa::path(
+    get,
+    path = "/api/admin/orders/export",
+    tag = "admin",
+    security(("bearer_token" = [])),
+    params(
+        ExportParams,
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Matching orders, oldest first, streamed in batches", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn export_orders(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    let format = params.format;
+    let mut export = state.export_orders.handle(ExportOrdersQuery {
+        criteria: OrderCriteria {
+            tenant_id: Some(tenant_id),
+            status: params.status,
+            created_from: params.from,
+            created_until: params.to,
+        },
+        batch_size: EXPORT_BATCH_SIZE,
+    });
+
+    // Batches are encoded as they are read; a slow client applies backpressure
+    let (tx, rx) = tokio::sync::mpsc::channel::<Result<Bytes, std::io::Error>>(2);
+    tokio::spawn(async move {
+        if tx.send(Ok(format.export_header().into())).await.is_err() {
+            return;
+        }
+        loop {
+            let chunk = match export.next_batch().await {
+                Ok(Some(batch)) => format.write_orders(&batch).map(Bytes::from),
+                Ok(None) => break,
+                Err(err) => {
+                    // Headers are already sent: abort the body so the client sees a failure
+                    tracing::error!("Order export failed: {}", err);
+                    Err(std::io::Error::other(err.to_string()))
+                }
+            };
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    (
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"orders.{}\"", format.extension()),
+            ),
+        ],
+        Body::from_stream(ReceiverStream::new(rx)),
+    )
+        .into_response()
+}
@@ -0,0 +1,254 @@
+use super::{ApiError, AppState, ProblemDetails, TenantHeader};
+use crate::application::audit::Actor;
+use crate::application::commands::{
+    CartChange, CheckoutCartCommand, CreateCartCommand, MergeCartsCommand, UpdateCartCommand,
+};
+use crate::application::dto::{
+    AddCartLineRequest, CartResponse, CreateCartRequest, CreateCartResponse, CreateOrderResponse,
+    MergeCartsRequest, MergeCartsResponse, UpdateCartLineRequest,
+};
+use crate::application::queries::GetCartQuery;
+use crate::domain::value_objects::{CartId, CustomerId, ProductId, TenantId};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+/// POST /api/carts
+#[utoipa::path(
+    post,
+    path = "/api/carts",
+    tag = "carts",
+    request_body = CreateCartRequest,
+    params(TenantHeader),
+    responses(
+        (status = 201, description = "Cart opened (the existing one for a customer who already has a cart)", body = CreateCartResponse),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn create_cart(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Json(request): Json<CreateCartRequest>,
+) -> Result<(StatusCode, Json<CreateCartResponse>), ApiError> {
+    let cart_id = state
+        .create_cart
+        .handle(CreateCartCommand {
+            tenant_id,
+            customer_id: request.customer_id,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(CreateCartResponse { cart_id })))
+}
+
+/// GET /api/carts/{cart_id}
+#[utoipa::path(
+    get,
+    path = "/api/carts/{cart_id}",
+    tag = "carts",
+    params(
+        TenantHeader,
+        ("cart_id" = CartId, Path, description = "Cart identifier"),
+    ),
+    responses(
+        (status = 200, description = "Cart found", body = CartResponse),
+        (status = 404, description = "Cart not found (or expired and purged)", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_cart(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(cart_id): Path<CartId>,
+) -> Result<Json<CartResponse>, ApiError> {
+    let cart = state
+        .get_cart
+        .handle(GetCartQuery { tenant_id, cart_id })
+        .await?;
+    Ok(Json(cart))
+}
+
+/// POST /api/carts/{cart_id}/lines
+#[utoipa::path(
+    post,
+    path = "/api/carts/{cart_id}/lines",
+    tag = "carts",
+    request_body = AddCartLineRequest,
+    params(
+        TenantHeader,
+        ("cart_id" = CartId, Path, description = "Cart identifier"),
+    ),
+    responses(
+        (status = 204, description = "Product added (quantities add up when already in the cart)"),
+        (status = 404, description = "Cart not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Cart expired", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid quantity, product name or price, or too many lines", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn add_cart_line(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(cart_id): Path<CartId>,
+    Json(request): Json<AddCartLineRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_cart
+        .handle(UpdateCartCommand {
+            tenant_id,
+            cart_id,
+            change: CartChange::AddLine {
+                product_id: request.product_id,
+                product_name: request.product_name,
+                quantity: request.quantity,
+                unit_price: request.unit_price,
+            },
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PUT /api/carts/{cart_id}/lines/{product_id}
+#[utoipa::path(
+    put,
+    path = "/api/carts/{cart_id}/lines/{product_id}",
+    tag = "carts",
+    request_body = UpdateCartLineRequest,
+    params(
+        TenantHeader,
+        ("cart_id" = CartId, Path, description = "Cart identifier"),
+        ("product_id" = ProductId, Path, description = "Product of the line"),
+    ),
+    responses(
+        (status = 204, description = "Quantity updated"),
+        (status = 404, description = "Cart not found or product not in the cart", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Cart expired", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid quantity", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn update_cart_line(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path((cart_id, product_id)): Path<(CartId, ProductId)>,
+    Json(request): Json<UpdateCartLineRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_cart
+        .handle(UpdateCartCommand {
+            tenant_id,
+            cart_id,
+            change: CartChange::SetQuantity {
+                product_id,
+                quantity: request.quantity,
+            },
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/carts/{cart_id}/lines/{product_id}
+#[utoipa::path(
+    delete,
+    path = "/api/carts/{cart_id}/lines/{product_id}",
+    tag = "carts",
+    params(
+        TenantHeader,
+        ("cart_id" = CartId, Path, description = "Cart identifier"),
+        ("product_id" = ProductId, Path, description = "Product of the line"),
+    ),
+    responses(
+        (status = 204, description = "Product removed"),
+        (status = 404, description = "Cart not found or product not in the cart", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Cart expired", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_cart_line(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path((cart_id, product_id)): Path<(CartId, ProductId)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_cart
+        .handle(UpdateCartCommand {
+            tenant_id,
+            cart_id,
+            change: CartChange::RemoveLine { product_id },
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/customers/{customer_id}/cart/merge
+#[utoipa::path(
+    post,
+    path = "/api/customers/{customer_id}/cart/merge",
+    tag = "carts",
+    request_body = MergeCartsRequest,
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Customer who just logged in"),
+    ),
+    responses(
+        (status = 200, description = "Anonymous cart merged into the customer's cart (or assigned to the customer)", body = MergeCartsResponse),
+        (status = 404, description = "Anonymous cart not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Cart expired or already owned by a customer", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Too many lines once merged", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn merge_carts(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+    Json(request): Json<MergeCartsRequest>,
+) -> Result<Json<MergeCartsResponse>, ApiError> {
+    let cart_id = state
+        .merge_carts
+        .handle(MergeCartsCommand {
+            tenant_id,
+            customer_id,
+            anonymous_cart_id: request.anonymous_cart_id,
+        })
+        .await?;
+    Ok(Json(MergeCartsResponse { cart_id }))
+}
+
+/// POST /api/carts/{cart_id}/checkout
+#[utoipa::path(
+    post,
+    path = "/api/carts/{cart_id}/checkout",
+    tag = "carts",
+    security((), ("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("cart_id" = CartId, Path, description = "Cart identifier"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the first response when the same request is retried"),
+    ),
+    responses(
+        (status = 201, description = "Order created in PENDING status; the cart is deleted", body = CreateOrderResponse),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Cart not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Cart expired or anonymous, prices changed (the cart was repriced), insufficient stock, or Idempotency-Key reused with another request or still in progress", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Empty cart or product no longer sold", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn checkout_cart(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path(cart_id): Path<CartId>,
+) -> Result<(StatusCode, Json<CreateOrderResponse>), ApiError> {
+    let order_id = state
+        .checkout_cart
+        .handle(CheckoutCartCommand {
+            tenant_id,
+            cart_id,
+            actor,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(CreateOrderResponse { order_id })))
+}
@@ -0,0 +1,219 @@
+use crate::application::error::ApplicationError;
+use crate::domain::errors::{DomainError, InfrastructureError};
+use axum::{
+    http::{header::CONTENT_TYPE, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Media type of error bodies (RFC 7807)
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Error body returned by every endpoint, as `application/problem+json` (RFC 7807)
+///
+/// 400: invalid header, 401: missing or invalid bearer token, 403: event
+/// stream of another customer, tenant mismatch or operator role required,
+/// 404: order, item, shipment, cart, cart line, invoice, webhook, wishlist or saved product not
+/// found,
+/// 409: invalid status transition, order no longer modifiable or held for review, shipment
+/// already shipped, cart expired or already assigned, prices or stock changed, order not paid
+/// yet, invoice already fully credited, idempotency key reused,
+/// 422: business rule violation (empty order or cart, too many items, quantity, money,
+/// shipment quantities, product no longer sold, credit note lines or reason, webhook URL or
+/// events, wishlist full or note too long, missing rejection reason),
+/// 429: rate limit exceeded, 500: storage failure.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    /// URI identifying the kind of problem, derived from `code`
+    #[serde(rename = "type")]
+    #[schema(example = "urn:problem:ordering:empty-order")]
+    pub problem_type: String,
+    /// Reason phrase of the status
+    #[schema(example = "Unprocessable Entity")]
+    pub title: String,
+    #[schema(example = 422)]
+    pub status: u16,
+    /// What went wrong with this request
+    #[schema(example = "Order cannot be empty")]
+    pub detail: String,
+    /// Stable machine-readable code, for clients to branch on
+    #[schema(example = "EMPTY_ORDER")]
+    pub code: String,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: &str, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: format!(
+                "urn:problem:ordering:{}",
+                code.to_ascii_lowercase().replace('_', "-")
+            ),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: detail.into(),
+            code: code.to_string(),
+        }
+    }
+}
+
+/// HTTP error: status code, stable code and detail, rendered as problem details
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    detail: String,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+}
+
+impl From<DomainError> for ApiError {
+    fn from(err: DomainError) -> Self {
+        let status = match &err {
+            DomainError::OrderItemNotFound
+            | DomainError::ShipmentNotFound
+            | DomainError::CartLineNotFound
+            | DomainError::WishlistItemNotFound
+            | DomainError::UnknownTenant(_) => StatusCode::NOT_FOUND,
+            DomainError::InvalidStatusTransition { .. }
+            | DomainError::CannotCancelTerminalOrder
+            | DomainError::CannotModifyNonPendingOrder
+            | DomainError::CannotRemoveLastItem
+            | DomainError::OrderHasShipments
+            | DomainError::CannotAnonymizeOrderInProgress
+            | DomainError::OrderAwaitingReview
+            | DomainError::OrderNotAwaitingShipment
+            | DomainError::ShipmentAlreadyShipped
+            | DomainError::CartExpired
+            | DomainError::AnonymousCart
+            | DomainError::CartAlreadyAssigned
+            | DomainError::PricesChanged
+            | DomainError::InsufficientStock { .. }
+            | DomainError::OrderNotPaid
+            | DomainError::InvoiceFullyCredited => StatusCode::CONFLICT,
+            DomainError::EmptyOrder
+            | DomainError::InvalidQuantity
+            | DomainError::InvalidProductName
+            | DomainError::TooManyItems { .. }
+            | DomainError::EmptyShipment
+            | DomainError::ShipmentExceedsOrderedQuantity
+            | DomainError::CannotMergeShipmentWithItself
+            | DomainError::InvalidTrackingNumber
+            | DomainError::EmptyCart
+            | DomainError::CannotMergeCartWithItself
+            | DomainError::ProductUnavailable { .. }
+            | DomainError::CannotCreditCreditNote
+            | DomainError::InvalidCreditNoteReason
+            | DomainError::EmptyRejectionReason
+            | DomainError::EmptyCreditNote
+            | DomainError::InvoiceLineNotFound
+            | DomainError::CreditNoteExceedsInvoice
+            | DomainError::CurrencyNotAccepted { .. }
+            | DomainError::WishlistFull { .. }
+            | DomainError::WishlistNoteTooLong { .. }
+            | DomainError::InvalidTaxRate
+            | DomainError::InvalidPriceRule { .. }
+            | DomainError::InvalidRiskRule { .. }
+            | DomainError::MoneyError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        Self::new(status, err.code(), err.to_string())
+    }
+}
+
+impl From<InfrastructureError> for ApiError {
+    /// The cause stays in the logs: clients only learn that the service failed
+    fn from(err: InfrastructureError) -> Self {
+        let code = err.code();
+        tracing::error!("Request failed: {}", ApplicationError::from(err).report());
+        Self::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            code,
+            "The service could not complete the request",
+        )
+    }
+}
+
+impl From<ApplicationError> for ApiError {
+    fn from(err: ApplicationError) -> Self {
+        let status = match err {
+            ApplicationError::Domain(err) => return err.into(),
+            ApplicationError::Infrastructure(err) => return err.into(),
+            ApplicationError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApplicationError::Conflict(_) => StatusCode::CONFLICT,
+            ApplicationError::Unauthorized(_) => StatusCode::FORBIDDEN,
+        };
+        Self::new(status, err.code(), err.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ProblemDetails::new(self.status, self.code, self.detail);
+        let mut response = (self.status, Json(body)).into_response();
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::Resource;
+    use axum::body::to_bytes;
+
+    async fn render(err: ApiError) -> (Response, ProblemDetails) {
+        let response = err.into_response();
+        let (parts, body) = response.into_parts();
+        let body = to_bytes(body, usize::MAX).await.unwrap();
+        let problem = serde_json::from_slice(&body).unwrap();
+        (Response::from_parts(parts, Default::default()), problem)
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_rendered_as_problem_details() {
+        let (response, problem) = render(ApplicationError::NotFound(Resource::Order).into()).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[CONTENT_TYPE], PROBLEM_JSON);
+        assert_eq!(problem.problem_type, "urn:problem:ordering:order-not-found");
+        assert_eq!(problem.title, "Not Found");
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.detail, "Order not found");
+        assert_eq!(problem.code, "ORDER_NOT_FOUND");
+
+        let (response, problem) =
+            render(ApplicationError::from(DomainError::EmptyOrder).into()).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(problem.code, "EMPTY_ORDER");
+    }
+
+    #[tokio::test]
+    async fn test_infrastructure_causes_are_not_exposed() {
+        let err = InfrastructureError::storage("Database error").with_source(
+            std::io::Error::other("password authentication failed for user \"orders\""),
+        );
+        let (response, problem) = render(ApplicationError::from(err).into()).await;
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.code, "STORAGE_FAILURE");
+        assert!(!problem.detail.contains("password"));
+    }
+}
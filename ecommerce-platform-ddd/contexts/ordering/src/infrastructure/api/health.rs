@@ -0,0 +1,38 @@
+use super::AppState;
+use crate::infrastructure::health::{HealthStatus, ReadinessReport};
+use axum::{extract::State, http::StatusCode, Json};
+
+/// GET /
+pub async fn root() -> &'static str {
+    "E-Commerce Platform - Order Service (DDD Architecture)"
+}
+
+/// GET /health (liveness: the process answers, dependencies are not probed)
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses((status = 200, description = "Process is alive", body = String, content_type = "text/plain"))
+)]
+pub async fn health_check() -> &'static str {
+    "OK"
+}
+
+/// GET /ready (readiness: repository, broker, ... must be reachable)
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "All components are up", body = ReadinessReport),
+        (status = 503, description = "At least one component is down", body = ReadinessReport),
+    )
+)]
+pub async fn readiness_check(State(state): State<AppState>) -> (StatusCode, Json<ReadinessReport>) {
+    let report = state.readiness.report().await;
+    let status = match report.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status, Json(report))
+}
@@ -0,0 +1,443 @@
+use super::{ApiError, AppState};
+use crate::domain::value_objects::TenantId;
+use crate::infrastructure::idempotency::{IdempotencyOutcome, StoredResponse};
+use crate::infrastructure::tenancy::Caller;
+use axum::{
+    body::{to_bytes, Body},
+    extract::{FromRequestParts, Request, State},
+    http::{header, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+const MAX_KEY_LENGTH: usize = 255;
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Middleware: replays the stored response for a repeated `Idempotency-Key`
+///
+/// Requests without the header go through untouched. Keys are scoped to the
+/// resolved tenant and the caller, so the same key sent by another storefront or
+/// customer starts a request of its own. Server errors release the key so the
+/// client can retry.
+pub async fn idempotency_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (Some(store), Some(key)) = (
+        state.idempotency_store.clone(),
+        request.headers().get(IDEMPOTENCY_KEY_HEADER),
+    ) else {
+        return next.run(request).await;
+    };
+    let key = match key.to_str() {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => key.to_string(),
+        _ => {
+            return ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_IDEMPOTENCY_KEY",
+                "Invalid Idempotency-Key header",
+            )
+            .into_response()
+        }
+    };
+
+    let (mut parts, body) = request.into_parts();
+    let tenant_id = match TenantId::from_request_parts(&mut parts, &state).await {
+        Ok(tenant_id) => tenant_id,
+        Err(err) => return err.into_response(),
+    };
+    // Requests without a subject share the tenant scope; bad tokens were refused above
+    let caller = Caller::from_request_parts(&mut parts, &state).await.ok();
+    let key = scoped_key(&tenant_id, caller.as_ref(), &key);
+
+    let Ok(body) = to_bytes(body, MAX_BODY_BYTES).await else {
+        return ApiError::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "PAYLOAD_TOO_LARGE",
+            "Request body too large",
+        )
+        .into_response();
+    };
+    let fingerprint = fingerprint(&parts.method, parts.uri.path(), &body);
+
+    match store.begin(&key, &fingerprint).await {
+        Ok(IdempotencyOutcome::Started) => {}
+        Ok(IdempotencyOutcome::Replay(stored)) => return replay(stored),
+        Ok(IdempotencyOutcome::InProgress) => {
+            return ApiError::new(
+                StatusCode::CONFLICT,
+                "IDEMPOTENCY_KEY_IN_USE",
+                "A request with this Idempotency-Key is still being processed",
+            )
+            .into_response()
+        }
+        Ok(IdempotencyOutcome::Mismatch) => {
+            return ApiError::new(
+                StatusCode::CONFLICT,
+                "IDEMPOTENCY_KEY_REUSED",
+                "Idempotency-Key was already used with a different request",
+            )
+            .into_response()
+        }
+        Err(err) => return ApiError::from(err).into_response(),
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+
+    if response.status().is_server_error() {
+        if let Err(err) = store.abandon(&key).await {
+            tracing::warn!("Failed to release idempotency key {}: {}", key, err);
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::warn!(
+                "Failed to buffer response for idempotency key {}: {}",
+                key,
+                err
+            );
+            let _ = store.abandon(&key).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let stored = StoredResponse {
+        status: parts.status.as_u16(),
+        headers: parts
+            .headers
+            .iter()
+            // Recomputed for the replayed body
+            .filter(|(name, _)| *name != header::CONTENT_LENGTH)
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+        body: body.to_vec(),
+    };
+    if let Err(err) = store.complete(&key, stored).await {
+        tracing::warn!(
+            "Failed to store response for idempotency key {}: {}",
+            key,
+            err
+        );
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Store key: the client key within the tenant and caller that sent it
+///
+/// The subject is hashed with the key, which holds no newline, so that neither
+/// its length nor its characters can make two scopes collide.
+fn scoped_key(tenant_id: &TenantId, caller: Option<&Caller>, key: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(
+        caller
+            .map(|caller| caller.subject.as_str())
+            .unwrap_or_default(),
+    );
+    hasher.update(b"\n");
+    hasher.update(key);
+    format!("{}:{:x}", tenant_id, hasher.finalize())
+}
+
+/// Hash of method, path and body identifying the request payload
+fn fingerprint(method: &Method, path: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update(b" ");
+    hasher.update(path);
+    hasher.update(b"\n");
+    hasher.update(body);
+    format!("{:x}", hasher.finalize())
+}
+
+/// The stored response as first sent, flagged with `Idempotent-Replayed`
+///
+/// Responses stored without their headers are JSON, the only body the routes return.
+fn replay(stored: StoredResponse) -> Response {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = status;
+    let headers = response.headers_mut();
+    if stored.headers.is_empty() {
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+    }
+    for (name, value) in &stored.headers {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::{
+        repositories::OrderRepository,
+        value_objects::{CustomerId, Money, ProductId, TenantId},
+    };
+    use crate::infrastructure::api::{router, AppState, TEST_JWT_SECRET};
+    use crate::infrastructure::catalog::InMemoryCatalog;
+    use crate::infrastructure::idempotency::{InMemoryIdempotencyStore, StoredResponse};
+    use crate::infrastructure::messaging::NoOpEventPublisher;
+    use crate::infrastructure::persistence::{
+        repositories::InMemoryOrderRepository, InMemoryInvoiceRepository,
+    };
+    use crate::infrastructure::tenancy::jwt::encode_hs256;
+    use axum::{body::Body, http::Request, Router};
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn app(repo: Arc<InMemoryOrderRepository>) -> Router {
+        let mut state = AppState::for_tests(repo, Arc::new(NoOpEventPublisher));
+        state.idempotency_store =
+            Some(Arc::new(InMemoryIdempotencyStore::new(Duration::hours(24))));
+        router(state)
+    }
+
+    fn bearer(claims: Value) -> String {
+        format!("Bearer {}", encode_hs256(&claims, TEST_JWT_SECRET))
+    }
+
+    fn order_body(customer_id: CustomerId) -> String {
+        json!({
+            "customer_id": customer_id,
+            "items": [{
+                "product_id": "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10",
+                "product_name": "Keyboard",
+                "quantity": 1,
+                "unit_price": "49.90"
+            }]
+        })
+        .to_string()
+    }
+
+    fn post(key: &str, body: String) -> Request<Body> {
+        Request::post("/api/orders")
+            .header("content-type", "application/json")
+            .header("idempotency-key", key)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn json_body(response: axum::response::Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_retry_replays_first_response() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let app = app(repo.clone());
+        let customer_id = CustomerId::new();
+
+        let first = app
+            .clone()
+            .oneshot(post("abc", order_body(customer_id)))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), 201);
+        let content_type = first.headers()["content-type"].clone();
+        let first = json_body(first).await;
+
+        let retry = app
+            .oneshot(post("abc", order_body(customer_id)))
+            .await
+            .unwrap();
+        assert_eq!(retry.status(), 201);
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        assert_eq!(retry.headers()["content-type"], content_type);
+        assert_eq!(json_body(retry).await, first);
+
+        assert_eq!(
+            repo.find_by_customer(&TenantId::default(), customer_id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_replay_restores_the_stored_headers() {
+        let replayed = super::replay(StoredResponse {
+            status: 201,
+            headers: vec![
+                ("content-type".to_string(), "text/plain".to_string()),
+                ("location".to_string(), "/api/orders/42".to_string()),
+            ],
+            body: b"created".to_vec(),
+        });
+
+        assert_eq!(replayed.status(), 201);
+        assert_eq!(replayed.headers()["content-type"], "text/plain");
+        assert_eq!(replayed.headers()["location"], "/api/orders/42");
+        assert_eq!(replayed.headers()["idempotent-replayed"], "true");
+    }
+
+    #[tokio::test]
+    async fn test_reused_key_with_different_body_conflicts() {
+        let app = app(Arc::new(InMemoryOrderRepository::new()));
+
+        let first = app
+            .clone()
+            .oneshot(post("abc", order_body(CustomerId::new())))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), 201);
+
+        let reused = app
+            .oneshot(post("abc", order_body(CustomerId::new())))
+            .await
+            .unwrap();
+        assert_eq!(reused.status(), 409);
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_by_another_tenant_is_not_replayed() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let app = app(repo.clone());
+        let customer_id = CustomerId::new();
+
+        let first = app
+            .clone()
+            .oneshot(post("abc", order_body(customer_id)))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), 201);
+
+        // Same key and body, tenant taken from the token rather than a header
+        let mut other_tenant = post("abc", order_body(customer_id));
+        other_tenant.headers_mut().insert(
+            "authorization",
+            bearer(json!({ "tenant_id": "acme" })).parse().unwrap(),
+        );
+        let reused = app.oneshot(other_tenant).await.unwrap();
+        assert_eq!(reused.status(), 201);
+        assert!(reused.headers().get("idempotent-replayed").is_none());
+
+        let acme: TenantId = "acme".parse().unwrap();
+        assert_eq!(
+            repo.find_by_customer(&acme, customer_id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_by_another_caller_is_not_replayed() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let app = app(repo.clone());
+        let customer_id = CustomerId::new();
+        let as_caller = |subject: &str| {
+            let mut request = post("abc", order_body(customer_id));
+            request.headers_mut().insert(
+                "authorization",
+                bearer(json!({ "sub": subject })).parse().unwrap(),
+            );
+            request
+        };
+
+        let first = app.clone().oneshot(as_caller("alice")).await.unwrap();
+        assert_eq!(first.status(), 201);
+        let reused = app.clone().oneshot(as_caller("bob")).await.unwrap();
+        assert_eq!(reused.status(), 201);
+        assert!(reused.headers().get("idempotent-replayed").is_none());
+        let retry = app.oneshot(as_caller("alice")).await.unwrap();
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+
+        assert_eq!(
+            repo.find_by_customer(&TenantId::default(), customer_id)
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wishlist_order_retry_replays_first_response() {
+        let product_id = ProductId::new();
+        let catalog = Arc::new(InMemoryCatalog::new());
+        catalog.set_product(product_id, Money::eur(Decimal::new(4990, 2)).unwrap(), 5);
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let mut state = AppState::for_tests_with_adapters(
+            repo.clone(),
+            Arc::new(NoOpEventPublisher),
+            catalog,
+            Arc::new(InMemoryInvoiceRepository::new()),
+        );
+        state.idempotency_store =
+            Some(Arc::new(InMemoryIdempotencyStore::new(Duration::hours(24))));
+        let app = router(state);
+        let customer_id = CustomerId::new();
+        let wishlist = format!("/api/wishlists/{}", customer_id);
+
+        let saved = app
+            .clone()
+            .oneshot(
+                Request::put(format!("{}/items/{}", wishlist, product_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({ "product_name": "Keyboard" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(saved.status(), 204);
+
+        // Kept in the wishlist, so that only the key tells the retry apart
+        let order = || {
+            Request::post(format!("{}/orders", wishlist))
+                .header("content-type", "application/json")
+                .header("idempotency-key", "abc")
+                .body(Body::from(
+                    json!({
+                        "items": [{ "product_id": product_id, "quantity": 1 }],
+                        "keep_in_wishlist": true
+                    })
+                    .to_string(),
+                ))
+                .unwrap()
+        };
+        let first = app.clone().oneshot(order()).await.unwrap();
+        assert_eq!(first.status(), 201);
+        let first = json_body(first).await;
+
+        let retry = app.oneshot(order()).await.unwrap();
+        assert_eq!(retry.status(), 201);
+        assert_eq!(retry.headers()["idempotent-replayed"], "true");
+        assert_eq!(json_body(retry).await, first);
+
+        assert_eq!(
+            repo.find_by_customer(&TenantId::default(), customer_id)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
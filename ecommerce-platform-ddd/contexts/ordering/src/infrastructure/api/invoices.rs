@@ -0,0 +1,158 @@
+use super::{ApiError, AppState, Operator, ProblemDetails, TenantHeader};
+use crate::application::commands::IssueCreditNoteCommand;
+use crate::application::dto::{InvoiceResponse, IssueCreditNoteRequest, IssueCreditNoteResponse};
+use crate::application::queries::{GetInvoiceQuery, ListOrderInvoicesQuery};
+use crate::domain::value_objects::{InvoiceId, OrderId, TenantId};
+use crate::infrastructure::invoicing::{render_html, render_pdf, InvoiceFormat};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct InvoiceParams {
+    /// Rendering of the document (default: json)
+    #[serde(default)]
+    pub format: InvoiceFormat,
+}
+
+/// GET /api/invoices/{invoice_id}
+#[utoipa::path(
+    get,
+    path = "/api/invoices/{invoice_id}",
+    tag = "invoices",
+    params(
+        ("invoice_id" = InvoiceId, Path, description = "Invoice or credit note identifier"),
+        InvoiceParams,
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Invoice or credit note found", content(
+            (InvoiceResponse = "application/json"),
+            (String = "text/html"),
+            (Vec<u8> = "application/pdf"),
+        )),
+        (status = 404, description = "Invoice not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_invoice(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(invoice_id): Path<InvoiceId>,
+    Query(params): Query<InvoiceParams>,
+) -> Result<Response, ApiError> {
+    let invoice = state
+        .get_invoice
+        .handle(GetInvoiceQuery {
+            tenant_id,
+            invoice_id,
+        })
+        .await?;
+    let content_type = params.format.content_type();
+    let response = match params.format {
+        InvoiceFormat::Json => Json(invoice).into_response(),
+        InvoiceFormat::Html => (
+            [(header::CONTENT_TYPE, content_type)],
+            render_html(&invoice),
+        )
+            .into_response(),
+        InvoiceFormat::Pdf => (
+            [
+                (header::CONTENT_TYPE, content_type.to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"{}.pdf\"", invoice.number),
+                ),
+            ],
+            render_pdf(&invoice),
+        )
+            .into_response(),
+    };
+    Ok(response)
+}
+
+/// GET /api/orders/{order_id}/invoices
+#[utoipa::path(
+    get,
+    path = "/api/orders/{order_id}/invoices",
+    tag = "invoices",
+    params(
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Invoice and credit notes of the order, oldest first (empty until the order is paid)", body = [InvoiceResponse]),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn list_order_invoices(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(order_id): Path<OrderId>,
+) -> Result<Json<Vec<InvoiceResponse>>, ApiError> {
+    let invoices = state
+        .list_order_invoices
+        .handle(ListOrderInvoicesQuery {
+            tenant_id,
+            order_id,
+        })
+        .await?;
+    Ok(Json(invoices))
+}
+
+/// POST /api/invoices/{invoice_id}/credit-notes
+#[utoipa::path(
+    post,
+    path = "/api/invoices/{invoice_id}/credit-notes",
+    tag = "invoices",
+    security(("bearer_token" = [])),
+    params(
+        ("invoice_id" = InvoiceId, Path, description = "Invoice refunded"),
+        TenantHeader,
+    ),
+    request_body = IssueCreditNoteRequest,
+    responses(
+        (status = 201, description = "Credit note issued", body = IssueCreditNoteResponse),
+        (status = 404, description = "Invoice not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Invoice already fully credited", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Credit note of a credit note, missing reason, unknown item or quantities beyond those invoiced", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn issue_credit_note(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(invoice_id): Path<InvoiceId>,
+    Json(request): Json<IssueCreditNoteRequest>,
+) -> Result<(StatusCode, Json<IssueCreditNoteResponse>), ApiError> {
+    let credit_note = state
+        .issue_credit_note
+        .handle(IssueCreditNoteCommand {
+            tenant_id,
+            invoice_id,
+            lines: request.lines.map(|lines| {
+                lines
+                    .into_iter()
+                    .map(|line| (line.order_item_id, line.quantity))
+                    .collect()
+            }),
+            reason: request.reason,
+        })
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(IssueCreditNoteResponse {
+            invoice_id: credit_note.id(),
+            number: credit_note.number().to_string(),
+        }),
+    ))
+}
@@ -0,0 +1,1715 @@
+pub mod admin;
+pub mod carts;
+pub mod error;
+pub mod health;
+pub mod idempotency;
+pub mod invoices;
+pub mod openapi;
+pub mod orders;
+pub mod rate_limit;
+pub mod shipments;
+pub mod streams;
+pub mod tenant;
+pub mod webhooks;
+pub mod wishlists;
+
+use crate::application::commands::{
+    CancelOrderHandler, CheckoutCartHandler, ConfirmOrderHandler, CreateCartHandler,
+    CreateOrderHandler, CreateShipmentHandler, ImportOrdersHandler, IssueCreditNoteHandler,
+    MergeCartsHandler, MergeShipmentsHandler, OrderWishlistItemsHandler, ReviewOrderHandler,
+    ShareWishlistHandler, ShipShipmentHandler, UpdateCartHandler, UpdateWishlistHandler,
+};
+use crate::application::queries::{
+    ExportOrdersHandler, GetCartHandler, GetInvoiceHandler, GetOrderHandler,
+    GetOrderTimelineHandler, GetSharedWishlistHandler, GetWishlistHandler,
+    ListCustomerOrdersHandler, ListOrderInvoicesHandler,
+};
+use crate::infrastructure::health::ReadinessChecker;
+use crate::infrastructure::idempotency::IdempotencyStore;
+use crate::infrastructure::messaging::OrderEventHub;
+use crate::infrastructure::observability::{self, CorrelationId, Metrics};
+use crate::infrastructure::tenancy::TenantResolver;
+use crate::infrastructure::webhooks::WebhookStore;
+use axum::{
+    extract::{DefaultBodyLimit, Request},
+    middleware,
+    routing::{get, post, put},
+    Router,
+};
+use std::sync::Arc;
+use tower_http::trace::{DefaultOnResponse, TraceLayer};
+use tracing::Level;
+
+pub use error::{ApiError, ProblemDetails};
+pub use rate_limit::{RateLimitLayer, RateLimiter};
+pub use tenant::{Operator, TenantHeader};
+
+/// Shared state injected into the HTTP handlers
+#[derive(Clone)]
+pub struct AppState {
+    pub create_order: Arc<CreateOrderHandler>,
+    pub confirm_order: Arc<ConfirmOrderHandler>,
+    pub cancel_order: Arc<CancelOrderHandler>,
+    pub review_order: Arc<ReviewOrderHandler>,
+    pub create_shipment: Arc<CreateShipmentHandler>,
+    pub ship_shipment: Arc<ShipShipmentHandler>,
+    pub merge_shipments: Arc<MergeShipmentsHandler>,
+    pub get_order: Arc<GetOrderHandler>,
+    pub list_customer_orders: Arc<ListCustomerOrdersHandler>,
+    pub get_order_timeline: Arc<GetOrderTimelineHandler>,
+    pub import_orders: Arc<ImportOrdersHandler>,
+    pub export_orders: Arc<ExportOrdersHandler>,
+    pub create_cart: Arc<CreateCartHandler>,
+    pub update_cart: Arc<UpdateCartHandler>,
+    pub merge_carts: Arc<MergeCartsHandler>,
+    pub checkout_cart: Arc<CheckoutCartHandler>,
+    pub get_cart: Arc<GetCartHandler>,
+    pub issue_credit_note: Arc<IssueCreditNoteHandler>,
+    pub get_invoice: Arc<GetInvoiceHandler>,
+    pub list_order_invoices: Arc<ListOrderInvoicesHandler>,
+    pub update_wishlist: Arc<UpdateWishlistHandler>,
+    pub share_wishlist: Arc<ShareWishlistHandler>,
+    pub order_wishlist_items: Arc<OrderWishlistItemsHandler>,
+    pub get_wishlist: Arc<GetWishlistHandler>,
+    pub get_shared_wishlist: Arc<GetSharedWishlistHandler>,
+    pub webhook_store: Arc<dyn WebhookStore>,
+    /// Storefront of each request (`TenantId` extractor)
+    pub tenant_resolver: Arc<TenantResolver>,
+    /// Published events streamed to the storefront (SSE, WebSocket)
+    pub event_hub: Arc<OrderEventHub>,
+    /// `None` disables the `Idempotency-Key` handling
+    pub idempotency_store: Option<Arc<dyn IdempotencyStore>>,
+    /// `None` disables per-client rate limiting of the `/api` routes
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// Largest request body accepted by the JSON endpoints
+    pub max_body_bytes: usize,
+    pub metrics: Arc<Metrics>,
+    pub readiness: Arc<ReadinessChecker>,
+}
+
+/// HTTP application of the ordering context (routes + middleware stack)
+pub fn router(state: AppState) -> Router {
+    // Routes creating orders, where a retried request must not order twice
+    let idempotent =
+        middleware::from_fn_with_state(state.clone(), idempotency::idempotency_middleware);
+
+    let trace = TraceLayer::new_for_http()
+        .make_span_with(|request: &Request| {
+            let correlation_id = request
+                .extensions()
+                .get::<CorrelationId>()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            tracing::info_span!(
+                "http_request",
+                method = %request.method(),
+                uri = %request.uri(),
+                correlation_id = %correlation_id,
+            )
+        })
+        .on_response(DefaultOnResponse::new().level(Level::INFO));
+
+    let mut api = Router::new()
+        .route(
+            "/api/orders",
+            post(orders::create_order).layer(idempotent.clone()),
+        )
+        .route("/api/orders/{order_id}", get(orders::get_order))
+        .route(
+            "/api/orders/{order_id}/confirm",
+            post(orders::confirm_order),
+        )
+        .route("/api/orders/{order_id}/cancel", post(orders::cancel_order))
+        .route(
+            "/api/orders/{order_id}/shipments",
+            post(shipments::create_shipment),
+        )
+        .route(
+            "/api/orders/{order_id}/shipments/{shipment_id}/ship",
+            post(shipments::ship_shipment),
+        )
+        .route(
+            "/api/orders/{order_id}/shipments/{shipment_id}/merge",
+            post(shipments::merge_shipments),
+        )
+        .route(
+            "/api/orders/{order_id}/timeline",
+            get(orders::get_order_timeline),
+        )
+        .route(
+            "/api/orders/{order_id}/invoices",
+            get(invoices::list_order_invoices),
+        )
+        .route(
+            "/api/orders/{order_id}/events",
+            get(streams::stream_order_events),
+        )
+        .route(
+            "/api/orders/{order_id}/events/ws",
+            get(streams::stream_order_events_ws),
+        )
+        .route(
+            "/api/customers/{customer_id}/orders",
+            get(orders::list_customer_orders),
+        )
+        .route(
+            "/api/customers/{customer_id}/events",
+            get(streams::stream_customer_events),
+        )
+        .route(
+            "/api/customers/{customer_id}/events/ws",
+            get(streams::stream_customer_events_ws),
+        )
+        .route("/api/carts", post(carts::create_cart))
+        .route("/api/carts/{cart_id}", get(carts::get_cart))
+        .route("/api/carts/{cart_id}/lines", post(carts::add_cart_line))
+        .route(
+            "/api/carts/{cart_id}/lines/{product_id}",
+            put(carts::update_cart_line).delete(carts::remove_cart_line),
+        )
+        .route(
+            "/api/carts/{cart_id}/checkout",
+            post(carts::checkout_cart).layer(idempotent.clone()),
+        )
+        .route(
+            "/api/customers/{customer_id}/cart/merge",
+            post(carts::merge_carts),
+        )
+        .route("/api/wishlists/{customer_id}", get(wishlists::get_wishlist))
+        .route(
+            "/api/wishlists/{customer_id}/items/{product_id}",
+            put(wishlists::save_wishlist_item).delete(wishlists::remove_wishlist_item),
+        )
+        .route(
+            "/api/wishlists/{customer_id}/share",
+            post(wishlists::share_wishlist).delete(wishlists::stop_sharing_wishlist),
+        )
+        .route(
+            "/api/wishlists/{customer_id}/orders",
+            post(wishlists::order_wishlist_items).layer(idempotent),
+        )
+        .route(
+            "/api/shared-wishlists/{share_token}",
+            get(wishlists::get_shared_wishlist),
+        )
+        .route("/api/invoices/{invoice_id}", get(invoices::get_invoice))
+        .route(
+            "/api/invoices/{invoice_id}/credit-notes",
+            post(invoices::issue_credit_note),
+        )
+        .route(
+            "/api/webhooks",
+            post(webhooks::register_webhook).get(webhooks::list_webhooks),
+        )
+        .route(
+            "/api/webhooks/{webhook_id}",
+            get(webhooks::get_webhook).delete(webhooks::delete_webhook),
+        )
+        .route(
+            "/api/webhooks/{webhook_id}/pause",
+            post(webhooks::pause_webhook),
+        )
+        .route(
+            "/api/webhooks/{webhook_id}/resume",
+            post(webhooks::resume_webhook),
+        )
+        .route(
+            "/api/webhooks/{webhook_id}/deliveries",
+            get(webhooks::list_webhook_deliveries),
+        )
+        .route(
+            "/api/admin/orders/import",
+            post(admin::import_orders).layer(DefaultBodyLimit::max(admin::IMPORT_BODY_LIMIT)),
+        )
+        .route("/api/admin/orders/export", get(admin::export_orders))
+        .route(
+            "/api/admin/orders/{order_id}/review",
+            post(admin::review_order),
+        )
+        .layer(DefaultBodyLimit::max(state.max_body_bytes));
+    if let Some(limiter) = state.rate_limiter.clone() {
+        api = api.layer(RateLimitLayer::new(limiter));
+    }
+
+    Router::new()
+        .route("/", get(health::root))
+        .route("/health", get(health::health_check))
+        .route("/ready", get(health::readiness_check))
+        .route(
+            "/metrics",
+            get(observability::metrics_handler).with_state(state.metrics.clone()),
+        )
+        .route("/openapi.json", get(openapi::openapi_json))
+        .merge(api)
+        .route_layer(middleware::from_fn_with_state(
+            state.metrics.clone(),
+            observability::metrics_middleware,
+        ))
+        .layer(trace)
+        .layer(middleware::from_fn(observability::correlation_middleware))
+        .with_state(state)
+}
+
+/// Key of the bearer tokens accepted by `AppState::for_tests`
+#[cfg(test)]
+pub(crate) const TEST_JWT_SECRET: &str = "secret";
+
+#[cfg(test)]
+impl AppState {
+    /// State without idempotency, rate limiting nor readiness checks, for router tests
+    pub(crate) fn for_tests(
+        order_repository: Arc<dyn crate::domain::repositories::OrderRepository>,
+        event_publisher: Arc<dyn crate::infrastructure::messaging::EventPublisher>,
+    ) -> Self {
+        let catalog = Arc::new(crate::infrastructure::catalog::InMemoryCatalog::new());
+        let invoice_repository =
+            Arc::new(crate::infrastructure::persistence::InMemoryInvoiceRepository::new());
+        Self::for_tests_with_adapters(order_repository, event_publisher, catalog, invoice_repository)
+    }
+
+    /// Same, with in-memory carts checked out against `catalog` and invoices read from
+    /// `invoice_repository`
+    pub(crate) fn for_tests_with_adapters(
+        order_repository: Arc<dyn crate::domain::repositories::OrderRepository>,
+        event_publisher: Arc<dyn crate::infrastructure::messaging::EventPublisher>,
+        catalog: Arc<crate::infrastructure::catalog::InMemoryCatalog>,
+        invoice_repository: Arc<dyn crate::domain::repositories::InvoiceRepository>,
+    ) -> Self {
+        let cart_repository =
+            Arc::new(crate::infrastructure::persistence::InMemoryCartRepository::new());
+        let wishlist_repository =
+            Arc::new(crate::infrastructure::persistence::InMemoryWishlistRepository::new());
+        let audit_log = Arc::new(crate::infrastructure::audit::InMemoryAuditLog::new());
+        let clock = Arc::new(crate::domain::clock::SystemClock);
+        let ids = Arc::new(crate::domain::id_generator::UuidV7Generator);
+        // Holds the orders confirmed with addresses in different areas
+        let risk_policy = crate::domain::risk::RiskPolicy::new(
+            vec![crate::domain::risk::RiskRule::new(
+                "address-mismatch",
+                crate::domain::risk::RiskFactor::AddressMismatch,
+                100,
+            )],
+            100,
+        )
+        .unwrap();
+        let tenants: Arc<dyn crate::domain::tenant::TenantDirectory> = Arc::new(
+            crate::infrastructure::tenancy::TenantRegistry::new(Default::default())
+                .with_tenant(crate::domain::tenant::Tenant {
+                    currency: crate::domain::value_objects::Currency::USD,
+                    tax: crate::domain::tenant::TaxRule::new(rust_decimal::Decimal::new(20, 2), false)
+                        .unwrap(),
+                    ..crate::domain::tenant::Tenant::new("acme".parse().unwrap())
+                })
+                .with_tenant(crate::domain::tenant::Tenant::new("globex".parse().unwrap())),
+        );
+        let create_order = Arc::new(CreateOrderHandler::new(
+            order_repository.clone(),
+            tenants.clone(),
+            Arc::new(crate::infrastructure::pricing::InMemoryPriceLists::default()),
+            event_publisher.clone(),
+            audit_log.clone(),
+            ids.clone(),
+            clock.clone(),
+        ));
+        let event_hub = Arc::new(OrderEventHub::new(
+            order_repository.clone(),
+            crate::infrastructure::messaging::broadcast::DEFAULT_REPLAY_CAPACITY,
+        ));
+        Self {
+            import_orders: Arc::new(ImportOrdersHandler::new(create_order.clone())),
+            order_wishlist_items: Arc::new(OrderWishlistItemsHandler::new(
+                wishlist_repository.clone(),
+                tenants.clone(),
+                catalog.clone(),
+                create_order.clone(),
+                clock.clone(),
+            )),
+            export_orders: Arc::new(ExportOrdersHandler::new(order_repository.clone())),
+            create_order,
+            confirm_order: Arc::new(ConfirmOrderHandler::new(
+                order_repository.clone(),
+                tenants.clone(),
+                Arc::new(crate::infrastructure::risk::RuleBasedRiskAssessor::new(
+                    risk_policy,
+                    order_repository.clone(),
+                    clock.clone(),
+                )),
+                event_publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            cancel_order: Arc::new(CancelOrderHandler::new(
+                order_repository.clone(),
+                tenants.clone(),
+                event_publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            review_order: Arc::new(ReviewOrderHandler::new(
+                order_repository.clone(),
+                tenants.clone(),
+                event_publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            create_shipment: Arc::new(CreateShipmentHandler::new(
+                order_repository.clone(),
+                event_publisher.clone(),
+                audit_log.clone(),
+                ids.clone(),
+                clock.clone(),
+            )),
+            ship_shipment: Arc::new(ShipShipmentHandler::new(
+                order_repository.clone(),
+                tenants.clone(),
+                event_publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            merge_shipments: Arc::new(MergeShipmentsHandler::new(
+                order_repository.clone(),
+                event_publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            get_order: Arc::new(GetOrderHandler::new(order_repository.clone())),
+            list_customer_orders: Arc::new(ListCustomerOrdersHandler::new(
+                order_repository.clone(),
+            )),
+            get_order_timeline: Arc::new(GetOrderTimelineHandler::new(
+                audit_log.clone(),
+                order_repository.clone(),
+            )),
+            create_cart: Arc::new(CreateCartHandler::new(
+                cart_repository.clone(),
+                ids.clone(),
+                clock.clone(),
+            )),
+            update_cart: Arc::new(UpdateCartHandler::new(
+                cart_repository.clone(),
+                clock.clone(),
+            )),
+            merge_carts: Arc::new(MergeCartsHandler::new(cart_repository.clone(), clock.clone())),
+            checkout_cart: Arc::new(CheckoutCartHandler::new(
+                cart_repository.clone(),
+                order_repository,
+                tenants.clone(),
+                catalog.clone(),
+                Arc::new(crate::infrastructure::pricing::InMemoryPriceLists::default()),
+                catalog,
+                event_publisher,
+                audit_log,
+                ids.clone(),
+                clock.clone(),
+            )),
+            get_cart: Arc::new(GetCartHandler::new(cart_repository)),
+            issue_credit_note: Arc::new(IssueCreditNoteHandler::new(
+                invoice_repository.clone(),
+                ids,
+                clock.clone(),
+            )),
+            get_invoice: Arc::new(GetInvoiceHandler::new(invoice_repository.clone())),
+            list_order_invoices: Arc::new(ListOrderInvoicesHandler::new(invoice_repository)),
+            update_wishlist: Arc::new(UpdateWishlistHandler::new(
+                wishlist_repository.clone(),
+                clock.clone(),
+            )),
+            share_wishlist: Arc::new(ShareWishlistHandler::new(
+                wishlist_repository.clone(),
+                clock,
+            )),
+            get_wishlist: Arc::new(GetWishlistHandler::new(wishlist_repository.clone())),
+            get_shared_wishlist: Arc::new(GetSharedWishlistHandler::new(wishlist_repository)),
+            webhook_store: Arc::new(crate::infrastructure::webhooks::InMemoryWebhookStore::new()),
+            tenant_resolver: Arc::new(TenantResolver::new(
+                tenants,
+                crate::infrastructure::config::TenancySettings {
+                    jwt_secret: Some(TEST_JWT_SECRET.to_string()),
+                    ..Default::default()
+                },
+            )),
+            event_hub,
+            idempotency_store: None,
+            rate_limiter: None,
+            max_body_bytes: crate::infrastructure::config::ServerSettings::default().max_body_bytes,
+            metrics: Arc::new(Metrics::new()),
+            readiness: Arc::new(ReadinessChecker::new(std::time::Duration::from_secs(1))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{tenant::Tenant, value_objects::TenantId};
+    use crate::infrastructure::messaging::{
+        EventPublisher, InMemoryEventPublisher, InstrumentedEventPublisher,
+    };
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use axum::body::{to_bytes, Body};
+    use tower::ServiceExt;
+
+    fn app(publisher: Arc<InMemoryEventPublisher>) -> Router {
+        let metrics = Arc::new(Metrics::new());
+        let publisher: Arc<dyn EventPublisher> =
+            Arc::new(InstrumentedEventPublisher::new(publisher, metrics.clone()));
+        let mut state = AppState::for_tests(Arc::new(InMemoryOrderRepository::new()), publisher);
+        state.metrics = metrics;
+        router(state)
+    }
+
+    /// `Authorization` value of a token signed with the secret of the test state
+    fn bearer(claims: serde_json::Value) -> String {
+        format!(
+            "Bearer {}",
+            crate::infrastructure::tenancy::jwt::encode_hs256(&claims, TEST_JWT_SECRET)
+        )
+    }
+
+    fn create_order_request() -> axum::http::Request<Body> {
+        let body = serde_json::json!({
+            "customer_id": "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10",
+            "items": [{
+                "product_id": "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f",
+                "product_name": "Keyboard",
+                "quantity": 1,
+                "unit_price": "49.90"
+            }]
+        });
+        axum::http::Request::post("/api/orders")
+            .header("content-type", "application/json")
+            .header("x-correlation-id", "checkout-123")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_correlation_id_reaches_published_events() {
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+        let response = app(publisher.clone())
+            .oneshot(create_order_request())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.headers()["x-correlation-id"], "checkout-123");
+
+        let published = publisher.published().await;
+        assert_eq!(published.len(), 1);
+        assert_eq!(
+            published[0]
+                .metadata
+                .correlation_id
+                .as_ref()
+                .map(|id| id.as_str()),
+            Some("checkout-123")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_correlation_id_is_generated_when_missing() {
+        let response = app(Arc::new(InMemoryEventPublisher::new()))
+            .oneshot(
+                axum::http::Request::get("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert!(!response.headers()["x-correlation-id"].is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ready_reports_failing_component() {
+        struct BrokerDown;
+
+        #[async_trait::async_trait]
+        impl crate::infrastructure::health::HealthCheck for BrokerDown {
+            fn name(&self) -> &str {
+                "broker"
+            }
+
+            async fn check(&self) -> Result<(), String> {
+                Err("connection refused".to_string())
+            }
+        }
+
+        let mut state = AppState::for_tests(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+        );
+        state.readiness = Arc::new(
+            ReadinessChecker::new(std::time::Duration::from_secs(1))
+                .with_check(Arc::new(BrokerDown)),
+        );
+
+        let response = router(state)
+            .oneshot(
+                axum::http::Request::get("/ready")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 503);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let report: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report["status"], "down");
+        assert_eq!(report["components"][0]["name"], "broker");
+        assert_eq!(report["components"][0]["error"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_created_order_can_be_read_back() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let response = app.clone().oneshot(create_order_request()).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let order_id = created["order_id"].as_str().unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}", order_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(order["status"], "PENDING");
+        assert_eq!(order["total"]["amount"], "49.90");
+        assert_eq!(order["total"]["currency"], "EUR");
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get("/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_orders_are_isolated_per_tenant() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let token = |tenant: &str| bearer(serde_json::json!({ "tenant_id": tenant }));
+        let mut request = create_order_request();
+        request
+            .headers_mut()
+            .insert("authorization", token("acme").parse().unwrap());
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let order_id = created["order_id"].as_str().unwrap();
+
+        let get = |tenant: Option<&str>, authorization: Option<String>| {
+            let mut request = axum::http::Request::get(format!("/api/orders/{}", order_id));
+            if let Some(tenant) = tenant {
+                request = request.header("x-tenant-id", tenant);
+            }
+            if let Some(authorization) = authorization {
+                request = request.header("authorization", authorization);
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        let response = get(None, Some(token("acme"))).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        // acme sells in USD, prices before its 20% tax
+        assert_eq!(order["total"]["currency"], "USD");
+        assert_eq!(order["tax"]["amount"], "9.98");
+        assert_eq!(order["total_including_tax"]["amount"], "59.88");
+
+        assert_eq!(
+            get(None, Some(token("globex"))).await.unwrap().status(),
+            404
+        );
+        assert_eq!(get(None, None).await.unwrap().status(), 404);
+        assert_eq!(
+            get(None, Some(token("initech"))).await.unwrap().status(),
+            400
+        );
+        assert_eq!(
+            get(Some("acme"), Some(token("acme")))
+                .await
+                .unwrap()
+                .status(),
+            200
+        );
+        // The header only confirms the tenant of the token
+        assert_eq!(get(Some("acme"), None).await.unwrap().status(), 403);
+        assert_eq!(
+            get(Some("acme"), Some(token("globex")))
+                .await
+                .unwrap()
+                .status(),
+            403
+        );
+        let forged = format!(
+            "Bearer {}",
+            crate::infrastructure::tenancy::jwt::encode_hs256(
+                &serde_json::json!({ "tenant_id": "acme" }),
+                "guessed"
+            )
+        );
+        assert_eq!(get(None, Some(forged)).await.unwrap().status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_timeline_shows_who_did_what() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let response = app.clone().oneshot(create_order_request()).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let order_id = created["order_id"].as_str().unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::post(format!("/api/orders/{}/cancel", order_id))
+                    .header("content-type", "application/json")
+                    // Claims nothing: only the verified token names the caller
+                    .header("x-actor-id", "someone-else")
+                    .header(
+                        "authorization",
+                        bearer(serde_json::json!({"sub": "support-42"})),
+                    )
+                    .body(Body::from(r#"{"reason": "Customer request"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}/timeline", order_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let timeline: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(timeline[0]["command"], "CreateOrder");
+        assert_eq!(timeline[0]["actor"], "anonymous");
+        assert_eq!(timeline[1]["command"], "CancelOrder");
+        assert_eq!(timeline[1]["actor"], "user:support-42");
+        assert_eq!(timeline[1]["status_before"], "PENDING");
+        assert_eq!(timeline[1]["status_after"], "CANCELLED");
+        assert_eq!(timeline[1]["reason"], "Customer request");
+        assert_eq!(timeline[1]["events"][0]["type"], "ORDER_CANCELLED");
+    }
+
+    #[tokio::test]
+    async fn test_risky_order_waits_for_review() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let paris = serde_json::json!({
+            "line1": "12 rue de la Paix",
+            "postal_code": "75002",
+            "city": "Paris",
+            "country": "FR"
+        });
+        let lagos = serde_json::json!({
+            "line1": "1 Marina Road",
+            "postal_code": "101241",
+            "city": "Lagos",
+            "country": "NG"
+        });
+        let body = serde_json::json!({
+            "customer_id": "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10",
+            "items": [{
+                "product_id": "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f",
+                "product_name": "Keyboard",
+                "quantity": 1,
+                "unit_price": "49.90"
+            }],
+            "billing_address": paris,
+            "shipping_address": lagos
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::post("/api/orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let order_id = created["order_id"].as_str().unwrap();
+        let operator = bearer(serde_json::json!({"sub": "risk-analyst", "roles": ["operator"]}));
+        let request = |path: String, body: serde_json::Value, token: &str| {
+            let mut request = axum::http::Request::post(path)
+                .header("content-type", "application/json");
+            if !token.is_empty() {
+                request = request.header("authorization", token);
+            }
+            request.body(Body::from(body.to_string())).unwrap()
+        };
+        let post = |path: String, body: serde_json::Value| request(path, body, &operator);
+        // Screened on the addresses of the order: matching ones given now change nothing
+        let confirm = serde_json::json!({ "billing_address": paris, "shipping_address": paris });
+
+        let path = format!("/api/orders/{}/confirm", order_id);
+        let response = app.clone().oneshot(post(path.clone(), confirm.clone())).await;
+        assert_eq!(response.unwrap().status(), 202);
+        let response = app.clone().oneshot(post(path, confirm)).await;
+        assert_eq!(response.unwrap().status(), 409);
+
+        let path = format!("/api/admin/orders/{}/review", order_id);
+        let approve = serde_json::json!({ "decision": "APPROVE" });
+        // Only authenticated operators settle a review
+        let response = app
+            .clone()
+            .oneshot(request(path.clone(), approve.clone(), ""))
+            .await;
+        assert_eq!(response.unwrap().status(), 401);
+        let customer = bearer(serde_json::json!({"sub": "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10"}));
+        let response = app
+            .clone()
+            .oneshot(request(path.clone(), approve.clone(), &customer))
+            .await;
+        assert_eq!(response.unwrap().status(), 403);
+
+        let blank_reason = serde_json::json!({ "decision": "REJECT", "reason": " " });
+        let response = app.clone().oneshot(post(path.clone(), blank_reason)).await;
+        assert_eq!(response.unwrap().status(), 422);
+        let response = app.clone().oneshot(post(path, approve)).await;
+        assert_eq!(response.unwrap().status(), 204);
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}", order_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(order["status"], "CONFIRMED");
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}/timeline", order_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let timeline: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let review = timeline.as_array().unwrap().last().unwrap();
+        assert_eq!(review["status_after"], "CONFIRMED");
+        assert_eq!(review["actor"], "user:risk-analyst");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        app.clone().oneshot(create_order_request()).await.unwrap();
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get("/metrics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("ordering_order_status_transitions_total{status=\"PENDING\"} 1"));
+        assert!(body.contains("route=\"/api/orders\",status=\"201\""));
+    }
+
+    #[tokio::test]
+    async fn test_imported_orders_can_be_exported() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let operator = bearer(serde_json::json!({"sub": "ops-1", "roles": ["operator"]}));
+        let customer = bearer(serde_json::json!({"sub": "alice"}));
+        let csv = "order_ref,customer_id,product_id,product_name,quantity,unit_price\n\
+            L-1,6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10,0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f,Keyboard,1,49.90\n\
+            L-1,6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10,0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f,Mouse,0,19.99\n\
+            L-2,6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10,0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f,Screen,1,199.00\n";
+        let import = |token: &str| {
+            let mut request = axum::http::Request::post("/api/admin/orders/import?format=csv")
+                .header("content-type", "text/csv");
+            if !token.is_empty() {
+                request = request.header("authorization", token);
+            }
+            request.body(Body::from(csv)).unwrap()
+        };
+        let export = |token: &str| {
+            let mut request =
+                axum::http::Request::get("/api/admin/orders/export?format=ndjson&status=PENDING");
+            if !token.is_empty() {
+                request = request.header("authorization", token);
+            }
+            request.body(Body::empty()).unwrap()
+        };
+
+        // Only authenticated operators move orders in bulk
+        let response = app.clone().oneshot(import("")).await.unwrap();
+        assert_eq!(response.status(), 401);
+        let response = app.clone().oneshot(import(&customer)).await.unwrap();
+        assert_eq!(response.status(), 403);
+        let response = app.clone().oneshot(export("")).await.unwrap();
+        assert_eq!(response.status(), 401);
+        let response = app.clone().oneshot(export(&customer)).await.unwrap();
+        assert_eq!(response.status(), 403);
+
+        let response = app.clone().oneshot(import(&operator)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let report: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report["imported"], 1);
+        assert_eq!(report["failed"], 1);
+        assert_eq!(report["errors"][0]["line"], 2);
+
+        let response = app.oneshot(export(&operator)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "application/x-ndjson");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let lines: Vec<serde_json::Value> = body
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["items"][0]["product_name"], "Screen");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_answers_429_with_retry_after() {
+        let mut state = AppState::for_tests(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+        );
+        state.rate_limiter = Some(Arc::new(
+            RateLimiter::new(1, 1).with_api_keys(["shop-a".to_string(), "shop-b".to_string()]),
+        ));
+        let app = router(state);
+        let request = |api_key: &str| {
+            let mut request = create_order_request();
+            request
+                .headers_mut()
+                .insert(rate_limit::API_KEY_HEADER, api_key.parse().unwrap());
+            request
+        };
+
+        let response = app.clone().oneshot(request("shop-a")).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let response = app.clone().oneshot(request("shop-a")).await.unwrap();
+        assert_eq!(response.status(), 429);
+        assert_eq!(response.headers()["retry-after"], "1");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let problem: ProblemDetails = serde_json::from_slice(&body).unwrap();
+        assert_eq!(problem.code, "RATE_LIMITED");
+        assert_eq!(problem.detail, "Rate limit exceeded");
+
+        // Other clients and operational endpoints are not throttled
+        let response = app.clone().oneshot(request("shop-b")).await.unwrap();
+        assert_eq!(response.status(), 201);
+        let response = app
+            .oneshot(
+                axum::http::Request::get("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected() {
+        let mut state = AppState::for_tests(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+        );
+        state.max_body_bytes = 64;
+        let response = router(state)
+            .oneshot(create_order_request())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 413);
+    }
+
+    #[tokio::test]
+    async fn test_order_with_too_many_items_is_rejected() {
+        let item = serde_json::json!({
+            "product_id": "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f",
+            "product_name": "Keyboard",
+            "quantity": 1,
+            "unit_price": "49.90"
+        });
+        let body = serde_json::json!({
+            "customer_id": "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10",
+            "items": vec![item; crate::domain::aggregates::Order::MAX_ITEMS + 1],
+        });
+        let response = app(Arc::new(InMemoryEventPublisher::new()))
+            .oneshot(
+                axum::http::Request::post("/api/orders")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 422);
+    }
+
+    #[tokio::test]
+    async fn test_paid_order_ships_in_parcels() {
+        use crate::domain::{
+            aggregates::Order, clock::SystemClock, entities::OrderItem,
+            id_generator::UuidV4Generator, repositories::OrderRepository,
+            value_objects::{CustomerId, Money, ProductId},
+        };
+
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Chair".to_string(),
+            2,
+            Money::eur(rust_decimal::Decimal::new(4900, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order =
+            Order::create(&Tenant::default(), CustomerId::new(), vec![item], &UuidV4Generator, &SystemClock).unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order
+            .mark_as_paid(uuid::Uuid::new_v4(), &SystemClock)
+            .unwrap();
+        repo.save(&mut order).await.unwrap();
+        let app = router(AppState::for_tests(
+            repo,
+            Arc::new(InMemoryEventPublisher::new()),
+        ));
+        let post = |uri: String, body: serde_json::Value| {
+            axum::http::Request::post(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        let lines = serde_json::json!({
+            "lines": [{ "order_item_id": order.items()[0].id(), "quantity": 2 }]
+        });
+        let response = app
+            .clone()
+            .oneshot(post(format!("/api/orders/{}/shipments", order.id()), lines.clone()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let shipment_id = created["shipment_id"].as_str().unwrap();
+
+        // Every unit is already packed
+        let response = app
+            .clone()
+            .oneshot(post(format!("/api/orders/{}/shipments", order.id()), lines))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 422);
+
+        let response = app
+            .clone()
+            .oneshot(post(
+                format!("/api/orders/{}/shipments/{}/ship", order.id(), shipment_id),
+                serde_json::json!({ "tracking_number": "TRK-1" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}", order.id()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let read: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(read["status"], "SHIPPED");
+        assert_eq!(read["shipments"][0]["status"], "SHIPPED");
+        assert_eq!(read["shipments"][0]["tracking_number"], "TRK-1");
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_cart_is_merged_then_checked_out() {
+        use crate::domain::value_objects::{Money, ProductId};
+        use crate::infrastructure::catalog::InMemoryCatalog;
+
+        let product_id = ProductId::new();
+        let catalog = Arc::new(InMemoryCatalog::new());
+        catalog.set_product(
+            product_id,
+            Money::eur(rust_decimal::Decimal::new(4990, 2)).unwrap(),
+            5,
+        );
+        let app = router(AppState::for_tests_with_adapters(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+            catalog,
+            Arc::new(crate::infrastructure::persistence::InMemoryInvoiceRepository::new()),
+        ));
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            axum::http::Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let json = |body: &[u8]| serde_json::from_slice::<serde_json::Value>(body).unwrap();
+
+        // Anonymous shopper fills a cart
+        let response = app
+            .clone()
+            .oneshot(send("POST", "/api/carts".to_string(), serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let cart_id = json(&body)["cart_id"].as_str().unwrap().to_string();
+        let line = serde_json::json!({
+            "product_id": product_id,
+            "product_name": "Keyboard",
+            "quantity": 1,
+            "unit_price": "49.90"
+        });
+        let response = app
+            .clone()
+            .oneshot(send("POST", format!("/api/carts/{}/lines", cart_id), line))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+        let response = app
+            .clone()
+            .oneshot(send(
+                "PUT",
+                format!("/api/carts/{}/lines/{}", cart_id, product_id),
+                serde_json::json!({ "quantity": 2 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+
+        // Invisible to the other storefronts
+        let acme = bearer(serde_json::json!({ "tenant_id": "acme" }));
+        let as_acme = |method: &str, uri: String, body: serde_json::Value| {
+            let mut request = send(method, uri, body);
+            request
+                .headers_mut()
+                .insert("authorization", acme.parse().unwrap());
+            request
+        };
+        for (method, uri, body) in [
+            (
+                "GET",
+                format!("/api/carts/{}", cart_id),
+                serde_json::json!(null),
+            ),
+            (
+                "DELETE",
+                format!("/api/carts/{}/lines/{}", cart_id, product_id),
+                serde_json::json!(null),
+            ),
+            (
+                "POST",
+                "/api/customers/6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10/cart/merge".to_string(),
+                serde_json::json!({ "anonymous_cart_id": cart_id }),
+            ),
+            (
+                "POST",
+                format!("/api/carts/{}/checkout", cart_id),
+                serde_json::json!(null),
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(as_acme(method, uri.clone(), body))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 404, "{} {}", method, uri);
+        }
+
+        // Anonymous carts cannot be checked out
+        let checkout = format!("/api/carts/{}/checkout", cart_id);
+        let response = app
+            .clone()
+            .oneshot(send("POST", checkout.clone(), serde_json::json!(null)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 409);
+
+        // Logging in hands the cart over to the customer
+        let customer_id = "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10";
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("/api/customers/{}/cart/merge", customer_id),
+                serde_json::json!({ "anonymous_cart_id": cart_id }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(json(&body)["cart_id"], cart_id.as_str());
+
+        let response = app
+            .clone()
+            .oneshot(send("POST", checkout, serde_json::json!(null)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order_id = json(&body)["order_id"].as_str().unwrap().to_string();
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::get(format!("/api/orders/{}", order_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order = json(&body);
+        assert_eq!(order["customer_id"], customer_id);
+        assert_eq!(order["total"]["amount"], "99.80");
+
+        let response = app
+            .oneshot(
+                axum::http::Request::get(format!("/api/carts/{}", cart_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_wishlist_is_shared_then_ordered() {
+        use crate::domain::value_objects::{Money, ProductId};
+        use crate::infrastructure::catalog::InMemoryCatalog;
+
+        let (keyboard, mouse) = (ProductId::new(), ProductId::new());
+        let catalog = Arc::new(InMemoryCatalog::new());
+        catalog.set_product(
+            keyboard,
+            Money::eur(rust_decimal::Decimal::new(4990, 2)).unwrap(),
+            5,
+        );
+        let app = router(AppState::for_tests_with_adapters(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+            catalog,
+            Arc::new(crate::infrastructure::persistence::InMemoryInvoiceRepository::new()),
+        ));
+        let send = |method: &str, uri: String, body: serde_json::Value| {
+            axum::http::Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        let get = |uri: String| axum::http::Request::get(uri).body(Body::empty()).unwrap();
+        let json = |body: &[u8]| serde_json::from_slice::<serde_json::Value>(body).unwrap();
+
+        // The customer saves two products
+        let customer_id = "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10";
+        let wishlist = format!("/api/wishlists/{}", customer_id);
+        for (product_id, name, priority) in [(keyboard, "Keyboard", "HIGH"), (mouse, "Mouse", "LOW")] {
+            let response = app
+                .clone()
+                .oneshot(send(
+                    "PUT",
+                    format!("{}/items/{}", wishlist, product_id),
+                    serde_json::json!({ "product_name": name, "note": "in blue", "priority": priority }),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 204);
+        }
+
+        // Shared by link, without the identity of the customer
+        let response = app
+            .clone()
+            .oneshot(send("POST", format!("{}/share", wishlist), serde_json::json!(null)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let token = json(&body)["share_token"].as_str().unwrap().to_string();
+        let shared = format!("/api/shared-wishlists/{}", token);
+        let response = app.clone().oneshot(get(shared.clone())).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let read = json(&body);
+        assert_eq!(read["items"][0]["product_name"], "Keyboard");
+        assert_eq!(read["items"][1]["priority"], "LOW");
+        assert!(read.get("customer_id").is_none());
+
+        // Invisible to the other storefronts
+        let acme = bearer(serde_json::json!({ "tenant_id": "acme" }));
+        let as_acme = |method: &str, uri: String, body: serde_json::Value| {
+            let mut request = send(method, uri, body);
+            request
+                .headers_mut()
+                .insert("authorization", acme.parse().unwrap());
+            request
+        };
+        for (method, uri, body) in [
+            ("GET", wishlist.clone(), serde_json::json!(null)),
+            ("GET", shared.clone(), serde_json::json!(null)),
+            (
+                "POST",
+                format!("{}/share", wishlist),
+                serde_json::json!(null),
+            ),
+            (
+                "DELETE",
+                format!("{}/share", wishlist),
+                serde_json::json!(null),
+            ),
+            (
+                "DELETE",
+                format!("{}/items/{}", wishlist, keyboard),
+                serde_json::json!(null),
+            ),
+            (
+                "POST",
+                format!("{}/orders", wishlist),
+                serde_json::json!({ "items": [{ "product_id": keyboard, "quantity": 1 }] }),
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(as_acme(method, uri.clone(), body))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), 404, "{} {}", method, uri);
+        }
+
+        // The keyboard is ordered at the catalog price and leaves the wishlist
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("{}/orders", wishlist),
+                serde_json::json!({ "items": [{ "product_id": keyboard, "quantity": 2 }] }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order_id = json(&body)["order_id"].as_str().unwrap().to_string();
+        let response = app
+            .clone()
+            .oneshot(get(format!("/api/orders/{}", order_id)))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(json(&body)["total"]["amount"], "99.80");
+
+        let response = app.clone().oneshot(get(wishlist.clone())).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let read = json(&body);
+        assert_eq!(read["items"].as_array().unwrap().len(), 1);
+        assert_eq!(read["items"][0]["product_name"], "Mouse");
+        assert_eq!(read["share_token"], token.as_str());
+
+        // The mouse is not sold anymore; revoking the link hides the wishlist
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                format!("{}/orders", wishlist),
+                serde_json::json!({ "items": [{ "product_id": mouse }] }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 422);
+        let response = app
+            .clone()
+            .oneshot(send("DELETE", format!("{}/share", wishlist), serde_json::json!(null)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+        let response = app.oneshot(get(shared)).await.unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_invoice_is_rendered_and_credited() {
+        use crate::domain::{
+            aggregates::{Invoice, Order},
+            clock::SystemClock,
+            entities::OrderItem,
+            id_generator::UuidV4Generator,
+            repositories::{InvoiceRepository, OrderRepository},
+            value_objects::{CustomerId, Money, ProductId},
+        };
+        use crate::infrastructure::catalog::InMemoryCatalog;
+        use crate::infrastructure::persistence::InMemoryInvoiceRepository;
+
+        // A paid order, invoiced as on `OrderPaid`
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        let invoices = Arc::new(InMemoryInvoiceRepository::new());
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            2,
+            Money::eur(rust_decimal::Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order =
+            Order::create(&Tenant::default(), CustomerId::new(), vec![item], &UuidV4Generator, &SystemClock).unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order
+            .mark_as_paid(uuid::Uuid::new_v4(), &SystemClock)
+            .unwrap();
+        orders.save(&mut order).await.unwrap();
+        let draft = Invoice::draft_for_order(&order, &UuidV4Generator, &SystemClock).unwrap();
+        let invoice = invoices.issue(draft).await.unwrap();
+
+        let app = router(AppState::for_tests_with_adapters(
+            orders,
+            Arc::new(InMemoryEventPublisher::new()),
+            Arc::new(InMemoryCatalog::new()),
+            invoices,
+        ));
+        let get = |uri: String| {
+            axum::http::Request::get(uri)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let json = |body: &[u8]| serde_json::from_slice::<serde_json::Value>(body).unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(get(format!("/api/invoices/{}", invoice.id())))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let read = json(&body);
+        assert_eq!(read["number"], invoice.number().to_string());
+        assert_eq!(read["kind"], "INVOICE");
+        assert_eq!(read["total"]["amount"], "99.80");
+
+        let response = app
+            .clone()
+            .oneshot(get(format!("/api/invoices/{}?format=html", invoice.id())))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["content-type"], "text/html; charset=utf-8");
+        let response = app
+            .clone()
+            .oneshot(get(format!("/api/invoices/{}?format=pdf", invoice.id())))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["content-type"], "application/pdf");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.starts_with(b"%PDF-"));
+
+        // Invisible to the other storefronts, even to their operators
+        let acme = bearer(serde_json::json!({
+            "sub": "ops-acme",
+            "tenant_id": "acme",
+            "roles": ["operator"]
+        }));
+        let as_acme = |request: axum::http::request::Builder, body: Body| {
+            request
+                .header("authorization", acme.clone())
+                .header("content-type", "application/json")
+                .body(body)
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(as_acme(
+                axum::http::Request::get(format!("/api/invoices/{}", invoice.id())),
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+        let response = app
+            .clone()
+            .oneshot(as_acme(
+                axum::http::Request::get(format!("/api/orders/{}/invoices", order.id())),
+                Body::empty(),
+            ))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(json(&body), serde_json::json!([]));
+        let response = app
+            .clone()
+            .oneshot(as_acme(
+                axum::http::Request::post(format!("/api/invoices/{}/credit-notes", invoice.id())),
+                Body::from(serde_json::json!({ "reason": "Returned" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+
+        // Refund one keyboard, which only an operator may do
+        let request = serde_json::json!({
+            "lines": [{ "order_item_id": invoice.lines()[0].order_item_id(), "quantity": 1 }],
+            "reason": "Damaged in transit"
+        });
+        let credit = |token: &str| {
+            let mut credit =
+                axum::http::Request::post(format!("/api/invoices/{}/credit-notes", invoice.id()))
+                    .header("content-type", "application/json");
+            if !token.is_empty() {
+                credit = credit.header("authorization", token);
+            }
+            credit.body(Body::from(request.to_string())).unwrap()
+        };
+        let response = app.clone().oneshot(credit("")).await.unwrap();
+        assert_eq!(response.status(), 401);
+        let customer = bearer(serde_json::json!({"sub": "alice"}));
+        let response = app.clone().oneshot(credit(&customer)).await.unwrap();
+        assert_eq!(response.status(), 403);
+        let operator = bearer(serde_json::json!({"sub": "ops-1", "roles": ["operator"]}));
+        let response = app.clone().oneshot(credit(&operator)).await.unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let number = json(&body)["number"].as_str().unwrap().to_string();
+        assert!(number.starts_with("CN-"));
+
+        let response = app
+            .oneshot(get(format!("/api/orders/{}/invoices", order.id())))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let documents = json(&body);
+        assert_eq!(documents.as_array().unwrap().len(), 2);
+        assert_eq!(documents[1]["number"], number.as_str());
+        assert_eq!(documents[1]["total"]["amount"], "49.90");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_is_registered_paused_and_removed() {
+        let app = app(Arc::new(InMemoryEventPublisher::new()));
+        let operator = bearer(serde_json::json!({"sub": "ops-1", "roles": ["operator"]}));
+        let request = |method: &str, uri: String, body: Option<serde_json::Value>, token: &str| {
+            let mut request = axum::http::Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json");
+            if !token.is_empty() {
+                request = request.header("authorization", token);
+            }
+            request
+                .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+                .unwrap()
+        };
+        let send = |method: &str, uri: String, body: Option<serde_json::Value>| {
+            request(method, uri, body, &operator)
+        };
+        let json = |body: &[u8]| serde_json::from_slice::<serde_json::Value>(body).unwrap();
+        let registration = serde_json::json!({
+            "url": "https://partner.example.com/hooks",
+            "events": ["ORDER_PAID"]
+        });
+
+        // Only authenticated operators manage webhooks
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/webhooks".to_string(),
+                Some(registration.clone()),
+                "",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 401);
+        let customer = bearer(serde_json::json!({"sub": "alice"}));
+        let response = app
+            .clone()
+            .oneshot(request(
+                "POST",
+                "/api/webhooks".to_string(),
+                Some(registration.clone()),
+                &customer,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 403);
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/api/webhooks".to_string(), None, ""))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 401);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/webhooks".to_string(),
+                Some(serde_json::json!({
+                    "url": "https://partner.example.com/hooks",
+                    "events": ["ORDER_PAID", "ORDER_LOST"]
+                })),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 422);
+
+        let response = app
+            .clone()
+            .oneshot(send(
+                "POST",
+                "/api/webhooks".to_string(),
+                Some(registration),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 201);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let registered = json(&body);
+        assert!(registered["secret"].as_str().unwrap().starts_with("whsec_"));
+        let id = registered["id"].as_str().unwrap().to_string();
+
+        let response = app
+            .clone()
+            .oneshot(send("POST", format!("/api/webhooks/{}/pause", id), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(json(&body)["paused"], true);
+
+        // The secret is only shown at registration
+        let response = app
+            .clone()
+            .oneshot(send("GET", "/api/webhooks".to_string(), None))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let listed = json(&body);
+        assert_eq!(listed.as_array().unwrap().len(), 1);
+        assert_eq!(listed[0]["paused"], true);
+        assert!(listed[0].get("secret").is_none());
+
+        let response = app
+            .clone()
+            .oneshot(send("GET", format!("/api/webhooks/{}/deliveries", id), None))
+            .await
+            .unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(json(&body), serde_json::json!([]));
+
+        let response = app
+            .clone()
+            .oneshot(send("DELETE", format!("/api/webhooks/{}", id), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 204);
+        let response = app
+            .oneshot(send("POST", format!("/api/webhooks/{}/resume", id), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn test_customer_follows_order_events() {
+        use crate::domain::events::OrderEvent;
+        use crate::infrastructure::messaging::{EventEnvelope, EventSubscriber};
+        use tokio_stream::StreamExt;
+
+        let state = AppState::for_tests(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+        );
+        let hub = state.event_hub.clone();
+        let app = router(state);
+        let response = app.clone().oneshot(create_order_request()).await.unwrap();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let created: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let order_id = created["order_id"].as_str().unwrap().to_string();
+        let customer = "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10";
+        let follow = |token: Option<String>| {
+            let mut request = axum::http::Request::get(format!("/api/orders/{}/events", order_id));
+            if let Some(token) = token {
+                request = request.header("authorization", token);
+            }
+            request.body(Body::empty()).unwrap()
+        };
+
+        let response = app.clone().oneshot(follow(None)).await.unwrap();
+        assert_eq!(response.status(), 401);
+        let someone_else = bearer(serde_json::json!({"sub": "someone-else"}));
+        let response = app
+            .clone()
+            .oneshot(follow(Some(someone_else)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 403);
+        let operator = bearer(serde_json::json!({"sub": "ops-1", "roles": ["operator"]}));
+        let response = app.clone().oneshot(follow(Some(operator))).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let response = app
+            .clone()
+            .oneshot(follow(Some(bearer(serde_json::json!({"sub": customer})))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+        let confirmed = EventEnvelope::new(TenantId::default(), OrderEvent::OrderConfirmed {
+            order_id: order_id.parse().unwrap(),
+            timestamp: chrono::Utc::now(),
+        });
+        hub.on_event(&confirmed).await.unwrap();
+        let mut frames = response.into_body().into_data_stream();
+        let frame = frames.next().await.unwrap().unwrap();
+        let frame = String::from_utf8(frame.to_vec()).unwrap();
+        assert!(frame.contains("event: ORDER_CONFIRMED\n"), "{}", frame);
+        assert!(
+            frame.contains(&format!("id: {}\n", confirmed.metadata.event_id)),
+            "{}",
+            frame
+        );
+    }
+}
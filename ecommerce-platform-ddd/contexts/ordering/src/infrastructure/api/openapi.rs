@@ -0,0 +1,214 @@
+use super::webhooks::{
+    RegisterWebhookRequest, RegisterWebhookResponse, WebhookDeliveryResponse, WebhookResponse,
+};
+use super::{
+    admin, carts, health, invoices, orders, shipments, streams, webhooks, wishlists,
+    ProblemDetails,
+};
+use crate::application::audit::AuditEntry;
+use crate::application::dto::{
+    AddCartLineRequest, CancelOrderRequest, CartLineResponse, CartResponse, CreateCartRequest,
+    CreateCartResponse, MergeCartsRequest, MergeCartsResponse, UpdateCartLineRequest, CreateOrderItemRequest, CreateOrderRequest, CreateOrderResponse,
+    CreateShipmentRequest, CreateShipmentResponse, CreditNoteLineRequest, ImportReport,
+    ImportRowError, InvoiceLineResponse, InvoiceResponse, IssueCreditNoteRequest,
+    IssueCreditNoteResponse, MergeShipmentsRequest, OrderItemResponse, OrderResponse, ReviewOrderRequest, ShipShipmentRequest,
+    ShipmentResponse, OrderWishlistItemsRequest, SaveWishlistItemRequest, ShareWishlistResponse,
+    SharedWishlistResponse, WishlistItemResponse, WishlistResponse, WishlistSelectionRequest,
+};
+use crate::domain::entities::ShipmentLine;
+use crate::domain::value_objects::{
+    Currency, InvoiceKind, Money, OrderStatus, PostalAddress, ShipmentStatus, WishlistPriority,
+};
+use crate::infrastructure::bulk::BulkFormat;
+use crate::infrastructure::health::{ComponentHealth, HealthStatus, ReadinessReport};
+use crate::infrastructure::invoicing::InvoiceFormat;
+use axum::Json;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+/// OpenAPI 3 contract of the HTTP API, generated from the DTOs and handlers
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Ordering API",
+        description = "Order management bounded context",
+        license(name = "MIT")
+    ),
+    paths(
+        orders::create_order,
+        orders::get_order,
+        orders::list_customer_orders,
+        orders::confirm_order,
+        orders::cancel_order,
+        orders::get_order_timeline,
+        shipments::create_shipment,
+        shipments::ship_shipment,
+        shipments::merge_shipments,
+        carts::create_cart,
+        carts::get_cart,
+        carts::add_cart_line,
+        carts::update_cart_line,
+        carts::remove_cart_line,
+        carts::merge_carts,
+        carts::checkout_cart,
+        wishlists::get_wishlist,
+        wishlists::save_wishlist_item,
+        wishlists::remove_wishlist_item,
+        wishlists::share_wishlist,
+        wishlists::stop_sharing_wishlist,
+        wishlists::get_shared_wishlist,
+        wishlists::order_wishlist_items,
+        invoices::get_invoice,
+        invoices::list_order_invoices,
+        invoices::issue_credit_note,
+        streams::stream_order_events,
+        streams::stream_customer_events,
+        streams::stream_order_events_ws,
+        streams::stream_customer_events_ws,
+        webhooks::register_webhook,
+        webhooks::list_webhooks,
+        webhooks::get_webhook,
+        webhooks::delete_webhook,
+        webhooks::pause_webhook,
+        webhooks::resume_webhook,
+        webhooks::list_webhook_deliveries,
+        admin::import_orders,
+        admin::export_orders,
+        admin::review_order,
+        health::health_check,
+        health::readiness_check,
+    ),
+    components(schemas(
+        CreateOrderRequest,
+        CreateOrderItemRequest,
+        CreateOrderResponse,
+        OrderResponse,
+        OrderItemResponse,
+        CancelOrderRequest,
+        PostalAddress,
+        ReviewOrderRequest,
+        CreateShipmentRequest,
+        CreateShipmentResponse,
+        ShipShipmentRequest,
+        MergeShipmentsRequest,
+        ShipmentResponse,
+        ShipmentLine,
+        ShipmentStatus,
+        CreateCartRequest,
+        CreateCartResponse,
+        AddCartLineRequest,
+        UpdateCartLineRequest,
+        MergeCartsRequest,
+        MergeCartsResponse,
+        CartResponse,
+        CartLineResponse,
+        SaveWishlistItemRequest,
+        ShareWishlistResponse,
+        OrderWishlistItemsRequest,
+        WishlistSelectionRequest,
+        WishlistResponse,
+        SharedWishlistResponse,
+        WishlistItemResponse,
+        WishlistPriority,
+        InvoiceResponse,
+        InvoiceLineResponse,
+        InvoiceKind,
+        InvoiceFormat,
+        IssueCreditNoteRequest,
+        CreditNoteLineRequest,
+        IssueCreditNoteResponse,
+        RegisterWebhookRequest,
+        RegisterWebhookResponse,
+        WebhookResponse,
+        WebhookDeliveryResponse,
+        AuditEntry,
+        ImportReport,
+        ImportRowError,
+        BulkFormat,
+        OrderStatus,
+        Money,
+        Currency,
+        ProblemDetails,
+        ReadinessReport,
+        ComponentHealth,
+        HealthStatus,
+    )),
+    modifiers(&BearerToken),
+    tags(
+        (name = "orders", description = "Order lifecycle"),
+        (name = "shipments", description = "Multi-parcel fulfilment of paid orders"),
+        (name = "carts", description = "Shopping carts and checkout into orders"),
+        (name = "wishlists", description = "Products saved for later, shared by link and moved into orders"),
+        (name = "invoices", description = "Invoices of paid orders and credit notes for refunds"),
+        (name = "streams", description = "Real-time order events for the storefront (SSE, WebSocket)"),
+        (name = "webhooks", description = "Partner endpoints notified of order events"),
+        (name = "admin", description = "Bulk import and export for operators"),
+        (name = "health", description = "Liveness and readiness probes"),
+    )
+)]
+pub struct ApiDoc;
+
+/// `bearer_token` security scheme: HS256 JWT naming the caller (`sub`) and its `roles`
+struct BearerToken;
+
+impl Modify for BearerToken {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_token",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+/// GET /openapi.json
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json");
+
+    /// Fails when the contract changes; review the diff, then regenerate with
+    /// `UPDATE_OPENAPI_SNAPSHOT=1 cargo test openapi`
+    #[test]
+    fn test_openapi_snapshot() {
+        let actual = ApiDoc::openapi().to_pretty_json().unwrap() + "\n";
+        if std::env::var_os("UPDATE_OPENAPI_SNAPSHOT").is_some() {
+            std::fs::write(SNAPSHOT, &actual).unwrap();
+        }
+
+        let expected = std::fs::read_to_string(SNAPSHOT).unwrap_or_default();
+        assert!(
+            actual == expected,
+            "OpenAPI contract changed, review {} (regenerate with UPDATE_OPENAPI_SNAPSHOT=1)",
+            SNAPSHOT
+        );
+    }
+
+    #[test]
+    fn test_order_status_values_are_documented() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let values = &doc["components"]["schemas"]["OrderStatus"]["enum"];
+        assert_eq!(
+            values,
+            &serde_json::json!([
+                "PENDING",
+                "ON_HOLD",
+                "CONFIRMED",
+                "PAID",
+                "SHIPPED",
+                "DELIVERED",
+                "CANCELLED"
+            ])
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use super::{ApiError, AppState, ProblemDetails, TenantHeader};
+use crate::application::audit::{Actor, AuditEntry};
+use crate::application::commands::{CancelOrderCommand, ConfirmOrderCommand};
+use crate::application::dto::{
+    CancelOrderRequest, CreateOrderRequest, CreateOrderResponse, OrderResponse,
+};
+use crate::application::queries::{GetOrderQuery, GetOrderTimelineQuery, ListCustomerOrdersQuery};
+use crate::domain::value_objects::{CustomerId, OrderId, OrderStatus, TenantId};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+/// POST /api/orders
+#[utoipa::path(
+    post,
+    path = "/api/orders",
+    tag = "orders",
+    security((), ("bearer_token" = [])),
+    request_body = CreateOrderRequest,
+    params(
+        TenantHeader,
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the first response when the same request is retried"),
+    ),
+    responses(
+        (status = 201, description = "Order created in PENDING status", body = CreateOrderResponse),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Idempotency-Key reused with another request or still in progress", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 413, description = "Request body larger than server.max_body_bytes"),
+        (status = 422, description = "Business rule violated (empty order, too many items, invalid quantity or product name, negative price)", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 429, description = "Rate limit exceeded; retry after the Retry-After delay (seconds)", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn create_order(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Json(request): Json<CreateOrderRequest>,
+) -> Result<(StatusCode, Json<CreateOrderResponse>), ApiError> {
+    let order_id = state
+        .create_order
+        .handle(request.into_command(tenant_id, actor))
+        .await?;
+    Ok((StatusCode::CREATED, Json(CreateOrderResponse { order_id })))
+}
+
+/// GET /api/orders/{order_id}
+#[utoipa::path(
+    get,
+    path = "/api/orders/{order_id}",
+    tag = "orders",
+    params(
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Order found", body = OrderResponse),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_order(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(order_id): Path<OrderId>,
+) -> Result<Json<OrderResponse>, ApiError> {
+    let order = state
+        .get_order
+        .handle(GetOrderQuery {
+            tenant_id,
+            order_id,
+        })
+        .await?;
+    Ok(Json(order))
+}
+
+/// GET /api/customers/{customer_id}/orders
+#[utoipa::path(
+    get,
+    path = "/api/customers/{customer_id}/orders",
+    tag = "orders",
+    params(
+        ("customer_id" = CustomerId, Path, description = "Customer identifier"),
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Orders of the customer, most recent first", body = [OrderResponse]),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn list_customer_orders(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+) -> Result<Json<Vec<OrderResponse>>, ApiError> {
+    let orders = state
+        .list_customer_orders
+        .handle(ListCustomerOrdersQuery {
+            tenant_id,
+            customer_id,
+        })
+        .await?;
+    Ok(Json(orders))
+}
+
+/// POST /api/orders/{order_id}/confirm
+#[utoipa::path(
+    post,
+    path = "/api/orders/{order_id}/confirm",
+    tag = "orders",
+    security((), ("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+    ),
+    responses(
+        (status = 202, description = "Order held for review (ON_HOLD) by the risk assessment of the order and its addresses"),
+        (status = 204, description = "Order confirmed"),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order is not PENDING, or held for review", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn confirm_order(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path(order_id): Path<OrderId>,
+) -> Result<StatusCode, ApiError> {
+    let status = state
+        .confirm_order
+        .handle(ConfirmOrderCommand {
+            tenant_id,
+            order_id,
+            actor,
+        })
+        .await?;
+    Ok(match status {
+        OrderStatus::OnHold => StatusCode::ACCEPTED,
+        _ => StatusCode::NO_CONTENT,
+    })
+}
+
+/// POST /api/orders/{order_id}/cancel
+#[utoipa::path(
+    post,
+    path = "/api/orders/{order_id}/cancel",
+    tag = "orders",
+    security((), ("bearer_token" = [])),
+    request_body = CancelOrderRequest,
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+    ),
+    responses(
+        (status = 204, description = "Order cancelled"),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order already paid, shipped, delivered or cancelled", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn cancel_order(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path(order_id): Path<OrderId>,
+    Json(request): Json<CancelOrderRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .cancel_order
+        .handle(CancelOrderCommand {
+            tenant_id,
+            order_id,
+            reason: request.reason,
+            actor,
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/orders/{order_id}/timeline
+#[utoipa::path(
+    get,
+    path = "/api/orders/{order_id}/timeline",
+    tag = "orders",
+    params(
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        TenantHeader,
+    ),
+    responses(
+        (status = 200, description = "Audit trail of the order, oldest first", body = [AuditEntry]),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_order_timeline(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(order_id): Path<OrderId>,
+) -> Result<Json<Vec<AuditEntry>>, ApiError> {
+    let timeline = state
+        .get_order_timeline
+        .handle(GetOrderTimelineQuery {
+            tenant_id,
+            order_id,
+        })
+        .await?;
+    Ok(Json(timeline))
+}
@@ -0,0 +1,262 @@
+use super::ApiError;
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::{header::RETRY_AFTER, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+use tower::{Layer, Service};
+
+/// Identifies the client of the public API; without a known key the peer IP is used
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Above this many tracked clients, buckets that refilled completely are dropped
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Per-client token buckets: `burst` requests at once, refilled at `per_second`
+pub struct RateLimiter {
+    capacity: f64,
+    per_second: f64,
+    /// Keys issued to clients: any other value is not a client identity
+    api_keys: HashSet<String>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(per_second: u32, burst: u32) -> Self {
+        Self {
+            capacity: f64::from(burst.max(1)),
+            per_second: f64::from(per_second.max(1)),
+            api_keys: HashSet::new(),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Give each of these API keys its own bucket
+    pub fn with_api_keys(mut self, keys: impl IntoIterator<Item = String>) -> Self {
+        self.api_keys.extend(
+            keys.into_iter()
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty()),
+        );
+        self
+    }
+
+    /// Take a token for `client`, or return how long to wait for the next one
+    pub fn acquire(&self, client: &str, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(client) {
+            buckets.retain(|_, bucket| self.refill(*bucket, now).tokens < self.capacity);
+        }
+
+        let bucket = buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: self.capacity,
+            refilled_at: now,
+        });
+        *bucket = self.refill(*bucket, now);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.per_second,
+            ))
+        }
+    }
+
+    fn refill(&self, bucket: Bucket, now: Instant) -> Bucket {
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        Bucket {
+            tokens: (bucket.tokens + elapsed.as_secs_f64() * self.per_second).min(self.capacity),
+            refilled_at: now,
+        }
+    }
+}
+
+/// Tower layer answering `429 Too Many Requests` once a client exhausts its bucket
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitLayer {
+    pub fn new(limiter: Arc<RateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimit {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimit<S> {
+    inner: S,
+    limiter: Arc<RateLimiter>,
+}
+
+impl<S> Service<Request> for RateLimit<S>
+where
+    S: Service<Request, Response = Response> + Send + 'static,
+    S::Error: Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        match self
+            .limiter
+            .acquire(&client_key(&request, &self.limiter), Instant::now())
+        {
+            Ok(()) => Box::pin(self.inner.call(request)),
+            Err(wait) => Box::pin(std::future::ready(Ok(too_many_requests(wait)))),
+        }
+    }
+}
+
+/// `key:<API key>` for a key the limiter knows, `ip:<peer address>` otherwise
+///
+/// Unknown keys are ignored: a client inventing a new key per request must not get a
+/// fresh bucket each time. The peer address is the TCP one: behind a proxy, clients
+/// must send an issued API key.
+fn client_key(request: &Request, limiter: &RateLimiter) -> String {
+    if let Some(key) = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| limiter.api_keys.contains(*key))
+    {
+        return format!("key:{}", key);
+    }
+    match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+        Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+        None => "ip:unknown".to_string(),
+    }
+}
+
+fn too_many_requests(wait: Duration) -> Response {
+    // Whole seconds, rounded up so that a retry at that time succeeds
+    let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    let mut response = ApiError::new(
+        StatusCode::TOO_MANY_REQUESTS,
+        "RATE_LIMITED",
+        "Rate limit exceeded",
+    )
+    .into_response();
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(seconds.max(1)));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_then_refill() {
+        let limiter = RateLimiter::new(2, 3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.acquire("ip:10.0.0.1", start).is_ok());
+        }
+        let wait = limiter.acquire("ip:10.0.0.1", start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        assert!(limiter
+            .acquire("ip:10.0.0.1", start + Duration::from_millis(500))
+            .is_ok());
+        assert!(limiter
+            .acquire("ip:10.0.0.1", start + Duration::from_millis(500))
+            .is_err());
+    }
+
+    #[test]
+    fn test_clients_have_separate_buckets() {
+        let limiter = RateLimiter::new(1, 1);
+        let now = Instant::now();
+
+        assert!(limiter.acquire("key:a", now).is_ok());
+        assert!(limiter.acquire("key:a", now).is_err());
+        assert!(limiter.acquire("key:b", now).is_ok());
+    }
+
+    #[test]
+    fn test_refill_is_capped_at_burst() {
+        let limiter = RateLimiter::new(10, 2);
+        let start = Instant::now();
+        assert!(limiter.acquire("key:a", start).is_ok());
+
+        let later = start + Duration::from_secs(60);
+        assert!(limiter.acquire("key:a", later).is_ok());
+        assert!(limiter.acquire("key:a", later).is_ok());
+        assert!(limiter.acquire("key:a", later).is_err());
+    }
+
+    #[test]
+    fn test_retry_after_is_rounded_up() {
+        let response = too_many_requests(Duration::from_millis(1200));
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[RETRY_AFTER], "2");
+    }
+
+    fn request(api_key: &str) -> Request {
+        let mut request = Request::new(axum::body::Body::empty());
+        request
+            .headers_mut()
+            .insert(API_KEY_HEADER, api_key.parse().unwrap());
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 40000))));
+        request
+    }
+
+    #[tokio::test]
+    async fn test_unknown_api_keys_share_the_peer_bucket() {
+        use tower::ServiceExt;
+
+        let limiter = Arc::new(RateLimiter::new(1, 2).with_api_keys(["issued".to_string()]));
+        let service = RateLimitLayer::new(limiter).layer(tower::service_fn(|_: Request| async {
+            Ok::<_, std::convert::Infallible>(StatusCode::OK.into_response())
+        }));
+        let status = |api_key: &str| {
+            let service = service.clone();
+            let request = request(api_key);
+            async move { service.oneshot(request).await.unwrap().status() }
+        };
+
+        // A new made-up key per request still draws from the bucket of the IP
+        assert_eq!(status("rotated-1").await, StatusCode::OK);
+        assert_eq!(status("rotated-2").await, StatusCode::OK);
+        assert_eq!(status("rotated-3").await, StatusCode::TOO_MANY_REQUESTS);
+        // An issued key has its own bucket
+        assert_eq!(status("issued").await, StatusCode::OK);
+    }
+}
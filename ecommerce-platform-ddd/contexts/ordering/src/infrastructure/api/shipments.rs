@@ -0,0 +1,138 @@
+use super::{ApiError, AppState, ProblemDetails, TenantHeader};
+use crate::application::audit::Actor;
+use crate::application::commands::{
+    CreateShipmentCommand, MergeShipmentsCommand, ShipShipmentCommand,
+};
+use crate::application::dto::{
+    CreateShipmentRequest, CreateShipmentResponse, MergeShipmentsRequest, ShipShipmentRequest,
+};
+use crate::domain::value_objects::{OrderId, ShipmentId, TenantId};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+/// POST /api/orders/{order_id}/shipments
+#[utoipa::path(
+    post,
+    path = "/api/orders/{order_id}/shipments",
+    tag = "shipments",
+    security((), ("bearer_token" = [])),
+    request_body = CreateShipmentRequest,
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+    ),
+    responses(
+        (status = 201, description = "Parcel created in PENDING status", body = CreateShipmentResponse),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order or order item not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order is not PAID", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Empty parcel, zero quantity or more units than ordered", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn create_shipment(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path(order_id): Path<OrderId>,
+    Json(request): Json<CreateShipmentRequest>,
+) -> Result<(StatusCode, Json<CreateShipmentResponse>), ApiError> {
+    let shipment_id = state
+        .create_shipment
+        .handle(CreateShipmentCommand {
+            tenant_id,
+            order_id,
+            lines: request.lines,
+            actor,
+        })
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateShipmentResponse { shipment_id }),
+    ))
+}
+
+/// POST /api/orders/{order_id}/shipments/{shipment_id}/ship
+#[utoipa::path(
+    post,
+    path = "/api/orders/{order_id}/shipments/{shipment_id}/ship",
+    tag = "shipments",
+    security((), ("bearer_token" = [])),
+    request_body = ShipShipmentRequest,
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        ("shipment_id" = ShipmentId, Path, description = "Shipment identifier"),
+    ),
+    responses(
+        (status = 204, description = "Parcel shipped; the order is SHIPPED once every unit has shipped"),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order or shipment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order is not PAID or parcel already shipped", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Empty tracking number", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn ship_shipment(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path((order_id, shipment_id)): Path<(OrderId, ShipmentId)>,
+    Json(request): Json<ShipShipmentRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .ship_shipment
+        .handle(ShipShipmentCommand {
+            tenant_id,
+            order_id,
+            shipment_id,
+            tracking_number: request.tracking_number,
+            actor,
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/orders/{order_id}/shipments/{shipment_id}/merge
+#[utoipa::path(
+    post,
+    path = "/api/orders/{order_id}/shipments/{shipment_id}/merge",
+    tag = "shipments",
+    security((), ("bearer_token" = [])),
+    request_body = MergeShipmentsRequest,
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        ("shipment_id" = ShipmentId, Path, description = "Parcel receiving the lines"),
+    ),
+    responses(
+        (status = 204, description = "Parcels merged"),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order or shipment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Order is not PAID or a parcel already shipped", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Parcel merged with itself", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn merge_shipments(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path((order_id, shipment_id)): Path<(OrderId, ShipmentId)>,
+    Json(request): Json<MergeShipmentsRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .merge_shipments
+        .handle(MergeShipmentsCommand {
+            tenant_id,
+            order_id,
+            shipment_id,
+            merged_shipment_id: request.merged_shipment_id,
+            actor,
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
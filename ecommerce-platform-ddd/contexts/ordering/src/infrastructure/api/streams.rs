@@ -0,0 +1,340 @@
+use super::{ApiError, AppState, ProblemDetails, TenantHeader};
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetOrderQuery;
+use crate::domain::value_objects::{CustomerId, OrderId, TenantId};
+use crate::infrastructure::messaging::{EventStream, StreamFilter, StreamItem};
+use crate::infrastructure::tenancy::{Caller, OPERATOR_ROLE};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+};
+use serde::Deserialize;
+use std::convert::Infallible;
+use tokio_stream::wrappers::ReceiverStream;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+/// Sent by `EventSource` when it reconnects
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+/// Name of the SSE event (and WebSocket message) asking the client to reload the state
+const RESYNC: &str = "resync";
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StreamParams {
+    /// Resume after this event, for clients that cannot send the `Last-Event-ID` header
+    pub last_event_id: Option<Uuid>,
+}
+
+/// Only the customer follows their orders (the `sub` of their token), or an operator
+fn authorize(caller: &Caller, customer_id: CustomerId) -> Result<(), ApiError> {
+    if caller.subject == customer_id.to_string() || caller.has_role(OPERATOR_ROLE) {
+        return Ok(());
+    }
+    Err(ApplicationError::Unauthorized("Not allowed to follow these orders".to_string()).into())
+}
+
+fn last_event_id(headers: &HeaderMap, params: &StreamParams) -> Result<Option<Uuid>, ApiError> {
+    let Some(value) = headers.get(LAST_EVENT_ID_HEADER) else {
+        return Ok(params.last_event_id);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "INVALID_LAST_EVENT_ID",
+                "Invalid Last-Event-ID header",
+            )
+        })
+}
+
+/// Subscribe to the events of an order, on behalf of its customer
+async fn order_stream(
+    state: &AppState,
+    tenant_id: TenantId,
+    order_id: OrderId,
+    caller: &Caller,
+    last_event_id: Option<Uuid>,
+) -> Result<EventStream, ApiError> {
+    let order = state
+        .get_order
+        .handle(GetOrderQuery {
+            tenant_id,
+            order_id,
+        })
+        .await?;
+    authorize(caller, order.customer_id)?;
+    Ok(state
+        .event_hub
+        .subscribe(StreamFilter::Order(order_id), last_event_id))
+}
+
+fn customer_stream(
+    state: &AppState,
+    tenant_id: TenantId,
+    customer_id: CustomerId,
+    caller: &Caller,
+    last_event_id: Option<Uuid>,
+) -> Result<EventStream, ApiError> {
+    authorize(caller, customer_id)?;
+    Ok(state.event_hub.subscribe(
+        StreamFilter::Customer(tenant_id, customer_id),
+        last_event_id,
+    ))
+}
+
+/// SSE frame of a stream item: `id` is the event id to resume from
+fn sse_event(item: StreamItem) -> Result<Event, axum::Error> {
+    match item {
+        StreamItem::Event(event) => Event::default()
+            .id(event.envelope.metadata.event_id.to_string())
+            .event(event.envelope.event.event_name())
+            .json_data(&event.envelope),
+        StreamItem::Resync => Ok(Event::default().event(RESYNC).data("{}")),
+    }
+}
+
+/// WebSocket message of a stream item, same fields as the SSE frame
+fn ws_message(item: StreamItem) -> String {
+    let message = match item {
+        StreamItem::Event(event) => serde_json::json!({
+            "id": event.envelope.metadata.event_id,
+            "event": event.envelope.event.event_name(),
+            "data": event.envelope,
+        }),
+        StreamItem::Resync => serde_json::json!({ "event": RESYNC }),
+    };
+    message.to_string()
+}
+
+fn sse(mut stream: EventStream) -> Response {
+    let (tx, rx) = tokio::sync::mpsc::channel::<Result<Event, Infallible>>(16);
+    tokio::spawn(async move {
+        loop {
+            let item = tokio::select! {
+                item = stream.next() => item,
+                // Client gone: stop following the hub
+                _ = tx.closed() => break,
+            };
+            let Some(item) = item else { break };
+            match sse_event(item) {
+                Ok(event) => {
+                    if tx.send(Ok(event)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(err) => tracing::error!("Cannot encode streamed event: {}", err),
+            }
+        }
+    });
+    Sse::new(ReceiverStream::new(rx))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+async fn forward(mut socket: WebSocket, mut stream: EventStream) {
+    loop {
+        tokio::select! {
+            item = stream.next() => {
+                let Some(item) = item else { break };
+                if socket.send(Message::Text(ws_message(item).into())).await.is_err() {
+                    break;
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // The stream is one way: client messages are ignored
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// GET /api/orders/{order_id}/events
+#[utoipa::path(
+    get,
+    path = "/api/orders/{order_id}/events",
+    tag = "streams",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        ("Last-Event-ID" = Option<String>, Header, description = "Resume after this event (sent by EventSource on reconnect)"),
+        StreamParams,
+    ),
+    responses(
+        (status = 200, description = "Server-Sent Events: `id` = event id, `event` = event name, `data` = event envelope; `resync` when events were missed", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Invalid Last-Event-ID header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is neither the customer of the order nor an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn stream_order_events(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(order_id): Path<OrderId>,
+    caller: Caller,
+    headers: HeaderMap,
+    Query(params): Query<StreamParams>,
+) -> Result<Response, ApiError> {
+    let last_event_id = last_event_id(&headers, &params)?;
+    let stream = order_stream(&state, tenant_id, order_id, &caller, last_event_id).await?;
+    Ok(sse(stream))
+}
+
+/// GET /api/customers/{customer_id}/events
+#[utoipa::path(
+    get,
+    path = "/api/customers/{customer_id}/events",
+    tag = "streams",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Customer identifier"),
+        ("Last-Event-ID" = Option<String>, Header, description = "Resume after this event (sent by EventSource on reconnect)"),
+        StreamParams,
+    ),
+    responses(
+        (status = 200, description = "Server-Sent Events of every order of the customer, same frames as the order stream", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Invalid Last-Event-ID header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is neither this customer nor an operator", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn stream_customer_events(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+    caller: Caller,
+    headers: HeaderMap,
+    Query(params): Query<StreamParams>,
+) -> Result<Response, ApiError> {
+    let last_event_id = last_event_id(&headers, &params)?;
+    let stream = customer_stream(&state, tenant_id, customer_id, &caller, last_event_id)?;
+    Ok(sse(stream))
+}
+
+/// GET /api/orders/{order_id}/events/ws
+#[utoipa::path(
+    get,
+    path = "/api/orders/{order_id}/events/ws",
+    tag = "streams",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("order_id" = OrderId, Path, description = "Order identifier"),
+        StreamParams,
+    ),
+    responses(
+        (status = 101, description = "WebSocket: one JSON text message per event, `{\"id\", \"event\", \"data\"}`, or `{\"event\": \"resync\"}`"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is neither the customer of the order nor an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Order not found", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn stream_order_events_ws(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(order_id): Path<OrderId>,
+    caller: Caller,
+    Query(params): Query<StreamParams>,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    let stream = order_stream(&state, tenant_id, order_id, &caller, params.last_event_id).await?;
+    Ok(upgrade.on_upgrade(move |socket| forward(socket, stream)))
+}
+
+/// GET /api/customers/{customer_id}/events/ws
+#[utoipa::path(
+    get,
+    path = "/api/customers/{customer_id}/events/ws",
+    tag = "streams",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Customer identifier"),
+        StreamParams,
+    ),
+    responses(
+        (status = 101, description = "WebSocket: same messages as the order stream, for every order of the customer"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "Caller is neither this customer nor an operator", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn stream_customer_events_ws(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+    caller: Caller,
+    Query(params): Query<StreamParams>,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ApiError> {
+    let stream = customer_stream(
+        &state,
+        tenant_id,
+        customer_id,
+        &caller,
+        params.last_event_id,
+    )?;
+    Ok(upgrade.on_upgrade(move |socket| forward(socket, stream)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_customer_or_an_operator_may_follow_orders() {
+        let customer_id = CustomerId::new();
+        let caller = |subject: String, roles: &[&str]| Caller {
+            subject,
+            roles: roles.iter().map(|role| role.to_string()).collect(),
+        };
+
+        assert!(authorize(&caller(customer_id.to_string(), &[]), customer_id).is_ok());
+        assert!(authorize(&caller("ops-1".to_string(), &[OPERATOR_ROLE]), customer_id).is_ok());
+        assert_eq!(
+            authorize(&caller(CustomerId::new().to_string(), &[]), customer_id)
+                .unwrap_err()
+                .status(),
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    #[test]
+    fn test_last_event_id_header_wins_over_query() {
+        let from_query = Uuid::new_v4();
+        let from_header = Uuid::new_v4();
+        let params = StreamParams {
+            last_event_id: Some(from_query),
+        };
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(last_event_id(&headers, &params).unwrap(), Some(from_query));
+        headers.insert(
+            LAST_EVENT_ID_HEADER,
+            from_header.to_string().parse().unwrap(),
+        );
+        assert_eq!(last_event_id(&headers, &params).unwrap(), Some(from_header));
+        headers.insert(LAST_EVENT_ID_HEADER, "42".parse().unwrap());
+        assert!(last_event_id(&headers, &params).is_err());
+    }
+
+    #[test]
+    fn test_resync_message() {
+        assert_eq!(ws_message(StreamItem::Resync), r#"{"event":"resync"}"#);
+    }
+}
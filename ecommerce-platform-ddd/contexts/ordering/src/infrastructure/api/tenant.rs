@@ -0,0 +1,114 @@
+use super::{ApiError, AppState};
+use crate::application::audit::Actor;
+use crate::domain::value_objects::TenantId;
+use crate::infrastructure::tenancy::{Caller, TenantResolutionError, OPERATOR_ROLE, TENANT_HEADER};
+use axum::{
+    extract::FromRequestParts,
+    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+};
+use utoipa::IntoParams;
+
+/// `X-Tenant-Id` header of the documented routes, listed in their `params`
+#[derive(IntoParams)]
+#[into_params(parameter_in = Header)]
+pub struct TenantHeader {
+    /// Storefront of the request, matching the tenant of the bearer token (default if absent)
+    #[param(rename = "X-Tenant-Id")]
+    pub tenant_id: Option<String>,
+}
+
+/// Tenant of the request, as resolved by `TenantResolver::resolve`
+impl FromRequestParts<AppState> for TenantId {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = |name| {
+            parts
+                .headers
+                .get(name)
+                .map(|value| value.to_str().unwrap_or_default())
+        };
+        state
+            .tenant_resolver
+            .resolve(header(TENANT_HEADER), header(AUTHORIZATION.as_str()))
+            .map_err(ApiError::from)
+    }
+}
+
+/// Caller of the request, from its verified bearer token (401 without one)
+impl FromRequestParts<AppState> for Caller {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let authorization = parts
+            .headers
+            .get(AUTHORIZATION)
+            .map(|value| value.to_str().unwrap_or_default());
+        state
+            .tenant_resolver
+            .authenticate(authorization)
+            .map_err(ApiError::from)
+    }
+}
+
+/// Actor of the audit trail, as resolved by `TenantResolver::actor` (`anonymous` without a token)
+impl FromRequestParts<AppState> for Actor {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let authorization = parts
+            .headers
+            .get(AUTHORIZATION)
+            .map(|value| value.to_str().unwrap_or_default());
+        state
+            .tenant_resolver
+            .actor(authorization)
+            .map_err(ApiError::from)
+    }
+}
+
+/// Caller holding the operator role: back-office staff (403 for other callers)
+pub struct Operator(pub Caller);
+
+impl FromRequestParts<AppState> for Operator {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let caller = Caller::from_request_parts(parts, state).await?;
+        if !caller.has_role(OPERATOR_ROLE) {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "OPERATOR_REQUIRED",
+                "Only operators may perform this action",
+            ));
+        }
+        Ok(Operator(caller))
+    }
+}
+
+impl From<TenantResolutionError> for ApiError {
+    fn from(err: TenantResolutionError) -> Self {
+        let (status, code) = match err {
+            TenantResolutionError::InvalidHeader => {
+                (StatusCode::BAD_REQUEST, "INVALID_TENANT_HEADER")
+            }
+            TenantResolutionError::UnknownTenant(_) => (StatusCode::BAD_REQUEST, "UNKNOWN_TENANT"),
+            TenantResolutionError::InvalidToken(_) => (StatusCode::UNAUTHORIZED, "INVALID_TOKEN"),
+            TenantResolutionError::MissingToken => (StatusCode::UNAUTHORIZED, "MISSING_TOKEN"),
+            TenantResolutionError::Mismatch => (StatusCode::FORBIDDEN, "TENANT_MISMATCH"),
+        };
+        ApiError::new(status, code, err.to_string())
+    }
+}
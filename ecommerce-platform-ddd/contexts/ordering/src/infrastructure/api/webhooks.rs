@@ -0,0 +1,344 @@
+use super::{ApiError, AppState, Operator, ProblemDetails, TenantHeader};
+use crate::application::error::{ApplicationError, Resource};
+use crate::domain::value_objects::TenantId;
+use crate::infrastructure::webhooks::{DeliveryAttempt, WebhookSubscription};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Most attempts returned by the delivery log
+const MAX_DELIVERIES: u64 = 500;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RegisterWebhookRequest {
+    /// Endpoint receiving the events (https, on a public host)
+    #[schema(example = "https://partner.example.com/hooks/orders")]
+    pub url: String,
+    /// Event types delivered, as named in `OrderEvent`
+    #[schema(example = json!(["ORDER_PAID", "SHIPMENT_SHIPPED"]))]
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    pub paused: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Registered subscription, with the signing secret (never shown again)
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RegisterWebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub events: Vec<String>,
+    /// Key of the `X-Webhook-Signature` HMAC
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    /// Event id of the envelope, shared by the retries of a delivery
+    pub event_id: Uuid,
+    pub event_name: String,
+    pub attempt: u32,
+    /// HTTP status answered by the endpoint, absent when it could not be reached
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub duration_ms: u64,
+    pub attempted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DeliveriesParams {
+    /// Most recent attempts returned (default 50, at most 500)
+    pub limit: Option<u64>,
+}
+
+impl From<WebhookSubscription> for WebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            events: subscription.event_names,
+            paused: subscription.paused,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+impl From<DeliveryAttempt> for WebhookDeliveryResponse {
+    fn from(attempt: DeliveryAttempt) -> Self {
+        Self {
+            id: attempt.id,
+            event_id: attempt.event_id,
+            event_name: attempt.event_name,
+            attempt: attempt.attempt,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            succeeded: attempt.succeeded,
+            duration_ms: attempt.duration_ms,
+            attempted_at: attempt.attempted_at,
+        }
+    }
+}
+
+fn not_found() -> ApiError {
+    ApplicationError::NotFound(Resource::Webhook).into()
+}
+
+/// POST /api/webhooks
+#[utoipa::path(
+    post,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(TenantHeader),
+    request_body = RegisterWebhookRequest,
+    responses(
+        (status = 201, description = "Subscription registered and active", body = RegisterWebhookResponse),
+        (status = 422, description = "Invalid URL, no event or unknown event", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn register_webhook(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Json(request): Json<RegisterWebhookRequest>,
+) -> Result<(StatusCode, Json<RegisterWebhookResponse>), ApiError> {
+    let subscription =
+        WebhookSubscription::new(tenant_id, &request.url, request.events, Utc::now()).map_err(
+            |err| {
+                ApiError::new(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    err.code(),
+                    err.to_string(),
+                )
+            },
+        )?;
+    state.webhook_store.register(subscription.clone()).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(RegisterWebhookResponse {
+            id: subscription.id,
+            url: subscription.url,
+            events: subscription.event_names,
+            secret: subscription.secret,
+            created_at: subscription.created_at,
+        }),
+    ))
+}
+
+/// GET /api/webhooks
+#[utoipa::path(
+    get,
+    path = "/api/webhooks",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(TenantHeader),
+    responses(
+        (status = 200, description = "Every subscription, oldest first", body = [WebhookResponse]),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+) -> Result<Json<Vec<WebhookResponse>>, ApiError> {
+    let subscriptions = state.webhook_store.list(&tenant_id).await?;
+    Ok(Json(subscriptions.into_iter().map(Into::into).collect()))
+}
+
+/// GET /api/webhooks/{webhook_id}
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{webhook_id}",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("webhook_id" = Uuid, Path, description = "Subscription identifier"),
+    ),
+    responses(
+        (status = 200, description = "Subscription found", body = WebhookResponse),
+        (status = 404, description = "Webhook not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_webhook(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    let subscription = state
+        .webhook_store
+        .find(&tenant_id, webhook_id)
+        .await?
+        .ok_or_else(not_found)?;
+    Ok(Json(subscription.into()))
+}
+
+/// DELETE /api/webhooks/{webhook_id}
+#[utoipa::path(
+    delete,
+    path = "/api/webhooks/{webhook_id}",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("webhook_id" = Uuid, Path, description = "Subscription identifier"),
+    ),
+    responses(
+        (status = 204, description = "Subscription and delivery log removed"),
+        (status = 404, description = "Webhook not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    if !state.webhook_store.delete(&tenant_id, webhook_id).await? {
+        return Err(not_found());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/webhooks/{webhook_id}/pause
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/{webhook_id}/pause",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("webhook_id" = Uuid, Path, description = "Subscription identifier"),
+    ),
+    responses(
+        (status = 200, description = "Subscription paused: no delivery nor retry until resumed", body = WebhookResponse),
+        (status = 404, description = "Webhook not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn pause_webhook(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    set_paused(&state, &tenant_id, webhook_id, true).await
+}
+
+/// POST /api/webhooks/{webhook_id}/resume
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/{webhook_id}/resume",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("webhook_id" = Uuid, Path, description = "Subscription identifier"),
+    ),
+    responses(
+        (status = 200, description = "Subscription active again (events published while paused are not sent)", body = WebhookResponse),
+        (status = 404, description = "Webhook not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn resume_webhook(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    set_paused(&state, &tenant_id, webhook_id, false).await
+}
+
+async fn set_paused(
+    state: &AppState,
+    tenant_id: &TenantId,
+    webhook_id: Uuid,
+    paused: bool,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    if !state
+        .webhook_store
+        .set_paused(tenant_id, webhook_id, paused)
+        .await?
+    {
+        return Err(not_found());
+    }
+    let subscription = state
+        .webhook_store
+        .find(tenant_id, webhook_id)
+        .await?
+        .ok_or_else(not_found)?;
+    Ok(Json(subscription.into()))
+}
+
+/// GET /api/webhooks/{webhook_id}/deliveries
+#[utoipa::path(
+    get,
+    path = "/api/webhooks/{webhook_id}/deliveries",
+    tag = "webhooks",
+    security(("bearer_token" = [])),
+    params(
+        TenantHeader,
+        ("webhook_id" = Uuid, Path, description = "Subscription identifier"),
+        DeliveriesParams,
+    ),
+    responses(
+        (status = 200, description = "Delivery attempts, most recent first", body = [WebhookDeliveryResponse]),
+        (status = 404, description = "Webhook not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "Missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "The caller is not an operator", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    _operator: Operator,
+    Path(webhook_id): Path<Uuid>,
+    Query(params): Query<DeliveriesParams>,
+) -> Result<Json<Vec<WebhookDeliveryResponse>>, ApiError> {
+    if state
+        .webhook_store
+        .find(&tenant_id, webhook_id)
+        .await?
+        .is_none()
+    {
+        return Err(not_found());
+    }
+    let limit = params.limit.unwrap_or(50).clamp(1, MAX_DELIVERIES);
+    let attempts = state.webhook_store.attempts(webhook_id, limit).await?;
+    Ok(Json(attempts.into_iter().map(Into::into).collect()))
+}
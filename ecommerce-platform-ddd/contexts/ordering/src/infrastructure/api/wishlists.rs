@@ -0,0 +1,257 @@
+use super::{ApiError, AppState, ProblemDetails, TenantHeader};
+use crate::application::audit::Actor;
+use crate::application::commands::{
+    OrderWishlistItemsCommand, ShareWishlistCommand, UpdateWishlistCommand, WishlistChange,
+    WishlistSelection,
+};
+use crate::application::dto::{
+    CreateOrderResponse, OrderWishlistItemsRequest, SaveWishlistItemRequest, ShareWishlistResponse,
+    SharedWishlistResponse, WishlistResponse,
+};
+use crate::application::queries::{GetSharedWishlistQuery, GetWishlistQuery};
+use crate::domain::value_objects::{CustomerId, ProductId, ShareToken, TenantId};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+/// GET /api/wishlists/{customer_id}
+#[utoipa::path(
+    get,
+    path = "/api/wishlists/{customer_id}",
+    tag = "wishlists",
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+    ),
+    responses(
+        (status = 200, description = "Wishlist found", body = WishlistResponse),
+        (status = 404, description = "Customer has not saved any product yet", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_wishlist(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+) -> Result<Json<WishlistResponse>, ApiError> {
+    let wishlist = state
+        .get_wishlist
+        .handle(GetWishlistQuery {
+            tenant_id,
+            customer_id,
+        })
+        .await?;
+    Ok(Json(wishlist))
+}
+
+/// PUT /api/wishlists/{customer_id}/items/{product_id}
+#[utoipa::path(
+    put,
+    path = "/api/wishlists/{customer_id}/items/{product_id}",
+    tag = "wishlists",
+    request_body = SaveWishlistItemRequest,
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+        ("product_id" = ProductId, Path, description = "Product to save"),
+    ),
+    responses(
+        (status = 204, description = "Product saved (note and priority replaced when already saved); the wishlist is created with its first product"),
+        (status = 422, description = "Invalid product name, note too long or wishlist full", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn save_wishlist_item(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path((customer_id, product_id)): Path<(CustomerId, ProductId)>,
+    Json(request): Json<SaveWishlistItemRequest>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_wishlist
+        .handle(UpdateWishlistCommand {
+            tenant_id,
+            customer_id,
+            change: WishlistChange::SaveItem {
+                product_id,
+                product_name: request.product_name,
+                note: request.note,
+                priority: request.priority,
+            },
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// DELETE /api/wishlists/{customer_id}/items/{product_id}
+#[utoipa::path(
+    delete,
+    path = "/api/wishlists/{customer_id}/items/{product_id}",
+    tag = "wishlists",
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+        ("product_id" = ProductId, Path, description = "Saved product"),
+    ),
+    responses(
+        (status = 204, description = "Product removed"),
+        (status = 404, description = "Wishlist not found or product not saved", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_wishlist_item(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path((customer_id, product_id)): Path<(CustomerId, ProductId)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_wishlist
+        .handle(UpdateWishlistCommand {
+            tenant_id,
+            customer_id,
+            change: WishlistChange::RemoveItem { product_id },
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /api/wishlists/{customer_id}/share
+#[utoipa::path(
+    post,
+    path = "/api/wishlists/{customer_id}/share",
+    tag = "wishlists",
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+    ),
+    responses(
+        (status = 200, description = "Wishlist shared; the token of the current link when already shared", body = ShareWishlistResponse),
+        (status = 404, description = "Wishlist not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn share_wishlist(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+) -> Result<Json<ShareWishlistResponse>, ApiError> {
+    let share_token = state
+        .share_wishlist
+        .handle(ShareWishlistCommand {
+            tenant_id,
+            customer_id,
+        })
+        .await?;
+    Ok(Json(ShareWishlistResponse { share_token }))
+}
+
+/// DELETE /api/wishlists/{customer_id}/share
+#[utoipa::path(
+    delete,
+    path = "/api/wishlists/{customer_id}/share",
+    tag = "wishlists",
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+    ),
+    responses(
+        (status = 204, description = "Share link revoked, the wishlist is private"),
+        (status = 404, description = "Wishlist not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn stop_sharing_wishlist(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(customer_id): Path<CustomerId>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .update_wishlist
+        .handle(UpdateWishlistCommand {
+            tenant_id,
+            customer_id,
+            change: WishlistChange::StopSharing,
+        })
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /api/shared-wishlists/{share_token}
+#[utoipa::path(
+    get,
+    path = "/api/shared-wishlists/{share_token}",
+    tag = "wishlists",
+    params(
+        TenantHeader,
+        ("share_token" = String, Path, description = "Token of the share link"),
+    ),
+    responses(
+        (status = 200, description = "Shared wishlist, without the identity of its owner", body = SharedWishlistResponse),
+        (status = 404, description = "Unknown or revoked link", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_shared_wishlist(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    Path(share_token): Path<ShareToken>,
+) -> Result<Json<SharedWishlistResponse>, ApiError> {
+    let wishlist = state
+        .get_shared_wishlist
+        .handle(GetSharedWishlistQuery {
+            tenant_id,
+            share_token,
+        })
+        .await?;
+    Ok(Json(wishlist))
+}
+
+/// POST /api/wishlists/{customer_id}/orders
+#[utoipa::path(
+    post,
+    path = "/api/wishlists/{customer_id}/orders",
+    tag = "wishlists",
+    security((), ("bearer_token" = [])),
+    request_body = OrderWishlistItemsRequest,
+    params(
+        TenantHeader,
+        ("customer_id" = CustomerId, Path, description = "Owner of the wishlist"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Replays the first response when the same request is retried"),
+    ),
+    responses(
+        (status = 201, description = "Order created in PENDING status at the catalog prices; the ordered products leave the wishlist unless kept", body = CreateOrderResponse),
+        (status = 401, description = "Invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "Wishlist not found or product not saved", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "Idempotency-Key reused with another request or still in progress", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "Nothing selected, invalid quantity, too many items, or product no longer sold or not sold in the currency of the tenant", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "Storage failure", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+pub async fn order_wishlist_items(
+    State(state): State<AppState>,
+    tenant_id: TenantId,
+    actor: Actor,
+    Path(customer_id): Path<CustomerId>,
+    Json(request): Json<OrderWishlistItemsRequest>,
+) -> Result<(StatusCode, Json<CreateOrderResponse>), ApiError> {
+    let order_id = state
+        .order_wishlist_items
+        .handle(OrderWishlistItemsCommand {
+            tenant_id,
+            customer_id,
+            items: request
+                .items
+                .into_iter()
+                .map(|item| WishlistSelection {
+                    product_id: item.product_id,
+                    quantity: item.quantity,
+                })
+                .collect(),
+            keep_in_wishlist: request.keep_in_wishlist,
+            actor,
+        })
+        .await?;
+    Ok((StatusCode::CREATED, Json(CreateOrderResponse { order_id })))
+}
@@ -0,0 +1,66 @@
+use crate::application::audit::{AuditEntry, AuditLog, ErasureRecord};
+use crate::domain::{
+    errors::InfrastructureError,
+    value_objects::{CustomerId, OrderId, TenantId},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory audit log (single instance deployments and tests)
+#[derive(Default)]
+pub struct InMemoryAuditLog {
+    entries: Arc<RwLock<HashMap<OrderId, Vec<AuditEntry>>>>,
+    erasures: Arc<RwLock<Vec<ErasureRecord>>>,
+}
+
+impl InMemoryAuditLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AuditLog for InMemoryAuditLog {
+    async fn record(&self, entry: AuditEntry) -> Result<(), InfrastructureError> {
+        let mut entries = self.entries.write().await;
+        entries.entry(entry.order_id).or_default().push(entry);
+        Ok(())
+    }
+
+    async fn timeline(&self, order_id: OrderId) -> Result<Vec<AuditEntry>, InfrastructureError> {
+        let entries = self.entries.read().await;
+        Ok(entries.get(&order_id).cloned().unwrap_or_default())
+    }
+
+    async fn anonymize(
+        &self,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        let mut entries = self.entries.write().await;
+        for entry in entries.get_mut(&order_id).into_iter().flatten() {
+            entry.anonymize(customer_id, pseudonym);
+        }
+        Ok(())
+    }
+
+    async fn record_erasure(&self, erasure: ErasureRecord) -> Result<(), InfrastructureError> {
+        self.erasures.write().await.push(erasure);
+        Ok(())
+    }
+
+    async fn erasures(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ErasureRecord>, InfrastructureError> {
+        let erasures = self.erasures.read().await;
+        Ok(erasures
+            .iter()
+            .filter(|erasure| &erasure.tenant_id == tenant_id)
+            .cloned()
+            .collect())
+    }
+}
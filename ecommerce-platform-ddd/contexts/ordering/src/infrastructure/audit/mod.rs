@@ -0,0 +1,5 @@
+pub mod in_memory;
+pub mod sql;
+
+pub use in_memory::InMemoryAuditLog;
+pub use sql::SqlAuditLog;
@@ -0,0 +1,300 @@
+use crate::application::audit::{AuditEntry, AuditLog, ErasureRecord};
+use crate::domain::{
+    errors::InfrastructureError,
+    value_objects::{CustomerId, OrderId, TenantId},
+};
+use crate::infrastructure::persistence::corrupted;
+use crate::infrastructure::persistence::entities::audit_entry::{
+    ActiveModel, Column, Entity, Model,
+};
+use crate::infrastructure::persistence::entities::erasure_request;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+
+/// SeaORM implementation, append-only `audit_entries` table (rows are only rewritten
+/// to erase personal data) and `erasure_requests` table
+pub struct SqlAuditLog {
+    db: DatabaseConnection,
+}
+
+impl SqlAuditLog {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditLog for SqlAuditLog {
+    async fn record(&self, entry: AuditEntry) -> Result<(), InfrastructureError> {
+        Entity::insert(to_row(entry)?)
+            .exec_without_returning(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn timeline(&self, order_id: OrderId) -> Result<Vec<AuditEntry>, InfrastructureError> {
+        Entity::find()
+            .filter(Column::OrderId.eq(order_id.value()))
+            .order_by_asc(Column::OccurredAt)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(to_entry)
+            .collect()
+    }
+
+    async fn anonymize(
+        &self,
+        order_id: OrderId,
+        customer_id: CustomerId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+        let rows = Entity::find()
+            .filter(Column::OrderId.eq(order_id.value()))
+            .all(&txn)
+            .await?;
+        for row in rows {
+            let mut entry = to_entry(row)?;
+            entry.anonymize(customer_id, pseudonym);
+            Entity::update(to_row(entry)?).exec(&txn).await?;
+        }
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn record_erasure(&self, erasure: ErasureRecord) -> Result<(), InfrastructureError> {
+        erasure_request::Entity::insert(erasure_request::ActiveModel {
+            id: Set(erasure.id),
+            tenant_id: Set(erasure.tenant_id.to_string()),
+            reference: Set(erasure.reference),
+            actor: Set(erasure.actor.to_string()),
+            orders_anonymized: Set(erasure.orders_anonymized as i32),
+            occurred_at: Set(erasure.occurred_at),
+        })
+        .exec_without_returning(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn erasures(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ErasureRecord>, InfrastructureError> {
+        erasure_request::Entity::find()
+            .filter(erasure_request::Column::TenantId.eq(tenant_id.as_str()))
+            .order_by_asc(erasure_request::Column::OccurredAt)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(to_erasure)
+            .collect()
+    }
+}
+
+fn to_row(entry: AuditEntry) -> Result<ActiveModel, InfrastructureError> {
+    let events = serde_json::to_string(&entry.events).map_err(|err| {
+        InfrastructureError::storage(format!("audit entry {}", entry.id)).with_source(err)
+    })?;
+    Ok(ActiveModel {
+        id: Set(entry.id),
+        order_id: Set(entry.order_id.value()),
+        actor: Set(entry.actor.to_string()),
+        command: Set(entry.command),
+        status_before: Set(entry.status_before.map(|status| status.to_string())),
+        status_after: Set(entry.status_after.to_string()),
+        reason: Set(entry.reason),
+        events: Set(events),
+        occurred_at: Set(entry.occurred_at),
+    })
+}
+
+fn to_entry(row: Model) -> Result<AuditEntry, InfrastructureError> {
+    let what = format!("audit entry {}", row.id);
+    Ok(AuditEntry {
+        id: row.id,
+        order_id: OrderId::from_uuid(row.order_id),
+        actor: row.actor.parse().map_err(corrupted(what.clone()))?,
+        command: row.command.clone(),
+        status_before: row
+            .status_before
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(corrupted(what.clone()))?,
+        status_after: row.status_after.parse().map_err(corrupted(what.clone()))?,
+        reason: row.reason.clone(),
+        events: serde_json::from_str(&row.events).map_err(corrupted(what))?,
+        occurred_at: row.occurred_at,
+    })
+}
+
+fn to_erasure(row: erasure_request::Model) -> Result<ErasureRecord, InfrastructureError> {
+    let what = format!("erasure request {}", row.id);
+    Ok(ErasureRecord {
+        id: row.id,
+        tenant_id: row.tenant_id.parse().map_err(corrupted(what.clone()))?,
+        reference: row.reference,
+        actor: row.actor.parse().map_err(corrupted(what.clone()))?,
+        orders_anonymized: u32::try_from(row.orders_anonymized).map_err(corrupted(what))?,
+        occurred_at: row.occurred_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::audit::Actor;
+    use crate::domain::{
+        events::{OrderEvent, ERASED},
+        value_objects::{Money, OrderStatus},
+    };
+    use crate::infrastructure::persistence::Migrator;
+    use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn audit_log() -> SqlAuditLog {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlAuditLog::new(db)
+    }
+
+    #[tokio::test]
+    async fn test_timeline_round_trip_in_order() {
+        let log = audit_log().await;
+        let order_id = OrderId::new();
+        let now = Utc::now();
+
+        let cancelled = OrderEvent::OrderCancelled {
+            order_id,
+            reason: "Customer request".to_string(),
+            timestamp: now,
+        };
+        let confirmed = OrderEvent::OrderConfirmed {
+            order_id,
+            timestamp: now - Duration::minutes(5),
+        };
+        log.record(
+            AuditEntry::new(
+                order_id,
+                Actor::user("support-42"),
+                "CancelOrder",
+                Some(OrderStatus::Confirmed),
+                OrderStatus::Cancelled,
+                vec![cancelled],
+            )
+            .with_reason("Customer request"),
+        )
+        .await
+        .unwrap();
+        log.record(AuditEntry::new(
+            order_id,
+            Actor::Anonymous,
+            "ConfirmOrder",
+            Some(OrderStatus::Pending),
+            OrderStatus::Confirmed,
+            vec![confirmed],
+        ))
+        .await
+        .unwrap();
+        log.record(AuditEntry::new(
+            OrderId::new(),
+            Actor::Anonymous,
+            "ConfirmOrder",
+            Some(OrderStatus::Pending),
+            OrderStatus::Confirmed,
+            Vec::new(),
+        ))
+        .await
+        .unwrap();
+
+        let timeline = log.timeline(order_id).await.unwrap();
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].command, "ConfirmOrder");
+        assert_eq!(timeline[1].actor, Actor::user("support-42"));
+        assert_eq!(timeline[1].status_before, Some(OrderStatus::Confirmed));
+        assert_eq!(timeline[1].reason.as_deref(), Some("Customer request"));
+        assert_eq!(timeline[1].events[0].event_name(), "ORDER_CANCELLED");
+    }
+
+    #[tokio::test]
+    async fn test_anonymized_entries_are_rewritten() {
+        let log = audit_log().await;
+        let (order_id, other_order_id) = (OrderId::new(), OrderId::new());
+        let (customer_id, pseudonym) = (CustomerId::new(), CustomerId::new());
+        let created = |order_id| OrderEvent::OrderCreated {
+            order_id,
+            customer_id,
+            total: Money::eur(Decimal::new(4990, 2)).unwrap(),
+            timestamp: Utc::now(),
+        };
+        for order_id in [order_id, other_order_id] {
+            log.record(
+                AuditEntry::new(
+                    order_id,
+                    Actor::user(customer_id.to_string()),
+                    "CreateOrder",
+                    None,
+                    OrderStatus::Pending,
+                    vec![created(order_id)],
+                )
+                .with_reason("Gift for Jane Doe"),
+            )
+            .await
+            .unwrap();
+        }
+
+        log.anonymize(order_id, customer_id, pseudonym)
+            .await
+            .unwrap();
+
+        let entry = &log.timeline(order_id).await.unwrap()[0];
+        assert_eq!(entry.actor, Actor::Anonymous);
+        assert_eq!(entry.reason.as_deref(), Some(ERASED));
+        assert!(matches!(
+            entry.events[0],
+            OrderEvent::OrderCreated { customer_id, .. } if customer_id == pseudonym
+        ));
+        let untouched = &log.timeline(other_order_id).await.unwrap()[0];
+        assert_eq!(untouched.reason.as_deref(), Some("Gift for Jane Doe"));
+    }
+
+    #[tokio::test]
+    async fn test_erasures_round_trip_per_tenant() {
+        let log = audit_log().await;
+        let acme: TenantId = "acme".parse().unwrap();
+        let now = Utc::now();
+        log.record_erasure(ErasureRecord::new(
+            acme.clone(),
+            "DSR-2025-021",
+            Actor::user("dpo"),
+            0,
+            now,
+        ))
+        .await
+        .unwrap();
+        log.record_erasure(ErasureRecord::new(
+            acme.clone(),
+            "DSR-2025-020",
+            Actor::user("dpo"),
+            3,
+            now - Duration::days(1),
+        ))
+        .await
+        .unwrap();
+
+        let erasures = log.erasures(&acme).await.unwrap();
+        let references: Vec<_> = erasures.iter().map(|e| e.reference.as_str()).collect();
+        assert_eq!(references, ["DSR-2025-020", "DSR-2025-021"]);
+        assert_eq!(erasures[0].orders_anonymized, 3);
+        assert_eq!(erasures[1].actor, Actor::user("dpo"));
+        assert!(log.erasures(&TenantId::default()).await.unwrap().is_empty());
+    }
+}
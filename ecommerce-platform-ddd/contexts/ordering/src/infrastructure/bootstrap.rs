@@ -0,0 +1,421 @@
+use crate::application::audit::AuditLog;
+use crate::application::commands::{
+    CancelOrderHandler, CheckoutCartHandler, ConfirmOrderHandler, CreateCartHandler,
+    CreateOrderHandler, CreateShipmentHandler, ImportOrdersHandler, IssueCreditNoteHandler,
+    IssueInvoiceHandler, MergeCartsHandler, MergeShipmentsHandler, OrderWishlistItemsHandler,
+    ReviewOrderHandler, ShareWishlistHandler, ShipShipmentHandler, UpdateCartHandler,
+    UpdateWishlistHandler,
+};
+use crate::application::queries::{
+    ExportOrdersHandler, GetCartHandler, GetInvoiceHandler, GetOrderHandler,
+    GetOrderTimelineHandler, GetSharedWishlistHandler, GetWishlistHandler,
+    ListCustomerOrdersHandler, ListOrderInvoicesHandler,
+};
+use crate::domain::{
+    catalog::{PriceCatalog, StockChecker},
+    clock::Clock,
+    pricing::PriceLists,
+    repositories::{CartRepository, InvoiceRepository, OrderRepository, WishlistRepository},
+    risk::RiskAssessor,
+    tenant::TenantDirectory,
+    IdGenerator, SystemClock, UuidV7Generator,
+};
+use crate::infrastructure::{
+    api::{AppState, RateLimiter},
+    audit::{InMemoryAuditLog, SqlAuditLog},
+    catalog::InMemoryCatalog,
+    config::{DatabaseSettings, RepositoryBackend, Settings},
+    health::{BrokerHealthCheck, DatabaseHealthCheck, ReadinessChecker},
+    idempotency::{IdempotencyStore, InMemoryIdempotencyStore, SqlIdempotencyStore},
+    invoicing::InvoiceOnPayment,
+    messaging::{
+        broadcast::DEFAULT_REPLAY_CAPACITY, EventPublisher, IggyEventPublisher,
+        InstrumentedEventPublisher, NoOpEventPublisher, OrderEventHub, SubscribingEventPublisher,
+    },
+    observability::Metrics,
+    persistence::{
+        InMemoryCartRepository, InMemoryInvoiceRepository, InMemoryOrderRepository,
+        InMemoryWishlistRepository, Migrator, SqlCartRepository, SqlInvoiceRepository,
+        SqlOrderRepository, SqlWishlistRepository,
+    },
+    pricing::InMemoryPriceLists,
+    risk::RuleBasedRiskAssessor,
+    tenancy::{TenantRegistry, TenantResolver},
+    webhooks::{InMemoryWebhookStore, SqlWebhookStore, WebhookDispatcher, WebhookStore},
+};
+use sea_orm::{ConnectOptions, Database, DatabaseConnection};
+use sea_orm_migration::MigratorTrait;
+use std::sync::Arc;
+
+/// Repositories, idempotency store, audit log and webhook store, all on the same backend
+type Storage = (
+    Arc<dyn OrderRepository>,
+    Arc<dyn CartRepository>,
+    Arc<dyn InvoiceRepository>,
+    Arc<dyn WishlistRepository>,
+    Arc<dyn IdempotencyStore>,
+    Arc<dyn AuditLog>,
+    Arc<dyn WebhookStore>,
+);
+
+/// Adapters selected by the configuration, shared by the server and `ordering-admin`
+pub struct Adapters {
+    pub order_repository: Arc<dyn OrderRepository>,
+    pub cart_repository: Arc<dyn CartRepository>,
+    pub invoice_repository: Arc<dyn InvoiceRepository>,
+    pub wishlist_repository: Arc<dyn WishlistRepository>,
+    pub price_catalog: Arc<dyn PriceCatalog>,
+    pub stock_checker: Arc<dyn StockChecker>,
+    pub price_lists: Arc<dyn PriceLists>,
+    /// Fraud screening of the orders being confirmed
+    pub risk_assessor: Arc<dyn RiskAssessor>,
+    /// Storefronts of the configuration
+    pub tenants: Arc<dyn TenantDirectory>,
+    pub idempotency_store: Arc<dyn IdempotencyStore>,
+    pub audit_log: Arc<dyn AuditLog>,
+    pub webhook_store: Arc<dyn WebhookStore>,
+    /// Fed by `event_publisher`, followed by the SSE and WebSocket clients
+    pub event_hub: Arc<OrderEventHub>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub metrics: Arc<Metrics>,
+    pub readiness: Arc<ReadinessChecker>,
+    pub clock: Arc<dyn Clock>,
+    pub ids: Arc<dyn IdGenerator>,
+}
+
+impl Adapters {
+    /// Connect the storage and broker adapters (runs migrations if configured)
+    pub async fn connect(settings: &Settings) -> anyhow::Result<Self> {
+        let idempotency_ttl =
+            chrono::Duration::seconds(settings.features.idempotency_ttl_secs as i64);
+        let mut readiness = ReadinessChecker::new(settings.server.readiness_timeout());
+        let (
+            order_repository,
+            cart_repository,
+            invoice_repository,
+            wishlist_repository,
+            idempotency_store,
+            audit_log,
+            webhook_store,
+        ): Storage = match settings.repository.backend {
+            RepositoryBackend::InMemory => (
+                Arc::new(InMemoryOrderRepository::new()),
+                Arc::new(InMemoryCartRepository::new()),
+                Arc::new(InMemoryInvoiceRepository::new()),
+                Arc::new(InMemoryWishlistRepository::new()),
+                Arc::new(InMemoryIdempotencyStore::new(idempotency_ttl)),
+                Arc::new(InMemoryAuditLog::new()),
+                Arc::new(InMemoryWebhookStore::new()),
+            ),
+            RepositoryBackend::Sql => {
+                let db = connect_database(&settings.database).await?;
+                if settings.database.run_migrations {
+                    Migrator::up(&db, None).await?;
+                }
+                readiness = readiness.with_check(Arc::new(DatabaseHealthCheck::new(db.clone())));
+                (
+                    Arc::new(SqlOrderRepository::new(db.clone())),
+                    Arc::new(SqlCartRepository::new(db.clone())),
+                    Arc::new(SqlInvoiceRepository::new(db.clone())),
+                    Arc::new(SqlWishlistRepository::new(db.clone())),
+                    Arc::new(SqlIdempotencyStore::new(db.clone(), idempotency_ttl)),
+                    Arc::new(SqlAuditLog::new(db.clone())),
+                    Arc::new(SqlWebhookStore::new(db)),
+                )
+            }
+        };
+
+        let catalog = Arc::new(InMemoryCatalog::from_settings(&settings.catalog)?);
+        let price_lists = Arc::new(InMemoryPriceLists::from_settings(&settings.pricing)?);
+        let tenants: Arc<dyn TenantDirectory> = Arc::new(TenantRegistry::from_settings(settings)?);
+
+        let metrics = Arc::new(Metrics::new());
+        let event_publisher: Arc<dyn EventPublisher> = if settings.features.publish_events {
+            readiness = readiness.with_check(Arc::new(BrokerHealthCheck::new(
+                settings.broker.address.clone(),
+            )));
+            Arc::new(IggyEventPublisher::new(settings.broker.clone()))
+        } else {
+            Arc::new(NoOpEventPublisher)
+        };
+        let event_publisher: Arc<dyn EventPublisher> = Arc::new(InstrumentedEventPublisher::new(
+            event_publisher,
+            metrics.clone(),
+        ));
+
+        // In-process reactions to the events of the context
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let ids: Arc<dyn IdGenerator> = Arc::new(UuidV7Generator);
+        let risk_assessor = Arc::new(RuleBasedRiskAssessor::new(
+            RuleBasedRiskAssessor::policy_from_settings(&settings.risk)?,
+            order_repository.clone(),
+            clock.clone(),
+        ));
+        let issue_invoice = Arc::new(IssueInvoiceHandler::new(
+            invoice_repository.clone(),
+            order_repository.clone(),
+            ids.clone(),
+            clock.clone(),
+        ));
+        let event_hub = Arc::new(OrderEventHub::new(
+            order_repository.clone(),
+            DEFAULT_REPLAY_CAPACITY,
+        ));
+        let mut subscribing = SubscribingEventPublisher::new(event_publisher)
+            .with_subscriber(Arc::new(InvoiceOnPayment::new(issue_invoice)))
+            .with_subscriber(event_hub.clone());
+        if settings.webhooks.enabled {
+            subscribing = subscribing.with_subscriber(Arc::new(WebhookDispatcher::new(
+                webhook_store.clone(),
+                &settings.webhooks,
+            )));
+        }
+        let event_publisher: Arc<dyn EventPublisher> = Arc::new(subscribing);
+
+        Ok(Self {
+            order_repository,
+            cart_repository,
+            invoice_repository,
+            wishlist_repository,
+            price_catalog: catalog.clone(),
+            stock_checker: catalog,
+            price_lists,
+            risk_assessor,
+            tenants,
+            idempotency_store,
+            audit_log,
+            webhook_store,
+            event_hub,
+            event_publisher,
+            metrics,
+            readiness: Arc::new(readiness),
+            clock,
+            ids,
+        })
+    }
+}
+
+/// Open the SQL connection pool described by the configuration
+pub async fn connect_database(settings: &DatabaseSettings) -> anyhow::Result<DatabaseConnection> {
+    let url = settings.url.clone().unwrap_or_default();
+    let mut options = ConnectOptions::new(url);
+    options.max_connections(settings.max_connections);
+    Ok(Database::connect(options).await?)
+}
+
+/// Application handlers (use cases) wired on the adapters
+pub struct Handlers {
+    pub create_order: Arc<CreateOrderHandler>,
+    pub confirm_order: Arc<ConfirmOrderHandler>,
+    pub cancel_order: Arc<CancelOrderHandler>,
+    pub review_order: Arc<ReviewOrderHandler>,
+    pub create_shipment: Arc<CreateShipmentHandler>,
+    pub ship_shipment: Arc<ShipShipmentHandler>,
+    pub merge_shipments: Arc<MergeShipmentsHandler>,
+    pub get_order: Arc<GetOrderHandler>,
+    pub list_customer_orders: Arc<ListCustomerOrdersHandler>,
+    pub get_order_timeline: Arc<GetOrderTimelineHandler>,
+    pub import_orders: Arc<ImportOrdersHandler>,
+    pub export_orders: Arc<ExportOrdersHandler>,
+    pub create_cart: Arc<CreateCartHandler>,
+    pub update_cart: Arc<UpdateCartHandler>,
+    pub merge_carts: Arc<MergeCartsHandler>,
+    pub checkout_cart: Arc<CheckoutCartHandler>,
+    pub get_cart: Arc<GetCartHandler>,
+    pub issue_credit_note: Arc<IssueCreditNoteHandler>,
+    pub get_invoice: Arc<GetInvoiceHandler>,
+    pub list_order_invoices: Arc<ListOrderInvoicesHandler>,
+    pub update_wishlist: Arc<UpdateWishlistHandler>,
+    pub share_wishlist: Arc<ShareWishlistHandler>,
+    pub order_wishlist_items: Arc<OrderWishlistItemsHandler>,
+    pub get_wishlist: Arc<GetWishlistHandler>,
+    pub get_shared_wishlist: Arc<GetSharedWishlistHandler>,
+}
+
+impl Handlers {
+    pub fn new(adapters: &Adapters) -> Self {
+        let create_order = Arc::new(CreateOrderHandler::new(
+            adapters.order_repository.clone(),
+            adapters.tenants.clone(),
+            adapters.price_lists.clone(),
+            adapters.event_publisher.clone(),
+            adapters.audit_log.clone(),
+            adapters.ids.clone(),
+            adapters.clock.clone(),
+        ));
+        Self {
+            import_orders: Arc::new(ImportOrdersHandler::new(create_order.clone())),
+            order_wishlist_items: Arc::new(OrderWishlistItemsHandler::new(
+                adapters.wishlist_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.price_catalog.clone(),
+                create_order.clone(),
+                adapters.clock.clone(),
+            )),
+            create_order,
+            confirm_order: Arc::new(ConfirmOrderHandler::new(
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.risk_assessor.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            )),
+            cancel_order: Arc::new(CancelOrderHandler::new(
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            )),
+            review_order: Arc::new(ReviewOrderHandler::new(
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            )),
+            create_shipment: Arc::new(CreateShipmentHandler::new(
+                adapters.order_repository.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.ids.clone(),
+                adapters.clock.clone(),
+            )),
+            ship_shipment: Arc::new(ShipShipmentHandler::new(
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            )),
+            merge_shipments: Arc::new(MergeShipmentsHandler::new(
+                adapters.order_repository.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.clock.clone(),
+            )),
+            get_order: Arc::new(GetOrderHandler::new(adapters.order_repository.clone())),
+            list_customer_orders: Arc::new(ListCustomerOrdersHandler::new(
+                adapters.order_repository.clone(),
+            )),
+            get_order_timeline: Arc::new(GetOrderTimelineHandler::new(
+                adapters.audit_log.clone(),
+                adapters.order_repository.clone(),
+            )),
+            export_orders: Arc::new(ExportOrdersHandler::new(adapters.order_repository.clone())),
+            create_cart: Arc::new(CreateCartHandler::new(
+                adapters.cart_repository.clone(),
+                adapters.ids.clone(),
+                adapters.clock.clone(),
+            )),
+            update_cart: Arc::new(UpdateCartHandler::new(
+                adapters.cart_repository.clone(),
+                adapters.clock.clone(),
+            )),
+            merge_carts: Arc::new(MergeCartsHandler::new(
+                adapters.cart_repository.clone(),
+                adapters.clock.clone(),
+            )),
+            checkout_cart: Arc::new(CheckoutCartHandler::new(
+                adapters.cart_repository.clone(),
+                adapters.order_repository.clone(),
+                adapters.tenants.clone(),
+                adapters.price_catalog.clone(),
+                adapters.price_lists.clone(),
+                adapters.stock_checker.clone(),
+                adapters.event_publisher.clone(),
+                adapters.audit_log.clone(),
+                adapters.ids.clone(),
+                adapters.clock.clone(),
+            )),
+            get_cart: Arc::new(GetCartHandler::new(adapters.cart_repository.clone())),
+            issue_credit_note: Arc::new(IssueCreditNoteHandler::new(
+                adapters.invoice_repository.clone(),
+                adapters.ids.clone(),
+                adapters.clock.clone(),
+            )),
+            get_invoice: Arc::new(GetInvoiceHandler::new(adapters.invoice_repository.clone())),
+            list_order_invoices: Arc::new(ListOrderInvoicesHandler::new(
+                adapters.invoice_repository.clone(),
+            )),
+            update_wishlist: Arc::new(UpdateWishlistHandler::new(
+                adapters.wishlist_repository.clone(),
+                adapters.clock.clone(),
+            )),
+            share_wishlist: Arc::new(ShareWishlistHandler::new(
+                adapters.wishlist_repository.clone(),
+                adapters.clock.clone(),
+            )),
+            get_wishlist: Arc::new(GetWishlistHandler::new(
+                adapters.wishlist_repository.clone(),
+            )),
+            get_shared_wishlist: Arc::new(GetSharedWishlistHandler::new(
+                adapters.wishlist_repository.clone(),
+            )),
+        }
+    }
+}
+
+/// State of the HTTP application, as configured for the server
+pub fn app_state(
+    settings: &Settings,
+    adapters: &Adapters,
+    handlers: &Handlers,
+    tenant_resolver: Arc<TenantResolver>,
+) -> AppState {
+    AppState {
+        create_order: handlers.create_order.clone(),
+        confirm_order: handlers.confirm_order.clone(),
+        review_order: handlers.review_order.clone(),
+        cancel_order: handlers.cancel_order.clone(),
+        create_shipment: handlers.create_shipment.clone(),
+        ship_shipment: handlers.ship_shipment.clone(),
+        merge_shipments: handlers.merge_shipments.clone(),
+        get_order: handlers.get_order.clone(),
+        list_customer_orders: handlers.list_customer_orders.clone(),
+        get_order_timeline: handlers.get_order_timeline.clone(),
+        import_orders: handlers.import_orders.clone(),
+        export_orders: handlers.export_orders.clone(),
+        create_cart: handlers.create_cart.clone(),
+        update_cart: handlers.update_cart.clone(),
+        merge_carts: handlers.merge_carts.clone(),
+        checkout_cart: handlers.checkout_cart.clone(),
+        get_cart: handlers.get_cart.clone(),
+        issue_credit_note: handlers.issue_credit_note.clone(),
+        get_invoice: handlers.get_invoice.clone(),
+        list_order_invoices: handlers.list_order_invoices.clone(),
+        update_wishlist: handlers.update_wishlist.clone(),
+        share_wishlist: handlers.share_wishlist.clone(),
+        order_wishlist_items: handlers.order_wishlist_items.clone(),
+        get_wishlist: handlers.get_wishlist.clone(),
+        get_shared_wishlist: handlers.get_shared_wishlist.clone(),
+        webhook_store: adapters.webhook_store.clone(),
+        tenant_resolver,
+        event_hub: adapters.event_hub.clone(),
+        idempotency_store: settings
+            .features
+            .idempotency
+            .then(|| adapters.idempotency_store.clone()),
+        rate_limiter: settings.rate_limit.enabled.then(|| {
+            Arc::new(
+                RateLimiter::new(
+                    settings.rate_limit.requests_per_second,
+                    settings.rate_limit.burst,
+                )
+                .with_api_keys(settings.rate_limit.api_keys.clone()),
+            )
+        }),
+        max_body_bytes: settings.server.max_body_bytes,
+        metrics: adapters.metrics.clone(),
+        readiness: adapters.readiness.clone(),
+    }
+}
+
+/// Tenant of each request, shared by the HTTP and gRPC interfaces
+pub fn tenant_resolver(settings: &Settings, adapters: &Adapters) -> Arc<TenantResolver> {
+    Arc::new(TenantResolver::new(
+        adapters.tenants.clone(),
+        settings.tenancy.clone(),
+    ))
+}
@@ -0,0 +1,173 @@
+use crate::application::commands::ImportRow;
+use crate::application::dto::{CreateOrderItemRequest, CreateOrderRequest, OrderResponse};
+use crate::domain::value_objects::{
+    Currency, CustomerId, OrderId, OrderItemId, OrderStatus, ProductId,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Import row: one line per item, consecutive lines sharing `order_ref` form an order.
+/// `order_id` is accepted as the reference so that an export can be imported back.
+#[derive(Debug, Deserialize)]
+struct ImportLine {
+    customer_id: CustomerId,
+    product_id: ProductId,
+    product_name: String,
+    quantity: u32,
+    #[serde(deserialize_with = "decimal")]
+    unit_price: Decimal,
+}
+
+/// Export row: one line per item, order columns repeated
+#[derive(Debug, Serialize)]
+struct ExportLine<'a> {
+    order_id: OrderId,
+    customer_id: CustomerId,
+    status: OrderStatus,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    item_id: OrderItemId,
+    product_id: ProductId,
+    product_name: &'a str,
+    quantity: u32,
+    unit_price: Decimal,
+    currency: Currency,
+}
+
+const EXPORT_HEADER: &str = "order_id,customer_id,status,created_at,updated_at,item_id,\
+product_id,product_name,quantity,unit_price,currency\n";
+
+/// Order being assembled from consecutive lines
+struct Pending {
+    order_ref: String,
+    row: ImportRow,
+}
+
+pub(super) fn read_orders(input: &str) -> Vec<ImportRow> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .trim(::csv::Trim::All)
+        .from_reader(input.as_bytes());
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(err) => return vec![file_error(err.to_string())],
+    };
+    let Some(ref_column) = headers
+        .iter()
+        .position(|name| name == "order_ref" || name == "order_id")
+    else {
+        return vec![file_error("missing `order_ref` column".to_string())];
+    };
+
+    let mut rows = Vec::new();
+    let mut current: Option<Pending> = None;
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(err) => {
+                let line = err.position().map_or(0, |p| p.line() as usize);
+                rows.extend(current.take().map(|pending| pending.row));
+                rows.push(ImportRow {
+                    line,
+                    order: Err(err.to_string()),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |p| p.line() as usize);
+        let order_ref = record.get(ref_column).unwrap_or_default().to_string();
+        let parsed = record
+            .deserialize::<ImportLine>(Some(&headers))
+            .map_err(|err| format!("line {line}: {err}"));
+
+        match current.as_mut() {
+            Some(pending) if pending.order_ref == order_ref => add_line(&mut pending.row, parsed),
+            _ => {
+                rows.extend(current.take().map(|pending| pending.row));
+                current = Some(Pending {
+                    order_ref,
+                    row: ImportRow {
+                        line,
+                        order: parsed.map(|line| CreateOrderRequest {
+                            customer_id: line.customer_id,
+                            items: vec![item(line)],
+                            billing_address: None,
+                            shipping_address: None,
+                        }),
+                    },
+                });
+            }
+        }
+    }
+    rows.extend(current.map(|pending| pending.row));
+    rows
+}
+
+/// Append an item line to the order it belongs to; any bad line rejects the whole order
+fn add_line(row: &mut ImportRow, parsed: Result<ImportLine, String>) {
+    let Ok(order) = &mut row.order else {
+        return;
+    };
+    row.order = match parsed {
+        Ok(line) if line.customer_id != order.customer_id => {
+            Err("lines of the same order have different customer_id".to_string())
+        }
+        Ok(line) => {
+            order.items.push(item(line));
+            return;
+        }
+        Err(err) => Err(err),
+    };
+}
+
+fn item(line: ImportLine) -> CreateOrderItemRequest {
+    CreateOrderItemRequest {
+        product_id: line.product_id,
+        product_name: line.product_name,
+        quantity: line.quantity,
+        unit_price: line.unit_price,
+    }
+}
+
+/// Parse prices as written ("10.00"), never through a float
+fn decimal<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+fn file_error(error: String) -> ImportRow {
+    ImportRow {
+        line: 1,
+        order: Err(error),
+    }
+}
+
+pub(super) fn header() -> Vec<u8> {
+    EXPORT_HEADER.as_bytes().to_vec()
+}
+
+pub(super) fn write_orders(orders: &[OrderResponse]) -> Result<Vec<u8>, ::csv::Error> {
+    let mut writer = ::csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    for order in orders {
+        for item in &order.items {
+            writer.serialize(ExportLine {
+                order_id: order.id,
+                customer_id: order.customer_id,
+                status: order.status,
+                created_at: order.created_at,
+                updated_at: order.updated_at,
+                item_id: item.id,
+                product_id: item.product_id,
+                product_name: &item.product_name,
+                quantity: item.quantity,
+                unit_price: item.unit_price.amount(),
+                currency: item.unit_price.currency(),
+            })?;
+        }
+    }
+    writer
+        .into_inner()
+        .map_err(|err| ::csv::Error::from(err.into_error()))
+}
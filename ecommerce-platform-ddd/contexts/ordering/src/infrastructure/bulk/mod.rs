@@ -0,0 +1,193 @@
+// Bulk import/export formats (CSV and JSON Lines)
+mod csv;
+
+use crate::application::commands::ImportRow;
+use crate::application::dto::{CreateOrderRequest, OrderResponse};
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// File format of an import or export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkFormat {
+    /// One line per item; lines sharing `order_ref` form one order
+    Csv,
+    /// One JSON order per line (`CreateOrderRequest` on import, `OrderResponse` on export)
+    #[default]
+    Ndjson,
+}
+
+impl BulkFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            BulkFormat::Csv => "text/csv",
+            BulkFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BulkFormat::Csv => "csv",
+            BulkFormat::Ndjson => "ndjson",
+        }
+    }
+
+    /// Parse an import file into orders, keeping per-row errors
+    pub fn read_orders(&self, input: &str) -> Vec<ImportRow> {
+        match self {
+            BulkFormat::Csv => csv::read_orders(input),
+            BulkFormat::Ndjson => input
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(index, line)| ImportRow {
+                    line: index + 1,
+                    order: serde_json::from_str::<CreateOrderRequest>(line)
+                        .map_err(|err| err.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Bytes written once at the start of an export
+    pub fn export_header(&self) -> Vec<u8> {
+        match self {
+            BulkFormat::Csv => csv::header(),
+            BulkFormat::Ndjson => Vec::new(),
+        }
+    }
+
+    /// Encode one batch of exported orders
+    pub fn write_orders(&self, orders: &[OrderResponse]) -> Result<Vec<u8>, std::io::Error> {
+        match self {
+            BulkFormat::Csv => csv::write_orders(orders).map_err(std::io::Error::other),
+            BulkFormat::Ndjson => {
+                let mut out = Vec::new();
+                for order in orders {
+                    serde_json::to_writer(&mut out, order)?;
+                    out.push(b'\n');
+                }
+                Ok(out)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for BulkFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "csv" => Ok(BulkFormat::Csv),
+            "ndjson" | "jsonl" => Ok(BulkFormat::Ndjson),
+            other => Err(format!("unknown format `{other}` (expected csv or ndjson)")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{CustomerId, Money, ProductId},
+    };
+    use rust_decimal::Decimal;
+
+    const CUSTOMER: &str = "7d3f6a51-1a8e-4c1b-9a53-0c2f4f1e9b10";
+    const PRODUCT: &str = "0b6c2e1a-5f3d-4e7b-8a9c-1d2e3f4a5b6c";
+
+    fn order() -> OrderResponse {
+        let item = |name: &str, cents| {
+            OrderItem::new(
+                ProductId::new(),
+                name.to_string(),
+                2,
+                Money::eur(Decimal::new(cents, 2)).unwrap(),
+                &UuidV4Generator,
+            )
+            .unwrap()
+        };
+        let order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item("Keyboard, US layout", 4990), item("Mouse", 1999)],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        OrderResponse::from(&order)
+    }
+
+    #[test]
+    fn test_csv_groups_consecutive_lines_into_orders() {
+        let input = format!(
+            "order_ref,customer_id,product_id,product_name,quantity,unit_price\n\
+             A-1,{CUSTOMER},{PRODUCT},Keyboard,1,49.90\n\
+             A-1,{CUSTOMER},{PRODUCT},Mouse,2,19.99\n\
+             A-2,{CUSTOMER},{PRODUCT},Screen,x,199.00\n\
+             A-3,{CUSTOMER},{PRODUCT},Cable,1,9.90\n"
+        );
+
+        let rows = BulkFormat::Csv.read_orders(&input);
+
+        assert_eq!(rows.len(), 3);
+        let first = rows[0].order.as_ref().unwrap();
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.items[1].unit_price, Decimal::new(1999, 2));
+        assert_eq!(rows[1].line, 4);
+        assert!(rows[1].order.is_err());
+        assert!(rows[2].order.is_ok());
+    }
+
+    #[test]
+    fn test_csv_export_can_be_imported_back() {
+        let exported = order();
+        let mut file = BulkFormat::Csv.export_header();
+        file.extend(
+            BulkFormat::Csv
+                .write_orders(std::slice::from_ref(&exported))
+                .unwrap(),
+        );
+
+        let rows = BulkFormat::Csv.read_orders(std::str::from_utf8(&file).unwrap());
+
+        assert_eq!(rows.len(), 1);
+        let imported = rows[0].order.as_ref().unwrap();
+        assert_eq!(imported.customer_id, exported.customer_id);
+        assert_eq!(imported.items.len(), 2);
+        assert_eq!(imported.items[0].product_name, "Keyboard, US layout");
+        assert_eq!(imported.items[0].unit_price, Decimal::new(4990, 2));
+    }
+
+    #[test]
+    fn test_ndjson_reports_line_numbers() {
+        let valid = format!(
+            r#"{{"customer_id":"{CUSTOMER}","items":[{{"product_id":"{PRODUCT}","product_name":"Mouse","quantity":1,"unit_price":"19.99"}}]}}"#
+        );
+        let input = format!("{valid}\n\n{{\"customer_id\":\"nope\"}}\n");
+
+        let rows = BulkFormat::Ndjson.read_orders(&input);
+
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].order.is_ok());
+        assert_eq!(rows[1].line, 3);
+        assert!(rows[1].order.is_err());
+    }
+
+    #[test]
+    fn test_ndjson_export_writes_one_order_per_line() {
+        let bytes = BulkFormat::Ndjson
+            .write_orders(&[order(), order()])
+            .unwrap();
+        let lines: Vec<_> = std::str::from_utf8(&bytes).unwrap().lines().collect();
+        assert_eq!(lines.len(), 2);
+        let parsed: OrderResponse = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(parsed.items.len(), 2);
+    }
+}
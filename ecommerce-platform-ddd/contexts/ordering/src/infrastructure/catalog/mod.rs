@@ -0,0 +1,72 @@
+use crate::domain::{
+    catalog::{PriceCatalog, StockChecker},
+    errors::{DomainError, InfrastructureError},
+    value_objects::{Money, ProductId},
+};
+use crate::infrastructure::config::CatalogSettings;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Price and stock of a product, as known by the catalog
+#[derive(Debug, Clone, Copy)]
+struct CatalogEntry {
+    price: Money,
+    stock: u32,
+}
+
+/// Catalog held in memory, seeded from the configuration
+///
+/// Stands in for the catalog and inventory contexts until they expose an API:
+/// a product it does not list is not sold.
+#[derive(Debug, Default)]
+pub struct InMemoryCatalog {
+    products: RwLock<HashMap<ProductId, CatalogEntry>>,
+}
+
+impl InMemoryCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Catalog listing the `[[catalog.products]]` of the configuration (prices in EUR)
+    pub fn from_settings(settings: &CatalogSettings) -> Result<Self, DomainError> {
+        let catalog = Self::new();
+        for product in &settings.products {
+            catalog.set_product(
+                ProductId::from_uuid(product.id),
+                Money::eur(product.price)?,
+                product.stock,
+            );
+        }
+        Ok(catalog)
+    }
+
+    /// List a product, or change its price and stock
+    pub fn set_product(&self, product_id: ProductId, price: Money, stock: u32) {
+        let mut products = self.products.write().unwrap_or_else(|err| err.into_inner());
+        products.insert(product_id, CatalogEntry { price, stock });
+    }
+
+    fn entry(&self, product_id: ProductId) -> Option<CatalogEntry> {
+        let products = self.products.read().unwrap_or_else(|err| err.into_inner());
+        products.get(&product_id).copied()
+    }
+}
+
+#[async_trait]
+impl PriceCatalog for InMemoryCatalog {
+    async fn current_price(
+        &self,
+        product_id: ProductId,
+    ) -> Result<Option<Money>, InfrastructureError> {
+        Ok(self.entry(product_id).map(|entry| entry.price))
+    }
+}
+
+#[async_trait]
+impl StockChecker for InMemoryCatalog {
+    async fn available_quantity(&self, product_id: ProductId) -> Result<u32, InfrastructureError> {
+        Ok(self.entry(product_id).map_or(0, |entry| entry.stock))
+    }
+}
@@ -0,0 +1,1163 @@
+use crate::domain::value_objects::{Currency, OrderStatus, TenantId};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Environment variable pointing to the TOML configuration file
+pub const CONFIG_FILE_ENV: &str = "ORDERING_CONFIG";
+/// Configuration file loaded when it exists and `ORDERING_CONFIG` is not set
+pub const DEFAULT_CONFIG_FILE: &str = "config/ordering.toml";
+
+/// Typed configuration of the ordering service
+///
+/// Sources, from lowest to highest priority:
+/// defaults < TOML file < `.env` < environment variables
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub server: ServerSettings,
+    pub log: LogSettings,
+    pub database: DatabaseSettings,
+    pub broker: BrokerSettings,
+    pub repository: RepositorySettings,
+    pub features: FeatureToggles,
+    pub jobs: JobSettings,
+    pub rate_limit: RateLimitSettings,
+    pub webhooks: WebhookSettings,
+    pub catalog: CatalogSettings,
+    pub pricing: PricingSettings,
+    pub risk: RiskSettings,
+    pub tenancy: TenancySettings,
+    /// `[tenants.<id>]` storefronts served by the deployment
+    pub tenants: BTreeMap<String, TenantSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSettings {
+    pub host: IpAddr,
+    pub port: u16,
+    /// Port of the gRPC server (same host)
+    pub grpc_port: u16,
+    /// Time allowed to drain requests and flush events on SIGTERM
+    pub shutdown_timeout_secs: u64,
+    /// Time allowed to each readiness check
+    pub readiness_timeout_ms: u64,
+    /// Largest JSON request body, in bytes (bulk import has its own limit)
+    pub max_body_bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogSettings {
+    /// `tracing` filter directives (same syntax as `RUST_LOG`)
+    pub filter: String,
+    pub format: LogFormat,
+}
+
+/// Log output: human-readable lines or one JSON object per line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseSettings {
+    pub url: Option<String>,
+    pub max_connections: u32,
+    pub run_migrations: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BrokerSettings {
+    pub address: String,
+    pub stream: String,
+    pub topic: String,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RepositorySettings {
+    pub backend: RepositoryBackend,
+}
+
+/// Storage used for orders and idempotency keys
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryBackend {
+    #[default]
+    InMemory,
+    Sql,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeatureToggles {
+    /// Honour the `Idempotency-Key` header on order creation
+    pub idempotency: bool,
+    pub idempotency_ttl_secs: u64,
+    /// Publish domain events to the broker (no-op publisher otherwise)
+    pub publish_events: bool,
+    /// Serve the gRPC interface next to HTTP
+    pub grpc: bool,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            host: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: 3000,
+            grpc_port: 50051,
+            shutdown_timeout_secs: 30,
+            readiness_timeout_ms: 2000,
+            max_body_bytes: 64 * 1024,
+        }
+    }
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            filter: "ordering_context=debug,tower_http=debug".to_string(),
+            format: LogFormat::default(),
+        }
+    }
+}
+
+impl Default for DatabaseSettings {
+    fn default() -> Self {
+        Self {
+            url: None,
+            max_connections: 10,
+            run_migrations: true,
+        }
+    }
+}
+
+impl Default for BrokerSettings {
+    fn default() -> Self {
+        Self {
+            address: "127.0.0.1:8090".to_string(),
+            stream: "ecommerce".to_string(),
+            topic: "orders".to_string(),
+            username: "iggy".to_string(),
+            password: "iggy".to_string(),
+        }
+    }
+}
+
+/// Scheduled jobs run by every instance of the service
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JobSettings {
+    pub enabled: bool,
+    /// Period between two runs of each job
+    pub interval_secs: u64,
+    /// Pending orders older than this are cancelled
+    pub pending_ttl_secs: u64,
+    /// Shipped orders are marked delivered after this carrier delay
+    pub delivery_delay_secs: u64,
+}
+
+/// Per-client token bucket on the `/api` routes (client = API key, else peer IP)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Sustained rate, in requests per second
+    pub requests_per_second: u32,
+    /// Requests a client may send at once after being idle
+    pub burst: u32,
+    /// API keys issued to clients, each rate limited on its own; other `X-Api-Key`
+    /// values count against the peer IP
+    pub api_keys: Vec<String>,
+}
+
+/// Delivery of order events to the webhook subscriptions
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebhookSettings {
+    pub enabled: bool,
+    /// Calls made per event and subscription, first try included
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled after each failure
+    pub initial_backoff_ms: u64,
+    /// Upper bound of the delay between two retries
+    pub max_backoff_secs: u64,
+    /// Time allowed to the partner endpoint to answer
+    pub timeout_ms: u64,
+}
+
+/// Reference prices and stock checked at cart checkout
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CatalogSettings {
+    pub products: Vec<CatalogProduct>,
+}
+
+/// One `[[catalog.products]]` entry
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogProduct {
+    pub id: Uuid,
+    /// Current price, in EUR
+    pub price: Decimal,
+    /// Units available for sale
+    pub stock: u32,
+}
+
+/// Price rules applied to order lines, and the group of each customer
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PricingSettings {
+    /// Customer id -> group, e.g. `"<uuid>" = "wholesale"`
+    pub customer_groups: BTreeMap<Uuid, String>,
+    pub rules: Vec<PriceRuleSettings>,
+}
+
+/// One `[[pricing.rules]]` entry
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriceRuleSettings {
+    /// Recorded on the order lines priced by the rule
+    pub name: String,
+    pub product_id: Uuid,
+    /// Unit price
+    pub price: Decimal,
+    /// EUR when absent; lines in another currency ignore the rule
+    #[serde(default)]
+    pub currency: Option<Currency>,
+    /// Customers of this group only; every customer when absent
+    #[serde(default)]
+    pub customer_group: Option<String>,
+    /// First quantity of the tier
+    #[serde(default = "default_min_quantity")]
+    pub min_quantity: u32,
+    /// Sale period (start included, end excluded)
+    #[serde(default)]
+    pub valid_from: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+fn default_min_quantity() -> u32 {
+    1
+}
+
+/// Fraud screening of the orders being confirmed
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RiskSettings {
+    /// Score from which an order is held for review instead of confirmed
+    pub review_score: u32,
+    /// Without rules every order is confirmed
+    pub rules: Vec<RiskRuleSettings>,
+}
+
+/// One `[[risk.rules]]` entry
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RiskRuleSettings {
+    /// Recorded in the event of the orders held because of the rule
+    pub name: String,
+    pub factor: RiskFactorKind,
+    /// Threshold of `order_total` (amount), `item_count` (units) and `velocity` (orders)
+    #[serde(default)]
+    pub above: Option<Decimal>,
+    /// Period of `velocity`
+    #[serde(default)]
+    pub window_secs: Option<u64>,
+    /// Points added to the score of the orders matching the rule
+    pub score: u32,
+}
+
+/// What a `[[risk.rules]]` entry looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskFactorKind {
+    OrderTotal,
+    ItemCount,
+    Velocity,
+    AddressMismatch,
+}
+
+/// How the tenant of a request is resolved
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TenancySettings {
+    /// Tenant of the requests naming none (single-storefront deployments)
+    pub default_tenant: TenantId,
+    /// Claim of the `Authorization: Bearer` JWT holding the tenant id
+    pub jwt_claim: String,
+    /// HS256 secret checking the JWT signature; without it bearer tokens are refused and
+    /// `X-Tenant-Id` names the tenant (set by a trusted gateway)
+    pub jwt_secret: Option<String>,
+}
+
+/// One `[tenants.<id>]` entry; a tenant without entry sells in EUR, untaxed
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TenantSettings {
+    pub currency: Currency,
+    /// e.g. `"0.20"` for 20% VAT
+    pub tax_rate: Decimal,
+    /// Item prices include the tax (B2C) or not (B2B)
+    pub prices_include_tax: bool,
+    /// Status changes enabled, e.g. `"PENDING->CONFIRMED"`; every standard one if absent
+    /// (those of the review of held orders always are)
+    pub transitions: Option<Vec<String>>,
+}
+
+impl Default for FeatureToggles {
+    fn default() -> Self {
+        Self {
+            idempotency: true,
+            idempotency_ttl_secs: 24 * 60 * 60,
+            publish_events: true,
+            grpc: true,
+        }
+    }
+}
+
+impl Default for JobSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 60,
+            pending_ttl_secs: 24 * 60 * 60,
+            delivery_delay_secs: 3 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            requests_per_second: 10,
+            burst: 20,
+            api_keys: Vec::new(),
+        }
+    }
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: 6,
+            initial_backoff_ms: 1000,
+            max_backoff_secs: 5 * 60,
+            timeout_ms: 5000,
+        }
+    }
+}
+
+impl Default for TenancySettings {
+    fn default() -> Self {
+        Self {
+            default_tenant: TenantId::default(),
+            jwt_claim: "tenant_id".to_string(),
+            jwt_secret: None,
+        }
+    }
+}
+
+impl Default for RiskSettings {
+    fn default() -> Self {
+        Self {
+            review_score: 100,
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl Default for TenantSettings {
+    fn default() -> Self {
+        Self {
+            currency: Currency::EUR,
+            tax_rate: Decimal::ZERO,
+            prices_include_tax: true,
+            transitions: None,
+        }
+    }
+}
+
+impl TenantSettings {
+    /// `"PENDING->CONFIRMED"` entries as status pairs
+    pub fn parse_transitions(&self) -> Result<Option<Vec<(OrderStatus, OrderStatus)>>, String> {
+        let Some(transitions) = &self.transitions else {
+            return Ok(None);
+        };
+        transitions
+            .iter()
+            .map(|transition| {
+                let (from, to) = transition
+                    .split_once("->")
+                    .ok_or_else(|| format!("'{}' is not FROM->TO", transition))?;
+                let from = from.trim().parse().map_err(|err| format!("{}", err))?;
+                let to = to.trim().parse().map_err(|err| format!("{}", err))?;
+                Ok((from, to))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map(Some)
+    }
+}
+
+impl FromStr for RepositoryBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "in_memory" | "memory" => Ok(RepositoryBackend::InMemory),
+            "sql" => Ok(RepositoryBackend::Sql),
+            other => Err(format!(
+                "unknown backend '{}' (expected in_memory or sql)",
+                other
+            )),
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" | "text" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "unknown log format '{}' (expected pretty or json)",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Cannot read config file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid config file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid value for {key}: {message}")]
+    InvalidEnv { key: &'static str, message: String },
+
+    #[error("Invalid configuration: {}", .0.join("; "))]
+    Invalid(Vec<String>),
+}
+
+impl ServerSettings {
+    pub fn addr(&self) -> SocketAddr {
+        SocketAddr::new(self.host, self.port)
+    }
+
+    pub fn grpc_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.host, self.grpc_port)
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+
+    pub fn readiness_timeout(&self) -> Duration {
+        Duration::from_millis(self.readiness_timeout_ms)
+    }
+}
+
+impl JobSettings {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+
+    pub fn pending_ttl(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.pending_ttl_secs as i64)
+    }
+
+    pub fn delivery_delay(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.delivery_delay_secs as i64)
+    }
+}
+
+impl WebhookSettings {
+    pub fn initial_backoff(&self) -> Duration {
+        Duration::from_millis(self.initial_backoff_ms)
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        Duration::from_secs(self.max_backoff_secs)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+impl Settings {
+    /// Load `.env`, the TOML file and the process environment, then validate
+    pub fn load() -> Result<Self, ConfigError> {
+        // `.env` never overrides variables already set in the environment
+        dotenvy::dotenv().ok();
+
+        let path = std::env::var(CONFIG_FILE_ENV).ok();
+        let mut settings = match &path {
+            Some(path) => Self::from_file(Path::new(path))?,
+            None if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                Self::from_file(Path::new(DEFAULT_CONFIG_FILE))?
+            }
+            None => Self::default(),
+        };
+
+        settings.apply_env(|key| std::env::var(key).ok())?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Defaults overridden by a TOML file (missing keys keep their default)
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml(&content).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Override settings with environment variables
+    ///
+    /// `ORDERING_<SECTION>_<KEY>` for every key, plus the conventional
+    /// `DATABASE_URL`, `IGGY_SERVER_ADDR` and `RUST_LOG`.
+    pub fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        override_with(&env, "ORDERING_SERVER_HOST", &mut self.server.host)?;
+        override_with(&env, "ORDERING_SERVER_PORT", &mut self.server.port)?;
+        override_with(
+            &env,
+            "ORDERING_SERVER_GRPC_PORT",
+            &mut self.server.grpc_port,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_SERVER_SHUTDOWN_TIMEOUT_SECS",
+            &mut self.server.shutdown_timeout_secs,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_SERVER_READINESS_TIMEOUT_MS",
+            &mut self.server.readiness_timeout_ms,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_SERVER_MAX_BODY_BYTES",
+            &mut self.server.max_body_bytes,
+        )?;
+
+        override_with(&env, "RUST_LOG", &mut self.log.filter)?;
+        override_with(&env, "ORDERING_LOG_FILTER", &mut self.log.filter)?;
+        override_with(&env, "ORDERING_LOG_FORMAT", &mut self.log.format)?;
+
+        for key in ["DATABASE_URL", "ORDERING_DATABASE_URL"] {
+            if let Some(url) = env(key) {
+                self.database.url = Some(url);
+            }
+        }
+        override_with(
+            &env,
+            "ORDERING_DATABASE_MAX_CONNECTIONS",
+            &mut self.database.max_connections,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_DATABASE_RUN_MIGRATIONS",
+            &mut self.database.run_migrations,
+        )?;
+
+        override_with(&env, "IGGY_SERVER_ADDR", &mut self.broker.address)?;
+        override_with(&env, "ORDERING_BROKER_ADDRESS", &mut self.broker.address)?;
+        override_with(&env, "ORDERING_BROKER_STREAM", &mut self.broker.stream)?;
+        override_with(&env, "ORDERING_BROKER_TOPIC", &mut self.broker.topic)?;
+        override_with(&env, "ORDERING_BROKER_USERNAME", &mut self.broker.username)?;
+        override_with(&env, "ORDERING_BROKER_PASSWORD", &mut self.broker.password)?;
+
+        override_with(
+            &env,
+            "ORDERING_REPOSITORY_BACKEND",
+            &mut self.repository.backend,
+        )?;
+
+        override_with(
+            &env,
+            "ORDERING_FEATURES_IDEMPOTENCY",
+            &mut self.features.idempotency,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_FEATURES_IDEMPOTENCY_TTL_SECS",
+            &mut self.features.idempotency_ttl_secs,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_FEATURES_PUBLISH_EVENTS",
+            &mut self.features.publish_events,
+        )?;
+        override_with(&env, "ORDERING_FEATURES_GRPC", &mut self.features.grpc)?;
+
+        override_with(&env, "ORDERING_JOBS_ENABLED", &mut self.jobs.enabled)?;
+        override_with(
+            &env,
+            "ORDERING_JOBS_INTERVAL_SECS",
+            &mut self.jobs.interval_secs,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_JOBS_PENDING_TTL_SECS",
+            &mut self.jobs.pending_ttl_secs,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_JOBS_DELIVERY_DELAY_SECS",
+            &mut self.jobs.delivery_delay_secs,
+        )?;
+
+        override_with(
+            &env,
+            "ORDERING_RATE_LIMIT_ENABLED",
+            &mut self.rate_limit.enabled,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_RATE_LIMIT_REQUESTS_PER_SECOND",
+            &mut self.rate_limit.requests_per_second,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_RATE_LIMIT_BURST",
+            &mut self.rate_limit.burst,
+        )?;
+        if let Some(keys) = env("ORDERING_RATE_LIMIT_API_KEYS") {
+            self.rate_limit.api_keys = keys.split(',').map(str::to_string).collect();
+        }
+
+        override_with(
+            &env,
+            "ORDERING_WEBHOOKS_ENABLED",
+            &mut self.webhooks.enabled,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_WEBHOOKS_MAX_ATTEMPTS",
+            &mut self.webhooks.max_attempts,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_WEBHOOKS_INITIAL_BACKOFF_MS",
+            &mut self.webhooks.initial_backoff_ms,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_WEBHOOKS_MAX_BACKOFF_SECS",
+            &mut self.webhooks.max_backoff_secs,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_WEBHOOKS_TIMEOUT_MS",
+            &mut self.webhooks.timeout_ms,
+        )?;
+
+        override_with(
+            &env,
+            "ORDERING_TENANCY_DEFAULT_TENANT",
+            &mut self.tenancy.default_tenant,
+        )?;
+        override_with(
+            &env,
+            "ORDERING_TENANCY_JWT_CLAIM",
+            &mut self.tenancy.jwt_claim,
+        )?;
+        if let Some(secret) = env("ORDERING_TENANCY_JWT_SECRET") {
+            self.tenancy.jwt_secret = Some(secret);
+        }
+
+        Ok(())
+    }
+
+    /// Check cross-field rules, reporting every problem at once
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        if self.server.port == 0 {
+            problems.push("server.port must not be 0".to_string());
+        }
+        if self.features.grpc
+            && (self.server.grpc_port == 0 || self.server.grpc_port == self.server.port)
+        {
+            problems.push("server.grpc_port must not be 0 nor equal to server.port".to_string());
+        }
+        if self.server.readiness_timeout_ms == 0 {
+            problems.push("server.readiness_timeout_ms must be greater than 0".to_string());
+        }
+        if self.server.max_body_bytes == 0 {
+            problems.push("server.max_body_bytes must be greater than 0".to_string());
+        }
+
+        if let Err(err) = tracing_subscriber::EnvFilter::try_new(&self.log.filter) {
+            problems.push(format!("log.filter is invalid: {}", err));
+        }
+
+        match (&self.repository.backend, &self.database.url) {
+            (RepositoryBackend::Sql, None) => problems
+                .push("database.url (or DATABASE_URL) is required for the sql backend".to_string()),
+            (_, Some(url)) if !(url.starts_with("postgres://") || url.starts_with("sqlite:")) => {
+                problems.push("database.url must start with postgres:// or sqlite:".to_string())
+            }
+            _ => {}
+        }
+        if self.database.max_connections == 0 {
+            problems.push("database.max_connections must be greater than 0".to_string());
+        }
+
+        if self.features.publish_events {
+            if self.broker.address.trim().is_empty() {
+                problems.push("broker.address is required when publishing events".to_string());
+            }
+            if self.broker.stream.trim().is_empty() || self.broker.topic.trim().is_empty() {
+                problems.push("broker.stream and broker.topic must not be empty".to_string());
+            }
+        }
+
+        if self.features.idempotency && self.features.idempotency_ttl_secs == 0 {
+            problems.push("features.idempotency_ttl_secs must be greater than 0".to_string());
+        }
+
+        if self.jobs.enabled {
+            if self.jobs.interval_secs == 0 {
+                problems.push("jobs.interval_secs must be greater than 0".to_string());
+            }
+            if self.jobs.pending_ttl_secs == 0 || self.jobs.delivery_delay_secs == 0 {
+                problems.push(
+                    "jobs.pending_ttl_secs and jobs.delivery_delay_secs must be greater than 0"
+                        .to_string(),
+                );
+            }
+        }
+
+        if self.rate_limit.enabled
+            && (self.rate_limit.requests_per_second == 0 || self.rate_limit.burst == 0)
+        {
+            problems.push(
+                "rate_limit.requests_per_second and rate_limit.burst must be greater than 0"
+                    .to_string(),
+            );
+        }
+
+        if self.webhooks.enabled {
+            if self.webhooks.max_attempts == 0 || self.webhooks.timeout_ms == 0 {
+                problems.push(
+                    "webhooks.max_attempts and webhooks.timeout_ms must be greater than 0"
+                        .to_string(),
+                );
+            }
+            if self.webhooks.initial_backoff() > self.webhooks.max_backoff() {
+                problems.push(
+                    "webhooks.initial_backoff_ms must not exceed webhooks.max_backoff_secs"
+                        .to_string(),
+                );
+            }
+        }
+
+        let mut listed = std::collections::HashSet::new();
+        for product in &self.catalog.products {
+            if product.price.is_sign_negative() {
+                problems.push(format!(
+                    "catalog product {} has a negative price",
+                    product.id
+                ));
+            }
+            if !listed.insert(product.id) {
+                problems.push(format!("catalog product {} is listed twice", product.id));
+            }
+        }
+
+        let mut names = std::collections::HashSet::new();
+        for rule in &self.pricing.rules {
+            if rule.name.trim().is_empty() {
+                problems.push("pricing.rules names must not be empty".to_string());
+            } else if !names.insert(rule.name.as_str()) {
+                problems.push(format!("price rule {} is defined twice", rule.name));
+            }
+            if rule.price.is_sign_negative() {
+                problems.push(format!("price rule {} has a negative price", rule.name));
+            }
+            if rule.min_quantity == 0 {
+                problems.push(format!(
+                    "price rule {} must have a min_quantity greater than 0",
+                    rule.name
+                ));
+            }
+            if let (Some(from), Some(until)) = (rule.valid_from, rule.valid_until) {
+                if from >= until {
+                    problems.push(format!(
+                        "price rule {} must have valid_from before valid_until",
+                        rule.name
+                    ));
+                }
+            }
+        }
+
+        if self.risk.review_score == 0 {
+            problems.push("risk.review_score must be greater than 0".to_string());
+        }
+        let mut names = std::collections::HashSet::new();
+        for rule in &self.risk.rules {
+            if rule.name.trim().is_empty() {
+                problems.push("risk.rules names must not be empty".to_string());
+            } else if !names.insert(rule.name.as_str()) {
+                problems.push(format!("risk rule {} is defined twice", rule.name));
+            }
+            if rule.score == 0 {
+                problems.push(format!(
+                    "risk rule {} must have a score greater than 0",
+                    rule.name
+                ));
+            }
+            match (rule.factor, rule.above) {
+                (RiskFactorKind::AddressMismatch, _) => {}
+                (_, None) => {
+                    problems.push(format!("risk rule {} requires above", rule.name));
+                }
+                (RiskFactorKind::OrderTotal, Some(above)) if above.is_sign_negative() => {
+                    problems.push(format!("risk rule {} has a negative above", rule.name));
+                }
+                (RiskFactorKind::ItemCount | RiskFactorKind::Velocity, Some(above))
+                    if above.is_sign_negative() || !above.fract().is_zero() =>
+                {
+                    problems.push(format!(
+                        "risk rule {} must have a whole number above",
+                        rule.name
+                    ));
+                }
+                _ => {}
+            }
+            if rule.factor == RiskFactorKind::Velocity && rule.window_secs.unwrap_or(0) == 0 {
+                problems.push(format!(
+                    "risk rule {} requires a window_secs greater than 0",
+                    rule.name
+                ));
+            }
+        }
+
+        if self.tenancy.jwt_claim.trim().is_empty() {
+            problems.push("tenancy.jwt_claim must not be empty".to_string());
+        }
+        if self
+            .tenancy
+            .jwt_secret
+            .as_ref()
+            .is_some_and(|secret| secret.is_empty())
+        {
+            problems.push("tenancy.jwt_secret must not be empty when set".to_string());
+        }
+        for (id, tenant) in &self.tenants {
+            if id.parse::<TenantId>().is_err() {
+                problems.push(format!(
+                    "tenant id '{}' must be lowercase letters, digits, - or _",
+                    id
+                ));
+            }
+            if tenant.tax_rate.is_sign_negative() || tenant.tax_rate >= Decimal::ONE {
+                problems.push(format!("tenants.{}.tax_rate must be in [0, 1)", id));
+            }
+            if let Err(err) = tenant.parse_transitions() {
+                problems.push(format!("tenants.{}.transitions: {}", id, err));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(problems))
+        }
+    }
+}
+
+fn override_with<T>(
+    env: &impl Fn(&str) -> Option<String>,
+    key: &'static str,
+    target: &mut T,
+) -> Result<(), ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if let Some(value) = env(key) {
+        *target = value
+            .parse()
+            .map_err(|err: T::Err| ConfigError::InvalidEnv {
+                key,
+                message: err.to_string(),
+            })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| vars.get(key).cloned()
+    }
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let settings = Settings::default();
+        assert_eq!(
+            settings.server.addr(),
+            SocketAddr::from(([127, 0, 0, 1], 3000))
+        );
+        assert_eq!(settings.repository.backend, RepositoryBackend::InMemory);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_toml_overrides_defaults() {
+        let settings = Settings::from_toml(
+            r#"
+            [server]
+            port = 8080
+
+            [repository]
+            backend = "sql"
+
+            [database]
+            url = "postgres://localhost/orders"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.server.port, 8080);
+        assert_eq!(settings.server.host, ServerSettings::default().host);
+        assert_eq!(settings.repository.backend, RepositoryBackend::Sql);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_catalog_products_are_read_from_toml() {
+        let settings = Settings::from_toml(
+            r#"
+            [[catalog.products]]
+            id = "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f"
+            price = "49.90"
+            stock = 12
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.catalog.products.len(), 1);
+        assert_eq!(settings.catalog.products[0].price, Decimal::new(4990, 2));
+        assert_eq!(settings.catalog.products[0].stock, 12);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_price_rules_are_read_from_toml() {
+        let settings = Settings::from_toml(
+            r#"
+            [pricing.customer_groups]
+            "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10" = "wholesale"
+
+            [[pricing.rules]]
+            name = "keyboard-10"
+            product_id = "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f"
+            price = "44.90"
+            min_quantity = 10
+
+            [[pricing.rules]]
+            name = "keyboard-black-friday"
+            product_id = "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f"
+            price = "39.90"
+            customer_group = "wholesale"
+            valid_from = "2025-11-28T00:00:00Z"
+            valid_until = "2025-12-01T00:00:00Z"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.pricing.customer_groups.len(), 1);
+        assert_eq!(settings.pricing.rules[0].min_quantity, 10);
+        assert_eq!(settings.pricing.rules[1].min_quantity, 1);
+        assert!(settings.pricing.rules[1].valid_until.is_some());
+        assert!(settings.validate().is_ok());
+
+        let mut invalid = settings.clone();
+        invalid.pricing.rules[1].name = "keyboard-10".to_string();
+        invalid.pricing.rules[1].valid_until = invalid.pricing.rules[1].valid_from;
+        let ConfigError::Invalid(problems) = invalid.validate().unwrap_err() else {
+            panic!("expected validation error");
+        };
+        assert_eq!(problems.len(), 2);
+    }
+
+    #[test]
+    fn test_risk_rules_are_read_from_toml() {
+        let settings = Settings::from_toml(
+            r#"
+            [risk]
+            review_score = 80
+
+            [[risk.rules]]
+            name = "card-testing"
+            factor = "velocity"
+            above = 3
+            window_secs = 3600
+            score = 80
+
+            [[risk.rules]]
+            name = "address-mismatch"
+            factor = "address_mismatch"
+            score = 30
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.risk.review_score, 80);
+        assert_eq!(settings.risk.rules[0].factor, RiskFactorKind::Velocity);
+        assert_eq!(settings.risk.rules[0].above, Some(Decimal::new(3, 0)));
+        assert_eq!(settings.risk.rules[1].above, None);
+        assert!(settings.validate().is_ok());
+
+        let mut invalid = settings.clone();
+        invalid.risk.rules[0].window_secs = None;
+        invalid.risk.rules[1].factor = RiskFactorKind::ItemCount;
+        let ConfigError::Invalid(problems) = invalid.validate().unwrap_err() else {
+            panic!("expected validation error");
+        };
+        assert_eq!(problems.len(), 2);
+    }
+
+    #[test]
+    fn test_tenants_are_read_from_toml() {
+        let settings = Settings::from_toml(
+            r#"
+            [tenancy]
+            default_tenant = "acme-fr"
+
+            [tenants.acme-fr]
+            tax_rate = "0.20"
+
+            [tenants.acme-us]
+            currency = "USD"
+            tax_rate = "0.0825"
+            prices_include_tax = false
+            transitions = ["PENDING->CONFIRMED", "CONFIRMED->PAID"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.tenancy.default_tenant.as_str(), "acme-fr");
+        assert_eq!(settings.tenants["acme-fr"].currency, Currency::EUR);
+        assert!(settings.tenants["acme-fr"].prices_include_tax);
+        assert_eq!(
+            settings.tenants["acme-us"].parse_transitions().unwrap(),
+            Some(vec![
+                (OrderStatus::Pending, OrderStatus::Confirmed),
+                (OrderStatus::Confirmed, OrderStatus::Paid)
+            ])
+        );
+        assert!(settings.validate().is_ok());
+
+        let invalid = Settings::from_toml(
+            r#"
+            [tenants.Acme]
+            tax_rate = "1.5"
+            transitions = ["PENDING=>CONFIRMED"]
+            "#,
+        )
+        .unwrap();
+        let ConfigError::Invalid(problems) = invalid.validate().unwrap_err() else {
+            panic!("expected validation error");
+        };
+        assert_eq!(problems.len(), 3);
+    }
+
+    #[test]
+    fn test_unknown_toml_key_is_rejected() {
+        assert!(Settings::from_toml("[server]\nprot = 8080").is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let mut settings = Settings::from_toml("[server]\nport = 8080").unwrap();
+        settings
+            .apply_env(env(&[
+                ("ORDERING_SERVER_PORT", "9090"),
+                ("DATABASE_URL", "sqlite::memory:"),
+                ("ORDERING_REPOSITORY_BACKEND", "sql"),
+                ("ORDERING_FEATURES_PUBLISH_EVENTS", "false"),
+                ("ORDERING_LOG_FORMAT", "json"),
+                ("ORDERING_RATE_LIMIT_API_KEYS", "partner-a,partner-b"),
+            ]))
+            .unwrap();
+
+        assert_eq!(settings.server.port, 9090);
+        assert_eq!(settings.database.url.as_deref(), Some("sqlite::memory:"));
+        assert_eq!(settings.repository.backend, RepositoryBackend::Sql);
+        assert!(!settings.features.publish_events);
+        assert_eq!(settings.log.format, LogFormat::Json);
+        assert_eq!(settings.rate_limit.api_keys, ["partner-a", "partner-b"]);
+    }
+
+    #[test]
+    fn test_invalid_env_value_names_the_variable() {
+        let mut settings = Settings::default();
+        let err = settings
+            .apply_env(env(&[("ORDERING_SERVER_PORT", "http")]))
+            .unwrap_err();
+        assert!(err.to_string().contains("ORDERING_SERVER_PORT"));
+    }
+
+    #[test]
+    fn test_validation_reports_every_problem() {
+        let mut settings = Settings::default();
+        settings.server.port = 0;
+        settings.repository.backend = RepositoryBackend::Sql;
+
+        let ConfigError::Invalid(problems) = settings.validate().unwrap_err() else {
+            panic!("expected validation error");
+        };
+        assert_eq!(problems.len(), 2);
+    }
+}
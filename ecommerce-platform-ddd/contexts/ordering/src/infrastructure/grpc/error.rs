@@ -0,0 +1,128 @@
+use crate::application::error::ApplicationError;
+use crate::domain::errors::DomainError;
+use tonic::{metadata::MetadataValue, Code, Status};
+
+/// Metadata carrying the stable error code, e.g. `ORDER_NOT_FOUND`
+pub const ERROR_CODE_METADATA: &str = "x-error-code";
+
+fn status(code: Code, error_code: &'static str, message: String) -> Status {
+    let mut status = Status::new(code, message);
+    status
+        .metadata_mut()
+        .insert(ERROR_CODE_METADATA, MetadataValue::from_static(error_code));
+    status
+}
+
+/// Same classification as the HTTP `ApiError`, expressed as gRPC codes
+impl From<DomainError> for Status {
+    fn from(err: DomainError) -> Self {
+        let code = match &err {
+            DomainError::OrderItemNotFound
+            | DomainError::ShipmentNotFound
+            | DomainError::CartLineNotFound
+            | DomainError::WishlistItemNotFound
+            | DomainError::UnknownTenant(_) => Code::NotFound,
+            DomainError::InvalidStatusTransition { .. }
+            | DomainError::CannotCancelTerminalOrder
+            | DomainError::CannotModifyNonPendingOrder
+            | DomainError::CannotRemoveLastItem
+            | DomainError::OrderHasShipments
+            | DomainError::CannotAnonymizeOrderInProgress
+            | DomainError::OrderAwaitingReview
+            | DomainError::OrderNotAwaitingShipment
+            | DomainError::ShipmentAlreadyShipped
+            | DomainError::CartExpired
+            | DomainError::AnonymousCart
+            | DomainError::CartAlreadyAssigned
+            | DomainError::PricesChanged
+            | DomainError::InsufficientStock { .. }
+            | DomainError::OrderNotPaid
+            | DomainError::InvoiceFullyCredited => Code::FailedPrecondition,
+            DomainError::EmptyOrder
+            | DomainError::InvalidQuantity
+            | DomainError::InvalidProductName
+            | DomainError::TooManyItems { .. }
+            | DomainError::EmptyShipment
+            | DomainError::ShipmentExceedsOrderedQuantity
+            | DomainError::CannotMergeShipmentWithItself
+            | DomainError::InvalidTrackingNumber
+            | DomainError::EmptyCart
+            | DomainError::CannotMergeCartWithItself
+            | DomainError::ProductUnavailable { .. }
+            | DomainError::CannotCreditCreditNote
+            | DomainError::InvalidCreditNoteReason
+            | DomainError::EmptyRejectionReason
+            | DomainError::EmptyCreditNote
+            | DomainError::InvoiceLineNotFound
+            | DomainError::CreditNoteExceedsInvoice
+            | DomainError::CurrencyNotAccepted { .. }
+            | DomainError::WishlistFull { .. }
+            | DomainError::WishlistNoteTooLong { .. }
+            | DomainError::InvalidTaxRate
+            | DomainError::InvalidPriceRule { .. }
+            | DomainError::InvalidRiskRule { .. }
+            | DomainError::MoneyError(_) => Code::InvalidArgument,
+        };
+        status(code, err.code(), err.to_string())
+    }
+}
+
+impl From<ApplicationError> for Status {
+    fn from(err: ApplicationError) -> Self {
+        let code = match err {
+            ApplicationError::Domain(err) => return err.into(),
+            ApplicationError::NotFound(_) => Code::NotFound,
+            ApplicationError::Conflict(_) => Code::Aborted,
+            ApplicationError::Unauthorized(_) => Code::PermissionDenied,
+            ApplicationError::Infrastructure(_) => {
+                // The cause stays in the logs, as for HTTP
+                tracing::error!("gRPC call failed: {}", err.report());
+                return status(
+                    Code::Internal,
+                    err.code(),
+                    "The service could not complete the request".to_string(),
+                );
+            }
+        };
+        status(code, err.code(), err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::Resource;
+    use crate::domain::{errors::InfrastructureError, value_objects::OrderStatus};
+
+    #[test]
+    fn test_domain_errors_map_to_grpc_codes() {
+        let status = Status::from(ApplicationError::NotFound(Resource::Order));
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "ORDER_NOT_FOUND"
+        );
+        assert_eq!(
+            Status::from(DomainError::EmptyOrder).code(),
+            Code::InvalidArgument
+        );
+        assert_eq!(
+            Status::from(DomainError::InvalidStatusTransition {
+                from: OrderStatus::Pending,
+                to: OrderStatus::Shipped,
+            })
+            .code(),
+            Code::FailedPrecondition
+        );
+        let status = Status::from(ApplicationError::from(
+            InfrastructureError::storage("Database error")
+                .with_source(std::io::Error::other("down")),
+        ));
+        assert_eq!(status.code(), Code::Internal);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "STORAGE_FAILURE"
+        );
+        assert!(!status.message().contains("down"));
+    }
+}
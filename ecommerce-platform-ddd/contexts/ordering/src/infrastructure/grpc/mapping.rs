@@ -0,0 +1,126 @@
+use super::proto;
+use crate::application::audit::Actor;
+use crate::application::commands::{CreateOrderCommand, CreateOrderItemDto};
+use crate::application::dto::{OrderItemResponse, OrderResponse};
+use crate::domain::value_objects::{
+    CustomerId, Money, OrderId, OrderStatus, PostalAddress, ProductId, TenantId,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::time::SystemTime;
+use tonic::Status;
+use uuid::Uuid;
+
+impl From<OrderStatus> for proto::OrderStatus {
+    fn from(status: OrderStatus) -> Self {
+        match status {
+            OrderStatus::Pending => Self::Pending,
+            OrderStatus::OnHold => Self::OnHold,
+            OrderStatus::Confirmed => Self::Confirmed,
+            OrderStatus::Paid => Self::Paid,
+            OrderStatus::Shipped => Self::Shipped,
+            OrderStatus::Delivered => Self::Delivered,
+            OrderStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<Money> for proto::Money {
+    fn from(money: Money) -> Self {
+        Self {
+            amount: money.amount().to_string(),
+            currency: money.currency().to_string(),
+        }
+    }
+}
+
+impl From<OrderItemResponse> for proto::OrderItem {
+    fn from(item: OrderItemResponse) -> Self {
+        Self {
+            id: item.id.to_string(),
+            product_id: item.product_id.to_string(),
+            product_name: item.product_name,
+            quantity: item.quantity,
+            unit_price: Some(item.unit_price.into()),
+            subtotal: Some(item.subtotal.into()),
+            list_price: Some(item.list_price.into()),
+            price_rule: item.price_rule,
+        }
+    }
+}
+
+impl From<OrderResponse> for proto::Order {
+    fn from(order: OrderResponse) -> Self {
+        Self {
+            id: order.id.to_string(),
+            customer_id: order.customer_id.to_string(),
+            status: proto::OrderStatus::from(order.status).into(),
+            items: order.items.into_iter().map(Into::into).collect(),
+            total: Some(order.total.into()),
+            created_at: Some(timestamp(order.created_at)),
+            updated_at: Some(timestamp(order.updated_at)),
+        }
+    }
+}
+
+impl From<proto::PostalAddress> for PostalAddress {
+    fn from(address: proto::PostalAddress) -> Self {
+        Self {
+            line1: address.line1,
+            postal_code: address.postal_code,
+            city: address.city,
+            country: address.country,
+        }
+    }
+}
+
+/// Command issued by `actor` on `tenant_id`, rejecting malformed identifiers and amounts
+pub(super) fn create_order_command(
+    request: proto::CreateOrderRequest,
+    tenant_id: TenantId,
+    actor: Actor,
+) -> Result<CreateOrderCommand, Status> {
+    Ok(CreateOrderCommand {
+        tenant_id,
+        customer_id: CustomerId::from_uuid(parse_uuid("customer_id", &request.customer_id)?),
+        items: request
+            .items
+            .into_iter()
+            .map(|item| {
+                Ok(CreateOrderItemDto {
+                    product_id: ProductId::from_uuid(parse_uuid("product_id", &item.product_id)?),
+                    product_name: item.product_name,
+                    quantity: item.quantity,
+                    unit_price: parse_decimal("unit_price", &item.unit_price)?,
+                })
+            })
+            .collect::<Result<_, Status>>()?,
+        billing_address: request.billing_address.map(Into::into),
+        shipping_address: request.shipping_address.map(Into::into),
+        actor,
+    })
+}
+
+pub(super) fn parse_order_id(value: &str) -> Result<OrderId, Status> {
+    parse_uuid("order_id", value).map(OrderId::from_uuid)
+}
+
+pub(super) fn parse_customer_id(value: &str) -> Result<CustomerId, Status> {
+    parse_uuid("customer_id", value).map(CustomerId::from_uuid)
+}
+
+fn parse_uuid(field: &str, value: &str) -> Result<Uuid, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("{} must be a UUID", field)))
+}
+
+fn parse_decimal(field: &str, value: &str) -> Result<Decimal, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("{} must be a decimal number", field)))
+}
+
+fn timestamp(value: DateTime<Utc>) -> prost_types::Timestamp {
+    SystemTime::from(value).into()
+}
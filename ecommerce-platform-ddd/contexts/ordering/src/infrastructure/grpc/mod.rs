@@ -0,0 +1,497 @@
+pub mod error;
+pub mod mapping;
+
+use crate::application::audit::Actor;
+use crate::application::commands::{
+    CancelOrderCommand, CancelOrderHandler, ConfirmOrderCommand, ConfirmOrderHandler,
+    CreateOrderHandler,
+};
+use crate::application::queries::{
+    GetOrderHandler, GetOrderQuery, ListCustomerOrdersHandler, ListCustomerOrdersQuery,
+};
+use crate::domain::value_objects::TenantId;
+use crate::infrastructure::observability::{CorrelationId, CORRELATION_ID_HEADER};
+use crate::infrastructure::tenancy::{TenantResolutionError, TenantResolver, TENANT_HEADER};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+/// Code generated from `proto/ordering/v1/ordering.proto`
+pub mod proto {
+    tonic::include_proto!("ordering.v1");
+}
+
+use proto::ordering_service_server::{OrderingService, OrderingServiceServer};
+
+/// gRPC adapter: same application handlers as the HTTP API
+pub struct OrderingGrpcService {
+    create_order: Arc<CreateOrderHandler>,
+    confirm_order: Arc<ConfirmOrderHandler>,
+    cancel_order: Arc<CancelOrderHandler>,
+    get_order: Arc<GetOrderHandler>,
+    list_customer_orders: Arc<ListCustomerOrdersHandler>,
+    tenant_resolver: Arc<TenantResolver>,
+}
+
+impl OrderingGrpcService {
+    pub fn new(
+        create_order: Arc<CreateOrderHandler>,
+        confirm_order: Arc<ConfirmOrderHandler>,
+        cancel_order: Arc<CancelOrderHandler>,
+        get_order: Arc<GetOrderHandler>,
+        list_customer_orders: Arc<ListCustomerOrdersHandler>,
+        tenant_resolver: Arc<TenantResolver>,
+    ) -> Self {
+        Self {
+            create_order,
+            confirm_order,
+            cancel_order,
+            get_order,
+            list_customer_orders,
+            tenant_resolver,
+        }
+    }
+
+    /// Tenant of the call, from the `authorization` and `x-tenant-id` metadata as for HTTP
+    fn tenant<T>(&self, request: &Request<T>) -> Result<TenantId, Status> {
+        self.tenant_resolver
+            .resolve(
+                metadata(request, TENANT_HEADER),
+                metadata(request, "authorization"),
+            )
+            .map_err(status)
+    }
+
+    /// Actor of the call, from the verified `authorization` bearer token as for HTTP
+    fn actor<T>(&self, request: &Request<T>) -> Result<Actor, Status> {
+        self.tenant_resolver
+            .actor(metadata(request, "authorization"))
+            .map_err(status)
+    }
+
+    /// Wrap the service for `tonic::transport::Server::add_service`
+    pub fn into_server(self) -> OrderingServiceServer<Self> {
+        OrderingServiceServer::new(self)
+    }
+}
+
+#[tonic::async_trait]
+impl OrderingService for OrderingGrpcService {
+    async fn create_order(
+        &self,
+        request: Request<proto::CreateOrderRequest>,
+    ) -> Result<Response<proto::CreateOrderResponse>, Status> {
+        let correlation_id = correlation_id(&request);
+        let tenant_id = self.tenant(&request)?;
+        let actor = self.actor(&request)?;
+        let command = mapping::create_order_command(request.into_inner(), tenant_id, actor)?;
+        let order_id = correlation_id
+            .scope(self.create_order.handle(command))
+            .await?;
+        Ok(Response::new(proto::CreateOrderResponse {
+            order_id: order_id.to_string(),
+        }))
+    }
+
+    async fn confirm_order(
+        &self,
+        request: Request<proto::ConfirmOrderRequest>,
+    ) -> Result<Response<proto::ConfirmOrderResponse>, Status> {
+        let correlation_id = correlation_id(&request);
+        let command = ConfirmOrderCommand {
+            tenant_id: self.tenant(&request)?,
+            order_id: mapping::parse_order_id(&request.get_ref().order_id)?,
+            actor: self.actor(&request)?,
+        };
+        let status = correlation_id
+            .scope(self.confirm_order.handle(command))
+            .await?;
+        Ok(Response::new(proto::ConfirmOrderResponse {
+            status: proto::OrderStatus::from(status).into(),
+        }))
+    }
+
+    async fn cancel_order(
+        &self,
+        request: Request<proto::CancelOrderRequest>,
+    ) -> Result<Response<()>, Status> {
+        let correlation_id = correlation_id(&request);
+        let tenant_id = self.tenant(&request)?;
+        let actor = self.actor(&request)?;
+        let request = request.into_inner();
+        let command = CancelOrderCommand {
+            tenant_id,
+            order_id: mapping::parse_order_id(&request.order_id)?,
+            reason: request.reason,
+            actor,
+        };
+        correlation_id
+            .scope(self.cancel_order.handle(command))
+            .await?;
+        Ok(Response::new(()))
+    }
+
+    async fn get_order(
+        &self,
+        request: Request<proto::GetOrderRequest>,
+    ) -> Result<Response<proto::Order>, Status> {
+        let tenant_id = self.tenant(&request)?;
+        let order_id = mapping::parse_order_id(&request.get_ref().order_id)?;
+        let order = self
+            .get_order
+            .handle(GetOrderQuery {
+                tenant_id,
+                order_id,
+            })
+            .await?;
+        Ok(Response::new(order.into()))
+    }
+
+    async fn list_customer_orders(
+        &self,
+        request: Request<proto::ListCustomerOrdersRequest>,
+    ) -> Result<Response<proto::ListCustomerOrdersResponse>, Status> {
+        let tenant_id = self.tenant(&request)?;
+        let customer_id = mapping::parse_customer_id(&request.get_ref().customer_id)?;
+        let orders = self
+            .list_customer_orders
+            .handle(ListCustomerOrdersQuery {
+                tenant_id,
+                customer_id,
+            })
+            .await?;
+        Ok(Response::new(proto::ListCustomerOrdersResponse {
+            orders: orders.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
+
+/// ASCII metadata value, empty when not ASCII so that it is refused downstream
+fn metadata<'a, T>(request: &'a Request<T>, name: &str) -> Option<&'a str> {
+    request
+        .metadata()
+        .get(name)
+        .map(|value| value.to_str().unwrap_or_default())
+}
+
+/// gRPC status of a tenant or credentials error
+fn status(err: TenantResolutionError) -> Status {
+    match err {
+        TenantResolutionError::InvalidHeader | TenantResolutionError::UnknownTenant(_) => {
+            Status::invalid_argument(err.to_string())
+        }
+        TenantResolutionError::InvalidToken(_) | TenantResolutionError::MissingToken => {
+            Status::unauthenticated(err.to_string())
+        }
+        TenantResolutionError::Mismatch => Status::permission_denied(err.to_string()),
+    }
+}
+
+/// `x-correlation-id` metadata, or a fresh ID, as for HTTP requests
+fn correlation_id<T>(request: &Request<T>) -> CorrelationId {
+    request
+        .metadata()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(CorrelationId::parse)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::proto::ordering_service_client::OrderingServiceClient;
+    use super::*;
+    use crate::application::audit::AuditLog;
+    use crate::domain::{
+        repositories::OrderRepository,
+        risk::{RiskFactor, RiskPolicy, RiskRule},
+    };
+    use crate::infrastructure::audit::InMemoryAuditLog;
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use crate::infrastructure::pricing::InMemoryPriceLists;
+    use crate::infrastructure::risk::RuleBasedRiskAssessor;
+    use hyper_util::rt::TokioIo;
+    use tonic::transport::{Channel, Endpoint, Server, Uri};
+    use tonic::Code;
+
+    /// Client talking to the service over an in-process duplex stream
+    async fn client(
+        repository: Arc<dyn OrderRepository>,
+        publisher: Arc<InMemoryEventPublisher>,
+        audit_log: Arc<InMemoryAuditLog>,
+    ) -> OrderingServiceClient<Channel> {
+        let clock = Arc::new(crate::domain::clock::SystemClock);
+        let tenants: Arc<dyn crate::domain::tenant::TenantDirectory> =
+            Arc::new(crate::infrastructure::tenancy::TenantRegistry::new(TenantId::default()));
+        let service = OrderingGrpcService::new(
+            Arc::new(CreateOrderHandler::new(
+                repository.clone(),
+                tenants.clone(),
+                Arc::new(InMemoryPriceLists::default()),
+                publisher.clone(),
+                audit_log.clone(),
+                Arc::new(crate::domain::id_generator::UuidV7Generator),
+                clock.clone(),
+            )),
+            Arc::new(ConfirmOrderHandler::new(
+                repository.clone(),
+                tenants.clone(),
+                Arc::new(RuleBasedRiskAssessor::new(
+                    // Holds the orders of more than 5 units
+                    RiskPolicy::new(
+                        vec![RiskRule::new("bulk", RiskFactor::ItemCountAbove(5), 100)],
+                        100,
+                    )
+                    .unwrap(),
+                    repository.clone(),
+                    clock.clone(),
+                )),
+                publisher.clone(),
+                audit_log.clone(),
+                clock.clone(),
+            )),
+            Arc::new(CancelOrderHandler::new(
+                repository.clone(),
+                tenants.clone(),
+                publisher,
+                audit_log,
+                clock,
+            )),
+            Arc::new(GetOrderHandler::new(repository.clone())),
+            Arc::new(ListCustomerOrdersHandler::new(repository)),
+            Arc::new(TenantResolver::new(
+                tenants,
+                crate::infrastructure::config::TenancySettings {
+                    jwt_secret: Some(crate::infrastructure::api::TEST_JWT_SECRET.to_string()),
+                    ..Default::default()
+                },
+            )),
+        );
+
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(async move {
+            Server::builder()
+                .add_service(service.into_server())
+                .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server_io)))
+                .await
+        });
+
+        let mut client_io = Some(client_io);
+        let channel = Endpoint::from_static("http://in-process")
+            .connect_with_connector(tower::service_fn(move |_: Uri| {
+                let io = client_io.take();
+                async move {
+                    io.map(TokioIo::new)
+                        .ok_or_else(|| std::io::Error::other("client already connected"))
+                }
+            }))
+            .await
+            .unwrap();
+        OrderingServiceClient::new(channel)
+    }
+
+    fn create_request(customer_id: &str) -> proto::CreateOrderRequest {
+        proto::CreateOrderRequest {
+            customer_id: customer_id.to_string(),
+            items: vec![proto::CreateOrderItem {
+                product_id: "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f".to_string(),
+                product_name: "Keyboard".to_string(),
+                quantity: 2,
+                unit_price: "49.90".to_string(),
+            }],
+            billing_address: None,
+            shipping_address: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_then_get_order() {
+        let publisher = Arc::new(InMemoryEventPublisher::new());
+        let mut client = client(
+            Arc::new(InMemoryOrderRepository::new()),
+            publisher.clone(),
+            Arc::new(InMemoryAuditLog::new()),
+        )
+        .await;
+        let customer_id = "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10";
+
+        let mut request = tonic::Request::new(create_request(customer_id));
+        request
+            .metadata_mut()
+            .insert(CORRELATION_ID_HEADER, "grpc-123".parse().unwrap());
+        let order_id = client
+            .create_order(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .order_id;
+
+        let order = client
+            .get_order(proto::GetOrderRequest {
+                order_id: order_id.clone(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(order.id, order_id);
+        assert_eq!(order.status(), proto::OrderStatus::Pending);
+        let total = order.total.unwrap();
+        assert_eq!(total.amount, "99.80");
+        assert_eq!(total.currency, "EUR");
+        assert!(order.created_at.is_some());
+
+        let orders = client
+            .list_customer_orders(proto::ListCustomerOrdersRequest {
+                customer_id: customer_id.to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .orders;
+        assert_eq!(orders.len(), 1);
+
+        client
+            .cancel_order(proto::CancelOrderRequest {
+                order_id: order_id.clone(),
+                reason: "Customer request".to_string(),
+            })
+            .await
+            .unwrap();
+        let order = client
+            .get_order(proto::GetOrderRequest { order_id })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(order.status(), proto::OrderStatus::Cancelled);
+
+        let published = publisher.published().await;
+        assert_eq!(published.len(), 2);
+        assert_eq!(
+            published[0]
+                .metadata
+                .correlation_id
+                .as_ref()
+                .map(|id| id.as_str()),
+            Some("grpc-123")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_confirm_tells_confirmed_from_held() {
+        let client = client(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+            Arc::new(InMemoryAuditLog::new()),
+        )
+        .await;
+        let confirm = |quantity| {
+            let mut request = create_request("6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10");
+            request.items[0].quantity = quantity;
+            let mut client = client.clone();
+            async move {
+                let order_id = client
+                    .create_order(request)
+                    .await
+                    .unwrap()
+                    .into_inner()
+                    .order_id;
+                client
+                    .confirm_order(proto::ConfirmOrderRequest { order_id })
+                    .await
+                    .unwrap()
+                    .into_inner()
+                    .status()
+            }
+        };
+
+        assert_eq!(confirm(2).await, proto::OrderStatus::Confirmed);
+        assert_eq!(confirm(10).await, proto::OrderStatus::OnHold);
+    }
+
+    #[tokio::test]
+    async fn test_errors_map_to_status_codes() {
+        let mut client = client(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+            Arc::new(InMemoryAuditLog::new()),
+        )
+        .await;
+
+        let status = client
+            .get_order(proto::GetOrderRequest {
+                order_id: crate::domain::value_objects::OrderId::new().to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+
+        let status = client
+            .get_order(proto::GetOrderRequest {
+                order_id: "not-a-uuid".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let mut empty = create_request("6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10");
+        empty.items.clear();
+        let status = client.create_order(empty).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "Order cannot be empty");
+    }
+
+    #[tokio::test]
+    async fn test_actor_comes_from_the_bearer_token() {
+        let audit_log = Arc::new(InMemoryAuditLog::new());
+        let mut client = client(
+            Arc::new(InMemoryOrderRepository::new()),
+            Arc::new(InMemoryEventPublisher::new()),
+            audit_log.clone(),
+        )
+        .await;
+        let request = |token: &str| {
+            let mut request =
+                tonic::Request::new(create_request("6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10"));
+            // Claims nothing: only the verified token names the caller
+            request
+                .metadata_mut()
+                .insert("x-actor-id", "support-42".parse().unwrap());
+            if !token.is_empty() {
+                request
+                    .metadata_mut()
+                    .insert("authorization", token.parse().unwrap());
+            }
+            request
+        };
+
+        let anonymous = client.create_order(request("")).await.unwrap().into_inner();
+        let token = crate::infrastructure::tenancy::jwt::encode_hs256(
+            &serde_json::json!({"sub": "alice"}),
+            crate::infrastructure::api::TEST_JWT_SECRET,
+        );
+        let alice = client
+            .create_order(request(&format!("Bearer {}", token)))
+            .await
+            .unwrap()
+            .into_inner();
+        let forged = crate::infrastructure::tenancy::jwt::encode_hs256(
+            &serde_json::json!({"sub": "alice"}),
+            "guessed",
+        );
+        let status = client
+            .create_order(request(&format!("Bearer {}", forged)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+
+        let actor = |order_id: String| {
+            let audit_log = audit_log.clone();
+            async move {
+                let order_id = mapping::parse_order_id(&order_id).unwrap();
+                audit_log.timeline(order_id).await.unwrap()[0].actor.clone()
+            }
+        };
+        assert_eq!(actor(anonymous.order_id).await, Actor::Anonymous);
+        assert_eq!(actor(alice.order_id).await, Actor::user("alice"));
+    }
+}
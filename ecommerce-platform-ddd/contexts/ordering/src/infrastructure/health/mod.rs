@@ -0,0 +1,229 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseConnection;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use utoipa::ToSchema;
+
+/// Dependency probed by the readiness endpoint (Port)
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Component name shown in the report
+    fn name(&self) -> &str;
+
+    /// Ok when the component can serve requests
+    async fn check(&self) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub error: Option<String>,
+}
+
+/// JSON body of `GET /ready`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReadinessReport {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+/// Runs every registered check concurrently, each within a timeout
+pub struct ReadinessChecker {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    timeout: Duration,
+}
+
+impl ReadinessChecker {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            checks: Vec::new(),
+            timeout,
+        }
+    }
+
+    pub fn with_check(mut self, check: Arc<dyn HealthCheck>) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    pub async fn report(&self) -> ReadinessReport {
+        let mut tasks = JoinSet::new();
+        for (index, check) in self.checks.iter().cloned().enumerate() {
+            let timeout = self.timeout;
+            tasks.spawn(async move {
+                let started = Instant::now();
+                let result = match tokio::time::timeout(timeout, check.check()).await {
+                    Ok(result) => result,
+                    Err(_) => Err(format!("timed out after {} ms", timeout.as_millis())),
+                };
+                let component = ComponentHealth {
+                    name: check.name().to_string(),
+                    status: if result.is_ok() {
+                        HealthStatus::Up
+                    } else {
+                        HealthStatus::Down
+                    },
+                    latency_ms: started.elapsed().as_millis() as u64,
+                    error: result.err(),
+                };
+                (index, component)
+            });
+        }
+
+        let mut components: Vec<_> = tasks.join_all().await;
+        components.sort_by_key(|(index, _)| *index);
+        let components: Vec<_> = components.into_iter().map(|(_, c)| c).collect();
+
+        let status = if components.iter().all(|c| c.status == HealthStatus::Up) {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        ReadinessReport { status, components }
+    }
+}
+
+/// Repository check: pings the SQL database
+pub struct DatabaseHealthCheck {
+    db: DatabaseConnection,
+}
+
+impl DatabaseHealthCheck {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DatabaseHealthCheck {
+    fn name(&self) -> &str {
+        "repository"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.db.ping().await.map_err(|err| err.to_string())
+    }
+}
+
+/// Broker check: opens a TCP connection to the Iggy server
+pub struct BrokerHealthCheck {
+    address: String,
+}
+
+impl BrokerHealthCheck {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for BrokerHealthCheck {
+    fn name(&self) -> &str {
+        "broker"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        tokio::net::TcpStream::connect(&self.address)
+            .await
+            .map(|_| ())
+            .map_err(|err| format!("{}: {}", self.address, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeCheck {
+        name: &'static str,
+        result: Result<(), String>,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl HealthCheck for FakeCheck {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            tokio::time::sleep(self.delay).await;
+            self.result.clone()
+        }
+    }
+
+    fn fake(name: &'static str, result: Result<(), String>, delay_ms: u64) -> Arc<dyn HealthCheck> {
+        Arc::new(FakeCheck {
+            name,
+            result,
+            delay: Duration::from_millis(delay_ms),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_report_is_up_when_every_check_passes() {
+        let report = ReadinessChecker::new(Duration::from_secs(1))
+            .with_check(fake("repository", Ok(()), 0))
+            .with_check(fake("broker", Ok(()), 0))
+            .report()
+            .await;
+
+        assert_eq!(report.status, HealthStatus::Up);
+        assert_eq!(report.components[0].name, "repository");
+        assert_eq!(report.components[1].name, "broker");
+    }
+
+    #[tokio::test]
+    async fn test_failing_or_slow_check_marks_report_down() {
+        let report = ReadinessChecker::new(Duration::from_millis(20))
+            .with_check(fake("repository", Err("connection refused".into()), 0))
+            .with_check(fake("broker", Ok(()), 500))
+            .report()
+            .await;
+
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(
+            report.components[0].error.as_deref(),
+            Some("connection refused")
+        );
+        assert!(report.components[1]
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_database_check() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        assert!(DatabaseHealthCheck::new(db).check().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_broker_check_fails_when_nothing_listens() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        assert!(BrokerHealthCheck::new(address.clone())
+            .check()
+            .await
+            .is_ok());
+
+        drop(listener);
+        assert!(BrokerHealthCheck::new(address).check().await.is_err());
+    }
+}
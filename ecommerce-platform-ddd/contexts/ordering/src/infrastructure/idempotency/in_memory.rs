@@ -0,0 +1,197 @@
+use super::{IdempotencyOutcome, IdempotencyStore, StoredResponse};
+use crate::domain::errors::InfrastructureError;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+struct Entry {
+    fingerprint: String,
+    created_at: DateTime<Utc>,
+    response: Option<StoredResponse>,
+}
+
+#[derive(Default)]
+struct Entries {
+    by_key: HashMap<String, Entry>,
+    /// Keys in the order they were claimed, oldest first, to purge expired entries
+    claims: VecDeque<(DateTime<Utc>, String)>,
+}
+
+impl Entries {
+    /// Drop the entries claimed `ttl` ago or more
+    ///
+    /// A key claimed again since keeps its newer entry; an abandoned one is gone already.
+    fn purge(&mut self, expired_at: DateTime<Utc>) {
+        while let Some((created_at, _)) = self.claims.front() {
+            if *created_at > expired_at {
+                break;
+            }
+            let (created_at, key) = self.claims.pop_front().expect("front was checked");
+            if self
+                .by_key
+                .get(&key)
+                .is_some_and(|entry| entry.created_at == created_at)
+            {
+                self.by_key.remove(&key);
+            }
+        }
+    }
+}
+
+/// In-memory idempotency store (single instance deployments and tests)
+///
+/// Expired entries are dropped as new keys are claimed, so the store holds at most
+/// the keys of one TTL.
+pub struct InMemoryIdempotencyStore {
+    entries: Arc<RwLock<Entries>>,
+    ttl: Duration,
+}
+
+impl InMemoryIdempotencyStore {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(Entries::default())),
+            ttl,
+        }
+    }
+
+    #[cfg(test)]
+    async fn len(&self) -> usize {
+        self.entries.read().await.by_key.len()
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn begin(
+        &self,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<IdempotencyOutcome, InfrastructureError> {
+        let now = Utc::now();
+        let mut entries = self.entries.write().await;
+        entries.purge(now - self.ttl);
+
+        if let Some(entry) = entries.by_key.get(key) {
+            if entry.fingerprint != fingerprint {
+                return Ok(IdempotencyOutcome::Mismatch);
+            }
+            return Ok(match &entry.response {
+                Some(response) => IdempotencyOutcome::Replay(response.clone()),
+                None => IdempotencyOutcome::InProgress,
+            });
+        }
+
+        entries.by_key.insert(
+            key.to_string(),
+            Entry {
+                fingerprint: fingerprint.to_string(),
+                created_at: now,
+                response: None,
+            },
+        );
+        entries.claims.push_back((now, key.to_string()));
+        Ok(IdempotencyOutcome::Started)
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        response: StoredResponse,
+    ) -> Result<(), InfrastructureError> {
+        let mut entries = self.entries.write().await;
+        if let Some(entry) = entries.by_key.get_mut(key) {
+            entry.response = Some(response);
+        }
+        Ok(())
+    }
+
+    async fn abandon(&self, key: &str) -> Result<(), InfrastructureError> {
+        let mut entries = self.entries.write().await;
+        entries.by_key.remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status: 201,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: b"{}".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_after_completion() {
+        let store = InMemoryIdempotencyStore::new(Duration::hours(24));
+
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::InProgress
+        );
+
+        store.complete("k1", response()).await.unwrap();
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::Replay(response())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_different_fingerprint_is_mismatch() {
+        let store = InMemoryIdempotencyStore::new(Duration::hours(24));
+        store.begin("k1", "fp").await.unwrap();
+
+        assert_eq!(
+            store.begin("k1", "other").await.unwrap(),
+            IdempotencyOutcome::Mismatch
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_abandoned_keys_are_reusable() {
+        let store = InMemoryIdempotencyStore::new(Duration::zero());
+        store.begin("k1", "fp").await.unwrap();
+        assert_eq!(
+            store.begin("k1", "other").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+
+        let store = InMemoryIdempotencyStore::new(Duration::hours(24));
+        store.begin("k2", "fp").await.unwrap();
+        store.abandon("k2").await.unwrap();
+        assert_eq!(
+            store.begin("k2", "fp").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_purged() {
+        let store = InMemoryIdempotencyStore::new(Duration::milliseconds(50));
+        for key in ["k1", "k2", "k3"] {
+            store.begin(key, "fp").await.unwrap();
+        }
+        store.abandon("k2").await.unwrap();
+        assert_eq!(store.len().await, 2);
+
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        // Claiming a new key drops the expired ones, whatever their key
+        store.begin("k4", "fp").await.unwrap();
+        assert_eq!(store.len().await, 1);
+        assert_eq!(
+            store.begin("k4", "fp").await.unwrap(),
+            IdempotencyOutcome::InProgress
+        );
+    }
+}
@@ -0,0 +1,52 @@
+pub mod in_memory;
+pub mod sql;
+
+use crate::domain::errors::InfrastructureError;
+use async_trait::async_trait;
+
+pub use in_memory::InMemoryIdempotencyStore;
+pub use sql::SqlIdempotencyStore;
+
+/// Response recorded for an idempotency key, replayed on retries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    /// Headers of the response (`Location`, `Content-Type`…), in order
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Result of claiming an idempotency key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyOutcome {
+    /// First time the key is seen (or the previous record expired): process the request
+    Started,
+    /// The request was already processed: replay the stored response
+    Replay(StoredResponse),
+    /// A request with the same key is still being processed
+    InProgress,
+    /// The key was already used with a different request payload
+    Mismatch,
+}
+
+/// Storage for `Idempotency-Key` records (Port)
+/// Records older than the store TTL are treated as absent
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Claim a key for a request identified by its fingerprint
+    async fn begin(
+        &self,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<IdempotencyOutcome, InfrastructureError>;
+
+    /// Record the response of a request started with `begin`
+    async fn complete(
+        &self,
+        key: &str,
+        response: StoredResponse,
+    ) -> Result<(), InfrastructureError>;
+
+    /// Release a key whose request failed, so that a retry can process it again
+    async fn abandon(&self, key: &str) -> Result<(), InfrastructureError>;
+}
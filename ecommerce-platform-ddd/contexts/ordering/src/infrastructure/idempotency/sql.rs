@@ -0,0 +1,191 @@
+use super::{IdempotencyOutcome, IdempotencyStore, StoredResponse};
+use crate::domain::errors::InfrastructureError;
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::idempotency_key::{ActiveModel, Column, Entity},
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, TryInsertResult,
+};
+
+/// SeaORM implementation, shared by every instance of the service
+pub struct SqlIdempotencyStore {
+    db: DatabaseConnection,
+    ttl: Duration,
+}
+
+impl SqlIdempotencyStore {
+    pub fn new(db: DatabaseConnection, ttl: Duration) -> Self {
+        Self { db, ttl }
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for SqlIdempotencyStore {
+    async fn begin(
+        &self,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<IdempotencyOutcome, InfrastructureError> {
+        let now = Utc::now();
+        let claim = ActiveModel {
+            key: Set(key.to_string()),
+            fingerprint: Set(fingerprint.to_string()),
+            response_status: Set(None),
+            response_headers: Set(None),
+            response_body: Set(None),
+            created_at: Set(now),
+        };
+
+        // The primary key makes the claim atomic across concurrent requests
+        let inserted = Entity::insert(claim)
+            .on_conflict_do_nothing()
+            .exec_without_returning(&self.db)
+            .await?;
+        if matches!(inserted, TryInsertResult::Inserted(rows) if rows > 0) {
+            return Ok(IdempotencyOutcome::Started);
+        }
+
+        let Some(existing) = Entity::find_by_id(key.to_string()).one(&self.db).await? else {
+            // Abandoned in the meantime: let the client retry
+            return Ok(IdempotencyOutcome::InProgress);
+        };
+
+        if existing.created_at + self.ttl <= now {
+            // Expired: take the key over, only one concurrent request can win
+            let takeover = ActiveModel {
+                key: NotSet,
+                fingerprint: Set(fingerprint.to_string()),
+                response_status: Set(None),
+                response_headers: Set(None),
+                response_body: Set(None),
+                created_at: Set(now),
+            };
+            let result = Entity::update_many()
+                .set(takeover)
+                .filter(Column::Key.eq(key))
+                .filter(Column::CreatedAt.lte(now - self.ttl))
+                .exec(&self.db)
+                .await?;
+            return Ok(if result.rows_affected == 1 {
+                IdempotencyOutcome::Started
+            } else {
+                IdempotencyOutcome::InProgress
+            });
+        }
+
+        if existing.fingerprint != fingerprint {
+            return Ok(IdempotencyOutcome::Mismatch);
+        }
+
+        Ok(match (existing.response_status, existing.response_body) {
+            (Some(status), Some(body)) => IdempotencyOutcome::Replay(StoredResponse {
+                status: status as u16,
+                headers: existing
+                    .response_headers
+                    .map(|json| serde_json::from_str(&json))
+                    .transpose()
+                    .map_err(corrupted(format!("idempotency key {}", key)))?
+                    .unwrap_or_default(),
+                body,
+            }),
+            _ => IdempotencyOutcome::InProgress,
+        })
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        response: StoredResponse,
+    ) -> Result<(), InfrastructureError> {
+        let headers = serde_json::to_string(&response.headers).map_err(|err| {
+            InfrastructureError::storage(format!("idempotency key {}", key)).with_source(err)
+        })?;
+        let update = ActiveModel {
+            key: NotSet,
+            fingerprint: NotSet,
+            response_status: Set(Some(i32::from(response.status))),
+            response_headers: Set(Some(headers)),
+            response_body: Set(Some(response.body)),
+            created_at: NotSet,
+        };
+        Entity::update_many()
+            .set(update)
+            .filter(Column::Key.eq(key))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn abandon(&self, key: &str) -> Result<(), InfrastructureError> {
+        Entity::delete_by_id(key.to_string()).exec(&self.db).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::Migrator;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn store(ttl: Duration) -> SqlIdempotencyStore {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlIdempotencyStore::new(db, ttl)
+    }
+
+    #[tokio::test]
+    async fn test_replay_and_mismatch() {
+        let store = store(Duration::hours(24)).await;
+        let response = StoredResponse {
+            status: 201,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("location".to_string(), "/api/orders/x".to_string()),
+            ],
+            body: b"{\"order_id\":\"x\"}".to_vec(),
+        };
+
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::InProgress
+        );
+
+        store.complete("k1", response.clone()).await.unwrap();
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::Replay(response)
+        );
+        assert_eq!(
+            store.begin("k1", "other").await.unwrap(),
+            IdempotencyOutcome::Mismatch
+        );
+    }
+
+    #[tokio::test]
+    async fn test_expired_and_abandoned_keys_are_reusable() {
+        let store = store(Duration::zero()).await;
+        store.begin("k1", "fp").await.unwrap();
+        assert_eq!(
+            store.begin("k1", "other").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+
+        store.abandon("k1").await.unwrap();
+        assert_eq!(
+            store.begin("k1", "fp").await.unwrap(),
+            IdempotencyOutcome::Started
+        );
+    }
+}
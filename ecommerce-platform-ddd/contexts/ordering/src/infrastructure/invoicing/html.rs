@@ -0,0 +1,140 @@
+use super::format_money;
+use crate::application::dto::InvoiceResponse;
+use crate::domain::value_objects::InvoiceKind;
+use std::fmt::Write;
+
+/// Printable HTML rendering of an invoice or credit note (A4, print-ready styles)
+pub fn render_html(invoice: &InvoiceResponse) -> String {
+    let title = format!("{} {}", document_title(invoice.kind), invoice.number);
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>{}</title>", escape(&title));
+    html.push_str(STYLE);
+    html.push_str("</head>\n<body>\n");
+    let _ = writeln!(html, "<h1>{}</h1>", escape(&title));
+
+    html.push_str("<dl>\n");
+    let _ = writeln!(
+        html,
+        "<dt>Issued on</dt><dd>{}</dd>",
+        invoice.issued_at.format("%Y-%m-%d")
+    );
+    let _ = writeln!(html, "<dt>Order</dt><dd>{}</dd>", invoice.order_id);
+    let _ = writeln!(html, "<dt>Customer</dt><dd>{}</dd>", invoice.customer_id);
+    if let Some(credited_invoice_id) = invoice.credited_invoice_id {
+        let _ = writeln!(
+            html,
+            "<dt>Credits invoice</dt><dd>{}</dd>",
+            credited_invoice_id
+        );
+    }
+    if let Some(reason) = &invoice.reason {
+        let _ = writeln!(html, "<dt>Reason</dt><dd>{}</dd>", escape(reason));
+    }
+    html.push_str("</dl>\n");
+
+    html.push_str(
+        "<table>\n<thead><tr><th>Description</th><th>Quantity</th>\
+         <th>Unit price</th><th>Subtotal</th></tr></thead>\n<tbody>\n",
+    );
+    for line in &invoice.lines {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>\
+             <td class=\"num\">{}</td></tr>",
+            escape(&line.description),
+            line.quantity,
+            format_money(&line.unit_price),
+            format_money(&line.subtotal)
+        );
+    }
+    let _ = writeln!(
+        html,
+        "</tbody>\n<tfoot><tr><th colspan=\"3\">Total</th><td class=\"num\">{}</td></tr></tfoot>\n</table>",
+        format_money(&invoice.total)
+    );
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+pub(super) fn document_title(kind: InvoiceKind) -> &'static str {
+    match kind {
+        InvoiceKind::Invoice => "Invoice",
+        InvoiceKind::CreditNote => "Credit note",
+    }
+}
+
+const STYLE: &str = "<style>
+@page { size: A4; margin: 20mm; }
+body { font-family: Helvetica, Arial, sans-serif; font-size: 11pt; color: #222; }
+h1 { font-size: 18pt; margin-bottom: 8mm; }
+dl { display: grid; grid-template-columns: max-content auto; gap: 1mm 6mm; }
+dt { font-weight: bold; }
+dd { margin: 0; }
+table { width: 100%; border-collapse: collapse; margin-top: 8mm; }
+th, td { padding: 2mm; border-bottom: 1px solid #ccc; text-align: left; }
+.num { text-align: right; }
+tfoot th, tfoot td { font-weight: bold; border-bottom: none; }
+</style>
+";
+
+/// Escape text for HTML element content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::InvoiceLineResponse;
+    use crate::domain::value_objects::{
+        CustomerId, InvoiceId, Money, OrderId, OrderItemId, ProductId,
+    };
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_html_lists_lines_and_escapes_text() {
+        let unit_price = Money::eur(Decimal::new(4990, 2)).unwrap();
+        let invoice = InvoiceResponse {
+            id: InvoiceId::new(),
+            number: "CN-2026-000003".to_string(),
+            kind: InvoiceKind::CreditNote,
+            order_id: OrderId::new(),
+            customer_id: CustomerId::new(),
+            credited_invoice_id: Some(InvoiceId::new()),
+            reason: Some("<script>alert(1)</script>".to_string()),
+            lines: vec![InvoiceLineResponse {
+                order_item_id: OrderItemId::new(),
+                product_id: ProductId::new(),
+                description: "Keyboard & mouse".to_string(),
+                quantity: 1,
+                unit_price,
+                subtotal: unit_price,
+            }],
+            total: unit_price,
+            issued_at: Utc::now(),
+        };
+
+        let html = render_html(&invoice);
+
+        assert!(html.contains("<h1>Credit note CN-2026-000003</h1>"));
+        assert!(html.contains("Keyboard &amp; mouse"));
+        assert!(html.contains("49.90 EUR"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+    }
+}
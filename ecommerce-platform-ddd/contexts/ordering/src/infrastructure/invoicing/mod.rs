@@ -0,0 +1,138 @@
+pub mod html;
+pub mod pdf;
+
+use crate::application::commands::{IssueInvoiceCommand, IssueInvoiceHandler};
+use crate::application::error::ApplicationError;
+use crate::domain::{events::OrderEvent, value_objects::Money};
+use crate::infrastructure::messaging::{EventEnvelope, EventSubscriber};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+pub use html::render_html;
+pub use pdf::render_pdf;
+
+/// Rendering of an invoice or credit note
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum InvoiceFormat {
+    /// `InvoiceResponse` read model
+    #[default]
+    Json,
+    /// Printable page (A4), for browsers and print-to-PDF
+    Html,
+    /// Ready-to-send PDF document
+    Pdf,
+}
+
+impl InvoiceFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            InvoiceFormat::Json => "application/json",
+            InvoiceFormat::Html => "text/html; charset=utf-8",
+            InvoiceFormat::Pdf => "application/pdf",
+        }
+    }
+}
+
+/// Invoices an order as soon as it is paid
+pub struct InvoiceOnPayment {
+    issue_invoice: Arc<IssueInvoiceHandler>,
+}
+
+impl InvoiceOnPayment {
+    pub fn new(issue_invoice: Arc<IssueInvoiceHandler>) -> Self {
+        Self { issue_invoice }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for InvoiceOnPayment {
+    fn name(&self) -> &'static str {
+        "invoicing"
+    }
+
+    async fn on_event(&self, envelope: &EventEnvelope) -> Result<(), ApplicationError> {
+        if let OrderEvent::OrderPaid { order_id, .. } = envelope.event {
+            self.issue_invoice
+                .handle(IssueInvoiceCommand {
+                    tenant_id: envelope.metadata.tenant_id.clone(),
+                    order_id,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Amount as printed on documents: two decimals and the currency code
+fn format_money(money: &Money) -> String {
+    format!("{:.2} {}", money.amount(), money.currency())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        repositories::{InvoiceRepository, OrderRepository},
+        value_objects::{CustomerId, ProductId, TenantId},
+    };
+    use crate::infrastructure::messaging::{
+        EventPublisher, NoOpEventPublisher, SubscribingEventPublisher,
+    };
+    use crate::infrastructure::persistence::repositories::{
+        InMemoryInvoiceRepository, InMemoryOrderRepository,
+    };
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_order_paid_issues_the_invoice() {
+        let invoices = Arc::new(InMemoryInvoiceRepository::new());
+        let orders = Arc::new(InMemoryOrderRepository::new());
+        let issue_invoice = Arc::new(IssueInvoiceHandler::new(
+            invoices.clone(),
+            orders.clone(),
+            Arc::new(UuidV4Generator),
+            Arc::new(SystemClock),
+        ));
+        let publisher = SubscribingEventPublisher::new(Arc::new(NoOpEventPublisher))
+            .with_subscriber(Arc::new(InvoiceOnPayment::new(issue_invoice)));
+
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            1,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        order.confirm(&SystemClock).unwrap();
+        order.mark_as_paid(Uuid::new_v4(), &SystemClock).unwrap();
+        orders.save(&mut order).await.unwrap();
+        for event in order.take_events() {
+            publisher.publish(&TenantId::default(), event).await.unwrap();
+        }
+
+        let issued = invoices
+            .find_by_order(&TenantId::default(), order.id())
+            .await
+            .unwrap();
+        assert_eq!(issued.len(), 1);
+        assert_eq!(issued[0].total(), order.total());
+    }
+}
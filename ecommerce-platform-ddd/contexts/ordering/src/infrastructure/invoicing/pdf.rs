@@ -0,0 +1,327 @@
+//! Minimal PDF 1.4 writer for invoices
+//!
+//! Text only, with the standard Helvetica fonts (no embedding), on A4 pages.
+//! Characters outside the WinAnsi encoding are printed as `?`.
+
+use super::{format_money, html::document_title};
+use crate::application::dto::InvoiceResponse;
+use std::fmt::Write;
+
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const TOP: u32 = 790;
+const BOTTOM: u32 = 70;
+
+/// Column positions of the line table
+const DESCRIPTION_X: u32 = 50;
+const QUANTITY_X: u32 = 330;
+const UNIT_PRICE_X: u32 = 380;
+const SUBTOTAL_X: u32 = 470;
+/// Longest description fitting before the quantity column
+const DESCRIPTION_CHARS: usize = 48;
+
+/// One line of text on the page
+struct Row {
+    height: u32,
+    size: u32,
+    bold: bool,
+    cells: Vec<(u32, String)>,
+}
+
+impl Row {
+    fn text(cells: Vec<(u32, String)>) -> Self {
+        Self {
+            height: 16,
+            size: 10,
+            bold: false,
+            cells,
+        }
+    }
+
+    fn bold(cells: Vec<(u32, String)>) -> Self {
+        Self {
+            bold: true,
+            ..Self::text(cells)
+        }
+    }
+
+    fn blank() -> Self {
+        Self::text(Vec::new())
+    }
+}
+
+/// PDF rendering of an invoice or credit note
+pub fn render_pdf(invoice: &InvoiceResponse) -> Vec<u8> {
+    let pages = paginate(rows(invoice));
+    let page_count = pages.len();
+
+    // Objects: 1 catalog, 2 page tree, 3-4 fonts, then a page and its content per page
+    let page_ids: Vec<usize> = (0..page_count).map(|i| 5 + 2 * i).collect();
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            page_count
+        )
+        .into_bytes(),
+        font("Helvetica"),
+        font("Helvetica-Bold"),
+    ];
+    for (index, rows) in pages.iter().enumerate() {
+        let content = page_content(rows, index + 1, page_count);
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                page_ids[index] + 1
+            )
+            .into_bytes(),
+        );
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(&content);
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, body) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend_from_slice(body);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(xref, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(
+        xref,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    );
+    pdf.extend_from_slice(xref.as_bytes());
+    pdf
+}
+
+fn rows(invoice: &InvoiceResponse) -> Vec<Row> {
+    let mut rows = vec![
+        Row {
+            height: 30,
+            size: 18,
+            bold: true,
+            cells: vec![(
+                DESCRIPTION_X,
+                format!("{} {}", document_title(invoice.kind), invoice.number),
+            )],
+        },
+        Row::text(vec![(
+            DESCRIPTION_X,
+            format!("Issued on: {}", invoice.issued_at.format("%Y-%m-%d")),
+        )]),
+        Row::text(vec![(
+            DESCRIPTION_X,
+            format!("Order: {}", invoice.order_id),
+        )]),
+        Row::text(vec![(
+            DESCRIPTION_X,
+            format!("Customer: {}", invoice.customer_id),
+        )]),
+    ];
+    if let Some(credited_invoice_id) = invoice.credited_invoice_id {
+        rows.push(Row::text(vec![(
+            DESCRIPTION_X,
+            format!("Credits invoice: {}", credited_invoice_id),
+        )]));
+    }
+    if let Some(reason) = &invoice.reason {
+        rows.push(Row::text(vec![(
+            DESCRIPTION_X,
+            format!("Reason: {}", reason),
+        )]));
+    }
+
+    rows.push(Row::blank());
+    rows.push(Row::bold(vec![
+        (DESCRIPTION_X, "Description".to_string()),
+        (QUANTITY_X, "Qty".to_string()),
+        (UNIT_PRICE_X, "Unit price".to_string()),
+        (SUBTOTAL_X, "Subtotal".to_string()),
+    ]));
+    for line in &invoice.lines {
+        rows.push(Row::text(vec![
+            (
+                DESCRIPTION_X,
+                truncate(&line.description, DESCRIPTION_CHARS),
+            ),
+            (QUANTITY_X, line.quantity.to_string()),
+            (UNIT_PRICE_X, format_money(&line.unit_price)),
+            (SUBTOTAL_X, format_money(&line.subtotal)),
+        ]));
+    }
+    rows.push(Row::blank());
+    rows.push(Row::bold(vec![
+        (UNIT_PRICE_X, "Total".to_string()),
+        (SUBTOTAL_X, format_money(&invoice.total)),
+    ]));
+    rows
+}
+
+/// Split the rows into pages, leaving room for the page footer
+fn paginate(rows: Vec<Row>) -> Vec<Vec<Row>> {
+    let mut pages = vec![Vec::new()];
+    let mut used = 0;
+    for row in rows {
+        if used + row.height > TOP - BOTTOM {
+            pages.push(Vec::new());
+            used = 0;
+        }
+        used += row.height;
+        pages.last_mut().expect("at least one page").push(row);
+    }
+    pages
+}
+
+fn page_content(rows: &[Row], page: usize, page_count: usize) -> Vec<u8> {
+    let mut content = Vec::new();
+    let mut y = TOP;
+    for row in rows {
+        y -= row.height;
+        let font = if row.bold { "F2" } else { "F1" };
+        for (x, text) in &row.cells {
+            show_text(&mut content, font, row.size, *x, y, text);
+        }
+    }
+    show_text(
+        &mut content,
+        "F1",
+        8,
+        DESCRIPTION_X,
+        BOTTOM - 30,
+        &format!("Page {} / {}", page, page_count),
+    );
+    content
+}
+
+fn show_text(content: &mut Vec<u8>, font: &str, size: u32, x: u32, y: u32, text: &str) {
+    content.extend_from_slice(format!("BT /{} {} Tf {} {} Td (", font, size, x, y).as_bytes());
+    content.extend_from_slice(&encode(text));
+    content.extend_from_slice(b") Tj ET\n");
+}
+
+fn font(base: &str) -> Vec<u8> {
+    format!(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+        base
+    )
+    .into_bytes()
+}
+
+/// Text as a PDF literal string body in WinAnsi encoding
+fn encode(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                bytes.push(b'\\');
+                bytes.push(c as u8);
+            }
+            ' '..='~' => bytes.push(c as u8),
+            // Latin-1 letters share their code with WinAnsi
+            '\u{a0}'..='\u{ff}' => {
+                bytes.extend_from_slice(format!("\\{:03o}", c as u32).as_bytes())
+            }
+            '€' => bytes.extend_from_slice(b"\\200"),
+            _ => bytes.push(b'?'),
+        }
+    }
+    bytes
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars - 3).collect();
+    truncated.push_str("...");
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::InvoiceLineResponse;
+    use crate::domain::value_objects::{
+        CustomerId, InvoiceId, InvoiceKind, Money, OrderId, OrderItemId, ProductId,
+    };
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    fn invoice(lines: usize) -> InvoiceResponse {
+        let unit_price = Money::eur(Decimal::new(4990, 2)).unwrap();
+        InvoiceResponse {
+            id: InvoiceId::new(),
+            number: "INV-2026-000001".to_string(),
+            kind: InvoiceKind::Invoice,
+            order_id: OrderId::new(),
+            customer_id: CustomerId::new(),
+            credited_invoice_id: None,
+            reason: None,
+            lines: (0..lines)
+                .map(|i| InvoiceLineResponse {
+                    order_item_id: OrderItemId::new(),
+                    product_id: ProductId::new(),
+                    description: format!("Clavier (modèle {})", i),
+                    quantity: 1,
+                    unit_price,
+                    subtotal: unit_price,
+                })
+                .collect(),
+            total: unit_price,
+            issued_at: Utc::now(),
+        }
+    }
+
+    fn find(pdf: &[u8], needle: &str) -> Option<usize> {
+        pdf.windows(needle.len())
+            .rposition(|w| w == needle.as_bytes())
+    }
+
+    #[test]
+    fn test_pdf_is_well_formed() {
+        let pdf = render_pdf(&invoice(1));
+
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(find(&pdf, "(Invoice INV-2026-000001) Tj").is_some());
+        assert!(find(&pdf, "(Clavier \\(mod\\350le 0\\)) Tj").is_some());
+
+        // The cross-reference table points at each object
+        let xref = find(&pdf, "endobj\nxref\n").unwrap() + "endobj\n".len();
+        let trailer = std::str::from_utf8(&pdf[xref..]).unwrap();
+        let startxref: usize = trailer.lines().rev().nth(1).unwrap().parse().unwrap();
+        assert_eq!(startxref, xref);
+        for (index, entry) in trailer.lines().skip(3).take(6).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_long_invoices_span_several_pages() {
+        let pdf = render_pdf(&invoice(50));
+
+        assert!(find(&pdf, "/Count 2").is_some());
+        assert!(find(&pdf, "(Page 2 / 2) Tj").is_some());
+    }
+}
@@ -0,0 +1,300 @@
+use super::{EventEnvelope, EventSubscriber};
+use crate::application::error::ApplicationError;
+use crate::domain::{
+    events::OrderEvent,
+    repositories::OrderRepository,
+    value_objects::{CustomerId, OrderId, TenantId},
+};
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::Uuid;
+
+/// Events kept for clients resuming a stream with `Last-Event-ID`
+pub const DEFAULT_REPLAY_CAPACITY: usize = 1024;
+
+/// Event published by the context, with the customer of its order
+#[derive(Debug)]
+pub struct StreamedEvent {
+    /// `None` when the order could not be read when the event was published
+    pub customer_id: Option<CustomerId>,
+    pub envelope: EventEnvelope,
+}
+
+/// Events followed by a client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamFilter {
+    Order(OrderId),
+    /// Orders of a customer on one storefront
+    Customer(TenantId, CustomerId),
+}
+
+impl StreamFilter {
+    fn matches(&self, event: &StreamedEvent) -> bool {
+        match self {
+            StreamFilter::Order(order_id) => event.envelope.event.order_id() == *order_id,
+            StreamFilter::Customer(tenant_id, customer_id) => {
+                event.envelope.metadata.tenant_id == *tenant_id
+                    && event.customer_id == Some(*customer_id)
+            }
+        }
+    }
+}
+
+/// Next item of a client stream
+#[derive(Debug, Clone)]
+pub enum StreamItem {
+    Event(Arc<StreamedEvent>),
+    /// Events were missed (resume point too old, or client too slow): the client must
+    /// reload the current state before relying on the stream again
+    Resync,
+}
+
+/// In-process fan-out of the published events to the real-time clients (SSE, WebSocket)
+///
+/// Attached to the publisher pipeline as a subscriber. The latest events are kept so
+/// that a reconnecting client gets what it missed, from the last event id it saw.
+pub struct OrderEventHub {
+    sender: broadcast::Sender<Arc<StreamedEvent>>,
+    recent: Mutex<VecDeque<Arc<StreamedEvent>>>,
+    capacity: usize,
+    order_repository: Arc<dyn OrderRepository>,
+}
+
+impl OrderEventHub {
+    pub fn new(order_repository: Arc<dyn OrderRepository>, capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            sender,
+            recent: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            order_repository,
+        }
+    }
+
+    /// Follow the events matching `filter`, starting after `last_event_id` if given
+    pub fn subscribe(&self, filter: StreamFilter, last_event_id: Option<Uuid>) -> EventStream {
+        // Under the lock, every event is either in the replay or received live, not both
+        let recent = self.recent.lock().expect("event hub lock poisoned");
+        let live = self.sender.subscribe();
+
+        let mut pending = VecDeque::new();
+        if let Some(last_event_id) = last_event_id {
+            match recent
+                .iter()
+                .position(|event| event.envelope.metadata.event_id == last_event_id)
+            {
+                Some(position) => pending.extend(
+                    recent
+                        .iter()
+                        .skip(position + 1)
+                        .filter(|event| filter.matches(event))
+                        .cloned()
+                        .map(StreamItem::Event),
+                ),
+                None => pending.push_back(StreamItem::Resync),
+            }
+        }
+
+        EventStream {
+            filter,
+            pending,
+            live,
+        }
+    }
+
+    /// Customer of the order, for the customer streams
+    async fn customer_of(&self, envelope: &EventEnvelope) -> Option<CustomerId> {
+        let event = &envelope.event;
+        if let OrderEvent::OrderCreated { customer_id, .. } = event {
+            return Some(*customer_id);
+        }
+        match self
+            .order_repository
+            .find_by_id(&envelope.metadata.tenant_id, event.order_id())
+            .await
+        {
+            Ok(order) => order.map(|order| order.customer_id()),
+            Err(err) => {
+                tracing::warn!("Cannot read order {}: {}", event.order_id(), err);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for OrderEventHub {
+    fn name(&self) -> &'static str {
+        "event-hub"
+    }
+
+    async fn on_event(&self, envelope: &EventEnvelope) -> Result<(), ApplicationError> {
+        let event = Arc::new(StreamedEvent {
+            customer_id: self.customer_of(envelope).await,
+            envelope: envelope.clone(),
+        });
+
+        let mut recent = self.recent.lock().expect("event hub lock poisoned");
+        if recent.len() == self.capacity {
+            recent.pop_front();
+        }
+        recent.push_back(event.clone());
+        // No receiver is not an error: nobody is streaming right now
+        let _ = self.sender.send(event);
+        Ok(())
+    }
+}
+
+/// Events of one client: the replayed ones first, then the live ones
+pub struct EventStream {
+    filter: StreamFilter,
+    pending: VecDeque<StreamItem>,
+    live: broadcast::Receiver<Arc<StreamedEvent>>,
+}
+
+impl EventStream {
+    /// `None` once the hub is gone
+    pub async fn next(&mut self) -> Option<StreamItem> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+        loop {
+            match self.live.recv().await {
+                Ok(event) if self.filter.matches(&event) => return Some(StreamItem::Event(event)),
+                Ok(_) => continue,
+                Err(RecvError::Lagged(_)) => return Some(StreamItem::Resync),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tenant::Tenant;
+    use crate::domain::{
+        aggregates::Order,
+        clock::SystemClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        value_objects::{Money, ProductId},
+    };
+    use crate::infrastructure::persistence::InMemoryOrderRepository;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    async fn saved_order(repo: &InMemoryOrderRepository) -> Order {
+        let item = OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            1,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap();
+        let mut order = Order::create(
+            &Tenant::default(),
+            CustomerId::new(),
+            vec![item],
+            &UuidV4Generator,
+            &SystemClock,
+        )
+        .unwrap();
+        repo.save(&mut order).await.unwrap();
+        order
+    }
+
+    fn confirmed(order_id: OrderId) -> EventEnvelope {
+        EventEnvelope::new(
+            TenantId::default(),
+            OrderEvent::OrderConfirmed {
+                order_id,
+                timestamp: Utc::now(),
+            },
+        )
+    }
+
+    fn event_id(item: Option<StreamItem>) -> Uuid {
+        match item {
+            Some(StreamItem::Event(event)) => event.envelope.metadata.event_id,
+            other => panic!("expected an event, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_live_events_are_filtered_by_order_and_customer() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let order = saved_order(&repo).await;
+        let hub = OrderEventHub::new(repo.clone(), 16);
+        let mut by_order = hub.subscribe(StreamFilter::Order(order.id()), None);
+        let mut by_customer = hub.subscribe(
+            StreamFilter::Customer(TenantId::default(), order.customer_id()),
+            None,
+        );
+
+        let other = confirmed(OrderId::new());
+        let mine = confirmed(order.id());
+        hub.on_event(&other).await.unwrap();
+        hub.on_event(&mine).await.unwrap();
+
+        assert_eq!(event_id(by_order.next().await), mine.metadata.event_id);
+        // The customer is read from the repository for events that do not carry it
+        assert_eq!(event_id(by_customer.next().await), mine.metadata.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_resume_replays_the_events_after_the_last_seen_one() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let order = saved_order(&repo).await;
+        let hub = OrderEventHub::new(repo, 16);
+        let events: Vec<_> = (0..3).map(|_| confirmed(order.id())).collect();
+        for event in &events {
+            hub.on_event(event).await.unwrap();
+        }
+
+        let mut stream = hub.subscribe(
+            StreamFilter::Order(order.id()),
+            Some(events[0].metadata.event_id),
+        );
+        assert_eq!(event_id(stream.next().await), events[1].metadata.event_id);
+        assert_eq!(event_id(stream.next().await), events[2].metadata.event_id);
+
+        let live = confirmed(order.id());
+        hub.on_event(&live).await.unwrap();
+        assert_eq!(event_id(stream.next().await), live.metadata.event_id);
+    }
+
+    #[tokio::test]
+    async fn test_resume_point_no_longer_kept_asks_for_a_resync() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let order = saved_order(&repo).await;
+        let hub = OrderEventHub::new(repo, 2);
+        let first = confirmed(order.id());
+        hub.on_event(&first).await.unwrap();
+        for _ in 0..2 {
+            hub.on_event(&confirmed(order.id())).await.unwrap();
+        }
+
+        let mut stream = hub.subscribe(
+            StreamFilter::Order(order.id()),
+            Some(first.metadata.event_id),
+        );
+        assert!(matches!(stream.next().await, Some(StreamItem::Resync)));
+    }
+
+    #[tokio::test]
+    async fn test_slow_client_is_asked_to_resync() {
+        let repo = Arc::new(InMemoryOrderRepository::new());
+        let order = saved_order(&repo).await;
+        let hub = OrderEventHub::new(repo, 2);
+        let mut stream = hub.subscribe(StreamFilter::Order(order.id()), None);
+        for _ in 0..3 {
+            hub.on_event(&confirmed(order.id())).await.unwrap();
+        }
+
+        assert!(matches!(stream.next().await, Some(StreamItem::Resync)));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::domain::{events::OrderEvent, value_objects::TenantId};
+use crate::infrastructure::observability::CorrelationId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Technical metadata travelling with a domain event on the bus
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventMetadata {
+    pub event_id: Uuid,
+    /// Tenant owning the order; `default` for events published before multi-tenancy
+    #[serde(default)]
+    pub tenant_id: TenantId,
+    /// Correlation ID of the request that produced the event
+    pub correlation_id: Option<CorrelationId>,
+    pub published_at: DateTime<Utc>,
+}
+
+/// Message published to the broker: metadata + domain event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub metadata: EventMetadata,
+    pub event: OrderEvent,
+}
+
+impl EventEnvelope {
+    /// Wrap an event of a `tenant_id` order, capturing the correlation ID of the current request
+    pub fn new(tenant_id: TenantId, event: OrderEvent) -> Self {
+        Self {
+            metadata: EventMetadata {
+                event_id: Uuid::new_v4(),
+                tenant_id,
+                correlation_id: CorrelationId::current(),
+                published_at: Utc::now(),
+            },
+            event,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::OrderId;
+
+    fn event() -> OrderEvent {
+        OrderEvent::OrderConfirmed {
+            order_id: OrderId::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_envelope_captures_current_correlation_id() {
+        let correlation_id = CorrelationId::from("req-42".to_string());
+
+        let envelope = correlation_id
+            .clone()
+            .scope(async { EventEnvelope::new(TenantId::default(), event()) })
+            .await;
+
+        assert_eq!(envelope.metadata.correlation_id, Some(correlation_id));
+        assert!(EventEnvelope::new(TenantId::default(), event())
+            .metadata
+            .correlation_id
+            .is_none());
+    }
+
+    #[test]
+    fn test_envelope_serialization() {
+        let envelope = EventEnvelope::new("acme".parse().unwrap(), event());
+        let json = serde_json::to_value(&envelope).unwrap();
+
+        assert_eq!(json["event"]["type"], "ORDER_CONFIRMED");
+        assert!(json["metadata"]["event_id"].is_string());
+        assert_eq!(json["metadata"]["tenant_id"], "acme");
+    }
+
+    #[test]
+    fn test_envelope_without_tenant_belongs_to_the_default_tenant() {
+        let mut json =
+            serde_json::to_value(EventEnvelope::new(TenantId::default(), event())).unwrap();
+        json["metadata"]
+            .as_object_mut()
+            .unwrap()
+            .remove("tenant_id");
+
+        let envelope: EventEnvelope = serde_json::from_value(json).unwrap();
+        assert_eq!(envelope.metadata.tenant_id, TenantId::default());
+    }
+}
@@ -0,0 +1,39 @@
+use super::{EventEnvelope, EventPublisher};
+use crate::domain::errors::InfrastructureError;
+use crate::infrastructure::observability::Metrics;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Decorator recording status transitions and publish failures
+pub struct InstrumentedEventPublisher {
+    inner: Arc<dyn EventPublisher>,
+    metrics: Arc<Metrics>,
+}
+
+impl InstrumentedEventPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>, metrics: Arc<Metrics>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InstrumentedEventPublisher {
+    async fn publish_envelope(&self, envelope: EventEnvelope) -> Result<(), InfrastructureError> {
+        let event_name = envelope.event.event_name();
+        // The transition is already persisted, whatever happens to the publication
+        if let Some(status) = envelope.event.status() {
+            self.metrics.record_transition(status);
+        }
+
+        let result = self.inner.publish_envelope(envelope).await;
+        if let Err(err) = &result {
+            tracing::warn!("Failed to publish {}: {}", event_name, err);
+            self.metrics.record_publish_failure(event_name);
+        }
+        result
+    }
+
+    async fn flush(&self) -> Result<(), InfrastructureError> {
+        self.inner.flush().await
+    }
+}
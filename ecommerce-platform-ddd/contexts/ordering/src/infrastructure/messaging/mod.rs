@@ -1,28 +1,75 @@
-use crate::domain::{events::OrderEvent, errors::DomainError};
+pub mod broadcast;
+pub mod envelope;
+pub mod instrumented;
+pub mod subscribing;
+
+use crate::domain::{events::OrderEvent, errors::InfrastructureError, value_objects::TenantId};
+use crate::infrastructure::config::BrokerSettings;
 use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub use broadcast::{EventStream, OrderEventHub, StreamFilter, StreamItem, StreamedEvent};
+pub use envelope::{EventEnvelope, EventMetadata};
+pub use instrumented::InstrumentedEventPublisher;
+pub use subscribing::{EventSubscriber, SubscribingEventPublisher};
 
 /// Trait for publishing domain events
 #[async_trait]
 pub trait EventPublisher: Send + Sync {
-    async fn publish(&self, event: OrderEvent) -> Result<(), DomainError>;
+    /// Publish an event together with its metadata
+    async fn publish_envelope(&self, envelope: EventEnvelope) -> Result<(), InfrastructureError>;
+
+    /// Publish an event of a `tenant_id` order, with metadata taken from the current request
+    /// context
+    async fn publish(
+        &self,
+        tenant_id: &TenantId,
+        event: OrderEvent,
+    ) -> Result<(), InfrastructureError> {
+        self.publish_envelope(EventEnvelope::new(tenant_id.clone(), event))
+            .await
+    }
+
+    /// Deliver buffered events before shutdown
+    async fn flush(&self) -> Result<(), InfrastructureError> {
+        Ok(())
+    }
 }
 
 /// Iggy implementation (à compléter avec vraie connexion Iggy)
 pub struct IggyEventPublisher {
     // client: IggyClient
+    settings: BrokerSettings,
 }
 
 impl IggyEventPublisher {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(settings: BrokerSettings) -> Self {
+        Self { settings }
     }
 }
 
 #[async_trait]
 impl EventPublisher for IggyEventPublisher {
-    async fn publish(&self, event: OrderEvent) -> Result<(), DomainError> {
+    async fn publish_envelope(&self, envelope: EventEnvelope) -> Result<(), InfrastructureError> {
         // TODO: Implement actual Iggy publishing
-        tracing::info!("Publishing event: {:?}", event);
+        let payload = serde_json::to_string(&envelope).map_err(|err| {
+            InfrastructureError::messaging(format!("event {}", envelope.metadata.event_id))
+                .with_source(err)
+        })?;
+        tracing::info!(
+            "Publishing event to {}/{} on {}: {}",
+            self.settings.stream,
+            self.settings.topic,
+            self.settings.address,
+            payload
+        );
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), InfrastructureError> {
+        // TODO: Flush the Iggy producer once the client is connected
+        tracing::info!("Flushing events to {}", self.settings.address);
         Ok(())
     }
 }
@@ -32,7 +79,31 @@ pub struct NoOpEventPublisher;
 
 #[async_trait]
 impl EventPublisher for NoOpEventPublisher {
-    async fn publish(&self, _event: OrderEvent) -> Result<(), DomainError> {
+    async fn publish_envelope(&self, _envelope: EventEnvelope) -> Result<(), InfrastructureError> {
+        Ok(())
+    }
+}
+
+/// In-memory publisher keeping every published envelope (for testing)
+#[derive(Default)]
+pub struct InMemoryEventPublisher {
+    published: Arc<RwLock<Vec<EventEnvelope>>>,
+}
+
+impl InMemoryEventPublisher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn published(&self) -> Vec<EventEnvelope> {
+        self.published.read().await.clone()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for InMemoryEventPublisher {
+    async fn publish_envelope(&self, envelope: EventEnvelope) -> Result<(), InfrastructureError> {
+        self.published.write().await.push(envelope);
         Ok(())
     }
 }
@@ -0,0 +1,120 @@
+use super::{EventEnvelope, EventPublisher};
+use crate::application::error::ApplicationError;
+use crate::domain::errors::InfrastructureError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// In-process reaction to the events published by the context
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &'static str;
+
+    async fn on_event(&self, envelope: &EventEnvelope) -> Result<(), ApplicationError>;
+}
+
+/// Decorator handing every published event to in-process subscribers
+///
+/// Subscribers run after the event is handed to the inner publisher, even if that
+/// fails: the state change behind the event is already persisted. A failing
+/// subscriber is logged and does not fail the command that produced the event.
+pub struct SubscribingEventPublisher {
+    inner: Arc<dyn EventPublisher>,
+    subscribers: Vec<Arc<dyn EventSubscriber>>,
+}
+
+impl SubscribingEventPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>) -> Self {
+        Self {
+            inner,
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn with_subscriber(mut self, subscriber: Arc<dyn EventSubscriber>) -> Self {
+        self.subscribers.push(subscriber);
+        self
+    }
+}
+
+#[async_trait]
+impl EventPublisher for SubscribingEventPublisher {
+    async fn publish_envelope(&self, envelope: EventEnvelope) -> Result<(), InfrastructureError> {
+        let result = self.inner.publish_envelope(envelope.clone()).await;
+
+        for subscriber in &self.subscribers {
+            if let Err(err) = subscriber.on_event(&envelope).await {
+                tracing::error!(
+                    "Subscriber {} failed on {}: {}",
+                    subscriber.name(),
+                    envelope.event.event_name(),
+                    err.report()
+                );
+            }
+        }
+        result
+    }
+
+    async fn flush(&self) -> Result<(), InfrastructureError> {
+        self.inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::events::OrderEvent;
+    use crate::domain::value_objects::{OrderId, TenantId};
+    use crate::infrastructure::messaging::InMemoryEventPublisher;
+    use chrono::Utc;
+    use tokio::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        seen: Mutex<Vec<&'static str>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl EventSubscriber for Recorder {
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+
+        async fn on_event(&self, envelope: &EventEnvelope) -> Result<(), ApplicationError> {
+            self.seen.lock().await.push(envelope.event.event_name());
+            if self.fail {
+                return Err(InfrastructureError::storage("unavailable").into());
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_sees_the_event_even_when_one_fails() {
+        let inner = Arc::new(InMemoryEventPublisher::new());
+        let failing = Arc::new(Recorder {
+            fail: true,
+            ..Recorder::default()
+        });
+        let recorder = Arc::new(Recorder::default());
+        let publisher = SubscribingEventPublisher::new(inner.clone())
+            .with_subscriber(failing.clone())
+            .with_subscriber(recorder.clone());
+
+        publisher
+            .publish(
+                &TenantId::default(),
+                OrderEvent::OrderConfirmed {
+                    order_id: OrderId::new(),
+                    timestamp: Utc::now(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(inner.published().await.len(), 1);
+        assert_eq!(*failing.seen.lock().await, vec!["ORDER_CONFIRMED"]);
+        assert_eq!(*recorder.seen.lock().await, vec!["ORDER_CONFIRMED"]);
+    }
+}
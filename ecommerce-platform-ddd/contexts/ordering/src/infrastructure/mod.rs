@@ -1,5 +1,22 @@
+pub mod api;
+pub mod audit;
+pub mod bootstrap;
+pub mod bulk;
+pub mod catalog;
+pub mod config;
+pub mod crypto;
+pub mod grpc;
+pub mod health;
+pub mod idempotency;
+pub mod invoicing;
 pub mod messaging;
+pub mod observability;
 pub mod persistence;
+pub mod pricing;
+pub mod risk;
+pub mod scheduler;
+pub mod tenancy;
+pub mod webhooks;
 
 pub use messaging::EventPublisher;
 pub use persistence::*;
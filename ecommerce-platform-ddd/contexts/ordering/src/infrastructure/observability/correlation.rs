@@ -0,0 +1,83 @@
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use uuid::Uuid;
+
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+const MAX_CORRELATION_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static CURRENT: CorrelationId;
+}
+
+/// Identifier shared by every log line and event produced by one request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CorrelationId(String);
+
+impl CorrelationId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    /// Correlation ID of the request being processed, if any
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    /// Run a future with this correlation ID as the current one
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Accept a client-provided ID only if it is a reasonable header token
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let valid = !value.is_empty()
+            && value.len() <= MAX_CORRELATION_ID_LENGTH
+            && value.chars().all(|c| c.is_ascii_graphic());
+        valid.then(|| Self(value.to_string()))
+    }
+}
+
+impl Default for CorrelationId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<String> for CorrelationId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl std::fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Middleware: reuse or generate `X-Correlation-Id`, expose it to the request
+/// extensions and to the task, and echo it in the response
+pub async fn correlation_middleware(mut request: Request, next: Next) -> Response {
+    let correlation_id = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(CorrelationId::parse)
+        .unwrap_or_default();
+
+    request.extensions_mut().insert(correlation_id.clone());
+    let mut response = correlation_id.clone().scope(next.run(request)).await;
+
+    if let Ok(value) = HeaderValue::from_str(correlation_id.as_str()) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    response
+}
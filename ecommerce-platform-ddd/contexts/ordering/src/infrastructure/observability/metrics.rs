@@ -0,0 +1,151 @@
+use crate::domain::value_objects::OrderStatus;
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Prometheus metrics of the ordering service
+pub struct Metrics {
+    registry: Registry,
+    order_transitions: IntCounterVec,
+    publish_failures: IntCounterVec,
+    http_request_duration: HistogramVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new_custom(Some("ordering".to_string()), None)
+            .expect("metrics prefix is valid");
+
+        let order_transitions = IntCounterVec::new(
+            Opts::new(
+                "order_status_transitions_total",
+                "Orders entering each status",
+            ),
+            &["status"],
+        )
+        .expect("metric definition is valid");
+        let publish_failures = IntCounterVec::new(
+            Opts::new(
+                "event_publish_failures_total",
+                "Domain events that could not be published",
+            ),
+            &["event"],
+        )
+        .expect("metric definition is valid");
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP handler latency"),
+            &["method", "route", "status"],
+        )
+        .expect("metric definition is valid");
+
+        for collector in [
+            Box::new(order_transitions.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(publish_failures.clone()),
+            Box::new(http_request_duration.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metrics are registered once");
+        }
+
+        Self {
+            registry,
+            order_transitions,
+            publish_failures,
+            http_request_duration,
+        }
+    }
+
+    pub fn record_transition(&self, status: OrderStatus) {
+        self.order_transitions
+            .with_label_values(&[status.to_string().as_str()])
+            .inc();
+    }
+
+    pub fn record_publish_failure(&self, event_name: &str) {
+        self.publish_failures.with_label_values(&[event_name]).inc();
+    }
+
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        self.http_request_duration
+            .with_label_values(&[method, route, status.to_string().as_str()])
+            .observe(seconds);
+    }
+
+    /// Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("text encoding cannot fail");
+        String::from_utf8(buffer).expect("text encoding produces UTF-8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Middleware: handler latency by method, route template and status
+pub async fn metrics_middleware(
+    State(metrics): State<Arc<Metrics>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+
+    metrics.observe_request(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed().as_secs_f64(),
+    );
+    response
+}
+
+/// GET /metrics
+pub async fn metrics_handler(State(metrics): State<Arc<Metrics>>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics.render(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_exposes_recorded_metrics() {
+        let metrics = Metrics::new();
+        metrics.record_transition(OrderStatus::Pending);
+        metrics.record_transition(OrderStatus::Pending);
+        metrics.record_publish_failure("ORDER_CREATED");
+        metrics.observe_request("POST", "/api/orders", 201, 0.012);
+
+        let output = metrics.render();
+        assert!(output.contains("ordering_order_status_transitions_total{status=\"PENDING\"} 2"));
+        assert!(output.contains("ordering_event_publish_failures_total{event=\"ORDER_CREATED\"} 1"));
+        assert!(output.contains(
+            "ordering_http_request_duration_seconds_count{method=\"POST\",route=\"/api/orders\",status=\"201\"} 1"
+        ));
+    }
+}
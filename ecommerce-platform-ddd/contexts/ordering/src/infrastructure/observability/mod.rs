@@ -0,0 +1,27 @@
+pub mod correlation;
+pub mod metrics;
+
+use crate::infrastructure::config::{LogFormat, LogSettings};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+pub use correlation::{correlation_middleware, CorrelationId, CORRELATION_ID_HEADER};
+pub use metrics::{metrics_handler, metrics_middleware, Metrics};
+
+/// Install the global tracing subscriber (human-readable or JSON lines)
+pub fn init_tracing(settings: &LogSettings) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(&settings.filter)?;
+    let registry = tracing_subscriber::registry().with(filter);
+
+    match settings.format {
+        LogFormat::Pretty => registry.with(tracing_subscriber::fmt::layer()).try_init()?,
+        LogFormat::Json => registry
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_current_span(true)
+                    .with_span_list(false),
+            )
+            .try_init()?,
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "invoices")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub number: String,
+    pub kind: String,
+    pub year: i32,
+    pub sequence: i32,
+    pub order_id: Uuid,
+    pub customer_id: Uuid,
+    pub currency: String,
+    pub credited_invoice_id: Option<Uuid>,
+    pub reason: Option<String>,
+    pub issued_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::invoice_line::Entity")]
+    InvoiceLines,
+}
+
+impl Related<super::invoice_line::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InvoiceLines.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
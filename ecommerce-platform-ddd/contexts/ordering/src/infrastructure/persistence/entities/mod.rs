@@ -0,0 +1,17 @@
+// SeaORM entities (database models, not domain objects)
+pub mod audit_entry;
+pub mod cart;
+pub mod cart_line;
+pub mod erasure_request;
+pub mod idempotency_key;
+pub mod invoice;
+pub mod invoice_line;
+pub mod invoice_sequence;
+pub mod order;
+pub mod order_item;
+pub mod shipment;
+pub mod shipment_line;
+pub mod webhook_delivery;
+pub mod webhook_subscription;
+pub mod wishlist;
+pub mod wishlist_item;
@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "orders")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub tenant_id: String,
+    pub customer_id: Uuid,
+    pub status: String,
+    pub total_amount: Decimal,
+    pub currency: String,
+    pub tax_rate: Decimal,
+    pub tax_included: bool,
+    /// `PostalAddress` as JSON
+    pub billing_address: Option<String>,
+    pub shipping_address: Option<String>,
+    pub created_at: DateTimeUtc,
+    pub updated_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::order_item::Entity")]
+    OrderItems,
+    #[sea_orm(has_many = "super::shipment::Entity")]
+    Shipments,
+}
+
+impl Related<super::order_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrderItems.def()
+    }
+}
+
+impl Related<super::shipment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Shipments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "shipment_lines")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub shipment_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub order_item_id: Uuid,
+    pub order_id: Uuid,
+    pub position: i32,
+    pub quantity: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::shipment::Entity",
+        from = "Column::ShipmentId",
+        to = "super::shipment::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Shipment,
+}
+
+impl Related<super::shipment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Shipment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
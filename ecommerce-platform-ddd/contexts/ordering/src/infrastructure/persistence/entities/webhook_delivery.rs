@@ -0,0 +1,37 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_name: String,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub duration_ms: i64,
+    pub attempted_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::webhook_subscription::Entity",
+        from = "Column::SubscriptionId",
+        to = "super::webhook_subscription::Column::Id",
+        on_delete = "Cascade"
+    )]
+    WebhookSubscription,
+}
+
+impl Related<super::webhook_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookSubscription.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
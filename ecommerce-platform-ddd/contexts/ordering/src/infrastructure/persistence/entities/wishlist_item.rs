@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "wishlist_items")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tenant_id: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub customer_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub product_id: Uuid,
+    pub position: i32,
+    pub product_name: String,
+    pub note: Option<String>,
+    pub priority: String,
+    pub added_at: DateTimeUtc,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::wishlist::Entity",
+        from = "(Column::TenantId, Column::CustomerId)",
+        to = "(super::wishlist::Column::TenantId, super::wishlist::Column::CustomerId)",
+        on_delete = "Cascade"
+    )]
+    Wishlist,
+}
+
+impl Related<super::wishlist::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Wishlist.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Orders::Table)
+                    .if_not_exists()
+                    .col(uuid(Orders::Id).primary_key())
+                    .col(uuid(Orders::CustomerId))
+                    .col(string_len(Orders::Status, 32))
+                    .col(decimal_len(Orders::TotalAmount, 19, 4))
+                    .col(string_len(Orders::Currency, 3))
+                    .col(timestamp_with_time_zone(Orders::CreatedAt))
+                    .col(timestamp_with_time_zone(Orders::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_customer_id")
+                    .table(Orders::Table)
+                    .col(Orders::CustomerId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrderItems::Table)
+                    .if_not_exists()
+                    .col(uuid(OrderItems::Id).primary_key())
+                    .col(uuid(OrderItems::OrderId))
+                    .col(integer(OrderItems::Position))
+                    .col(uuid(OrderItems::ProductId))
+                    .col(string(OrderItems::ProductName))
+                    .col(integer(OrderItems::Quantity))
+                    .col(decimal_len(OrderItems::UnitPrice, 19, 4))
+                    .col(string_len(OrderItems::Currency, 3))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_order_items_order_id")
+                            .from(OrderItems::Table, OrderItems::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrderItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Orders::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    Id,
+    CustomerId,
+    Status,
+    TotalAmount,
+    Currency,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    Id,
+    OrderId,
+    Position,
+    ProductId,
+    ProductName,
+    Quantity,
+    UnitPrice,
+    Currency,
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEntries::Table)
+                    .if_not_exists()
+                    .col(uuid(AuditEntries::Id).primary_key())
+                    .col(uuid(AuditEntries::OrderId))
+                    .col(string(AuditEntries::Actor))
+                    .col(string_len(AuditEntries::Command, 64))
+                    .col(string_len_null(AuditEntries::StatusBefore, 32))
+                    .col(string_len(AuditEntries::StatusAfter, 32))
+                    .col(text_null(AuditEntries::Reason))
+                    .col(text(AuditEntries::Events))
+                    .col(timestamp_with_time_zone(AuditEntries::OccurredAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_entries_order_id")
+                    .table(AuditEntries::Table)
+                    .col(AuditEntries::OrderId)
+                    .col(AuditEntries::OccurredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEntries {
+    Table,
+    Id,
+    OrderId,
+    Actor,
+    Command,
+    StatusBefore,
+    StatusAfter,
+    Reason,
+    Events,
+    OccurredAt,
+}
@@ -0,0 +1,117 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Parcels of multi-parcel orders and the item quantities they carry
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Shipments::Table)
+                    .if_not_exists()
+                    .col(uuid(Shipments::Id).primary_key())
+                    .col(uuid(Shipments::OrderId))
+                    .col(integer(Shipments::Position))
+                    .col(string_len(Shipments::Status, 32))
+                    .col(string_null(Shipments::TrackingNumber))
+                    .col(timestamp_with_time_zone(Shipments::CreatedAt))
+                    .col(timestamp_with_time_zone_null(Shipments::ShippedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_shipments_order_id")
+                            .from(Shipments::Table, Shipments::OrderId)
+                            .to(Orders::Table, Orders::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_shipments_order_id")
+                    .table(Shipments::Table)
+                    .col(Shipments::OrderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ShipmentLines::Table)
+                    .if_not_exists()
+                    .col(uuid(ShipmentLines::ShipmentId))
+                    .col(uuid(ShipmentLines::OrderItemId))
+                    .col(uuid(ShipmentLines::OrderId))
+                    .col(integer(ShipmentLines::Position))
+                    .col(integer(ShipmentLines::Quantity))
+                    .primary_key(
+                        Index::create()
+                            .col(ShipmentLines::ShipmentId)
+                            .col(ShipmentLines::OrderItemId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_shipment_lines_shipment_id")
+                            .from(ShipmentLines::Table, ShipmentLines::ShipmentId)
+                            .to(Shipments::Table, Shipments::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_shipment_lines_order_id")
+                    .table(ShipmentLines::Table)
+                    .col(ShipmentLines::OrderId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ShipmentLines::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Shipments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Shipments {
+    Table,
+    Id,
+    OrderId,
+    Position,
+    Status,
+    TrackingNumber,
+    CreatedAt,
+    ShippedAt,
+}
+
+#[derive(DeriveIden)]
+enum ShipmentLines {
+    Table,
+    ShipmentId,
+    OrderItemId,
+    /// Denormalized to load and replace the lines of an order in one query
+    OrderId,
+    Position,
+    Quantity,
+}
@@ -0,0 +1,105 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Shopping carts, anonymous or owned by a customer, and their lines
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Carts::Table)
+                    .if_not_exists()
+                    .col(uuid(Carts::Id).primary_key())
+                    .col(uuid_null(Carts::CustomerId))
+                    .col(timestamp_with_time_zone(Carts::CreatedAt))
+                    .col(timestamp_with_time_zone(Carts::UpdatedAt))
+                    .col(timestamp_with_time_zone(Carts::ExpiresAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // At most one cart per customer (anonymous carts have none)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_carts_customer_id")
+                    .table(Carts::Table)
+                    .col(Carts::CustomerId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_carts_expires_at")
+                    .table(Carts::Table)
+                    .col(Carts::ExpiresAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CartLines::Table)
+                    .if_not_exists()
+                    .col(uuid(CartLines::CartId))
+                    .col(uuid(CartLines::ProductId))
+                    .col(integer(CartLines::Position))
+                    .col(string(CartLines::ProductName))
+                    .col(integer(CartLines::Quantity))
+                    .col(decimal_len(CartLines::UnitPrice, 19, 4))
+                    .col(string_len(CartLines::Currency, 3))
+                    .primary_key(
+                        Index::create()
+                            .col(CartLines::CartId)
+                            .col(CartLines::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_cart_lines_cart_id")
+                            .from(CartLines::Table, CartLines::CartId)
+                            .to(Carts::Table, Carts::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CartLines::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Carts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Carts {
+    Table,
+    Id,
+    CustomerId,
+    CreatedAt,
+    UpdatedAt,
+    ExpiresAt,
+}
+
+#[derive(DeriveIden)]
+enum CartLines {
+    Table,
+    CartId,
+    ProductId,
+    Position,
+    ProductName,
+    Quantity,
+    UnitPrice,
+    Currency,
+}
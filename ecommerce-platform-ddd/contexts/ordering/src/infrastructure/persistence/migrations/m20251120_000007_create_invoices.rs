@@ -0,0 +1,136 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Invoices and credit notes, their lines, and the counters numbering them
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Invoices::Table)
+                    .if_not_exists()
+                    .col(uuid(Invoices::Id).primary_key())
+                    .col(string_len_uniq(Invoices::Number, 32))
+                    .col(string_len(Invoices::Kind, 16))
+                    .col(integer(Invoices::Year))
+                    .col(integer(Invoices::Sequence))
+                    .col(uuid(Invoices::OrderId))
+                    .col(uuid(Invoices::CustomerId))
+                    .col(string_len(Invoices::Currency, 3))
+                    .col(uuid_null(Invoices::CreditedInvoiceId))
+                    .col(text_null(Invoices::Reason))
+                    .col(timestamp_with_time_zone(Invoices::IssuedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_invoices_order_id")
+                    .table(Invoices::Table)
+                    .col(Invoices::OrderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(InvoiceLines::Table)
+                    .if_not_exists()
+                    .col(uuid(InvoiceLines::InvoiceId))
+                    .col(integer(InvoiceLines::Position))
+                    .col(uuid(InvoiceLines::OrderItemId))
+                    .col(uuid(InvoiceLines::ProductId))
+                    .col(string(InvoiceLines::Description))
+                    .col(integer(InvoiceLines::Quantity))
+                    .col(decimal_len(InvoiceLines::UnitPrice, 19, 4))
+                    .col(string_len(InvoiceLines::Currency, 3))
+                    .primary_key(
+                        Index::create()
+                            .col(InvoiceLines::InvoiceId)
+                            .col(InvoiceLines::Position),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_invoice_lines_invoice_id")
+                            .from(InvoiceLines::Table, InvoiceLines::InvoiceId)
+                            .to(Invoices::Table, Invoices::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Last number issued per series: the row lock serializes concurrent issuers
+        manager
+            .create_table(
+                Table::create()
+                    .table(InvoiceSequences::Table)
+                    .if_not_exists()
+                    .col(string_len(InvoiceSequences::Kind, 16))
+                    .col(integer(InvoiceSequences::Year))
+                    .col(integer(InvoiceSequences::LastNumber))
+                    .primary_key(
+                        Index::create()
+                            .col(InvoiceSequences::Kind)
+                            .col(InvoiceSequences::Year),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InvoiceSequences::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(InvoiceLines::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Invoices::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Invoices {
+    Table,
+    Id,
+    Number,
+    Kind,
+    Year,
+    Sequence,
+    OrderId,
+    CustomerId,
+    Currency,
+    CreditedInvoiceId,
+    Reason,
+    IssuedAt,
+}
+
+#[derive(DeriveIden)]
+enum InvoiceLines {
+    Table,
+    InvoiceId,
+    Position,
+    OrderItemId,
+    ProductId,
+    Description,
+    Quantity,
+    UnitPrice,
+    Currency,
+}
+
+#[derive(DeriveIden)]
+enum InvoiceSequences {
+    Table,
+    Kind,
+    Year,
+    LastNumber,
+}
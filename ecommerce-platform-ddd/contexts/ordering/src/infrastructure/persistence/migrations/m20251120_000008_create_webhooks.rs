@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Webhook subscriptions of the partners and the log of their delivery attempts
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookSubscriptions::Table)
+                    .if_not_exists()
+                    .col(uuid(WebhookSubscriptions::Id).primary_key())
+                    .col(text(WebhookSubscriptions::Url))
+                    .col(string(WebhookSubscriptions::Secret))
+                    .col(text(WebhookSubscriptions::EventNames))
+                    .col(boolean(WebhookSubscriptions::Paused))
+                    .col(timestamp_with_time_zone(WebhookSubscriptions::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(uuid(WebhookDeliveries::Id).primary_key())
+                    .col(uuid(WebhookDeliveries::SubscriptionId))
+                    .col(uuid(WebhookDeliveries::EventId))
+                    .col(string_len(WebhookDeliveries::EventName, 32))
+                    .col(integer(WebhookDeliveries::Attempt))
+                    .col(integer_null(WebhookDeliveries::StatusCode))
+                    .col(text_null(WebhookDeliveries::Error))
+                    .col(boolean(WebhookDeliveries::Succeeded))
+                    .col(big_integer(WebhookDeliveries::DurationMs))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::AttemptedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_subscription_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::SubscriptionId)
+                            .to(WebhookSubscriptions::Table, WebhookSubscriptions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_subscription_id")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::SubscriptionId)
+                    .col(WebhookDeliveries::AttemptedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(WebhookSubscriptions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    Id,
+    Url,
+    Secret,
+    EventNames,
+    Paused,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    SubscriptionId,
+    EventId,
+    EventName,
+    Attempt,
+    StatusCode,
+    Error,
+    Succeeded,
+    DurationMs,
+    AttemptedAt,
+}
@@ -0,0 +1,80 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tenant owning each order and the tax rule it was placed with
+///
+/// Existing orders belong to the `default` tenant, untaxed.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement: SQLite cannot add several at once
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(string_len(Orders::TenantId, 64).default("default"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(decimal_len(Orders::TaxRate, 7, 4).default(0))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Orders::Table)
+                    .add_column(boolean(Orders::TaxIncluded).default(true))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_orders_tenant_id_customer_id")
+                    .table(Orders::Table)
+                    .col(Orders::TenantId)
+                    .col(Orders::CustomerId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_orders_tenant_id_customer_id")
+                    .table(Orders::Table)
+                    .to_owned(),
+            )
+            .await?;
+        for column in [Orders::TaxIncluded, Orders::TaxRate, Orders::TenantId] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Orders::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Orders {
+    Table,
+    TenantId,
+    CustomerId,
+    TaxRate,
+    TaxIncluded,
+}
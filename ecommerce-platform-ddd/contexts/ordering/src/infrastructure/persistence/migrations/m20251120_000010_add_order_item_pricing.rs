@@ -0,0 +1,51 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// List price of each order line and the price rule giving its unit price
+///
+/// Existing lines have no list price: their unit price stands for it.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement: SQLite cannot add several at once
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .add_column(decimal_len_null(OrderItems::ListPrice, 19, 4))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrderItems::Table)
+                    .add_column(string_len_null(OrderItems::PriceRule, 128))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [OrderItems::PriceRule, OrderItems::ListPrice] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(OrderItems::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum OrderItems {
+    Table,
+    ListPrice,
+    PriceRule,
+}
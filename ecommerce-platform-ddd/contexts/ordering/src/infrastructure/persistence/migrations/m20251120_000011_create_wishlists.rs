@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Wishlists, one per customer, and the products saved in them
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Wishlists::Table)
+                    .if_not_exists()
+                    .col(uuid(Wishlists::CustomerId).primary_key())
+                    .col(string_len_null(Wishlists::ShareToken, 32))
+                    .col(timestamp_with_time_zone(Wishlists::CreatedAt))
+                    .col(timestamp_with_time_zone(Wishlists::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Shared wishlists are read by token (private ones have none)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_wishlists_share_token")
+                    .table(Wishlists::Table)
+                    .col(Wishlists::ShareToken)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WishlistItems::Table)
+                    .if_not_exists()
+                    .col(uuid(WishlistItems::CustomerId))
+                    .col(uuid(WishlistItems::ProductId))
+                    .col(integer(WishlistItems::Position))
+                    .col(string(WishlistItems::ProductName))
+                    .col(text_null(WishlistItems::Note))
+                    .col(string_len(WishlistItems::Priority, 16))
+                    .col(timestamp_with_time_zone(WishlistItems::AddedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(WishlistItems::CustomerId)
+                            .col(WishlistItems::ProductId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_wishlist_items_customer_id")
+                            .from(WishlistItems::Table, WishlistItems::CustomerId)
+                            .to(Wishlists::Table, Wishlists::CustomerId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WishlistItems::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Wishlists::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Wishlists {
+    Table,
+    CustomerId,
+    ShareToken,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WishlistItems {
+    Table,
+    CustomerId,
+    ProductId,
+    Position,
+    ProductName,
+    Note,
+    Priority,
+    AddedAt,
+}
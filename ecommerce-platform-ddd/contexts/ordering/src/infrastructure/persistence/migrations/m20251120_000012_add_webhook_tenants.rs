@@ -0,0 +1,56 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tenant owning each webhook subscription
+///
+/// Existing subscriptions belong to the `default` tenant.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookSubscriptions::Table)
+                    .add_column(string_len(WebhookSubscriptions::TenantId, 64).default("default"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_subscriptions_tenant_id")
+                    .table(WebhookSubscriptions::Table)
+                    .col(WebhookSubscriptions::TenantId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_webhook_subscriptions_tenant_id")
+                    .table(WebhookSubscriptions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookSubscriptions::Table)
+                    .drop_column(WebhookSubscriptions::TenantId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookSubscriptions {
+    Table,
+    TenantId,
+}
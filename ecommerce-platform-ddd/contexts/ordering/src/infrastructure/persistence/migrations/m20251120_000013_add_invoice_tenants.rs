@@ -0,0 +1,234 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tenant owning each invoice: every tenant numbers its invoices in its own series
+///
+/// Existing invoices and counters belong to the `default` tenant. An invoice number is
+/// now only unique within its tenant; SQLite cannot relax a column constraint in place,
+/// so the table is rebuilt there.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// The rebuild must not leave a half-copied table behind
+    fn use_transaction(&self) -> Option<bool> {
+        Some(true)
+    }
+
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == DbBackend::Sqlite {
+            rebuild_invoices(manager, true).await?;
+        } else {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Invoices::Table)
+                        .add_column(string_len(Invoices::TenantId, 64).default("default"))
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .get_connection()
+                .execute_unprepared("ALTER TABLE invoices DROP CONSTRAINT invoices_number_key")
+                .await?;
+        }
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_invoices_tenant_id_number")
+                    .table(Invoices::Table)
+                    .col(Invoices::TenantId)
+                    .col(Invoices::Number)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        replace_sequences(
+            manager,
+            Table::create()
+                .table(InvoiceSequences::Table)
+                .col(string_len(InvoiceSequences::TenantId, 64))
+                .col(string_len(InvoiceSequences::Kind, 16))
+                .col(integer(InvoiceSequences::Year))
+                .col(integer(InvoiceSequences::LastNumber))
+                .primary_key(
+                    Index::create()
+                        .col(InvoiceSequences::TenantId)
+                        .col(InvoiceSequences::Kind)
+                        .col(InvoiceSequences::Year),
+                )
+                .to_owned(),
+            "INSERT INTO invoice_sequences (tenant_id, kind, year, last_number) \
+             SELECT 'default', kind, year, last_number FROM invoice_sequences_previous",
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Series merge back: each one resumes after the highest number of any tenant
+        replace_sequences(
+            manager,
+            Table::create()
+                .table(InvoiceSequences::Table)
+                .col(string_len(InvoiceSequences::Kind, 16))
+                .col(integer(InvoiceSequences::Year))
+                .col(integer(InvoiceSequences::LastNumber))
+                .primary_key(
+                    Index::create()
+                        .col(InvoiceSequences::Kind)
+                        .col(InvoiceSequences::Year),
+                )
+                .to_owned(),
+            "INSERT INTO invoice_sequences (kind, year, last_number) \
+             SELECT kind, year, MAX(last_number) FROM invoice_sequences_previous \
+             GROUP BY kind, year",
+        )
+        .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_invoices_tenant_id_number")
+                    .table(Invoices::Table)
+                    .to_owned(),
+            )
+            .await?;
+        // Fails if two tenants issued the same number: they cannot share one series again
+        if manager.get_database_backend() == DbBackend::Sqlite {
+            rebuild_invoices(manager, false).await
+        } else {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    "ALTER TABLE invoices ADD CONSTRAINT invoices_number_key UNIQUE (number)",
+                )
+                .await?;
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Invoices::Table)
+                        .drop_column(Invoices::TenantId)
+                        .to_owned(),
+                )
+                .await
+        }
+    }
+}
+
+/// Recreate `invoices` with (`with_tenant`) or without the tenant column, keeping its rows
+///
+/// The rows are set aside while the table is recreated. Invoice lines keep referencing
+/// `invoices`: their foreign key is only checked at commit, once the rows are back.
+async fn rebuild_invoices(manager: &SchemaManager<'_>, with_tenant: bool) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    db.execute_unprepared("PRAGMA defer_foreign_keys = ON")
+        .await?;
+    db.execute_unprepared("CREATE TABLE invoices_previous AS SELECT * FROM invoices")
+        .await?;
+    manager
+        .drop_table(Table::drop().table(Invoices::Table).to_owned())
+        .await?;
+
+    let mut table = Table::create();
+    table
+        .table(Invoices::Table)
+        .col(uuid(Invoices::Id).primary_key());
+    if with_tenant {
+        table.col(string_len(Invoices::TenantId, 64).default("default"));
+        table.col(string_len(Invoices::Number, 32));
+    } else {
+        table.col(string_len_uniq(Invoices::Number, 32));
+    }
+    table
+        .col(string_len(Invoices::Kind, 16))
+        .col(integer(Invoices::Year))
+        .col(integer(Invoices::Sequence))
+        .col(uuid(Invoices::OrderId))
+        .col(uuid(Invoices::CustomerId))
+        .col(string_len(Invoices::Currency, 3))
+        .col(uuid_null(Invoices::CreditedInvoiceId))
+        .col(text_null(Invoices::Reason))
+        .col(timestamp_with_time_zone(Invoices::IssuedAt));
+    manager.create_table(table.to_owned()).await?;
+
+    let columns = "id, number, kind, year, sequence, order_id, customer_id, currency, \
+                   credited_invoice_id, reason, issued_at";
+    db.execute_unprepared(&format!(
+        "INSERT INTO invoices ({columns}) SELECT {columns} FROM invoices_previous"
+    ))
+    .await?;
+    manager
+        .drop_table(Table::drop().table(InvoicesPrevious::Table).to_owned())
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name("idx_invoices_order_id")
+                .table(Invoices::Table)
+                .col(Invoices::OrderId)
+                .to_owned(),
+        )
+        .await
+}
+
+/// Swap `invoice_sequences` for the table `create`, filled by `copy` from the previous one
+async fn replace_sequences(
+    manager: &SchemaManager<'_>,
+    create: TableCreateStatement,
+    copy: &str,
+) -> Result<(), DbErr> {
+    manager
+        .rename_table(
+            Table::rename()
+                .table(InvoiceSequences::Table, InvoiceSequencesPrevious::Table)
+                .to_owned(),
+        )
+        .await?;
+    manager.create_table(create).await?;
+    manager.get_connection().execute_unprepared(copy).await?;
+    manager
+        .drop_table(
+            Table::drop()
+                .table(InvoiceSequencesPrevious::Table)
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum Invoices {
+    Table,
+    Id,
+    TenantId,
+    Number,
+    Kind,
+    Year,
+    Sequence,
+    OrderId,
+    CustomerId,
+    Currency,
+    CreditedInvoiceId,
+    Reason,
+    IssuedAt,
+}
+
+#[derive(DeriveIden)]
+enum InvoicesPrevious {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum InvoiceSequences {
+    Table,
+    TenantId,
+    Kind,
+    Year,
+    LastNumber,
+}
+
+#[derive(DeriveIden)]
+enum InvoiceSequencesPrevious {
+    Table,
+}
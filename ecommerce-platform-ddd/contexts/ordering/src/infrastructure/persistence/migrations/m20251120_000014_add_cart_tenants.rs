@@ -0,0 +1,78 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tenant owning each cart: a customer has at most one cart per tenant
+///
+/// Existing carts belong to the `default` tenant.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Carts::Table)
+                    .add_column(string_len(Carts::TenantId, 64).default("default"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_carts_customer_id")
+                    .table(Carts::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_carts_tenant_id_customer_id")
+                    .table(Carts::Table)
+                    .col(Carts::TenantId)
+                    .col(Carts::CustomerId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_carts_tenant_id_customer_id")
+                    .table(Carts::Table)
+                    .to_owned(),
+            )
+            .await?;
+        // Fails if a customer has carts on several tenants
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_carts_customer_id")
+                    .table(Carts::Table)
+                    .col(Carts::CustomerId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Carts::Table)
+                    .drop_column(Carts::TenantId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Carts {
+    Table,
+    TenantId,
+    CustomerId,
+}
@@ -0,0 +1,167 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Tenant owning each wishlist: a customer has one wishlist per tenant
+///
+/// Existing wishlists belong to the `default` tenant. The tenant becomes part of the primary
+/// key of both tables, which neither backend can change in place: the tables are rebuilt.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    /// The rebuild must not leave a half-copied table behind
+    fn use_transaction(&self) -> Option<bool> {
+        Some(true)
+    }
+
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        rebuild_wishlists(manager, true).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Fails if a customer has wishlists on several tenants
+        rebuild_wishlists(manager, false).await
+    }
+}
+
+/// Recreate both tables keyed with (`with_tenant`) or without the tenant, keeping their rows
+///
+/// The new tables are filled before the old ones are dropped, then take their names: the
+/// foreign key of the items follows the rename of the wishlists table.
+async fn rebuild_wishlists(manager: &SchemaManager<'_>, with_tenant: bool) -> Result<(), DbErr> {
+    let mut wishlists = Table::create();
+    wishlists.table(WishlistsNext::Table);
+    let mut items = Table::create();
+    items.table(WishlistItemsNext::Table);
+    let mut wishlist_key = Index::create();
+    let mut item_key = Index::create();
+    let mut foreign_key = ForeignKey::create();
+    foreign_key
+        .from_tbl(WishlistItemsNext::Table)
+        .to_tbl(WishlistsNext::Table)
+        .on_delete(ForeignKeyAction::Cascade);
+    if with_tenant {
+        wishlists.col(string_len(Wishlists::TenantId, 64).default("default"));
+        items.col(string_len(WishlistItems::TenantId, 64).default("default"));
+        wishlist_key.col(Wishlists::TenantId);
+        item_key.col(WishlistItems::TenantId);
+        foreign_key
+            .name("fk_wishlist_items_tenant_id_customer_id")
+            .from_col(WishlistItems::TenantId)
+            .to_col(Wishlists::TenantId);
+    } else {
+        foreign_key.name("fk_wishlist_items_customer_id");
+    }
+    wishlist_key.col(Wishlists::CustomerId);
+    item_key
+        .col(WishlistItems::CustomerId)
+        .col(WishlistItems::ProductId);
+    foreign_key
+        .from_col(WishlistItems::CustomerId)
+        .to_col(Wishlists::CustomerId);
+
+    manager
+        .create_table(
+            wishlists
+                .col(uuid(Wishlists::CustomerId))
+                .col(string_len_null(Wishlists::ShareToken, 32))
+                .col(timestamp_with_time_zone(Wishlists::CreatedAt))
+                .col(timestamp_with_time_zone(Wishlists::UpdatedAt))
+                .primary_key(&mut wishlist_key)
+                .to_owned(),
+        )
+        .await?;
+    manager
+        .create_table(
+            items
+                .col(uuid(WishlistItems::CustomerId))
+                .col(uuid(WishlistItems::ProductId))
+                .col(integer(WishlistItems::Position))
+                .col(string(WishlistItems::ProductName))
+                .col(text_null(WishlistItems::Note))
+                .col(string_len(WishlistItems::Priority, 16))
+                .col(timestamp_with_time_zone(WishlistItems::AddedAt))
+                .primary_key(&mut item_key)
+                .foreign_key(&mut foreign_key)
+                .to_owned(),
+        )
+        .await?;
+
+    let db = manager.get_connection();
+    let columns = "customer_id, share_token, created_at, updated_at";
+    db.execute_unprepared(&format!(
+        "INSERT INTO wishlists_next ({columns}) SELECT {columns} FROM wishlists"
+    ))
+    .await?;
+    let columns = "customer_id, product_id, position, product_name, note, priority, added_at";
+    db.execute_unprepared(&format!(
+        "INSERT INTO wishlist_items_next ({columns}) SELECT {columns} FROM wishlist_items"
+    ))
+    .await?;
+
+    manager
+        .drop_table(Table::drop().table(WishlistItems::Table).to_owned())
+        .await?;
+    manager
+        .drop_table(Table::drop().table(Wishlists::Table).to_owned())
+        .await?;
+    manager
+        .rename_table(
+            Table::rename()
+                .table(WishlistsNext::Table, Wishlists::Table)
+                .to_owned(),
+        )
+        .await?;
+    manager
+        .rename_table(
+            Table::rename()
+                .table(WishlistItemsNext::Table, WishlistItems::Table)
+                .to_owned(),
+        )
+        .await?;
+
+    // Shared wishlists are read by token (private ones have none)
+    manager
+        .create_index(
+            Index::create()
+                .name("idx_wishlists_share_token")
+                .table(Wishlists::Table)
+                .col(Wishlists::ShareToken)
+                .unique()
+                .to_owned(),
+        )
+        .await
+}
+
+#[derive(DeriveIden)]
+enum Wishlists {
+    Table,
+    TenantId,
+    CustomerId,
+    ShareToken,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum WishlistsNext {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum WishlistItems {
+    Table,
+    TenantId,
+    CustomerId,
+    ProductId,
+    Position,
+    ProductName,
+    Note,
+    Priority,
+    AddedAt,
+}
+
+#[derive(DeriveIden)]
+enum WishlistItemsNext {
+    Table,
+}
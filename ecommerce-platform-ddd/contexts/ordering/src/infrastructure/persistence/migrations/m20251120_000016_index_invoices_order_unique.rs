@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// An order has a single invoice (credit notes excepted), even when `OrderPaid` is
+/// handled twice at the same time
+///
+/// Fails if an order was already invoiced twice: one of its invoices must be credited
+/// and removed by hand first.
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_invoices_tenant_id_order_id_invoice")
+                    .table(Invoices::Table)
+                    .col(Invoices::TenantId)
+                    .col(Invoices::OrderId)
+                    .unique()
+                    .and_where(Expr::col(Invoices::Kind).eq("INVOICE"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_invoices_tenant_id_order_id_invoice")
+                    .table(Invoices::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Invoices {
+    Table,
+    TenantId,
+    OrderId,
+    Kind,
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Erasure requests handled, kept apart from the order trail: a customer without
+/// orders leaves no audit entry behind
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ErasureRequests::Table)
+                    .if_not_exists()
+                    .col(uuid(ErasureRequests::Id).primary_key())
+                    .col(string_len(ErasureRequests::TenantId, 64))
+                    .col(string(ErasureRequests::Reference))
+                    .col(string(ErasureRequests::Actor))
+                    .col(integer(ErasureRequests::OrdersAnonymized))
+                    .col(timestamp_with_time_zone(ErasureRequests::OccurredAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_erasure_requests_tenant_id")
+                    .table(ErasureRequests::Table)
+                    .col(ErasureRequests::TenantId)
+                    .col(ErasureRequests::OccurredAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ErasureRequests::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ErasureRequests {
+    Table,
+    Id,
+    TenantId,
+    Reference,
+    Actor,
+    OrdersAnonymized,
+    OccurredAt,
+}
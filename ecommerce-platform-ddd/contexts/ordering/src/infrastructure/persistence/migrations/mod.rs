@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+mod m20251120_000001_create_idempotency_keys;
+mod m20251120_000002_create_orders;
+mod m20251120_000003_create_audit_entries;
+mod m20251120_000004_index_orders_status;
+mod m20251120_000005_create_shipments;
+mod m20251120_000006_create_carts;
+mod m20251120_000007_create_invoices;
+mod m20251120_000008_create_webhooks;
+mod m20251120_000009_add_order_tenants;
+mod m20251120_000010_add_order_item_pricing;
+mod m20251120_000011_create_wishlists;
+mod m20251120_000012_add_webhook_tenants;
+mod m20251120_000013_add_invoice_tenants;
+mod m20251120_000014_add_cart_tenants;
+mod m20251120_000015_add_wishlist_tenants;
+mod m20251120_000016_index_invoices_order_unique;
+mod m20251120_000017_add_order_addresses;
+mod m20251120_000018_add_idempotency_response_headers;
+mod m20251120_000019_create_erasure_requests;
+
+/// Schema migrations for the ordering context
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20251120_000001_create_idempotency_keys::Migration),
+            Box::new(m20251120_000002_create_orders::Migration),
+            Box::new(m20251120_000003_create_audit_entries::Migration),
+            Box::new(m20251120_000004_index_orders_status::Migration),
+            Box::new(m20251120_000005_create_shipments::Migration),
+            Box::new(m20251120_000006_create_carts::Migration),
+            Box::new(m20251120_000007_create_invoices::Migration),
+            Box::new(m20251120_000008_create_webhooks::Migration),
+            Box::new(m20251120_000009_add_order_tenants::Migration),
+            Box::new(m20251120_000010_add_order_item_pricing::Migration),
+            Box::new(m20251120_000011_create_wishlists::Migration),
+            Box::new(m20251120_000012_add_webhook_tenants::Migration),
+            Box::new(m20251120_000013_add_invoice_tenants::Migration),
+            Box::new(m20251120_000014_add_cart_tenants::Migration),
+            Box::new(m20251120_000015_add_wishlist_tenants::Migration),
+            Box::new(m20251120_000016_index_invoices_order_unique::Migration),
+            Box::new(m20251120_000017_add_order_addresses::Migration),
+            Box::new(m20251120_000018_add_idempotency_response_headers::Migration),
+            Box::new(m20251120_000019_create_erasure_requests::Migration),
+        ]
+    }
+}
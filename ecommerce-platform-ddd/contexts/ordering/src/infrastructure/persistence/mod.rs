@@ -1,3 +1,29 @@
+pub mod entities;
+pub mod migrations;
 pub mod repositories;
 
+pub use migrations::Migrator;
 pub use repositories::*;
+
+use crate::domain::errors::InfrastructureError;
+
+impl From<sea_orm::DbErr> for InfrastructureError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        let error = match err.sql_err() {
+            Some(sea_orm::SqlErr::UniqueConstraintViolation(_)) => {
+                InfrastructureError::conflict("Database conflict")
+            }
+            _ => InfrastructureError::storage("Database error"),
+        };
+        error.with_source(err)
+    }
+}
+
+/// Error for a stored row of `what` that no longer reads back as a valid aggregate
+pub(crate) fn corrupted<E>(what: impl Into<String>) -> impl FnOnce(E) -> InfrastructureError
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let what = what.into();
+    move |err| InfrastructureError::corrupted(what).with_source(err)
+}
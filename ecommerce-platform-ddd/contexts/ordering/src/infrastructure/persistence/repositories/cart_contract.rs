@@ -0,0 +1,221 @@
+//! Behaviour every `CartRepository` adapter must honour
+//!
+//! Each check takes a fresh, empty repository; `cart_repository_contract_tests!`
+//! expands them into one test per check for a given adapter factory.
+
+use crate::domain::{
+    aggregates::Cart,
+    clock::FixedClock,
+    entities::CartLine,
+    id_generator::UuidV4Generator,
+    repositories::CartRepository,
+    value_objects::{CartId, CustomerId, Money, ProductId, TenantId},
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+
+/// Whole seconds, so that every backend stores timestamps without loss
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap()
+}
+
+fn tenant() -> TenantId {
+    TenantId::default()
+}
+
+fn acme() -> TenantId {
+    "acme".parse().unwrap()
+}
+
+fn line(name: &str, quantity: u32, cents: i64) -> CartLine {
+    CartLine::new(
+        ProductId::new(),
+        name.to_string(),
+        quantity,
+        Money::eur(Decimal::new(cents, 2)).unwrap(),
+    )
+    .unwrap()
+}
+
+pub async fn save_and_find_round_trip(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let mut cart = Cart::create(tenant(), None, &UuidV4Generator, &clock);
+    cart.add_line(line("Keyboard", 2, 4990), &clock).unwrap();
+    cart.add_line(line("Mouse", 1, 1999), &clock).unwrap();
+    repo.save(&cart).await.unwrap();
+
+    let found = repo
+        .find_by_id(&tenant(), cart.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.customer_id(), None);
+    assert_eq!(found.lines(), cart.lines());
+    assert_eq!(found.created_at(), cart.created_at());
+    assert_eq!(found.updated_at(), cart.updated_at());
+    assert_eq!(found.expires_at(), cart.expires_at());
+}
+
+pub async fn save_replaces_existing_cart(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut cart = Cart::create(tenant(), None, &UuidV4Generator, &clock);
+    let keyboard = line("Keyboard", 1, 4990);
+    cart.add_line(keyboard.clone(), &clock).unwrap();
+    cart.add_line(line("Mouse", 1, 1999), &clock).unwrap();
+    repo.save(&cart).await.unwrap();
+
+    clock.advance(Duration::hours(1));
+    cart.remove_line(keyboard.product_id(), &clock).unwrap();
+    cart.assign_to(customer_id, &clock).unwrap();
+    repo.save(&cart).await.unwrap();
+
+    let found = repo
+        .find_by_customer(&tenant(), customer_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), cart.id());
+    assert_eq!(found.lines().len(), 1);
+    assert_eq!(found.lines()[0].product_name(), "Mouse");
+    assert_eq!(found.expires_at(), cart.expires_at());
+}
+
+pub async fn empty_cart_round_trip(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let cart = Cart::create(tenant(), Some(CustomerId::new()), &UuidV4Generator, &clock);
+    repo.save(&cart).await.unwrap();
+
+    let found = repo
+        .find_by_id(&tenant(), cart.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.lines().is_empty());
+}
+
+pub async fn find_returns_none_for_unknown_cart(repo: &dyn CartRepository) {
+    assert!(repo
+        .find_by_id(&tenant(), CartId::new())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_customer(&tenant(), CustomerId::new())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn delete_expired_keeps_active_carts(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let mut expired = Cart::create(tenant(), None, &UuidV4Generator, &clock);
+    expired.add_line(line("Keyboard", 1, 4990), &clock).unwrap();
+    repo.save(&expired).await.unwrap();
+    clock.advance(Duration::days(1));
+    let active = Cart::create(tenant(), None, &UuidV4Generator, &clock);
+    repo.save(&active).await.unwrap();
+
+    let now = expired.expires_at();
+    assert_eq!(repo.delete_expired(now).await.unwrap(), 1);
+    assert!(repo
+        .find_by_id(&tenant(), expired.id())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_id(&tenant(), active.id())
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(repo.delete_expired(now).await.unwrap(), 0);
+}
+
+pub async fn delete_removes_cart(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let mut cart = Cart::create(tenant(), None, &UuidV4Generator, &clock);
+    cart.add_line(line("Keyboard", 1, 4990), &clock).unwrap();
+    repo.save(&cart).await.unwrap();
+
+    repo.delete(&tenant(), cart.id()).await.unwrap();
+    assert!(repo
+        .find_by_id(&tenant(), cart.id())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn carts_are_isolated_per_tenant(repo: &dyn CartRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut ours = Cart::create(tenant(), Some(customer_id), &UuidV4Generator, &clock);
+    ours.add_line(line("Keyboard", 1, 4990), &clock).unwrap();
+    repo.save(&ours).await.unwrap();
+    // The same customer may shop on another storefront
+    let theirs = Cart::create(acme(), Some(customer_id), &UuidV4Generator, &clock);
+    repo.save(&theirs).await.unwrap();
+
+    assert!(repo.find_by_id(&acme(), ours.id()).await.unwrap().is_none());
+    assert_eq!(
+        repo.find_by_customer(&acme(), customer_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .id(),
+        theirs.id()
+    );
+    repo.delete(&acme(), ours.id()).await.unwrap();
+    let kept = repo
+        .find_by_id(&tenant(), ours.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(kept.lines().len(), 1);
+
+    // An ID taken by another tenant cannot be taken over
+    let hijacked = Cart::reconstitute(
+        ours.id(),
+        acme(),
+        None,
+        Vec::new(),
+        ours.created_at(),
+        ours.updated_at(),
+        ours.expires_at(),
+    );
+    assert!(repo.save(&hijacked).await.is_err());
+    assert_eq!(
+        repo.find_by_id(&tenant(), ours.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .customer_id(),
+        Some(customer_id)
+    );
+}
+
+macro_rules! cart_repository_contract_tests {
+    ($factory:path) => {
+        $crate::infrastructure::persistence::repositories::cart_contract::cart_repository_contract_tests!(
+            @tests $factory;
+            save_and_find_round_trip,
+            save_replaces_existing_cart,
+            empty_cart_round_trip,
+            find_returns_none_for_unknown_cart,
+            delete_expired_keeps_active_carts,
+            delete_removes_cart,
+            carts_are_isolated_per_tenant,
+        );
+    };
+    (@tests $factory:path; $($check:ident),* $(,)?) => {
+        mod contract {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let repo = $factory().await;
+                    $crate::infrastructure::persistence::repositories::cart_contract::$check(&repo).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use cart_repository_contract_tests;
@@ -0,0 +1,546 @@
+//! Behaviour every `OrderRepository` adapter must honour
+//!
+//! Each check takes a fresh, empty repository; `order_repository_contract_tests!`
+//! expands them into one test per check for a given adapter factory.
+
+use crate::domain::{
+    aggregates::Order,
+    clock::{Clock, FixedClock},
+    entities::{OrderItem, ShipmentLine},
+    errors::InfrastructureErrorKind,
+    id_generator::UuidV4Generator,
+    pricing::{PriceList, PriceRule},
+    repositories::{OrderCriteria, OrderRepository},
+    tenant::{TaxRule, Tenant},
+    value_objects::{
+        CustomerId, Money, OrderId, OrderStatus, PostalAddress, ProductId, ShipmentStatus,
+        TenantId,
+    },
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+
+/// Whole seconds, so that every backend stores timestamps without loss
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap()
+}
+
+fn item(name: &str, quantity: u32, cents: i64) -> OrderItem {
+    OrderItem::new(
+        ProductId::new(),
+        name.to_string(),
+        quantity,
+        Money::eur(Decimal::new(cents, 2)).unwrap(),
+        &UuidV4Generator,
+    )
+    .unwrap()
+}
+
+fn create(customer_id: CustomerId, clock: &dyn Clock) -> Order {
+    create_for(&Tenant::default(), customer_id, clock)
+}
+
+fn create_for(tenant: &Tenant, customer_id: CustomerId, clock: &dyn Clock) -> Order {
+    Order::create(
+        tenant,
+        customer_id,
+        vec![item("Keyboard", 2, 4990), item("Mouse", 1, 1999)],
+        &UuidV4Generator,
+        clock,
+    )
+    .unwrap()
+}
+
+fn address(country: &str) -> PostalAddress {
+    PostalAddress {
+        line1: "1 Main Street".to_string(),
+        postal_code: "75002".to_string(),
+        city: "Paris".to_string(),
+        country: country.to_string(),
+    }
+}
+
+fn acme() -> TenantId {
+    "acme".parse().unwrap()
+}
+
+fn ids(orders: &[Order]) -> Vec<OrderId> {
+    let mut ids: Vec<_> = orders.iter().map(Order::id).collect();
+    ids.sort_by_key(|id| id.value());
+    ids
+}
+
+pub async fn save_and_find_round_trip(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let tenant = Tenant {
+        tax: TaxRule::new(Decimal::new(55, 3), false).unwrap(),
+        ..Tenant::new(acme())
+    };
+    let mut order = create_for(&tenant, CustomerId::new(), &clock)
+        .with_addresses(Some(address("FR")), Some(address("BE")));
+    let keyboard = order.items()[0].clone();
+    let pricing = PriceList::new(vec![PriceRule::new(
+        "keyboard-10",
+        keyboard.product_id(),
+        Money::eur(Decimal::new(4490, 2)).unwrap(),
+    )
+    .from_quantity(10)])
+    .unwrap()
+    .pricing(None, clock.now());
+    order
+        .change_item_quantity(keyboard.id(), 10, &pricing, &clock)
+        .unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    let found = repo.find_by_id(&acme(), order.id()).await.unwrap().unwrap();
+    assert_eq!(found.id(), order.id());
+    assert_eq!(found.tenant_id(), &acme());
+    assert_eq!(found.tax_rule(), tenant.tax);
+    assert_eq!(found.customer_id(), order.customer_id());
+    assert_eq!(found.billing_address(), Some(&address("FR")));
+    assert_eq!(found.shipping_address(), Some(&address("BE")));
+    assert_eq!(found.status(), OrderStatus::Pending);
+    assert_eq!(found.total(), order.total());
+    assert_eq!(found.created_at(), order.created_at());
+    assert_eq!(found.updated_at(), order.updated_at());
+    let items: Vec<_> = found
+        .items()
+        .iter()
+        .map(|i| {
+            (
+                i.id(),
+                i.product_id(),
+                i.product_name(),
+                i.quantity(),
+                i.unit_price(),
+                i.list_price(),
+                i.price_rule(),
+            )
+        })
+        .collect();
+    let expected: Vec<_> = order
+        .items()
+        .iter()
+        .map(|i| {
+            (
+                i.id(),
+                i.product_id(),
+                i.product_name(),
+                i.quantity(),
+                i.unit_price(),
+                i.list_price(),
+                i.price_rule(),
+            )
+        })
+        .collect();
+    assert_eq!(items, expected);
+}
+
+pub async fn saved_orders_carry_no_events(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut order = create(CustomerId::new(), &clock);
+    repo.save(&mut order).await.unwrap();
+
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.events().is_empty());
+}
+
+pub async fn save_replaces_existing_order(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut order = create(CustomerId::new(), &clock);
+    repo.save(&mut order).await.unwrap();
+
+    clock.advance(Duration::minutes(5));
+    let removed = order.items()[0].id();
+    order.remove_item(removed, &clock).unwrap();
+    order.add_item(item("Screen", 1, 19900), &clock).unwrap();
+    order.confirm(&clock).unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Confirmed);
+    assert_eq!(found.total(), order.total());
+    assert_eq!(found.updated_at(), clock.now());
+    assert_eq!(found.created_at(), start());
+    let names: Vec<_> = found.items().iter().map(OrderItem::product_name).collect();
+    assert_eq!(names, ["Mouse", "Screen"]);
+    assert!(found.items().iter().all(|i| i.id() != removed));
+}
+
+pub async fn shipments_round_trip(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut order = create(CustomerId::new(), &clock);
+    order.confirm(&clock).unwrap();
+    order.mark_as_paid(uuid::Uuid::new_v4(), &clock).unwrap();
+    let (keyboard, mouse) = (order.items()[0].id(), order.items()[1].id());
+    let line = |order_item_id, quantity| ShipmentLine {
+        order_item_id,
+        quantity,
+    };
+    let first = order
+        .create_shipment(vec![line(keyboard, 1)], &UuidV4Generator, &clock)
+        .unwrap();
+    let second = order
+        .create_shipment(
+            vec![line(mouse, 1), line(keyboard, 1)],
+            &UuidV4Generator,
+            &clock,
+        )
+        .unwrap();
+    clock.advance(Duration::minutes(5));
+    order
+        .ship_shipment(first, "TRK-1".to_string(), &clock)
+        .unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Paid);
+    let shipments = found.shipments();
+    assert_eq!(shipments.len(), 2);
+    assert_eq!(shipments[0].id(), first);
+    assert_eq!(shipments[0].status(), ShipmentStatus::Shipped);
+    assert_eq!(shipments[0].tracking_number(), Some("TRK-1"));
+    assert_eq!(shipments[0].shipped_at(), Some(clock.now()));
+    assert_eq!(shipments[1].id(), second);
+    assert_eq!(shipments[1].status(), ShipmentStatus::Pending);
+    assert_eq!(shipments[1].created_at(), start());
+    assert_eq!(shipments[1].lines(), order.shipments()[1].lines());
+
+    // Shipping the last parcel completes the order; shipments are replaced as a whole
+    let mut order = found;
+    order
+        .ship_shipment(second, "TRK-2".to_string(), &clock)
+        .unwrap();
+    repo.save(&mut order).await.unwrap();
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Shipped);
+    assert!(found
+        .shipments()
+        .iter()
+        .all(|shipment| shipment.status() == ShipmentStatus::Shipped));
+
+    repo.delete(&TenantId::default(), order.id()).await.unwrap();
+    assert!(repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn find_by_id_returns_none_for_unknown_order(repo: &dyn OrderRepository) {
+    assert!(repo
+        .find_by_id(&TenantId::default(), OrderId::new())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn find_by_customer_returns_only_their_orders(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut first = create(customer_id, &clock);
+    let mut second = create(customer_id, &clock);
+    let mut other = create(CustomerId::new(), &clock);
+    for order in [&mut first, &mut second, &mut other] {
+        repo.save(order).await.unwrap();
+    }
+
+    let found = repo
+        .find_by_customer(&TenantId::default(), customer_id)
+        .await
+        .unwrap();
+    assert_eq!(ids(&found), ids(&[first, second]));
+    assert!(repo
+        .find_by_customer(&TenantId::default(), CustomerId::new())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn anonymized_order_moves_to_its_pseudonym(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut order = create(customer_id, &clock).with_addresses(None, Some(address("FR")));
+    order.cancel("Moving house".to_string(), &clock).unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    let pseudonym = CustomerId::new();
+    clock.advance(Duration::days(30));
+    order.anonymize(pseudonym, &clock).unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    assert!(repo
+        .find_by_customer(&TenantId::default(), customer_id)
+        .await
+        .unwrap()
+        .is_empty());
+    let found = repo
+        .find_by_customer(&TenantId::default(), pseudonym)
+        .await
+        .unwrap();
+    assert_eq!(ids(&found), ids(std::slice::from_ref(&order)));
+    assert_eq!(found[0].total(), order.total());
+    assert!(found[0].shipping_address().is_none());
+    assert_eq!(found[0].updated_at(), clock.now());
+}
+
+pub async fn find_stale_filters_on_status_and_last_update(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut stale = create(CustomerId::new(), &clock);
+    repo.save(&mut stale).await.unwrap();
+    let mut confirmed = create(CustomerId::new(), &clock);
+    confirmed.confirm(&clock).unwrap();
+    repo.save(&mut confirmed).await.unwrap();
+
+    clock.advance(Duration::hours(1));
+    let mut fresh = create(CustomerId::new(), &clock);
+    repo.save(&mut fresh).await.unwrap();
+
+    // The bound is inclusive
+    let found = repo
+        .find_stale(OrderStatus::Pending, start())
+        .await
+        .unwrap();
+    assert_eq!(ids(&found), ids(std::slice::from_ref(&stale)));
+    let found = repo
+        .find_stale(OrderStatus::Pending, start() - Duration::seconds(1))
+        .await
+        .unwrap();
+    assert!(found.is_empty());
+    let found = repo
+        .find_stale(OrderStatus::Pending, clock.now())
+        .await
+        .unwrap();
+    assert_eq!(ids(&found), ids(&[stale, fresh]));
+}
+
+pub async fn find_page_walks_matching_orders_in_creation_order(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut expected = Vec::new();
+    for minute in 0..5 {
+        // Two orders share each creation instant: ties are broken by ID
+        for _ in 0..2 {
+            let mut order = create(CustomerId::new(), &clock);
+            repo.save(&mut order).await.unwrap();
+            if minute > 0 {
+                expected.push(order);
+            }
+        }
+        let mut cancelled = create(CustomerId::new(), &clock);
+        cancelled.cancel("Test".to_string(), &clock).unwrap();
+        repo.save(&mut cancelled).await.unwrap();
+        clock.advance(Duration::minutes(1));
+    }
+    expected.sort_by_key(|o| (o.created_at(), o.id().value()));
+    let expected: Vec<_> = expected.iter().map(Order::id).collect();
+
+    let criteria = OrderCriteria {
+        tenant_id: Some(TenantId::default()),
+        status: Some(OrderStatus::Pending),
+        created_from: Some(start() + Duration::minutes(1)),
+        created_until: Some(start() + Duration::minutes(5)),
+    };
+    let mut seen = Vec::new();
+    let mut after = None;
+    loop {
+        let page = repo.find_page(&criteria, after, 3).await.unwrap();
+        assert!(page.len() <= 3);
+        let Some(last) = page.last() else { break };
+        after = Some((last.created_at(), last.id()));
+        seen.extend(page.iter().map(Order::id));
+    }
+    assert_eq!(seen, expected);
+}
+
+pub async fn delete_removes_order(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut order = create(customer_id, &clock);
+    repo.save(&mut order).await.unwrap();
+    let mut kept = create(customer_id, &clock);
+    repo.save(&mut kept).await.unwrap();
+
+    repo.delete(&TenantId::default(), order.id()).await.unwrap();
+    assert!(repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .is_none());
+    let remaining = repo
+        .find_by_customer(&TenantId::default(), customer_id)
+        .await
+        .unwrap();
+    assert_eq!(ids(&remaining), ids(std::slice::from_ref(&kept)));
+
+    // Deleting an unknown order is not an error
+    repo.delete(&TenantId::default(), OrderId::new())
+        .await
+        .unwrap();
+}
+
+pub async fn orders_are_isolated_per_tenant(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut ours = create(customer_id, &clock);
+    let mut theirs = create_for(&Tenant::new(acme()), customer_id, &clock);
+    for order in [&mut ours, &mut theirs] {
+        repo.save(order).await.unwrap();
+    }
+
+    let default = TenantId::default();
+    assert!(repo
+        .find_by_id(&default, theirs.id())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_id(&acme(), theirs.id())
+        .await
+        .unwrap()
+        .is_some());
+    let found = repo.find_by_customer(&default, customer_id).await.unwrap();
+    assert_eq!(ids(&found), ids(std::slice::from_ref(&ours)));
+
+    let criteria = OrderCriteria {
+        tenant_id: Some(acme()),
+        ..Default::default()
+    };
+    let page = repo.find_page(&criteria, None, 10).await.unwrap();
+    assert_eq!(ids(&page), ids(std::slice::from_ref(&theirs)));
+    let page = repo
+        .find_page(&OrderCriteria::default(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 2);
+
+    // Deleting through another tenant leaves the order in place
+    repo.delete(&default, theirs.id()).await.unwrap();
+    assert!(repo
+        .find_by_id(&acme(), theirs.id())
+        .await
+        .unwrap()
+        .is_some());
+}
+
+pub async fn save_refuses_the_order_of_another_tenant(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut order = create(CustomerId::new(), &clock);
+    repo.save(&mut order).await.unwrap();
+
+    let mut hijacked = Order::reconstitute(
+        order.id(),
+        acme(),
+        order.customer_id(),
+        order.items().to_vec(),
+        OrderStatus::Cancelled,
+        TaxRule::none(),
+        Vec::new(),
+        order.created_at(),
+        clock.now(),
+    )
+    .unwrap();
+    assert!(repo.save(&mut hijacked).await.is_err());
+
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Pending);
+    assert_eq!(found.items().len(), 2);
+}
+
+pub async fn save_if_status_refuses_an_order_changed_since_read(repo: &dyn OrderRepository) {
+    let clock = FixedClock::new(start());
+    let mut order = create(CustomerId::new(), &clock);
+    repo.save(&mut order).await.unwrap();
+
+    // A job reads the order, the customer confirms it before the job saves
+    let mut read = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    clock.advance(Duration::minutes(1));
+    order.confirm(&clock).unwrap();
+    repo.save(&mut order).await.unwrap();
+
+    read.cancel("Expired".to_string(), &clock).unwrap();
+    let err = repo
+        .save_if_status(&mut read, OrderStatus::Pending)
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), InfrastructureErrorKind::Conflict);
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Confirmed);
+
+    // Unchanged since read: saved as a whole
+    clock.advance(Duration::minutes(1));
+    order.cancel("Out of stock".to_string(), &clock).unwrap();
+    repo.save_if_status(&mut order, OrderStatus::Confirmed)
+        .await
+        .unwrap();
+    let found = repo
+        .find_by_id(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.status(), OrderStatus::Cancelled);
+    assert_eq!(found.updated_at(), clock.now());
+    assert_eq!(found.items().len(), 2);
+}
+
+/// Expands to one `#[tokio::test]` per contract check, each on a repository
+/// built by `$factory` (an async fn returning the adapter under test)
+macro_rules! order_repository_contract_tests {
+    ($factory:path) => {
+        $crate::infrastructure::persistence::repositories::contract::order_repository_contract_tests!(
+            @tests $factory;
+            save_and_find_round_trip,
+            saved_orders_carry_no_events,
+            save_replaces_existing_order,
+            shipments_round_trip,
+            find_by_id_returns_none_for_unknown_order,
+            find_by_customer_returns_only_their_orders,
+            anonymized_order_moves_to_its_pseudonym,
+            find_stale_filters_on_status_and_last_update,
+            find_page_walks_matching_orders_in_creation_order,
+            delete_removes_order,
+            orders_are_isolated_per_tenant,
+            save_refuses_the_order_of_another_tenant,
+            save_if_status_refuses_an_order_changed_since_read,
+        );
+    };
+    (@tests $factory:path; $($check:ident),* $(,)?) => {
+        mod contract {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let repo = $factory().await;
+                    $crate::infrastructure::persistence::repositories::contract::$check(&repo).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use order_repository_contract_tests;
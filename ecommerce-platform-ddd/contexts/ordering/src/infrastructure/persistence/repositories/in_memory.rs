@@ -1,10 +1,11 @@
 use crate::domain::{
     aggregates::Order,
-    errors::DomainError,
-    repositories::OrderRepository,
-    value_objects::{CustomerId, OrderId},
+    errors::InfrastructureError,
+    repositories::{OrderCriteria, OrderCursor, OrderRepository},
+    value_objects::{CustomerId, OrderId, OrderStatus, TenantId},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -22,34 +23,131 @@ impl InMemoryOrderRepository {
     }
 }
 
+impl Default for InMemoryOrderRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl OrderRepository for InMemoryOrderRepository {
-    async fn save(&self, order: &mut Order) -> Result<(), DomainError> {
+    async fn save(&self, order: &mut Order) -> Result<(), InfrastructureError> {
+        // Events are not persisted (a reloaded order starts with none)
+        let mut stored = order.clone();
+        stored.take_events();
         let mut orders = self.orders.write().await;
-        orders.insert(order.id(), order.clone());
+        if let Some(existing) = orders.get(&order.id()) {
+            if existing.tenant_id() != order.tenant_id() {
+                return Err(other_tenant(order.id()));
+            }
+        }
+        orders.insert(order.id(), stored);
         Ok(())
     }
 
-    async fn find_by_id(&self, id: OrderId) -> Result<Option<Order>, DomainError> {
+    async fn save_if_status(
+        &self,
+        order: &mut Order,
+        expected: OrderStatus,
+    ) -> Result<(), InfrastructureError> {
+        let mut stored = order.clone();
+        stored.take_events();
+        let mut orders = self.orders.write().await;
+        let unchanged = orders.get(&order.id()).is_some_and(|existing| {
+            existing.tenant_id() == order.tenant_id() && existing.status() == expected
+        });
+        if !unchanged {
+            return Err(InfrastructureError::conflict(format!(
+                "order {} is no longer {}",
+                order.id(),
+                expected
+            )));
+        }
+        orders.insert(order.id(), stored);
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: OrderId,
+    ) -> Result<Option<Order>, InfrastructureError> {
         let orders = self.orders.read().await;
-        Ok(orders.get(&id).cloned())
+        Ok(orders
+            .get(&id)
+            .filter(|o| o.tenant_id() == tenant_id)
+            .cloned())
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, InfrastructureError> {
+        let orders = self.orders.read().await;
+        Ok(orders
+            .values()
+            .filter(|o| o.tenant_id() == tenant_id && o.customer_id() == customer_id)
+            .cloned()
+            .collect())
     }
 
-    async fn find_by_customer(&self, customer_id: CustomerId) -> Result<Vec<Order>, DomainError> {
+    async fn find_stale(
+        &self,
+        status: OrderStatus,
+        updated_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, InfrastructureError> {
         let orders = self.orders.read().await;
         Ok(orders
             .values()
-            .filter(|o| o.customer_id() == customer_id)
+            .filter(|o| o.status() == status && o.updated_at() <= updated_before)
             .cloned()
             .collect())
     }
 
-    async fn delete(&self, id: OrderId) -> Result<(), DomainError> {
+    async fn find_page(
+        &self,
+        criteria: &OrderCriteria,
+        after: Option<OrderCursor>,
+        limit: u64,
+    ) -> Result<Vec<Order>, InfrastructureError> {
+        let cursor = |o: &Order| (o.created_at(), o.id().value());
+        let orders = self.orders.read().await;
+        let mut page: Vec<Order> = orders
+            .values()
+            .filter(|o| criteria.matches(o))
+            .filter(|o| after.is_none_or(|(at, id)| cursor(o) > (at, id.value())))
+            .cloned()
+            .collect();
+        page.sort_by_key(cursor);
+        page.truncate(limit as usize);
+        Ok(page)
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: OrderId) -> Result<(), InfrastructureError> {
         let mut orders = self.orders.write().await;
-        orders.remove(&id);
+        if orders.get(&id).is_some_and(|o| o.tenant_id() == tenant_id) {
+            orders.remove(&id);
+        }
         Ok(())
     }
 }
 
+fn other_tenant(id: OrderId) -> InfrastructureError {
+    InfrastructureError::storage(format!("order {} belongs to another tenant", id))
+}
+
 // Implement Clone for Order (needed for in-memory storage)
 // This would go in the Order implementation
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::contract::order_repository_contract_tests;
+
+    async fn repository() -> InMemoryOrderRepository {
+        InMemoryOrderRepository::new()
+    }
+
+    order_repository_contract_tests!(super::repository);
+}
@@ -0,0 +1,98 @@
+use crate::domain::{
+    aggregates::Cart,
+    errors::InfrastructureError,
+    repositories::CartRepository,
+    value_objects::{CartId, CustomerId, TenantId},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation for testing
+pub struct InMemoryCartRepository {
+    carts: Arc<RwLock<HashMap<CartId, Cart>>>,
+}
+
+impl InMemoryCartRepository {
+    pub fn new() -> Self {
+        Self {
+            carts: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryCartRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CartRepository for InMemoryCartRepository {
+    async fn save(&self, cart: &Cart) -> Result<(), InfrastructureError> {
+        let mut carts = self.carts.write().await;
+        if let Some(existing) = carts.get(&cart.id()) {
+            if existing.tenant_id() != cart.tenant_id() {
+                return Err(InfrastructureError::storage(format!(
+                    "cart {} belongs to another tenant",
+                    cart.id()
+                )));
+            }
+        }
+        carts.insert(cart.id(), cart.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: CartId,
+    ) -> Result<Option<Cart>, InfrastructureError> {
+        let carts = self.carts.read().await;
+        Ok(carts
+            .get(&id)
+            .filter(|c| c.tenant_id() == tenant_id)
+            .cloned())
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Cart>, InfrastructureError> {
+        let carts = self.carts.read().await;
+        Ok(carts
+            .values()
+            .find(|c| c.tenant_id() == tenant_id && c.customer_id() == Some(customer_id))
+            .cloned())
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, InfrastructureError> {
+        let mut carts = self.carts.write().await;
+        let before = carts.len();
+        carts.retain(|_, c| !c.is_expired(now));
+        Ok((before - carts.len()) as u64)
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: CartId) -> Result<(), InfrastructureError> {
+        let mut carts = self.carts.write().await;
+        if carts.get(&id).is_some_and(|c| c.tenant_id() == tenant_id) {
+            carts.remove(&id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::cart_contract::cart_repository_contract_tests;
+
+    async fn repository() -> InMemoryCartRepository {
+        InMemoryCartRepository::new()
+    }
+
+    cart_repository_contract_tests!(super::repository);
+}
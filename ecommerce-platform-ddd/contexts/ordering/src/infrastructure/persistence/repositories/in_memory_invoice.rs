@@ -0,0 +1,146 @@
+use crate::domain::{
+    aggregates::{Invoice, InvoiceDraft},
+    errors::InfrastructureError,
+    repositories::InvoiceRepository,
+    value_objects::{CustomerId, InvoiceId, InvoiceKind, OrderId, TenantId},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Default)]
+struct Ledger {
+    invoices: Vec<Invoice>,
+    last_numbers: HashMap<(TenantId, InvoiceKind, i32), u32>,
+}
+
+/// In-memory implementation for testing
+pub struct InMemoryInvoiceRepository {
+    ledger: Arc<RwLock<Ledger>>,
+}
+
+impl InMemoryInvoiceRepository {
+    pub fn new() -> Self {
+        Self {
+            ledger: Arc::new(RwLock::new(Ledger::default())),
+        }
+    }
+}
+
+impl Default for InMemoryInvoiceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl InvoiceRepository for InMemoryInvoiceRepository {
+    async fn issue(&self, draft: InvoiceDraft) -> Result<Invoice, InfrastructureError> {
+        let mut ledger = self.ledger.write().await;
+        if ledger.invoices.iter().any(|i| i.id() == draft.id()) {
+            return Err(InfrastructureError::storage(format!(
+                "invoice {} already exists",
+                draft.id()
+            )));
+        }
+        if draft.kind() == InvoiceKind::Invoice
+            && ledger.invoices.iter().any(|i| {
+                i.kind() == InvoiceKind::Invoice
+                    && i.order_id() == draft.order_id()
+                    && i.tenant_id() == draft.tenant_id()
+            })
+        {
+            return Err(InfrastructureError::conflict(format!(
+                "order {} is already invoiced",
+                draft.order_id()
+            )));
+        }
+
+        if let Some(credited_id) = draft.credited_invoice_id() {
+            // Checked under the write lock: a concurrent refund may have been stored
+            // since the draft was made
+            let credited = ledger
+                .invoices
+                .iter()
+                .find(|i| i.id() == credited_id && i.tenant_id() == draft.tenant_id())
+                .ok_or_else(|| {
+                    InfrastructureError::storage(format!("invoice {} not found", credited_id))
+                })?;
+            let credit_notes: Vec<Invoice> = ledger
+                .invoices
+                .iter()
+                .filter(|i| i.credited_invoice_id() == Some(credited_id))
+                .cloned()
+                .collect();
+            credited
+                .ensure_creditable(&draft, &credit_notes)
+                .map_err(|err| InfrastructureError::conflict(err.to_string()))?;
+        }
+
+        let last_number = ledger
+            .last_numbers
+            .entry((draft.tenant_id().clone(), draft.kind(), draft.year()))
+            .or_default();
+        *last_number += 1;
+        let invoice = draft.into_invoice(*last_number);
+        ledger.invoices.push(invoice.clone());
+        Ok(invoice)
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: InvoiceId,
+    ) -> Result<Option<Invoice>, InfrastructureError> {
+        let ledger = self.ledger.read().await;
+        Ok(ledger
+            .invoices
+            .iter()
+            .find(|i| i.id() == id && i.tenant_id() == tenant_id)
+            .cloned())
+    }
+
+    async fn find_by_order(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+    ) -> Result<Vec<Invoice>, InfrastructureError> {
+        let ledger = self.ledger.read().await;
+        let mut invoices: Vec<Invoice> = ledger
+            .invoices
+            .iter()
+            .filter(|i| i.order_id() == order_id && i.tenant_id() == tenant_id)
+            .cloned()
+            .collect();
+        invoices.sort_by_key(|i| i.issued_at());
+        Ok(invoices)
+    }
+
+    async fn anonymize(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        let mut ledger = self.ledger.write().await;
+        ledger
+            .invoices
+            .iter_mut()
+            .filter(|i| i.order_id() == order_id && i.tenant_id() == tenant_id)
+            .for_each(|i| i.anonymize(pseudonym));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::invoice_contract::invoice_repository_contract_tests;
+
+    async fn repository() -> InMemoryInvoiceRepository {
+        InMemoryInvoiceRepository::new()
+    }
+
+    invoice_repository_contract_tests!(super::repository);
+}
@@ -0,0 +1,84 @@
+use crate::domain::{
+    aggregates::Wishlist,
+    errors::InfrastructureError,
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, ShareToken, TenantId},
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// In-memory implementation for testing
+pub struct InMemoryWishlistRepository {
+    wishlists: Arc<RwLock<HashMap<(TenantId, CustomerId), Wishlist>>>,
+}
+
+impl InMemoryWishlistRepository {
+    pub fn new() -> Self {
+        Self {
+            wishlists: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+impl Default for InMemoryWishlistRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl WishlistRepository for InMemoryWishlistRepository {
+    async fn save(&self, wishlist: &Wishlist) -> Result<(), InfrastructureError> {
+        let mut wishlists = self.wishlists.write().await;
+        wishlists.insert(
+            (wishlist.tenant_id().clone(), wishlist.customer_id()),
+            wishlist.clone(),
+        );
+        Ok(())
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Wishlist>, InfrastructureError> {
+        let wishlists = self.wishlists.read().await;
+        Ok(wishlists.get(&(tenant_id.clone(), customer_id)).cloned())
+    }
+
+    async fn find_by_share_token(
+        &self,
+        tenant_id: &TenantId,
+        token: &ShareToken,
+    ) -> Result<Option<Wishlist>, InfrastructureError> {
+        let wishlists = self.wishlists.read().await;
+        Ok(wishlists
+            .values()
+            .find(|w| w.tenant_id() == tenant_id && w.share_token() == Some(token))
+            .cloned())
+    }
+
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        let mut wishlists = self.wishlists.write().await;
+        wishlists.remove(&(tenant_id.clone(), customer_id));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::wishlist_contract::wishlist_repository_contract_tests;
+
+    async fn repository() -> InMemoryWishlistRepository {
+        InMemoryWishlistRepository::new()
+    }
+
+    wishlist_repository_contract_tests!(super::repository);
+}
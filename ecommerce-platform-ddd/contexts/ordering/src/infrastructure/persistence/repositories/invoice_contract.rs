@@ -0,0 +1,329 @@
+//! Behaviour every `InvoiceRepository` adapter must honour
+//!
+//! Each check takes a fresh, empty repository; `invoice_repository_contract_tests!`
+//! expands them into one test per check for a given adapter factory.
+
+use crate::domain::{
+    aggregates::{Invoice, InvoiceDraft, Order},
+    clock::FixedClock,
+    entities::OrderItem,
+    errors::InfrastructureErrorKind,
+    id_generator::UuidV4Generator,
+    repositories::InvoiceRepository,
+    tenant::Tenant,
+    value_objects::{CustomerId, InvoiceId, Money, OrderId, ProductId, TenantId},
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Whole seconds, so that every backend stores timestamps without loss
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 12, 31, 9, 0, 0).unwrap()
+}
+
+fn acme() -> TenantId {
+    "acme".parse().unwrap()
+}
+
+fn paid_order(clock: &FixedClock) -> Order {
+    paid_order_of(&Tenant::default(), clock)
+}
+
+fn paid_order_of(tenant: &Tenant, clock: &FixedClock) -> Order {
+    let items = vec![
+        OrderItem::new(
+            ProductId::new(),
+            "Keyboard".to_string(),
+            2,
+            Money::eur(Decimal::new(4990, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap(),
+        OrderItem::new(
+            ProductId::new(),
+            "Mouse".to_string(),
+            1,
+            Money::eur(Decimal::new(1999, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap(),
+    ];
+    let mut order =
+        Order::create(tenant, CustomerId::new(), items, &UuidV4Generator, clock).unwrap();
+    order.confirm(clock).unwrap();
+    order.mark_as_paid(Uuid::new_v4(), clock).unwrap();
+    order
+}
+
+async fn issue_invoice(repo: &dyn InvoiceRepository, clock: &FixedClock) -> Invoice {
+    let draft = Invoice::draft_for_order(&paid_order(clock), &UuidV4Generator, clock).unwrap();
+    repo.issue(draft).await.unwrap()
+}
+
+pub async fn issue_and_find_round_trip(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let invoice = issue_invoice(repo, &clock).await;
+
+    let found = repo
+        .find_by_id(&TenantId::default(), invoice.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.tenant_id(), &TenantId::default());
+    assert_eq!(found.number(), invoice.number());
+    assert_eq!(found.order_id(), invoice.order_id());
+    assert_eq!(found.customer_id(), invoice.customer_id());
+    assert_eq!(found.lines(), invoice.lines());
+    assert_eq!(found.total(), invoice.total());
+    assert_eq!(found.credited_invoice_id(), None);
+    assert_eq!(found.reason(), None);
+    assert_eq!(found.issued_at(), invoice.issued_at());
+}
+
+pub async fn numbers_are_sequential_per_kind_and_year(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let first = issue_invoice(repo, &clock).await;
+    let second = issue_invoice(repo, &clock).await;
+    let credit_note = first
+        .draft_credit_note(None, "Returned".to_string(), &[], &UuidV4Generator, &clock)
+        .unwrap();
+    let credit_note = repo.issue(credit_note).await.unwrap();
+    clock.advance(Duration::days(1));
+    let next_year = issue_invoice(repo, &clock).await;
+
+    assert_eq!(first.number().to_string(), "INV-2026-000001");
+    assert_eq!(second.number().to_string(), "INV-2026-000002");
+    assert_eq!(credit_note.number().to_string(), "CN-2026-000001");
+    assert_eq!(next_year.number().to_string(), "INV-2027-000001");
+}
+
+pub async fn failed_issue_does_not_consume_a_number(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let draft = Invoice::draft_for_order(&paid_order(&clock), &UuidV4Generator, &clock).unwrap();
+    repo.issue(draft.clone()).await.unwrap();
+
+    assert!(repo.issue(draft).await.is_err());
+    let next = issue_invoice(repo, &clock).await;
+    assert_eq!(next.number().to_string(), "INV-2026-000002");
+}
+
+pub async fn order_is_invoiced_once(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let order = paid_order(&clock);
+    let draft = || Invoice::draft_for_order(&order, &UuidV4Generator, &clock).unwrap();
+    repo.issue(draft()).await.unwrap();
+
+    let err = repo.issue(draft()).await.unwrap_err();
+    assert_eq!(err.kind(), InfrastructureErrorKind::Conflict);
+    assert_eq!(
+        repo.find_by_order(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    // The refused invoice consumed no number
+    let next = issue_invoice(repo, &clock).await;
+    assert_eq!(next.number().to_string(), "INV-2026-000002");
+}
+
+pub async fn find_by_order_lists_invoice_then_credit_notes(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let invoice = issue_invoice(repo, &clock).await;
+    issue_invoice(repo, &clock).await;
+    clock.advance(Duration::hours(1));
+    let keyboard = invoice.lines()[0].order_item_id();
+    let credit_note = invoice
+        .draft_credit_note(
+            Some(vec![(keyboard, 1)]),
+            "Damaged".to_string(),
+            &[],
+            &UuidV4Generator,
+            &clock,
+        )
+        .unwrap();
+    let credit_note = repo.issue(credit_note).await.unwrap();
+
+    let found = repo
+        .find_by_order(&TenantId::default(), invoice.order_id())
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].id(), invoice.id());
+    assert_eq!(found[1].id(), credit_note.id());
+    assert_eq!(found[1].credited_invoice_id(), Some(invoice.id()));
+    assert_eq!(found[1].reason(), Some("Damaged"));
+    assert_eq!(found[1].lines()[0].quantity(), 1);
+}
+
+/// Refund of `quantity` keyboards, drafted without looking at stored credit notes
+fn keyboard_refund(invoice: &Invoice, quantity: u32, clock: &FixedClock) -> InvoiceDraft {
+    let keyboard = invoice.lines()[0].order_item_id();
+    invoice
+        .draft_credit_note(
+            Some(vec![(keyboard, quantity)]),
+            "Damaged".to_string(),
+            &[],
+            &UuidV4Generator,
+            clock,
+        )
+        .unwrap()
+}
+
+pub async fn concurrent_credit_notes_never_refund_a_unit_twice(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    // Two keyboards billed: one refund each fits
+    let invoice = issue_invoice(repo, &clock).await;
+    let (first, second) = tokio::join!(
+        repo.issue(keyboard_refund(&invoice, 1, &clock)),
+        repo.issue(keyboard_refund(&invoice, 1, &clock)),
+    );
+    assert!(first.is_ok() && second.is_ok());
+
+    // Both keyboards twice, as by two requests handled at the same time
+    let invoice = issue_invoice(repo, &clock).await;
+    let (first, second) = tokio::join!(
+        repo.issue(keyboard_refund(&invoice, 2, &clock)),
+        repo.issue(keyboard_refund(&invoice, 2, &clock)),
+    );
+
+    let (stored, refused): (Vec<_>, Vec<_>) = [first, second].into_iter().partition(Result::is_ok);
+    assert_eq!(stored.len(), 1);
+    assert_eq!(
+        refused[0].as_ref().unwrap_err().kind(),
+        InfrastructureErrorKind::Conflict
+    );
+    assert_eq!(
+        repo.find_by_order(&TenantId::default(), invoice.order_id())
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    // The refused credit note consumed no number
+    assert_eq!(
+        stored[0].as_ref().unwrap().number().to_string(),
+        "CN-2026-000003"
+    );
+}
+
+pub async fn anonymize_replaces_only_the_customer_of_the_order(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let invoice = issue_invoice(repo, &clock).await;
+    let other = issue_invoice(repo, &clock).await;
+    let credit_note = invoice
+        .draft_credit_note(None, "Returned".to_string(), &[], &UuidV4Generator, &clock)
+        .unwrap();
+    repo.issue(credit_note).await.unwrap();
+    // Same order ID on another tenant: not the customer's order
+    repo.anonymize(&acme(), invoice.order_id(), CustomerId::new())
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.find_by_order(&TenantId::default(), invoice.order_id())
+            .await
+            .unwrap()[0]
+            .customer_id(),
+        invoice.customer_id()
+    );
+
+    let pseudonym = CustomerId::new();
+    repo.anonymize(&TenantId::default(), invoice.order_id(), pseudonym)
+        .await
+        .unwrap();
+
+    let found = repo
+        .find_by_order(&TenantId::default(), invoice.order_id())
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 2);
+    for kept in &found {
+        assert_eq!(kept.customer_id(), pseudonym);
+    }
+    assert_eq!(found[0].number(), invoice.number());
+    assert_eq!(found[0].lines(), invoice.lines());
+    assert_eq!(found[0].total(), invoice.total());
+    let other_found = repo
+        .find_by_id(&TenantId::default(), other.id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(other_found.customer_id(), other.customer_id());
+}
+
+pub async fn find_returns_nothing_for_unknown_invoice(repo: &dyn InvoiceRepository) {
+    let tenant_id = TenantId::default();
+    assert!(repo
+        .find_by_id(&tenant_id, InvoiceId::new())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_order(&tenant_id, OrderId::new())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+pub async fn invoices_are_numbered_and_isolated_per_tenant(repo: &dyn InvoiceRepository) {
+    let clock = FixedClock::new(start());
+    let default = issue_invoice(repo, &clock).await;
+    let order = paid_order_of(&Tenant::new(acme()), &clock);
+    let draft = Invoice::draft_for_order(&order, &UuidV4Generator, &clock).unwrap();
+    let acme_invoice = repo.issue(draft).await.unwrap();
+
+    // Each tenant has its own series
+    assert_eq!(default.number().to_string(), "INV-2026-000001");
+    assert_eq!(acme_invoice.number().to_string(), "INV-2026-000001");
+    assert_eq!(acme_invoice.tenant_id(), &acme());
+
+    assert!(repo
+        .find_by_id(&TenantId::default(), acme_invoice.id())
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .find_by_order(&TenantId::default(), order.id())
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(repo
+        .find_by_id(&acme(), default.id())
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        repo.find_by_order(&acme(), order.id()).await.unwrap().len(),
+        1
+    );
+}
+
+macro_rules! invoice_repository_contract_tests {
+    ($factory:path) => {
+        $crate::infrastructure::persistence::repositories::invoice_contract::invoice_repository_contract_tests!(
+            @tests $factory;
+            issue_and_find_round_trip,
+            numbers_are_sequential_per_kind_and_year,
+            failed_issue_does_not_consume_a_number,
+            order_is_invoiced_once,
+            find_by_order_lists_invoice_then_credit_notes,
+            concurrent_credit_notes_never_refund_a_unit_twice,
+            anonymize_replaces_only_the_customer_of_the_order,
+            find_returns_nothing_for_unknown_invoice,
+            invoices_are_numbered_and_isolated_per_tenant,
+        );
+    };
+    (@tests $factory:path; $($check:ident),* $(,)?) => {
+        mod contract {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let repo = $factory().await;
+                    $crate::infrastructure::persistence::repositories::invoice_contract::$check(&repo).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use invoice_repository_contract_tests;
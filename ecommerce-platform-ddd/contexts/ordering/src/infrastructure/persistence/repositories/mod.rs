@@ -1,3 +1,25 @@
+#[cfg(test)]
+pub(crate) mod cart_contract;
+#[cfg(test)]
+pub(crate) mod contract;
+#[cfg(test)]
+pub(crate) mod invoice_contract;
+#[cfg(test)]
+pub(crate) mod wishlist_contract;
 pub mod in_memory;
+pub mod in_memory_cart;
+pub mod in_memory_invoice;
+pub mod in_memory_wishlist;
+pub mod sql;
+pub mod sql_cart;
+pub mod sql_invoice;
+pub mod sql_wishlist;
 
 pub use in_memory::InMemoryOrderRepository;
+pub use in_memory_cart::InMemoryCartRepository;
+pub use in_memory_invoice::InMemoryInvoiceRepository;
+pub use in_memory_wishlist::InMemoryWishlistRepository;
+pub use sql::SqlOrderRepository;
+pub use sql_cart::SqlCartRepository;
+pub use sql_invoice::SqlInvoiceRepository;
+pub use sql_wishlist::SqlWishlistRepository;
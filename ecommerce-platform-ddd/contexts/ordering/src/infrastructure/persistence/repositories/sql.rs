@@ -0,0 +1,624 @@
+use crate::domain::{
+    aggregates::Order,
+    entities::{OrderItem, Shipment, ShipmentLine},
+    errors::InfrastructureError,
+    repositories::{OrderCriteria, OrderCursor, OrderRepository},
+    tenant::TaxRule,
+    value_objects::{
+        CustomerId, Money, OrderId, OrderItemId, OrderStatus, PostalAddress, ProductId,
+        ShipmentId, ShipmentStatus, TenantId,
+    },
+};
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::{order, order_item, shipment, shipment_line},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::{Alias, Expr, ExprTrait, OnConflict},
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, Condition, DatabaseConnection, DatabaseTransaction, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait,
+};
+
+/// SeaORM implementation (PostgreSQL in production, SQLite in tests)
+pub struct SqlOrderRepository {
+    db: DatabaseConnection,
+}
+
+impl SqlOrderRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Rebuild aggregates from their rows (children are sorted by position)
+    async fn load(&self, rows: Vec<order::Model>) -> Result<Vec<Order>, InfrastructureError> {
+        let ids: Vec<_> = rows.iter().map(|row| row.id).collect();
+        let items = order_item::Entity::find()
+            .filter(order_item::Column::OrderId.is_in(ids.clone()))
+            .order_by_asc(order_item::Column::Position)
+            .all(&self.db)
+            .await?;
+        let shipments = shipment::Entity::find()
+            .filter(shipment::Column::OrderId.is_in(ids.clone()))
+            .order_by_asc(shipment::Column::Position)
+            .all(&self.db)
+            .await?;
+        let lines = shipment_line::Entity::find()
+            .filter(shipment_line::Column::OrderId.is_in(ids))
+            .order_by_asc(shipment_line::Column::Position)
+            .all(&self.db)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let items = items
+                    .iter()
+                    .filter(|item| item.order_id == row.id)
+                    .map(to_domain_item)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let shipments = shipments
+                    .iter()
+                    .filter(|shipment| shipment.order_id == row.id)
+                    .map(|shipment| to_domain_shipment(shipment, &lines))
+                    .collect::<Result<Vec<_>, _>>()?;
+                to_domain_order(row, items, shipments)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl OrderRepository for SqlOrderRepository {
+    async fn save(&self, order: &mut Order) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+
+        let saved = order::Entity::insert(to_order_row(order)?)
+            .on_conflict(
+                OnConflict::column(order::Column::Id)
+                    .update_columns([
+                        // Change when the customer's personal data is erased
+                        order::Column::CustomerId,
+                        order::Column::BillingAddress,
+                        order::Column::ShippingAddress,
+                        order::Column::Status,
+                        order::Column::TotalAmount,
+                        order::Column::Currency,
+                        order::Column::UpdatedAt,
+                    ])
+                    // Never take over the order of another tenant
+                    .action_and_where(
+                        Expr::col((order::Entity, order::Column::TenantId))
+                            .eq(Expr::col((Alias::new("excluded"), order::Column::TenantId))),
+                    )
+                    .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+        if saved == 0 {
+            return Err(InfrastructureError::storage(format!(
+                "order {} belongs to another tenant",
+                order.id()
+            )));
+        }
+
+        replace_children(&txn, order).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn save_if_status(
+        &self,
+        order: &mut Order,
+        expected: OrderStatus,
+    ) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+
+        let mut row = to_order_row(order)?;
+        row.id = NotSet;
+        row.tenant_id = NotSet;
+        row.created_at = NotSet;
+        let updated = order::Entity::update_many()
+            .set(row)
+            .filter(order::Column::Id.eq(order.id().value()))
+            .filter(order::Column::TenantId.eq(order.tenant_id().as_str()))
+            .filter(order::Column::Status.eq(expected.to_string()))
+            .exec(&txn)
+            .await?;
+        if updated.rows_affected == 0 {
+            return Err(InfrastructureError::conflict(format!(
+                "order {} is no longer {}",
+                order.id(),
+                expected
+            )));
+        }
+
+        replace_children(&txn, order).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: OrderId,
+    ) -> Result<Option<Order>, InfrastructureError> {
+        let Some(row) = order::Entity::find_by_id(id.value())
+            .filter(order::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&self.db)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(self.load(vec![row]).await?.pop())
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Vec<Order>, InfrastructureError> {
+        let rows = order::Entity::find()
+            .filter(order::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(order::Column::CustomerId.eq(customer_id.value()))
+            .order_by_asc(order::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+        self.load(rows).await
+    }
+
+    async fn find_stale(
+        &self,
+        status: OrderStatus,
+        updated_before: DateTime<Utc>,
+    ) -> Result<Vec<Order>, InfrastructureError> {
+        let rows = order::Entity::find()
+            .filter(order::Column::Status.eq(status.to_string()))
+            .filter(order::Column::UpdatedAt.lte(updated_before))
+            .order_by_asc(order::Column::UpdatedAt)
+            .all(&self.db)
+            .await?;
+        self.load(rows).await
+    }
+
+    async fn find_page(
+        &self,
+        criteria: &OrderCriteria,
+        after: Option<OrderCursor>,
+        limit: u64,
+    ) -> Result<Vec<Order>, InfrastructureError> {
+        let mut query = order::Entity::find();
+        if let Some(tenant_id) = &criteria.tenant_id {
+            query = query.filter(order::Column::TenantId.eq(tenant_id.as_str()));
+        }
+        if let Some(status) = criteria.status {
+            query = query.filter(order::Column::Status.eq(status.to_string()));
+        }
+        if let Some(from) = criteria.created_from {
+            query = query.filter(order::Column::CreatedAt.gte(from));
+        }
+        if let Some(until) = criteria.created_until {
+            query = query.filter(order::Column::CreatedAt.lt(until));
+        }
+        if let Some((created_at, id)) = after {
+            query = query.filter(
+                Condition::any()
+                    .add(order::Column::CreatedAt.gt(created_at))
+                    .add(
+                        Condition::all()
+                            .add(order::Column::CreatedAt.eq(created_at))
+                            .add(order::Column::Id.gt(id.value())),
+                    ),
+            );
+        }
+        let rows = query
+            .order_by_asc(order::Column::CreatedAt)
+            .order_by_asc(order::Column::Id)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+        self.load(rows).await
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: OrderId) -> Result<(), InfrastructureError> {
+        let owned = order::Entity::find_by_id(id.value())
+            .filter(order::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&self.db)
+            .await?
+            .is_some();
+        if !owned {
+            return Ok(());
+        }
+        let txn = self.db.begin().await?;
+        delete_shipments(&txn, id).await?;
+        order_item::Entity::delete_many()
+            .filter(order_item::Column::OrderId.eq(id.value()))
+            .exec(&txn)
+            .await?;
+        order::Entity::delete_by_id(id.value()).exec(&txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+/// Items and shipments belong to the aggregate: replace them as a whole
+async fn replace_children(
+    txn: &DatabaseTransaction,
+    order: &Order,
+) -> Result<(), InfrastructureError> {
+    order_item::Entity::delete_many()
+        .filter(order_item::Column::OrderId.eq(order.id().value()))
+        .exec(txn)
+        .await?;
+    order_item::Entity::insert_many(
+        order
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(position, item)| to_item_row(order.id(), position, item)),
+    )
+    .exec_without_returning(txn)
+    .await?;
+
+    // Shipments too (lines first, they reference their shipment)
+    delete_shipments(txn, order.id()).await?;
+    if !order.shipments().is_empty() {
+        shipment::Entity::insert_many(
+            order
+                .shipments()
+                .iter()
+                .enumerate()
+                .map(|(position, shipment)| to_shipment_row(order.id(), position, shipment)),
+        )
+        .exec_without_returning(txn)
+        .await?;
+        shipment_line::Entity::insert_many(order.shipments().iter().flat_map(|shipment| {
+            shipment.lines().iter().enumerate().map(|(position, line)| {
+                to_shipment_line_row(order.id(), shipment.id(), position, line)
+            })
+        }))
+        .exec_without_returning(txn)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn delete_shipments(
+    txn: &DatabaseTransaction,
+    order_id: OrderId,
+) -> Result<(), InfrastructureError> {
+    shipment_line::Entity::delete_many()
+        .filter(shipment_line::Column::OrderId.eq(order_id.value()))
+        .exec(txn)
+        .await?;
+    shipment::Entity::delete_many()
+        .filter(shipment::Column::OrderId.eq(order_id.value()))
+        .exec(txn)
+        .await?;
+    Ok(())
+}
+
+// Mapping between domain objects and database rows
+
+fn to_order_row(order: &Order) -> Result<order::ActiveModel, InfrastructureError> {
+    let address = |address: Option<&PostalAddress>| {
+        address
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|err| {
+                InfrastructureError::storage(format!("order {}", order.id())).with_source(err)
+            })
+    };
+    Ok(order::ActiveModel {
+        id: Set(order.id().value()),
+        tenant_id: Set(order.tenant_id().to_string()),
+        customer_id: Set(order.customer_id().value()),
+        status: Set(order.status().to_string()),
+        total_amount: Set(order.total().amount()),
+        currency: Set(order.total().currency().to_string()),
+        tax_rate: Set(order.tax_rule().rate()),
+        tax_included: Set(order.tax_rule().included()),
+        billing_address: Set(address(order.billing_address())?),
+        shipping_address: Set(address(order.shipping_address())?),
+        created_at: Set(order.created_at()),
+        updated_at: Set(order.updated_at()),
+    })
+}
+
+fn to_item_row(order_id: OrderId, position: usize, item: &OrderItem) -> order_item::ActiveModel {
+    order_item::ActiveModel {
+        id: Set(item.id().value()),
+        order_id: Set(order_id.value()),
+        position: Set(position as i32),
+        product_id: Set(item.product_id().value()),
+        product_name: Set(item.product_name().to_string()),
+        quantity: Set(item.quantity() as i32),
+        unit_price: Set(item.unit_price().amount()),
+        currency: Set(item.unit_price().currency().to_string()),
+        list_price: Set(Some(item.list_price().amount())),
+        price_rule: Set(item.price_rule().map(str::to_string)),
+    }
+}
+
+fn to_shipment_row(
+    order_id: OrderId,
+    position: usize,
+    shipment: &Shipment,
+) -> shipment::ActiveModel {
+    shipment::ActiveModel {
+        id: Set(shipment.id().value()),
+        order_id: Set(order_id.value()),
+        position: Set(position as i32),
+        status: Set(shipment.status().to_string()),
+        tracking_number: Set(shipment.tracking_number().map(str::to_string)),
+        created_at: Set(shipment.created_at()),
+        shipped_at: Set(shipment.shipped_at()),
+    }
+}
+
+fn to_shipment_line_row(
+    order_id: OrderId,
+    shipment_id: ShipmentId,
+    position: usize,
+    line: &ShipmentLine,
+) -> shipment_line::ActiveModel {
+    shipment_line::ActiveModel {
+        shipment_id: Set(shipment_id.value()),
+        order_item_id: Set(line.order_item_id.value()),
+        order_id: Set(order_id.value()),
+        position: Set(position as i32),
+        quantity: Set(line.quantity as i32),
+    }
+}
+
+fn to_domain_order(
+    row: order::Model,
+    items: Vec<OrderItem>,
+    shipments: Vec<Shipment>,
+) -> Result<Order, InfrastructureError> {
+    let what = format!("order {}", row.id);
+    let status = row.status.parse().map_err(corrupted(what.clone()))?;
+    let tenant_id = row
+        .tenant_id
+        .parse::<TenantId>()
+        .map_err(corrupted(what.clone()))?;
+    let tax = TaxRule::new(row.tax_rate, row.tax_included).map_err(corrupted(what.clone()))?;
+    let address = |json: Option<&String>| {
+        json.map(|json| serde_json::from_str::<PostalAddress>(json))
+            .transpose()
+            .map_err(corrupted(what.clone()))
+    };
+    let billing_address = address(row.billing_address.as_ref())?;
+    let shipping_address = address(row.shipping_address.as_ref())?;
+    let order = Order::reconstitute(
+        OrderId::from_uuid(row.id),
+        tenant_id,
+        CustomerId::from_uuid(row.customer_id),
+        items,
+        status,
+        tax,
+        shipments,
+        row.created_at,
+        row.updated_at,
+    )
+    .map_err(corrupted(what))?;
+    Ok(order.with_addresses(billing_address, shipping_address))
+}
+
+fn to_domain_shipment(
+    row: &shipment::Model,
+    lines: &[shipment_line::Model],
+) -> Result<Shipment, InfrastructureError> {
+    let what = format!("shipment {}", row.id);
+    let status = row
+        .status
+        .parse::<ShipmentStatus>()
+        .map_err(corrupted(what.clone()))?;
+    let lines = lines
+        .iter()
+        .filter(|line| line.shipment_id == row.id)
+        .map(|line| {
+            Ok(ShipmentLine {
+                order_item_id: OrderItemId::from_uuid(line.order_item_id),
+                quantity: u32::try_from(line.quantity).map_err(corrupted(what.clone()))?,
+            })
+        })
+        .collect::<Result<Vec<_>, InfrastructureError>>()?;
+    Shipment::reconstitute(
+        ShipmentId::from_uuid(row.id),
+        lines,
+        status,
+        row.tracking_number.clone(),
+        row.created_at,
+        row.shipped_at,
+    )
+    .map_err(corrupted(what))
+}
+
+fn to_domain_item(row: &order_item::Model) -> Result<OrderItem, InfrastructureError> {
+    let what = format!("order item {}", row.id);
+    let quantity = u32::try_from(row.quantity).map_err(corrupted(what.clone()))?;
+    let currency = row.currency.parse().map_err(corrupted(what.clone()))?;
+    let unit_price = Money::new(row.unit_price, currency).map_err(corrupted(what.clone()))?;
+    let list_price = Money::new(row.list_price.unwrap_or(row.unit_price), currency)
+        .map_err(corrupted(what.clone()))?;
+    OrderItem::reconstitute(
+        OrderItemId::from_uuid(row.id),
+        ProductId::from_uuid(row.product_id),
+        row.product_name.clone(),
+        quantity,
+        unit_price,
+        list_price,
+        row.price_rule.clone(),
+    )
+    .map_err(corrupted(what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::clock::{Clock, FixedClock, SystemClock};
+    use crate::domain::id_generator::UuidV4Generator;
+    use crate::domain::tenant::Tenant;
+    use crate::infrastructure::persistence::repositories::contract::order_repository_contract_tests;
+    use crate::infrastructure::persistence::Migrator;
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn repository() -> SqlOrderRepository {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlOrderRepository::new(db)
+    }
+
+    order_repository_contract_tests!(super::repository);
+
+    fn item(name: &str, quantity: u32, cents: i64) -> OrderItem {
+        OrderItem::new(
+            ProductId::new(),
+            name.to_string(),
+            quantity,
+            Money::eur(Decimal::new(cents, 2)).unwrap(),
+            &UuidV4Generator,
+        )
+        .unwrap()
+    }
+
+    fn create(customer_id: CustomerId, items: Vec<OrderItem>, clock: &dyn Clock) -> Order {
+        Order::create(
+            &Tenant::default(),
+            customer_id,
+            items,
+            &UuidV4Generator,
+            clock,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find_round_trip() {
+        let repo = repository().await;
+        let mut order = create(
+            CustomerId::new(),
+            vec![item("Keyboard", 2, 4990), item("Mouse", 1, 1999)],
+            &SystemClock,
+        );
+        repo.save(&mut order).await.unwrap();
+
+        let found = repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.customer_id(), order.customer_id());
+        assert_eq!(found.status(), OrderStatus::Pending);
+        assert_eq!(found.total(), order.total());
+        assert_eq!(found.items().len(), 2);
+        assert_eq!(found.items()[0].id(), order.items()[0].id());
+        assert_eq!(found.items()[1].product_name(), "Mouse");
+    }
+
+    #[tokio::test]
+    async fn test_save_updates_existing_order() {
+        let repo = repository().await;
+        let customer_id = CustomerId::new();
+        let mut order = create(customer_id, vec![item("Keyboard", 1, 4990)], &SystemClock);
+        repo.save(&mut order).await.unwrap();
+
+        order
+            .add_item(item("Mouse", 1, 1999), &SystemClock)
+            .unwrap();
+        order.confirm(&SystemClock).unwrap();
+        repo.save(&mut order).await.unwrap();
+
+        let orders = repo
+            .find_by_customer(&TenantId::default(), customer_id)
+            .await
+            .unwrap();
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].status(), OrderStatus::Confirmed);
+        assert_eq!(orders[0].items().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_find_stale() {
+        let repo = repository().await;
+        let mut stale = create(
+            CustomerId::new(),
+            vec![item("Keyboard", 1, 4990)],
+            &SystemClock,
+        );
+        repo.save(&mut stale).await.unwrap();
+        let mut confirmed = create(
+            CustomerId::new(),
+            vec![item("Mouse", 1, 1999)],
+            &SystemClock,
+        );
+        confirmed.confirm(&SystemClock).unwrap();
+        repo.save(&mut confirmed).await.unwrap();
+
+        let clock = FixedClock::new(stale.updated_at() + Duration::hours(1));
+        let mut fresh = create(CustomerId::new(), vec![item("Screen", 1, 19900)], &clock);
+        repo.save(&mut fresh).await.unwrap();
+
+        let found = repo
+            .find_stale(OrderStatus::Pending, clock.now() - Duration::minutes(30))
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id(), stale.id());
+    }
+
+    #[tokio::test]
+    async fn test_find_page_walks_matching_orders_in_creation_order() {
+        let repo = repository().await;
+        let clock = FixedClock::new(Utc::now());
+        let mut created = Vec::new();
+        for _ in 0..5 {
+            let mut order = create(CustomerId::new(), vec![item("Keyboard", 1, 4990)], &clock);
+            repo.save(&mut order).await.unwrap();
+            created.push(order.id());
+            clock.advance(Duration::minutes(1));
+        }
+        let mut cancelled = create(CustomerId::new(), vec![item("Mouse", 1, 1999)], &clock);
+        cancelled.cancel("Test".to_string(), &clock).unwrap();
+        repo.save(&mut cancelled).await.unwrap();
+
+        let criteria = OrderCriteria {
+            status: Some(OrderStatus::Pending),
+            ..Default::default()
+        };
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = repo.find_page(&criteria, after, 2).await.unwrap();
+            let Some(last) = page.last() else { break };
+            after = Some((last.created_at(), last.id()));
+            seen.extend(page.iter().map(Order::id));
+        }
+        assert_eq!(seen, created);
+    }
+
+    #[tokio::test]
+    async fn test_delete() {
+        let repo = repository().await;
+        let mut order = create(
+            CustomerId::new(),
+            vec![item("Keyboard", 1, 4990)],
+            &SystemClock,
+        );
+        repo.save(&mut order).await.unwrap();
+
+        repo.delete(&TenantId::default(), order.id()).await.unwrap();
+        assert!(repo
+            .find_by_id(&TenantId::default(), order.id())
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::domain::{
+    aggregates::Cart,
+    entities::CartLine,
+    errors::InfrastructureError,
+    repositories::CartRepository,
+    value_objects::{CartId, CustomerId, Money, ProductId, TenantId},
+};
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::{cart, cart_line},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::{Alias, Expr, ExprTrait, OnConflict},
+    ActiveValue::Set,
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+
+/// SeaORM implementation (PostgreSQL in production, SQLite in tests)
+pub struct SqlCartRepository {
+    db: DatabaseConnection,
+}
+
+impl SqlCartRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Rebuild the aggregate from its row (lines are sorted by position)
+    async fn load(&self, row: cart::Model) -> Result<Cart, InfrastructureError> {
+        let lines = cart_line::Entity::find()
+            .filter(cart_line::Column::CartId.eq(row.id))
+            .order_by_asc(cart_line::Column::Position)
+            .all(&self.db)
+            .await?
+            .iter()
+            .map(to_domain_line)
+            .collect::<Result<Vec<_>, _>>()?;
+        let tenant_id = row
+            .tenant_id
+            .parse()
+            .map_err(corrupted(format!("cart {}", row.id)))?;
+        Ok(Cart::reconstitute(
+            CartId::from_uuid(row.id),
+            tenant_id,
+            row.customer_id.map(CustomerId::from_uuid),
+            lines,
+            row.created_at,
+            row.updated_at,
+            row.expires_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl CartRepository for SqlCartRepository {
+    async fn save(&self, cart: &Cart) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+
+        let saved = cart::Entity::insert(to_cart_row(cart))
+            .on_conflict(
+                OnConflict::column(cart::Column::Id)
+                    .update_columns([
+                        cart::Column::CustomerId,
+                        cart::Column::UpdatedAt,
+                        cart::Column::ExpiresAt,
+                    ])
+                    // Never take over the cart of another tenant
+                    .action_and_where(
+                        Expr::col((cart::Entity, cart::Column::TenantId))
+                            .eq(Expr::col((Alias::new("excluded"), cart::Column::TenantId))),
+                    )
+                    .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+        if saved == 0 {
+            return Err(InfrastructureError::storage(format!(
+                "cart {} belongs to another tenant",
+                cart.id()
+            )));
+        }
+
+        // Lines belong to the aggregate: replace them as a whole
+        cart_line::Entity::delete_many()
+            .filter(cart_line::Column::CartId.eq(cart.id().value()))
+            .exec(&txn)
+            .await?;
+        if !cart.lines().is_empty() {
+            cart_line::Entity::insert_many(
+                cart.lines()
+                    .iter()
+                    .enumerate()
+                    .map(|(position, line)| to_line_row(cart.id(), position, line)),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: CartId,
+    ) -> Result<Option<Cart>, InfrastructureError> {
+        let row = cart::Entity::find_by_id(id.value())
+            .filter(cart::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&self.db)
+            .await?;
+        match row {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Cart>, InfrastructureError> {
+        let row = cart::Entity::find()
+            .filter(cart::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(cart::Column::CustomerId.eq(customer_id.value()))
+            .one(&self.db)
+            .await?;
+        match row {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, InfrastructureError> {
+        let txn = self.db.begin().await?;
+        let expired: Vec<uuid::Uuid> = cart::Entity::find()
+            .select_only()
+            .column(cart::Column::Id)
+            .filter(cart::Column::ExpiresAt.lte(now))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if expired.is_empty() {
+            return Ok(0);
+        }
+
+        cart_line::Entity::delete_many()
+            .filter(cart_line::Column::CartId.is_in(expired.clone()))
+            .exec(&txn)
+            .await?;
+        let deleted = cart::Entity::delete_many()
+            .filter(cart::Column::Id.is_in(expired))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(deleted.rows_affected)
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: CartId) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+        let owned = cart::Entity::find_by_id(id.value())
+            .filter(cart::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&txn)
+            .await?
+            .is_some();
+        if !owned {
+            return Ok(());
+        }
+        cart_line::Entity::delete_many()
+            .filter(cart_line::Column::CartId.eq(id.value()))
+            .exec(&txn)
+            .await?;
+        cart::Entity::delete_by_id(id.value()).exec(&txn).await?;
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+// Mapping between domain objects and database rows
+
+fn to_cart_row(cart: &Cart) -> cart::ActiveModel {
+    cart::ActiveModel {
+        id: Set(cart.id().value()),
+        tenant_id: Set(cart.tenant_id().to_string()),
+        customer_id: Set(cart.customer_id().map(|id| id.value())),
+        created_at: Set(cart.created_at()),
+        updated_at: Set(cart.updated_at()),
+        expires_at: Set(cart.expires_at()),
+    }
+}
+
+fn to_line_row(cart_id: CartId, position: usize, line: &CartLine) -> cart_line::ActiveModel {
+    cart_line::ActiveModel {
+        cart_id: Set(cart_id.value()),
+        product_id: Set(line.product_id().value()),
+        position: Set(position as i32),
+        product_name: Set(line.product_name().to_string()),
+        quantity: Set(line.quantity() as i32),
+        unit_price: Set(line.unit_price().amount()),
+        currency: Set(line.unit_price().currency().to_string()),
+    }
+}
+
+fn to_domain_line(row: &cart_line::Model) -> Result<CartLine, InfrastructureError> {
+    let what = format!("cart {}", row.cart_id);
+    let quantity = u32::try_from(row.quantity).map_err(corrupted(what.clone()))?;
+    let currency = row.currency.parse().map_err(corrupted(what.clone()))?;
+    let unit_price = Money::new(row.unit_price, currency).map_err(corrupted(what.clone()))?;
+    CartLine::new(
+        ProductId::from_uuid(row.product_id),
+        row.product_name.clone(),
+        quantity,
+        unit_price,
+    )
+    .map_err(corrupted(what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::cart_contract::cart_repository_contract_tests;
+    use crate::infrastructure::persistence::Migrator;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn repository() -> SqlCartRepository {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlCartRepository::new(db)
+    }
+
+    cart_repository_contract_tests!(super::repository);
+}
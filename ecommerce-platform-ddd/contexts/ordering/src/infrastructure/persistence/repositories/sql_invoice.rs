@@ -0,0 +1,270 @@
+use crate::domain::{
+    aggregates::{Invoice, InvoiceDraft},
+    entities::InvoiceLine,
+    errors::InfrastructureError,
+    repositories::InvoiceRepository,
+    value_objects::{
+        CustomerId, InvoiceId, InvoiceNumber, Money, OrderId, OrderItemId, ProductId, TenantId,
+    },
+};
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::{invoice, invoice_line, invoice_sequence},
+};
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{Expr, ExprTrait, OnConflict},
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, TransactionTrait,
+};
+
+/// SeaORM implementation (PostgreSQL in production, SQLite in tests)
+pub struct SqlInvoiceRepository {
+    db: DatabaseConnection,
+}
+
+impl SqlInvoiceRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+/// Rebuild the aggregate from its row (lines are sorted by position)
+async fn load(
+    db: &impl ConnectionTrait,
+    row: invoice::Model,
+) -> Result<Invoice, InfrastructureError> {
+    let lines = invoice_line::Entity::find()
+        .filter(invoice_line::Column::InvoiceId.eq(row.id))
+        .order_by_asc(invoice_line::Column::Position)
+        .all(db)
+        .await?
+        .iter()
+        .map(to_domain_line)
+        .collect::<Result<Vec<_>, _>>()?;
+    let what = format!("invoice {}", row.id);
+    let number: InvoiceNumber = row.number.parse().map_err(corrupted(what.clone()))?;
+    let tenant_id = row.tenant_id.parse().map_err(corrupted(what.clone()))?;
+    let currency = row.currency.parse().map_err(corrupted(what.clone()))?;
+    Invoice::reconstitute(
+        InvoiceId::from_uuid(row.id),
+        number,
+        tenant_id,
+        OrderId::from_uuid(row.order_id),
+        CustomerId::from_uuid(row.customer_id),
+        lines,
+        currency,
+        row.credited_invoice_id.map(InvoiceId::from_uuid),
+        row.reason,
+        row.issued_at,
+    )
+    .map_err(corrupted(what))
+}
+
+#[async_trait]
+impl InvoiceRepository for SqlInvoiceRepository {
+    async fn issue(&self, draft: InvoiceDraft) -> Result<Invoice, InfrastructureError> {
+        let txn = self.db.begin().await?;
+        let tenant_id = draft.tenant_id().to_string();
+        let kind = draft.kind().to_string();
+        let year = draft.year();
+
+        if let Some(credited_id) = draft.credited_invoice_id() {
+            // Lock the refunded invoice, then check the draft against the credit notes
+            // stored meanwhile: concurrent refunds cannot both credit the same units
+            let credited = invoice::Entity::find_by_id(credited_id.value())
+                .filter(invoice::Column::TenantId.eq(tenant_id.clone()))
+                .lock_exclusive()
+                .one(&txn)
+                .await?
+                .ok_or_else(|| {
+                    InfrastructureError::storage(format!("invoice {} not found", credited_id))
+                })?;
+            let credited = load(&txn, credited).await?;
+            let mut credit_notes = Vec::new();
+            for row in invoice::Entity::find()
+                .filter(invoice::Column::CreditedInvoiceId.eq(credited_id.value()))
+                .all(&txn)
+                .await?
+            {
+                credit_notes.push(load(&txn, row).await?);
+            }
+            credited
+                .ensure_creditable(&draft, &credit_notes)
+                .map_err(|err| InfrastructureError::conflict(err.to_string()))?;
+        }
+
+        // Take the next number of the series. The counter row stays locked until
+        // commit, and a rolled back issue leaves it untouched: no gap, no duplicate.
+        invoice_sequence::Entity::insert(invoice_sequence::ActiveModel {
+            tenant_id: Set(tenant_id.clone()),
+            kind: Set(kind.clone()),
+            year: Set(year),
+            last_number: Set(0),
+        })
+        .on_conflict(
+            OnConflict::columns([
+                invoice_sequence::Column::TenantId,
+                invoice_sequence::Column::Kind,
+                invoice_sequence::Column::Year,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+        invoice_sequence::Entity::update_many()
+            .col_expr(
+                invoice_sequence::Column::LastNumber,
+                Expr::col(invoice_sequence::Column::LastNumber).add(1),
+            )
+            .filter(invoice_sequence::Column::TenantId.eq(tenant_id.clone()))
+            .filter(invoice_sequence::Column::Kind.eq(kind.clone()))
+            .filter(invoice_sequence::Column::Year.eq(year))
+            .exec(&txn)
+            .await?;
+        let sequence = invoice_sequence::Entity::find_by_id((tenant_id, kind, year))
+            .one(&txn)
+            .await?
+            .map(|row| row.last_number)
+            .ok_or_else(|| InfrastructureError::storage("invoice sequence missing"))?;
+
+        let invoice = draft.into_invoice(sequence as u32);
+        invoice::Entity::insert(to_invoice_row(&invoice))
+            .exec_without_returning(&txn)
+            .await?;
+        invoice_line::Entity::insert_many(
+            invoice
+                .lines()
+                .iter()
+                .enumerate()
+                .map(|(position, line)| to_line_row(invoice.id(), position, line)),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+
+        txn.commit().await?;
+        Ok(invoice)
+    }
+
+    async fn find_by_id(
+        &self,
+        tenant_id: &TenantId,
+        id: InvoiceId,
+    ) -> Result<Option<Invoice>, InfrastructureError> {
+        match invoice::Entity::find_by_id(id.value())
+            .filter(invoice::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&self.db)
+            .await?
+        {
+            Some(row) => Ok(Some(load(&self.db, row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_order(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+    ) -> Result<Vec<Invoice>, InfrastructureError> {
+        let rows = invoice::Entity::find()
+            .filter(invoice::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(invoice::Column::OrderId.eq(order_id.value()))
+            .order_by_asc(invoice::Column::IssuedAt)
+            .order_by_asc(invoice::Column::Sequence)
+            .all(&self.db)
+            .await?;
+        let mut invoices = Vec::with_capacity(rows.len());
+        for row in rows {
+            invoices.push(load(&self.db, row).await?);
+        }
+        Ok(invoices)
+    }
+
+    async fn anonymize(
+        &self,
+        tenant_id: &TenantId,
+        order_id: OrderId,
+        pseudonym: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        invoice::Entity::update_many()
+            .col_expr(invoice::Column::CustomerId, Expr::value(pseudonym.value()))
+            .filter(invoice::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(invoice::Column::OrderId.eq(order_id.value()))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+// Mapping between domain objects and database rows
+
+fn to_invoice_row(invoice: &Invoice) -> invoice::ActiveModel {
+    let number = invoice.number();
+    invoice::ActiveModel {
+        id: Set(invoice.id().value()),
+        tenant_id: Set(invoice.tenant_id().to_string()),
+        number: Set(number.to_string()),
+        kind: Set(number.kind().to_string()),
+        year: Set(number.year()),
+        sequence: Set(number.sequence() as i32),
+        order_id: Set(invoice.order_id().value()),
+        customer_id: Set(invoice.customer_id().value()),
+        currency: Set(invoice.total().currency().to_string()),
+        credited_invoice_id: Set(invoice.credited_invoice_id().map(|id| id.value())),
+        reason: Set(invoice.reason().map(str::to_string)),
+        issued_at: Set(invoice.issued_at()),
+    }
+}
+
+fn to_line_row(
+    invoice_id: InvoiceId,
+    position: usize,
+    line: &InvoiceLine,
+) -> invoice_line::ActiveModel {
+    invoice_line::ActiveModel {
+        invoice_id: Set(invoice_id.value()),
+        position: Set(position as i32),
+        order_item_id: Set(line.order_item_id().value()),
+        product_id: Set(line.product_id().value()),
+        description: Set(line.description().to_string()),
+        quantity: Set(line.quantity() as i32),
+        unit_price: Set(line.unit_price().amount()),
+        currency: Set(line.unit_price().currency().to_string()),
+    }
+}
+
+fn to_domain_line(row: &invoice_line::Model) -> Result<InvoiceLine, InfrastructureError> {
+    let what = format!("invoice {}", row.invoice_id);
+    let quantity = u32::try_from(row.quantity).map_err(corrupted(what.clone()))?;
+    let currency = row.currency.parse().map_err(corrupted(what.clone()))?;
+    let unit_price = Money::new(row.unit_price, currency).map_err(corrupted(what.clone()))?;
+    InvoiceLine::new(
+        OrderItemId::from_uuid(row.order_item_id),
+        ProductId::from_uuid(row.product_id),
+        row.description.clone(),
+        quantity,
+        unit_price,
+    )
+    .map_err(corrupted(what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::invoice_contract::invoice_repository_contract_tests;
+    use crate::infrastructure::persistence::Migrator;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn repository() -> SqlInvoiceRepository {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlInvoiceRepository::new(db)
+    }
+
+    invoice_repository_contract_tests!(super::repository);
+}
@@ -0,0 +1,204 @@
+use crate::domain::{
+    aggregates::Wishlist,
+    entities::WishlistItem,
+    errors::InfrastructureError,
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, ProductId, ShareToken, TenantId},
+};
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::{wishlist, wishlist_item},
+};
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::OnConflict, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait,
+    QueryFilter, QueryOrder, TransactionTrait,
+};
+
+/// SeaORM implementation (PostgreSQL in production, SQLite in tests)
+pub struct SqlWishlistRepository {
+    db: DatabaseConnection,
+}
+
+impl SqlWishlistRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Rebuild the aggregate from its row (items are sorted by position)
+    async fn load(&self, row: wishlist::Model) -> Result<Wishlist, InfrastructureError> {
+        let what = format!("wishlist of customer {}", row.customer_id);
+        let items = wishlist_item::Entity::find()
+            .filter(wishlist_item::Column::TenantId.eq(row.tenant_id.as_str()))
+            .filter(wishlist_item::Column::CustomerId.eq(row.customer_id))
+            .order_by_asc(wishlist_item::Column::Position)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(to_domain_item)
+            .collect::<Result<Vec<_>, _>>()?;
+        let tenant_id = row.tenant_id.parse().map_err(corrupted(what.clone()))?;
+        let share_token = row
+            .share_token
+            .map(|token| token.parse())
+            .transpose()
+            .map_err(corrupted(what))?;
+        Ok(Wishlist::reconstitute(
+            tenant_id,
+            CustomerId::from_uuid(row.customer_id),
+            items,
+            share_token,
+            row.created_at,
+            row.updated_at,
+        ))
+    }
+}
+
+#[async_trait]
+impl WishlistRepository for SqlWishlistRepository {
+    async fn save(&self, wishlist: &Wishlist) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+
+        wishlist::Entity::insert(to_wishlist_row(wishlist))
+            .on_conflict(
+                OnConflict::columns([wishlist::Column::TenantId, wishlist::Column::CustomerId])
+                    .update_columns([wishlist::Column::ShareToken, wishlist::Column::UpdatedAt])
+                    .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+
+        // Items belong to the aggregate: replace them as a whole
+        let tenant_id = wishlist.tenant_id();
+        let customer_id = wishlist.customer_id();
+        wishlist_item::Entity::delete_many()
+            .filter(wishlist_item::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(wishlist_item::Column::CustomerId.eq(customer_id.value()))
+            .exec(&txn)
+            .await?;
+        if !wishlist.items().is_empty() {
+            wishlist_item::Entity::insert_many(
+                wishlist
+                    .items()
+                    .iter()
+                    .enumerate()
+                    .map(|(position, item)| to_item_row(tenant_id, customer_id, position, item)),
+            )
+            .exec_without_returning(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_customer(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<Option<Wishlist>, InfrastructureError> {
+        match wishlist::Entity::find_by_id((tenant_id.to_string(), customer_id.value()))
+            .one(&self.db)
+            .await?
+        {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_share_token(
+        &self,
+        tenant_id: &TenantId,
+        token: &ShareToken,
+    ) -> Result<Option<Wishlist>, InfrastructureError> {
+        let row = wishlist::Entity::find()
+            .filter(wishlist::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(wishlist::Column::ShareToken.eq(token.as_str()))
+            .one(&self.db)
+            .await?;
+        match row {
+            Some(row) => Ok(Some(self.load(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        customer_id: CustomerId,
+    ) -> Result<(), InfrastructureError> {
+        let txn = self.db.begin().await?;
+        wishlist_item::Entity::delete_many()
+            .filter(wishlist_item::Column::TenantId.eq(tenant_id.as_str()))
+            .filter(wishlist_item::Column::CustomerId.eq(customer_id.value()))
+            .exec(&txn)
+            .await?;
+        wishlist::Entity::delete_by_id((tenant_id.to_string(), customer_id.value()))
+            .exec(&txn)
+            .await?;
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+// Mapping between domain objects and database rows
+
+fn to_wishlist_row(wishlist: &Wishlist) -> wishlist::ActiveModel {
+    wishlist::ActiveModel {
+        tenant_id: Set(wishlist.tenant_id().to_string()),
+        customer_id: Set(wishlist.customer_id().value()),
+        share_token: Set(wishlist.share_token().map(|token| token.to_string())),
+        created_at: Set(wishlist.created_at()),
+        updated_at: Set(wishlist.updated_at()),
+    }
+}
+
+fn to_item_row(
+    tenant_id: &TenantId,
+    customer_id: CustomerId,
+    position: usize,
+    item: &WishlistItem,
+) -> wishlist_item::ActiveModel {
+    wishlist_item::ActiveModel {
+        tenant_id: Set(tenant_id.to_string()),
+        customer_id: Set(customer_id.value()),
+        product_id: Set(item.product_id().value()),
+        position: Set(position as i32),
+        product_name: Set(item.product_name().to_string()),
+        note: Set(item.note().map(str::to_string)),
+        priority: Set(item.priority().to_string()),
+        added_at: Set(item.added_at()),
+    }
+}
+
+fn to_domain_item(row: wishlist_item::Model) -> Result<WishlistItem, InfrastructureError> {
+    let what = format!("wishlist of customer {}", row.customer_id);
+    let priority = row.priority.parse().map_err(corrupted(what.clone()))?;
+    WishlistItem::new(
+        ProductId::from_uuid(row.product_id),
+        row.product_name,
+        row.note,
+        priority,
+        row.added_at,
+    )
+    .map_err(corrupted(what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repositories::wishlist_contract::wishlist_repository_contract_tests;
+    use crate::infrastructure::persistence::Migrator;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn repository() -> SqlWishlistRepository {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlWishlistRepository::new(db)
+    }
+
+    wishlist_repository_contract_tests!(super::repository);
+}
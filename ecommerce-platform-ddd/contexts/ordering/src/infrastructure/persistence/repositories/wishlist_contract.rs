@@ -0,0 +1,227 @@
+//! Behaviour every `WishlistRepository` adapter must honour
+//!
+//! Each check takes a fresh, empty repository; `wishlist_repository_contract_tests!`
+//! expands them into one test per check for a given adapter factory.
+
+use crate::domain::{
+    aggregates::Wishlist,
+    clock::{Clock, FixedClock},
+    entities::WishlistItem,
+    repositories::WishlistRepository,
+    value_objects::{CustomerId, ProductId, ShareToken, TenantId, WishlistPriority},
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// Whole seconds, so that every backend stores timestamps without loss
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap()
+}
+
+fn tenant() -> TenantId {
+    TenantId::default()
+}
+
+fn acme() -> TenantId {
+    "acme".parse().unwrap()
+}
+
+fn item(
+    name: &str,
+    note: Option<&str>,
+    priority: WishlistPriority,
+    clock: &FixedClock,
+) -> WishlistItem {
+    WishlistItem::new(
+        ProductId::new(),
+        name.to_string(),
+        note.map(str::to_string),
+        priority,
+        clock.now(),
+    )
+    .unwrap()
+}
+
+pub async fn save_and_find_round_trip(repo: &dyn WishlistRepository) {
+    let clock = FixedClock::new(start());
+    let mut wishlist = Wishlist::create(tenant(), CustomerId::new(), &clock);
+    wishlist
+        .save_item(
+            item("Keyboard", Some("in blue"), WishlistPriority::High, &clock),
+            &clock,
+        )
+        .unwrap();
+    clock.advance(Duration::minutes(5));
+    wishlist
+        .save_item(item("Mouse", None, WishlistPriority::Low, &clock), &clock)
+        .unwrap();
+    repo.save(&wishlist).await.unwrap();
+
+    let found = repo
+        .find_by_customer(&tenant(), wishlist.customer_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.items(), wishlist.items());
+    assert_eq!(found.share_token(), None);
+    assert_eq!(found.created_at(), wishlist.created_at());
+    assert_eq!(found.updated_at(), wishlist.updated_at());
+}
+
+pub async fn save_replaces_existing_wishlist(repo: &dyn WishlistRepository) {
+    let clock = FixedClock::new(start());
+    let mut wishlist = Wishlist::create(tenant(), CustomerId::new(), &clock);
+    let keyboard = item("Keyboard", None, WishlistPriority::Normal, &clock);
+    wishlist.save_item(keyboard.clone(), &clock).unwrap();
+    wishlist
+        .save_item(
+            item("Mouse", None, WishlistPriority::Normal, &clock),
+            &clock,
+        )
+        .unwrap();
+    repo.save(&wishlist).await.unwrap();
+
+    clock.advance(Duration::hours(1));
+    wishlist.remove_item(keyboard.product_id(), &clock).unwrap();
+    repo.save(&wishlist).await.unwrap();
+
+    let found = repo
+        .find_by_customer(&tenant(), wishlist.customer_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.items().len(), 1);
+    assert_eq!(found.items()[0].product_name(), "Mouse");
+    assert_eq!(found.updated_at(), clock.now());
+}
+
+pub async fn find_by_share_token_until_revoked(repo: &dyn WishlistRepository) {
+    let clock = FixedClock::new(start());
+    let mut wishlist = Wishlist::create(tenant(), CustomerId::new(), &clock);
+    wishlist
+        .save_item(
+            item("Keyboard", None, WishlistPriority::Normal, &clock),
+            &clock,
+        )
+        .unwrap();
+    let token = wishlist.share(&clock);
+    repo.save(&wishlist).await.unwrap();
+    repo.save(&Wishlist::create(tenant(), CustomerId::new(), &clock))
+        .await
+        .unwrap();
+
+    let found = repo
+        .find_by_share_token(&tenant(), &token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.customer_id(), wishlist.customer_id());
+    assert_eq!(found.share_token(), Some(&token));
+    assert!(repo
+        .find_by_share_token(&tenant(), &ShareToken::generate())
+        .await
+        .unwrap()
+        .is_none());
+
+    wishlist.stop_sharing(&clock);
+    repo.save(&wishlist).await.unwrap();
+    assert!(repo
+        .find_by_share_token(&tenant(), &token)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn find_returns_none_for_unknown_customer(repo: &dyn WishlistRepository) {
+    assert!(repo
+        .find_by_customer(&tenant(), CustomerId::new())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn delete_removes_wishlist(repo: &dyn WishlistRepository) {
+    let clock = FixedClock::new(start());
+    let mut wishlist = Wishlist::create(tenant(), CustomerId::new(), &clock);
+    wishlist
+        .save_item(
+            item("Keyboard", None, WishlistPriority::Normal, &clock),
+            &clock,
+        )
+        .unwrap();
+    repo.save(&wishlist).await.unwrap();
+
+    repo.delete(&tenant(), wishlist.customer_id())
+        .await
+        .unwrap();
+    assert!(repo
+        .find_by_customer(&tenant(), wishlist.customer_id())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+pub async fn wishlists_are_isolated_per_tenant(repo: &dyn WishlistRepository) {
+    let clock = FixedClock::new(start());
+    let customer_id = CustomerId::new();
+    let mut ours = Wishlist::create(tenant(), customer_id, &clock);
+    ours.save_item(
+        item("Keyboard", None, WishlistPriority::Normal, &clock),
+        &clock,
+    )
+    .unwrap();
+    let token = ours.share(&clock);
+    repo.save(&ours).await.unwrap();
+    // The same customer keeps a separate wishlist on another storefront
+    let mut theirs = Wishlist::create(acme(), customer_id, &clock);
+    theirs
+        .save_item(item("Mouse", None, WishlistPriority::Low, &clock), &clock)
+        .unwrap();
+    repo.save(&theirs).await.unwrap();
+
+    let found = repo
+        .find_by_customer(&acme(), customer_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.items()[0].product_name(), "Mouse");
+    assert!(repo
+        .find_by_share_token(&acme(), &token)
+        .await
+        .unwrap()
+        .is_none());
+
+    repo.delete(&acme(), customer_id).await.unwrap();
+    let kept = repo
+        .find_by_customer(&tenant(), customer_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(kept.items()[0].product_name(), "Keyboard");
+    assert_eq!(kept.share_token(), Some(&token));
+}
+
+macro_rules! wishlist_repository_contract_tests {
+    ($factory:path) => {
+        $crate::infrastructure::persistence::repositories::wishlist_contract::wishlist_repository_contract_tests!(
+            @tests $factory;
+            save_and_find_round_trip,
+            save_replaces_existing_wishlist,
+            find_by_share_token_until_revoked,
+            find_returns_none_for_unknown_customer,
+            delete_removes_wishlist,
+            wishlists_are_isolated_per_tenant,
+        );
+    };
+    (@tests $factory:path; $($check:ident),* $(,)?) => {
+        mod contract {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let repo = $factory().await;
+                    $crate::infrastructure::persistence::repositories::wishlist_contract::$check(&repo).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use wishlist_repository_contract_tests;
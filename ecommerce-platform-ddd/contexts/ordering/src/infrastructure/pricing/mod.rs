@@ -0,0 +1,123 @@
+use crate::domain::{
+    errors::{DomainError, InfrastructureError},
+    pricing::{PriceList, PriceLists, PriceRule, Pricing},
+    value_objects::{Currency, CustomerId, Money, ProductId},
+};
+use crate::infrastructure::config::PricingSettings;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Price rules and customer groups held in memory, seeded from the configuration
+///
+/// Stands in for the pricing service until it exposes an API: without rules every
+/// line is sold at the price it was ordered at.
+#[derive(Debug, Default)]
+pub struct InMemoryPriceLists {
+    list: PriceList,
+    customer_groups: HashMap<CustomerId, String>,
+}
+
+impl InMemoryPriceLists {
+    pub fn new(list: PriceList) -> Self {
+        Self {
+            list,
+            customer_groups: HashMap::new(),
+        }
+    }
+
+    /// Price lists of the `[pricing]` section of the configuration
+    pub fn from_settings(settings: &PricingSettings) -> Result<Self, DomainError> {
+        let rules = settings
+            .rules
+            .iter()
+            .map(|rule| {
+                let price = Money::new(rule.price, rule.currency.unwrap_or(Currency::EUR))?;
+                let mut price_rule =
+                    PriceRule::new(&rule.name, ProductId::from_uuid(rule.product_id), price)
+                        .from_quantity(rule.min_quantity)
+                        .valid_between(rule.valid_from, rule.valid_until);
+                price_rule.customer_group = rule.customer_group.clone();
+                Ok(price_rule)
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        let mut price_lists = Self::new(PriceList::new(rules)?);
+        for (customer_id, group) in &settings.customer_groups {
+            price_lists =
+                price_lists.with_customer_group(CustomerId::from_uuid(*customer_id), group);
+        }
+        Ok(price_lists)
+    }
+
+    /// Put a customer in a group, or move it to another one
+    pub fn with_customer_group(
+        mut self,
+        customer_id: CustomerId,
+        group: impl Into<String>,
+    ) -> Self {
+        self.customer_groups.insert(customer_id, group.into());
+        self
+    }
+}
+
+#[async_trait]
+impl PriceLists for InMemoryPriceLists {
+    async fn pricing_for(
+        &self,
+        customer_id: CustomerId,
+        at: DateTime<Utc>,
+    ) -> Result<Pricing, InfrastructureError> {
+        Ok(self
+            .list
+            .pricing(self.customer_groups.get(&customer_id).cloned(), at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::Settings;
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_customer_group_comes_from_the_settings() {
+        let settings = Settings::from_toml(
+            r#"
+            [pricing.customer_groups]
+            "6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10" = "wholesale"
+
+            [[pricing.rules]]
+            name = "wholesale-keyboard"
+            product_id = "0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f"
+            price = "39.90"
+            customer_group = "wholesale"
+            "#,
+        )
+        .unwrap();
+        let price_lists = InMemoryPriceLists::from_settings(&settings.pricing).unwrap();
+        let keyboard =
+            ProductId::from_uuid("0d8e5f8a-6a0e-4d3c-9a55-1b7a3c2d4e5f".parse().unwrap());
+        let list_price = Money::eur(Decimal::new(4990, 2)).unwrap();
+
+        let wholesaler =
+            CustomerId::from_uuid("6f1c2b7e-8f53-4c55-9a38-0c4d1c1e2f10".parse().unwrap());
+        let pricing = price_lists
+            .pricing_for(wholesaler, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(pricing.customer_group(), Some("wholesale"));
+        assert_eq!(
+            pricing
+                .best_rule(keyboard, list_price, 1)
+                .map(|rule| rule.unit_price),
+            Some(Money::eur(Decimal::new(3990, 2)).unwrap())
+        );
+
+        let pricing = price_lists
+            .pricing_for(CustomerId::new(), Utc::now())
+            .await
+            .unwrap();
+        assert!(pricing.best_rule(keyboard, list_price, 1).is_none());
+    }
+}
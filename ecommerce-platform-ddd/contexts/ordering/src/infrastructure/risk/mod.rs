@@ -0,0 +1,152 @@
+use crate::domain::{
+    aggregates::Order,
+    clock::Clock,
+    errors::{DomainError, InfrastructureError},
+    repositories::OrderRepository,
+    risk::{RiskAssessment, RiskAssessor, RiskFactor, RiskPolicy, RiskProfile, RiskRule},
+};
+use crate::infrastructure::config::{RiskFactorKind, RiskSettings};
+use async_trait::async_trait;
+use rust_decimal::prelude::ToPrimitive;
+use std::sync::Arc;
+
+/// Risk rules of the configuration, applied in process
+///
+/// Stands in for a fraud screening service: the velocity of the customer is read from
+/// their orders in the repository.
+pub struct RuleBasedRiskAssessor {
+    policy: RiskPolicy,
+    order_repository: Arc<dyn OrderRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl RuleBasedRiskAssessor {
+    pub fn new(
+        policy: RiskPolicy,
+        order_repository: Arc<dyn OrderRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            policy,
+            order_repository,
+            clock,
+        }
+    }
+
+    /// Policy of the `[risk]` section of the configuration
+    pub fn policy_from_settings(settings: &RiskSettings) -> Result<RiskPolicy, DomainError> {
+        let rules = settings
+            .rules
+            .iter()
+            .map(|rule| {
+                let invalid = |reason: &str| DomainError::InvalidRiskRule {
+                    name: rule.name.clone(),
+                    reason: reason.to_string(),
+                };
+                let above = || rule.above.ok_or_else(|| invalid("above is required"));
+                let count = || {
+                    above()?
+                        .to_u32()
+                        .ok_or_else(|| invalid("above must be a whole number"))
+                };
+                let factor = match rule.factor {
+                    RiskFactorKind::OrderTotal => RiskFactor::OrderTotalAbove(above()?),
+                    RiskFactorKind::ItemCount => RiskFactor::ItemCountAbove(count()?),
+                    RiskFactorKind::Velocity => {
+                        let window_secs = rule
+                            .window_secs
+                            .ok_or_else(|| invalid("window_secs is required"))?;
+                        RiskFactor::OrdersWithin {
+                            max_orders: count()?,
+                            window: chrono::Duration::seconds(
+                                i64::try_from(window_secs)
+                                    .map_err(|_| invalid("window_secs is too large"))?,
+                            ),
+                        }
+                    }
+                    RiskFactorKind::AddressMismatch => RiskFactor::AddressMismatch,
+                };
+                Ok(RiskRule::new(&rule.name, factor, rule.score))
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+        RiskPolicy::new(rules, settings.review_score)
+    }
+}
+
+#[async_trait]
+impl RiskAssessor for RuleBasedRiskAssessor {
+    async fn assess(&self, order: &Order) -> Result<RiskAssessment, InfrastructureError> {
+        let customer_orders = self
+            .order_repository
+            .find_by_customer(order.tenant_id(), order.customer_id())
+            .await?;
+        let profile = RiskProfile::of(order, &customer_orders, self.clock.now());
+        Ok(self.policy.assess(&profile))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        clock::FixedClock,
+        entities::OrderItem,
+        id_generator::UuidV4Generator,
+        tenant::Tenant,
+        value_objects::{CustomerId, Money, ProductId},
+    };
+    use crate::infrastructure::config::Settings;
+    use crate::infrastructure::persistence::repositories::InMemoryOrderRepository;
+    use chrono::{Duration, Utc};
+    use rust_decimal::Decimal;
+
+    #[tokio::test]
+    async fn test_velocity_comes_from_the_orders_of_the_customer() {
+        let settings = Settings::from_toml(
+            r#"
+            [[risk.rules]]
+            name = "card-testing"
+            factor = "velocity"
+            above = 2
+            window_secs = 600
+            score = 100
+            "#,
+        )
+        .unwrap();
+        let clock = Arc::new(FixedClock::new(Utc::now()));
+        let repository = Arc::new(InMemoryOrderRepository::new());
+        let assessor = RuleBasedRiskAssessor::new(
+            RuleBasedRiskAssessor::policy_from_settings(&settings.risk).unwrap(),
+            repository.clone(),
+            clock.clone(),
+        );
+
+        let customer_id = CustomerId::new();
+        let mut assessments = Vec::new();
+        for _ in 0..3 {
+            let item = OrderItem::new(
+                ProductId::new(),
+                "Gift card".to_string(),
+                1,
+                Money::eur(Decimal::new(100, 2)).unwrap(),
+                &UuidV4Generator,
+            )
+            .unwrap();
+            let mut order = Order::create(
+                &Tenant::default(),
+                customer_id,
+                vec![item],
+                &UuidV4Generator,
+                &*clock,
+            )
+            .unwrap();
+            repository.save(&mut order).await.unwrap();
+            assessments.push(assessor.assess(&order).await.unwrap());
+            clock.advance(Duration::minutes(1));
+        }
+
+        assert!(!assessments[1].requires_review());
+        assert!(assessments[2].requires_review());
+        assert_eq!(assessments[2].reasons, vec!["card-testing"]);
+    }
+}
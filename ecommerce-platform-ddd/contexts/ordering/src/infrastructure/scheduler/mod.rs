@@ -0,0 +1,176 @@
+use crate::application::commands::{
+    DeliverShippedOrdersCommand, DeliverShippedOrdersHandler, ExpireCartsCommand,
+    ExpireCartsHandler, ExpirePendingOrdersCommand, ExpirePendingOrdersHandler,
+};
+use crate::application::error::ApplicationError;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+use tokio::time::MissedTickBehavior;
+
+/// Background task run periodically by the `Scheduler`
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// Name shown in the logs
+    fn name(&self) -> &str;
+
+    /// Run once, returning the number of orders (or carts) processed
+    async fn run(&self) -> Result<usize, ApplicationError>;
+}
+
+/// Cancels the orders left pending for longer than `ttl`
+pub struct ExpirePendingOrdersJob {
+    handler: Arc<ExpirePendingOrdersHandler>,
+    ttl: chrono::Duration,
+}
+
+impl ExpirePendingOrdersJob {
+    pub fn new(handler: Arc<ExpirePendingOrdersHandler>, ttl: chrono::Duration) -> Self {
+        Self { handler, ttl }
+    }
+}
+
+#[async_trait]
+impl Job for ExpirePendingOrdersJob {
+    fn name(&self) -> &str {
+        "expire_pending_orders"
+    }
+
+    async fn run(&self) -> Result<usize, ApplicationError> {
+        self.handler
+            .handle(ExpirePendingOrdersCommand { ttl: self.ttl })
+            .await
+    }
+}
+
+/// Delivers the orders shipped for longer than the carrier delay
+pub struct DeliverShippedOrdersJob {
+    handler: Arc<DeliverShippedOrdersHandler>,
+    delay: chrono::Duration,
+}
+
+impl DeliverShippedOrdersJob {
+    pub fn new(handler: Arc<DeliverShippedOrdersHandler>, delay: chrono::Duration) -> Self {
+        Self { handler, delay }
+    }
+}
+
+#[async_trait]
+impl Job for DeliverShippedOrdersJob {
+    fn name(&self) -> &str {
+        "deliver_shipped_orders"
+    }
+
+    async fn run(&self) -> Result<usize, ApplicationError> {
+        self.handler
+            .handle(DeliverShippedOrdersCommand { delay: self.delay })
+            .await
+    }
+}
+
+/// Deletes the carts past their expiry
+pub struct ExpireCartsJob {
+    handler: Arc<ExpireCartsHandler>,
+}
+
+impl ExpireCartsJob {
+    pub fn new(handler: Arc<ExpireCartsHandler>) -> Self {
+        Self { handler }
+    }
+}
+
+#[async_trait]
+impl Job for ExpireCartsJob {
+    fn name(&self) -> &str {
+        "expire_carts"
+    }
+
+    async fn run(&self) -> Result<usize, ApplicationError> {
+        self.handler.handle(ExpireCartsCommand).await
+    }
+}
+
+/// Runs every registered job on its own period until shutdown
+#[derive(Default)]
+pub struct Scheduler {
+    jobs: Vec<(Arc<dyn Job>, Duration)>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn every(mut self, period: Duration, job: Arc<dyn Job>) -> Self {
+        self.jobs.push((job, period));
+        self
+    }
+
+    /// Run until `shutdown` changes; a run in progress completes first
+    pub async fn run(self, shutdown: watch::Receiver<()>) {
+        let mut tasks = JoinSet::new();
+        for (job, period) in self.jobs {
+            let mut shutdown = shutdown.clone();
+            tasks.spawn(async move {
+                let mut ticks = tokio::time::interval(period);
+                ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                loop {
+                    tokio::select! {
+                        _ = ticks.tick() => {}
+                        _ = shutdown.changed() => break,
+                    }
+                    match job.run().await {
+                        Ok(0) => {}
+                        Ok(count) => {
+                            tracing::info!("Job {} processed {} orders", job.name(), count)
+                        }
+                        Err(err) => tracing::error!("Job {} failed: {}", job.name(), err),
+                    }
+                }
+            });
+        }
+        while tasks.join_next().await.is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct CountingJob {
+        runs: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Job for CountingJob {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        async fn run(&self) -> Result<usize, ApplicationError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jobs_run_periodically_until_shutdown() {
+        let job = Arc::new(CountingJob::default());
+        let (shutdown_tx, shutdown_rx) = watch::channel(());
+        let scheduler = Scheduler::new().every(Duration::from_millis(10), job.clone());
+        let running = tokio::spawn(scheduler.run(shutdown_rx));
+
+        tokio::time::sleep(Duration::from_millis(55)).await;
+        shutdown_tx.send(()).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), running)
+            .await
+            .expect("scheduler stops on shutdown")
+            .unwrap();
+
+        assert!(job.runs.load(Ordering::SeqCst) >= 3);
+    }
+}
@@ -0,0 +1,153 @@
+//! Claims of a JWT sent as `Authorization: Bearer <token>`
+//!
+//! Tokens are issued by the identity provider of the storefronts and signed with HS256.
+//! Their claims are only read once the signature and the `exp` claim are checked: an
+//! unsigned or unverifiable token is refused, never trusted.
+
+use crate::infrastructure::crypto::verify_hmac_sha256;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JwtError {
+    #[error("malformed token")]
+    Malformed,
+
+    #[error("unsupported algorithm {0} (expected HS256)")]
+    UnsupportedAlgorithm(String),
+
+    #[error("invalid signature")]
+    InvalidSignature,
+
+    #[error("token expired")]
+    Expired,
+
+    #[error("bearer tokens are not accepted: tenancy.jwt_secret is not configured")]
+    NoSecret,
+}
+
+/// Claims of `token` once its HS256 signature and expiry are checked
+///
+/// `now` is the current Unix time, compared to `exp`.
+pub fn verify(token: &str, secret: &str, now: i64) -> Result<Map<String, Value>, JwtError> {
+    let mut parts = token.split('.');
+    let (Some(header), Some(payload), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(JwtError::Malformed);
+    };
+
+    let algorithm = decode_json(header)?
+        .get("alg")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    if algorithm != "HS256" {
+        return Err(JwtError::UnsupportedAlgorithm(algorithm));
+    }
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| JwtError::Malformed)?;
+    if !verify_hmac_sha256(
+        secret.as_bytes(),
+        format!("{}.{}", header, payload).as_bytes(),
+        &signature,
+    ) {
+        return Err(JwtError::InvalidSignature);
+    }
+
+    let claims = decode_json(payload)?;
+    if claims
+        .get("exp")
+        .and_then(Value::as_i64)
+        .is_some_and(|exp| exp <= now)
+    {
+        return Err(JwtError::Expired);
+    }
+    Ok(claims)
+}
+
+/// Value of the string `claim`, `None` if the claims have no such claim
+pub fn string_claim(claims: &Map<String, Value>, claim: &str) -> Result<Option<String>, JwtError> {
+    match claims.get(claim) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(JwtError::Malformed),
+    }
+}
+
+fn decode_json(segment: &str) -> Result<Map<String, Value>, JwtError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| JwtError::Malformed)?;
+    match serde_json::from_slice(&bytes) {
+        Ok(Value::Object(object)) => Ok(object),
+        _ => Err(JwtError::Malformed),
+    }
+}
+
+/// HS256 token carrying `claims`, for tests
+#[cfg(test)]
+pub(crate) fn encode_hs256(claims: &Value, secret: &str) -> String {
+    use crate::infrastructure::crypto::hmac_sha256;
+
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+    let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+    let signature = hmac_sha256(
+        secret.as_bytes(),
+        format!("{}.{}", header, payload).as_bytes(),
+    );
+    format!(
+        "{}.{}.{}",
+        header,
+        payload,
+        URL_SAFE_NO_PAD.encode(signature)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_signed_token_is_verified() {
+        let token = encode_hs256(&json!({"sub": "alice", "tenant_id": "acme"}), "secret");
+
+        let claims = verify(&token, "secret", NOW).unwrap();
+        assert_eq!(
+            string_claim(&claims, "tenant_id").unwrap(),
+            Some("acme".to_string())
+        );
+        assert_eq!(string_claim(&claims, "org"), Ok(None));
+        assert_eq!(
+            verify(&token, "other", NOW),
+            Err(JwtError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_expired_token_is_rejected() {
+        let token = encode_hs256(&json!({"tenant_id": "acme", "exp": NOW - 1}), "secret");
+
+        assert_eq!(verify(&token, "secret", NOW), Err(JwtError::Expired));
+    }
+
+    #[test]
+    fn test_unsigned_token_is_rejected() {
+        let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(r#"{"tenant_id":"acme"}"#);
+
+        assert_eq!(
+            verify(&format!("{}.{}.", header, payload), "secret", NOW),
+            Err(JwtError::UnsupportedAlgorithm("none".to_string()))
+        );
+        assert_eq!(
+            verify("not-a-token", "secret", NOW),
+            Err(JwtError::Malformed)
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Storefronts served by the deployment, and the tenant and caller of each request
+//!
+//! With `tenancy.jwt_secret` set, the tenant comes from the claim of the verified bearer
+//! token, else it is the configured default tenant; `X-Tenant-Id` can only confirm it.
+//! Without a secret no token is accepted and `X-Tenant-Id` names the tenant, as set by
+//! a trusted gateway.
+
+pub mod jwt;
+
+use crate::application::audit::Actor;
+use crate::domain::{
+    errors::DomainError,
+    tenant::{StatusTransitions, TaxRule, Tenant, TenantDirectory},
+    value_objects::TenantId,
+};
+use crate::infrastructure::config::{Settings, TenancySettings};
+use std::collections::HashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Names the tenant of a request (HTTP header or gRPC metadata)
+pub const TENANT_HEADER: &str = "x-tenant-id";
+
+/// Role of the back-office staff, in the `roles` claim of their token
+pub const OPERATOR_ROLE: &str = "operator";
+
+/// Tenants of the configuration, held in memory
+///
+/// The default tenant is always known, with the standard rules unless configured.
+#[derive(Debug, Clone)]
+pub struct TenantRegistry {
+    tenants: HashMap<TenantId, Tenant>,
+}
+
+impl TenantRegistry {
+    /// Registry knowing only `default_tenant`
+    pub fn new(default_tenant: TenantId) -> Self {
+        let tenant = Tenant::new(default_tenant.clone());
+        Self {
+            tenants: HashMap::from([(default_tenant, tenant)]),
+        }
+    }
+
+    /// Add a tenant, or replace its rules
+    pub fn with_tenant(mut self, tenant: Tenant) -> Self {
+        self.tenants.insert(tenant.id.clone(), tenant);
+        self
+    }
+
+    /// Registry of the `[tenants.<id>]` entries of the configuration
+    pub fn from_settings(settings: &Settings) -> anyhow::Result<Self> {
+        let mut registry = Self::new(settings.tenancy.default_tenant.clone());
+        for (id, entry) in &settings.tenants {
+            let id: TenantId = id.parse()?;
+            let transitions = match entry.parse_transitions().map_err(anyhow::Error::msg)? {
+                Some(transitions) => StatusTransitions::only(transitions)?,
+                None => StatusTransitions::standard(),
+            };
+            registry = registry.with_tenant(Tenant {
+                currency: entry.currency,
+                tax: TaxRule::new(entry.tax_rate, entry.prices_include_tax)?,
+                transitions,
+                ..Tenant::new(id)
+            });
+        }
+        Ok(registry)
+    }
+}
+
+impl TenantDirectory for TenantRegistry {
+    fn tenant(&self, id: &TenantId) -> Result<Tenant, DomainError> {
+        self.tenants
+            .get(id)
+            .cloned()
+            .ok_or_else(|| DomainError::UnknownTenant(id.clone()))
+    }
+}
+
+/// Why the tenant or the caller of a request could not be resolved
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TenantResolutionError {
+    #[error("Invalid X-Tenant-Id header")]
+    InvalidHeader,
+
+    #[error("Invalid bearer token: {0}")]
+    InvalidToken(jwt::JwtError),
+
+    #[error("A bearer token is required")]
+    MissingToken,
+
+    #[error("X-Tenant-Id does not match the tenant of the bearer token")]
+    Mismatch,
+
+    #[error("Unknown tenant: {0}")]
+    UnknownTenant(TenantId),
+}
+
+/// Identity of a verified bearer token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Caller {
+    /// `sub` claim
+    pub subject: String,
+    /// `roles` claim, empty when absent
+    pub roles: Vec<String>,
+}
+
+impl Caller {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|granted| granted == role)
+    }
+
+    /// The caller as recorded in the audit trail
+    pub fn actor(&self) -> Actor {
+        Actor::user(&self.subject)
+    }
+}
+
+/// Tenant and caller of a request, from its credentials and headers
+pub struct TenantResolver {
+    tenants: Arc<dyn TenantDirectory>,
+    settings: TenancySettings,
+}
+
+impl TenantResolver {
+    pub fn new(tenants: Arc<dyn TenantDirectory>, settings: TenancySettings) -> Self {
+        Self { tenants, settings }
+    }
+
+    /// Resolve from the `X-Tenant-Id` and `Authorization` values of a request
+    ///
+    /// A header naming another tenant than the verified one is refused rather than
+    /// ignored.
+    pub fn resolve(
+        &self,
+        header: Option<&str>,
+        authorization: Option<&str>,
+    ) -> Result<TenantId, TenantResolutionError> {
+        let from_header = header
+            .map(|value| {
+                value
+                    .trim()
+                    .parse::<TenantId>()
+                    .map_err(|_| TenantResolutionError::InvalidHeader)
+            })
+            .transpose()?;
+
+        let tenant_id = if self.settings.jwt_secret.is_some() {
+            let from_token = match self.claims(authorization)? {
+                Some(claims) => jwt::string_claim(&claims, &self.settings.jwt_claim)
+                    .map_err(TenantResolutionError::InvalidToken)?
+                    .map(|claim| {
+                        claim.parse::<TenantId>().map_err(|_| {
+                            TenantResolutionError::InvalidToken(jwt::JwtError::Malformed)
+                        })
+                    })
+                    .transpose()?,
+                None => None,
+            };
+            let tenant_id = from_token.unwrap_or_else(|| self.settings.default_tenant.clone());
+            if from_header.is_some_and(|header| header != tenant_id) {
+                return Err(TenantResolutionError::Mismatch);
+            }
+            tenant_id
+        } else {
+            // Nothing to check a token with: the request must not look authenticated
+            self.claims(authorization)?;
+            from_header.unwrap_or_else(|| self.settings.default_tenant.clone())
+        };
+        match self.tenants.tenant(&tenant_id) {
+            Ok(_) => Ok(tenant_id),
+            Err(_) => Err(TenantResolutionError::UnknownTenant(tenant_id)),
+        }
+    }
+
+    /// Caller of a request, from its `Authorization` value
+    pub fn authenticate(
+        &self,
+        authorization: Option<&str>,
+    ) -> Result<Caller, TenantResolutionError> {
+        let claims = self
+            .claims(authorization)?
+            .ok_or(TenantResolutionError::MissingToken)?;
+        let invalid = || TenantResolutionError::InvalidToken(jwt::JwtError::Malformed);
+        let subject = jwt::string_claim(&claims, "sub")
+            .map_err(TenantResolutionError::InvalidToken)?
+            .filter(|subject| !subject.trim().is_empty())
+            .ok_or_else(invalid)?;
+        let roles = match claims.get("roles") {
+            None | Some(serde_json::Value::Null) => Vec::new(),
+            Some(serde_json::Value::Array(roles)) => roles
+                .iter()
+                .map(|role| role.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)?,
+            Some(_) => return Err(invalid()),
+        };
+        Ok(Caller { subject, roles })
+    }
+
+    /// Actor recorded in the audit trail: the `sub` of the verified bearer token
+    ///
+    /// `anonymous` without a token or subject; never taken from a client-supplied
+    /// header, which anyone could set.
+    pub fn actor(&self, authorization: Option<&str>) -> Result<Actor, TenantResolutionError> {
+        let Some(claims) = self.claims(authorization)? else {
+            return Ok(Actor::Anonymous);
+        };
+        let subject =
+            jwt::string_claim(&claims, "sub").map_err(TenantResolutionError::InvalidToken)?;
+        Ok(subject
+            .filter(|subject| !subject.trim().is_empty())
+            .map_or(Actor::Anonymous, Actor::user))
+    }
+
+    /// Verified claims of the bearer token, `None` without one
+    ///
+    /// Other authorization schemes are not ours to check.
+    fn claims(
+        &self,
+        authorization: Option<&str>,
+    ) -> Result<Option<serde_json::Map<String, serde_json::Value>>, TenantResolutionError> {
+        let Some(token) = authorization.and_then(|value| value.strip_prefix("Bearer ")) else {
+            return Ok(None);
+        };
+        let secret = self
+            .settings
+            .jwt_secret
+            .as_deref()
+            .ok_or(TenantResolutionError::InvalidToken(jwt::JwtError::NoSecret))?;
+        jwt::verify(token.trim(), secret, chrono::Utc::now().timestamp())
+            .map(Some)
+            .map_err(TenantResolutionError::InvalidToken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Currency, OrderStatus};
+    use serde_json::json;
+
+    fn resolver(jwt_secret: Option<&str>) -> TenantResolver {
+        let registry = TenantRegistry::new(TenantId::default())
+            .with_tenant(Tenant::new("acme".parse().unwrap()))
+            .with_tenant(Tenant::new("globex".parse().unwrap()));
+        TenantResolver::new(
+            Arc::new(registry),
+            TenancySettings {
+                jwt_secret: jwt_secret.map(str::to_string),
+                ..TenancySettings::default()
+            },
+        )
+    }
+
+    fn bearer(claims: serde_json::Value) -> String {
+        format!("Bearer {}", jwt::encode_hs256(&claims, "secret"))
+    }
+
+    #[test]
+    fn test_verified_token_names_the_tenant() {
+        let resolver = resolver(Some("secret"));
+        let token = bearer(json!({"tenant_id": "acme"}));
+
+        assert_eq!(resolver.resolve(None, None).unwrap(), TenantId::default());
+        assert_eq!(
+            resolver.resolve(None, Some(&token)).unwrap().as_str(),
+            "acme"
+        );
+        assert_eq!(
+            resolver
+                .resolve(Some("acme"), Some(&token))
+                .unwrap()
+                .as_str(),
+            "acme"
+        );
+        // A token without the claim is for the default tenant
+        let untenanted = bearer(json!({"sub": "alice"}));
+        assert_eq!(
+            resolver.resolve(None, Some(&untenanted)).unwrap(),
+            TenantId::default()
+        );
+        // Other authorization schemes are not ours to check
+        assert_eq!(
+            resolver.resolve(None, Some("Basic YWxpY2U6")).unwrap(),
+            TenantId::default()
+        );
+    }
+
+    #[test]
+    fn test_header_alone_cannot_switch_tenant_when_tokens_are_checked() {
+        let resolver = resolver(Some("secret"));
+
+        assert_eq!(
+            resolver.resolve(Some("globex"), None),
+            Err(TenantResolutionError::Mismatch)
+        );
+        assert_eq!(
+            resolver.resolve(Some("globex"), Some(&bearer(json!({"sub": "alice"})))),
+            Err(TenantResolutionError::Mismatch)
+        );
+        assert_eq!(
+            resolver.resolve(Some("globex"), Some(&bearer(json!({"tenant_id": "acme"})))),
+            Err(TenantResolutionError::Mismatch)
+        );
+        assert_eq!(
+            resolver.resolve(Some("default"), None).unwrap(),
+            TenantId::default()
+        );
+    }
+
+    #[test]
+    fn test_header_names_the_tenant_behind_a_gateway() {
+        let resolver = resolver(None);
+
+        assert_eq!(
+            resolver.resolve(Some("globex"), None).unwrap().as_str(),
+            "globex"
+        );
+        assert_eq!(resolver.resolve(None, None).unwrap(), TenantId::default());
+        // Without a secret a token cannot be checked, so it is refused
+        assert_eq!(
+            resolver.resolve(None, Some(&bearer(json!({"tenant_id": "acme"})))),
+            Err(TenantResolutionError::InvalidToken(jwt::JwtError::NoSecret))
+        );
+    }
+
+    #[test]
+    fn test_rejected_requests() {
+        let resolver = resolver(Some("secret"));
+
+        assert_eq!(
+            resolver.resolve(Some("Not A Tenant"), None),
+            Err(TenantResolutionError::InvalidHeader)
+        );
+        assert!(matches!(
+            resolver.resolve(None, Some(&bearer(json!({"tenant_id": "initech"})))),
+            Err(TenantResolutionError::UnknownTenant(_))
+        ));
+        let forged = format!(
+            "Bearer {}",
+            jwt::encode_hs256(&json!({"tenant_id": "acme"}), "guessed")
+        );
+        assert!(matches!(
+            resolver.resolve(None, Some(&forged)),
+            Err(TenantResolutionError::InvalidToken(_))
+        ));
+    }
+
+    #[test]
+    fn test_caller_comes_from_verified_token() {
+        let resolver = resolver(Some("secret"));
+
+        let caller = resolver
+            .authenticate(Some(&bearer(
+                json!({"sub": "alice", "roles": ["operator"]}),
+            )))
+            .unwrap();
+        assert_eq!(caller.subject, "alice");
+        assert!(caller.has_role("operator"));
+        assert_eq!(caller.actor(), Actor::user("alice"));
+        assert!(!resolver
+            .authenticate(Some(&bearer(json!({"sub": "bob"}))))
+            .unwrap()
+            .has_role("operator"));
+
+        assert_eq!(
+            resolver.authenticate(None),
+            Err(TenantResolutionError::MissingToken)
+        );
+        assert!(matches!(
+            resolver.authenticate(Some(&bearer(json!({"roles": ["operator"]})))),
+            Err(TenantResolutionError::InvalidToken(_))
+        ));
+        let forged = format!(
+            "Bearer {}",
+            jwt::encode_hs256(&json!({"sub": "alice"}), "guessed")
+        );
+        assert!(matches!(
+            resolver.authenticate(Some(&forged)),
+            Err(TenantResolutionError::InvalidToken(_))
+        ));
+    }
+
+    #[test]
+    fn test_actor_is_the_verified_caller() {
+        let resolver = resolver(Some("secret"));
+
+        assert_eq!(
+            resolver.actor(Some(&bearer(json!({"sub": "alice"})))),
+            Ok(Actor::user("alice"))
+        );
+        assert_eq!(resolver.actor(None), Ok(Actor::Anonymous));
+        assert_eq!(
+            resolver.actor(Some(&bearer(json!({"tenant_id": "acme"})))),
+            Ok(Actor::Anonymous)
+        );
+        let forged = format!(
+            "Bearer {}",
+            jwt::encode_hs256(&json!({"sub": "alice"}), "guessed")
+        );
+        assert!(matches!(
+            resolver.actor(Some(&forged)),
+            Err(TenantResolutionError::InvalidToken(_))
+        ));
+    }
+
+    #[test]
+    fn test_registry_from_settings() {
+        let settings = Settings::from_toml(
+            r#"
+            [tenants.acme-us]
+            currency = "USD"
+            tax_rate = "0.0825"
+            prices_include_tax = false
+            transitions = ["PENDING->CONFIRMED", "CONFIRMED->PAID"]
+            "#,
+        )
+        .unwrap();
+        let registry = TenantRegistry::from_settings(&settings).unwrap();
+
+        let tenant = registry.tenant(&"acme-us".parse().unwrap()).unwrap();
+        assert_eq!(tenant.currency, Currency::USD);
+        assert!(!tenant.tax.included());
+        assert!(!tenant
+            .transitions
+            .allows(OrderStatus::Pending, OrderStatus::Cancelled));
+        assert_eq!(
+            registry.tenant(&TenantId::default()).unwrap(),
+            Tenant::default()
+        );
+        assert!(matches!(
+            registry.tenant(&"initech".parse().unwrap()),
+            Err(DomainError::UnknownTenant(_))
+        ));
+    }
+}
@@ -0,0 +1,212 @@
+//! Behaviour every `WebhookStore` adapter must honour
+//!
+//! Each check takes a fresh, empty store; `webhook_store_contract_tests!` expands them
+//! into one test per check for a given adapter factory.
+
+use super::{DeliveryAttempt, WebhookStore, WebhookSubscription};
+use crate::domain::value_objects::TenantId;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+/// Whole seconds, so that every backend stores timestamps without loss
+fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 12, 31, 9, 0, 0).unwrap()
+}
+
+fn tenant() -> TenantId {
+    TenantId::default()
+}
+
+fn subscription(created_at: DateTime<Utc>) -> WebhookSubscription {
+    WebhookSubscription::new(
+        tenant(),
+        "https://partner.example.com/hooks",
+        vec!["ORDER_PAID".to_string(), "ORDER_SHIPPED".to_string()],
+        created_at,
+    )
+    .unwrap()
+}
+
+fn attempt(subscription_id: Uuid, number: u32, at: DateTime<Utc>) -> DeliveryAttempt {
+    DeliveryAttempt {
+        id: Uuid::new_v4(),
+        subscription_id,
+        event_id: Uuid::nil(),
+        event_name: "ORDER_PAID".to_string(),
+        attempt: number,
+        status_code: (number > 1).then_some(500),
+        error: (number == 1).then(|| "connection refused".to_string()),
+        succeeded: false,
+        duration_ms: 12,
+        attempted_at: at,
+    }
+}
+
+pub async fn register_and_find_round_trip(store: &dyn WebhookStore) {
+    let subscription = subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+
+    assert_eq!(
+        store.find(&tenant(), subscription.id).await.unwrap(),
+        Some(subscription)
+    );
+    assert_eq!(store.find(&tenant(), Uuid::new_v4()).await.unwrap(), None);
+}
+
+pub async fn registering_the_same_id_twice_fails(store: &dyn WebhookStore) {
+    let subscription = subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+
+    assert!(store.register(subscription).await.is_err());
+    assert_eq!(store.list(&tenant()).await.unwrap().len(), 1);
+}
+
+pub async fn list_is_oldest_first(store: &dyn WebhookStore) {
+    let newer = subscription(start() + Duration::minutes(5));
+    let older = subscription(start());
+    store.register(newer.clone()).await.unwrap();
+    store.register(older.clone()).await.unwrap();
+
+    let ids: Vec<Uuid> = store
+        .list(&tenant())
+        .await
+        .unwrap()
+        .iter()
+        .map(|s| s.id)
+        .collect();
+    assert_eq!(ids, vec![older.id, newer.id]);
+}
+
+pub async fn subscriptions_can_be_paused_and_resumed(store: &dyn WebhookStore) {
+    let subscription = subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+
+    let tenant = tenant();
+    assert!(store
+        .set_paused(&tenant, subscription.id, true)
+        .await
+        .unwrap());
+    assert!(
+        store
+            .find(&tenant, subscription.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .paused
+    );
+    assert!(store
+        .set_paused(&tenant, subscription.id, false)
+        .await
+        .unwrap());
+    assert!(
+        !store
+            .find(&tenant, subscription.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .paused
+    );
+    assert!(!store
+        .set_paused(&tenant, Uuid::new_v4(), true)
+        .await
+        .unwrap());
+}
+
+pub async fn attempts_are_listed_most_recent_first(store: &dyn WebhookStore) {
+    let subscription = subscription(start());
+    let other = self::subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+    store.register(other.clone()).await.unwrap();
+    for number in 1..=3 {
+        let at = start() + Duration::seconds(number as i64);
+        store
+            .record_attempt(attempt(subscription.id, number, at))
+            .await
+            .unwrap();
+    }
+    store
+        .record_attempt(attempt(other.id, 1, start()))
+        .await
+        .unwrap();
+
+    let attempts = store.attempts(subscription.id, 10).await.unwrap();
+    let numbers: Vec<u32> = attempts.iter().map(|a| a.attempt).collect();
+    assert_eq!(numbers, vec![3, 2, 1]);
+    assert_eq!(attempts[0].status_code, Some(500));
+    assert_eq!(attempts[2].error.as_deref(), Some("connection refused"));
+    assert_eq!(attempts[2].attempted_at, start() + Duration::seconds(1));
+
+    assert_eq!(store.attempts(subscription.id, 2).await.unwrap().len(), 2);
+}
+
+pub async fn delete_removes_the_subscription_and_its_attempts(store: &dyn WebhookStore) {
+    let subscription = subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+    store
+        .record_attempt(attempt(subscription.id, 1, start()))
+        .await
+        .unwrap();
+
+    assert!(store.delete(&tenant(), subscription.id).await.unwrap());
+    assert_eq!(store.find(&tenant(), subscription.id).await.unwrap(), None);
+    assert!(store
+        .attempts(subscription.id, 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!store.delete(&tenant(), subscription.id).await.unwrap());
+}
+
+pub async fn subscriptions_are_isolated_per_tenant(store: &dyn WebhookStore) {
+    let acme = "acme".parse::<TenantId>().unwrap();
+    let subscription = subscription(start());
+    store.register(subscription.clone()).await.unwrap();
+    store
+        .record_attempt(attempt(subscription.id, 1, start()))
+        .await
+        .unwrap();
+
+    assert!(store.list(&acme).await.unwrap().is_empty());
+    assert_eq!(store.find(&acme, subscription.id).await.unwrap(), None);
+    assert!(!store
+        .set_paused(&acme, subscription.id, true)
+        .await
+        .unwrap());
+    assert!(!store.delete(&acme, subscription.id).await.unwrap());
+
+    // Nothing was touched on behalf of the other tenant
+    let kept = store
+        .find(&tenant(), subscription.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!kept.paused);
+    assert_eq!(store.attempts(subscription.id, 10).await.unwrap().len(), 1);
+}
+
+macro_rules! webhook_store_contract_tests {
+    ($factory:path) => {
+        $crate::infrastructure::webhooks::contract::webhook_store_contract_tests!(
+            @tests $factory;
+            register_and_find_round_trip,
+            registering_the_same_id_twice_fails,
+            list_is_oldest_first,
+            subscriptions_can_be_paused_and_resumed,
+            attempts_are_listed_most_recent_first,
+            delete_removes_the_subscription_and_its_attempts,
+            subscriptions_are_isolated_per_tenant,
+        );
+    };
+    (@tests $factory:path; $($check:ident),* $(,)?) => {
+        mod contract {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    let store = $factory().await;
+                    $crate::infrastructure::webhooks::contract::$check(&store).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use webhook_store_contract_tests;
@@ -0,0 +1,523 @@
+use super::{is_public_address, signature, DeliveryAttempt, WebhookStore, WebhookSubscription};
+use crate::application::error::ApplicationError;
+use crate::domain::errors::InfrastructureError;
+use crate::infrastructure::config::WebhookSettings;
+use crate::infrastructure::messaging::{EventEnvelope, EventSubscriber};
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::CONTENT_TYPE;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+pub const SUBSCRIPTION_HEADER: &str = "x-webhook-id";
+pub const EVENT_HEADER: &str = "x-webhook-event";
+/// Event id of the envelope, lets receivers drop the duplicates of a retried delivery
+pub const DELIVERY_HEADER: &str = "x-webhook-delivery";
+pub const ATTEMPT_HEADER: &str = "x-webhook-attempt";
+
+/// How many times, and how far apart, a delivery is attempted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Delay after the failed attempt number `attempt` (1-based), doubled each time
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl From<&WebhookSettings> for RetryPolicy {
+    fn from(settings: &WebhookSettings) -> Self {
+        Self {
+            max_attempts: settings.max_attempts,
+            initial_backoff: settings.initial_backoff(),
+            max_backoff: settings.max_backoff(),
+        }
+    }
+}
+
+/// Subscriber POSTing the published events to the webhook subscriptions
+///
+/// Each delivery runs in its own task so that slow partners never hold up the command
+/// that produced the event. Retries pending at shutdown are lost; the delivery log
+/// shows which events did not get through.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    store: Arc<dyn WebhookStore>,
+    client: reqwest::Client,
+    retry: RetryPolicy,
+}
+
+impl WebhookDispatcher {
+    pub fn new(store: Arc<dyn WebhookStore>, settings: &WebhookSettings) -> Self {
+        // Redirects could lead to an internal address the registration refused
+        let client = reqwest::Client::builder()
+            .timeout(settings.timeout())
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self::with_client(store, client, RetryPolicy::from(settings))
+    }
+
+    pub fn with_client(
+        store: Arc<dyn WebhookStore>,
+        client: reqwest::Client,
+        retry: RetryPolicy,
+    ) -> Self {
+        Self {
+            store,
+            client,
+            retry,
+        }
+    }
+
+    /// Deliver an event to one subscription, retrying until it succeeds, the attempts
+    /// run out or the subscription is paused or removed
+    ///
+    /// Returns whether the endpoint acknowledged the event.
+    pub async fn deliver(
+        &self,
+        mut subscription: WebhookSubscription,
+        envelope: &EventEnvelope,
+    ) -> Result<bool, InfrastructureError> {
+        let body = serde_json::to_vec(envelope).map_err(|err| {
+            InfrastructureError::messaging(format!("event {}", envelope.metadata.event_id))
+                .with_source(err)
+        })?;
+
+        for attempt in 1..=self.retry.max_attempts {
+            let outcome = self.send(&subscription, envelope, &body, attempt).await;
+            let succeeded = outcome.succeeded;
+            self.store.record_attempt(outcome).await?;
+            if succeeded {
+                return Ok(true);
+            }
+            if attempt == self.retry.max_attempts {
+                break;
+            }
+
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            match self
+                .store
+                .find(&subscription.tenant_id, subscription.id)
+                .await?
+            {
+                Some(current) if !current.paused => subscription = current,
+                _ => break,
+            }
+        }
+
+        tracing::warn!(
+            "Webhook {} gave up on event {} ({})",
+            subscription.id,
+            envelope.metadata.event_id,
+            envelope.event.event_name()
+        );
+        Ok(false)
+    }
+
+    async fn send(
+        &self,
+        subscription: &WebhookSubscription,
+        envelope: &EventEnvelope,
+        body: &[u8],
+        attempt: u32,
+    ) -> DeliveryAttempt {
+        let attempted_at = Utc::now();
+        let started = Instant::now();
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(SUBSCRIPTION_HEADER, subscription.id.to_string())
+            .header(EVENT_HEADER, envelope.event.event_name())
+            .header(DELIVERY_HEADER, envelope.metadata.event_id.to_string())
+            .header(ATTEMPT_HEADER, attempt.to_string())
+            .header(
+                signature::SIGNATURE_HEADER,
+                signature::sign(&subscription.secret, attempted_at.timestamp(), body),
+            )
+            .body(body.to_vec())
+            .send()
+            .await;
+
+        let (status_code, error) = match response {
+            Ok(response) => (Some(response.status().as_u16()), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+        DeliveryAttempt {
+            id: Uuid::new_v4(),
+            subscription_id: subscription.id,
+            event_id: envelope.metadata.event_id,
+            event_name: envelope.event.event_name().to_string(),
+            attempt,
+            status_code,
+            error,
+            succeeded: status_code.is_some_and(|code| (200..300).contains(&code)),
+            duration_ms: started.elapsed().as_millis() as u64,
+            attempted_at,
+        }
+    }
+}
+
+/// Resolver dropping the loopback, link-local and private addresses of a host
+///
+/// Stops a public name from being pointed at an internal service after registration.
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_address(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} has no public address", name.as_str()).into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for WebhookDispatcher {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn on_event(&self, envelope: &EventEnvelope) -> Result<(), ApplicationError> {
+        let event_name = envelope.event.event_name();
+        // Partners only hear about the orders of the storefront they registered with
+        for subscription in self.store.list(&envelope.metadata.tenant_id).await? {
+            if !subscription.wants(event_name) {
+                continue;
+            }
+            let dispatcher = self.clone();
+            let envelope = envelope.clone();
+            tokio::spawn(async move {
+                let id = subscription.id;
+                if let Err(err) = dispatcher.deliver(subscription, &envelope).await {
+                    tracing::error!("Webhook {} delivery failed: {}", id, err);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::events::OrderEvent;
+    use crate::domain::value_objects::{OrderId, TenantId};
+    use crate::infrastructure::webhooks::InMemoryWebhookStore;
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+    use tokio::sync::Mutex;
+
+    /// Local HTTP endpoint answering with scripted statuses (200 once exhausted)
+    #[derive(Clone, Default)]
+    struct Receiver {
+        statuses: Arc<Mutex<Vec<u16>>>,
+        received: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+    }
+
+    impl Receiver {
+        async fn start(statuses: Vec<u16>) -> (Self, String) {
+            let receiver = Self {
+                statuses: Arc::new(Mutex::new(statuses)),
+                ..Self::default()
+            };
+            let app = Router::new()
+                .route("/hooks", post(receive))
+                .with_state(receiver.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+            tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+            (receiver, url)
+        }
+
+        async fn received(&self) -> Vec<(HeaderMap, String)> {
+            self.received.lock().await.clone()
+        }
+    }
+
+    async fn receive(
+        State(receiver): State<Receiver>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        receiver.received.lock().await.push((headers, body));
+        let mut statuses = receiver.statuses.lock().await;
+        let status = if statuses.is_empty() {
+            200
+        } else {
+            statuses.remove(0)
+        };
+        StatusCode::from_u16(status).unwrap()
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        }
+    }
+
+    fn dispatcher(store: Arc<InMemoryWebhookStore>, max_attempts: u32) -> WebhookDispatcher {
+        WebhookDispatcher::with_client(store, reqwest::Client::new(), policy(max_attempts))
+    }
+
+    async fn subscribe(
+        store: &InMemoryWebhookStore,
+        url: &str,
+        event: &str,
+    ) -> WebhookSubscription {
+        subscribe_tenant(store, TenantId::default(), url, event).await
+    }
+
+    async fn subscribe_tenant(
+        store: &InMemoryWebhookStore,
+        tenant_id: TenantId,
+        url: &str,
+        event: &str,
+    ) -> WebhookSubscription {
+        // Local receivers are not valid registrations: only the URL is swapped in
+        let subscription = WebhookSubscription {
+            url: url.to_string(),
+            ..WebhookSubscription::new(
+                tenant_id,
+                "https://partner.example.com/hooks",
+                vec![event.to_string()],
+                Utc::now(),
+            )
+            .unwrap()
+        };
+        store.register(subscription.clone()).await.unwrap();
+        subscription
+    }
+
+    fn order_paid() -> EventEnvelope {
+        order_paid_for(TenantId::default())
+    }
+
+    fn order_paid_for(tenant_id: TenantId) -> EventEnvelope {
+        EventEnvelope::new(
+            tenant_id,
+            OrderEvent::OrderPaid {
+                order_id: OrderId::new(),
+                payment_id: Uuid::new_v4(),
+                timestamp: Utc::now(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_maximum() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(policy.backoff(7), Duration::from_secs(60));
+        assert_eq!(policy.backoff(40), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed_and_logged() {
+        let (receiver, url) = Receiver::start(Vec::new()).await;
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let subscription = subscribe(&store, &url, "ORDER_PAID").await;
+        let envelope = order_paid();
+
+        let delivered = dispatcher(store.clone(), 3)
+            .deliver(subscription.clone(), &envelope)
+            .await
+            .unwrap();
+
+        assert!(delivered);
+        let received = receiver.received().await;
+        assert_eq!(received.len(), 1);
+        let (headers, body) = &received[0];
+        assert_eq!(headers[EVENT_HEADER], "ORDER_PAID");
+        assert_eq!(
+            headers[DELIVERY_HEADER].to_str().unwrap(),
+            envelope.metadata.event_id.to_string()
+        );
+        let header = headers[signature::SIGNATURE_HEADER].to_str().unwrap();
+        assert!(signature::verify(&subscription.secret, header, body.as_bytes()).is_some());
+        let sent: EventEnvelope = serde_json::from_str(body).unwrap();
+        assert_eq!(sent.metadata, envelope.metadata);
+
+        let attempts = store.attempts(subscription.id, 10).await.unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert!(attempts[0].succeeded);
+        assert_eq!(attempts[0].status_code, Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_failed_deliveries_are_retried_and_every_attempt_is_logged() {
+        let (receiver, url) = Receiver::start(vec![500, 503]).await;
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let subscription = subscribe(&store, &url, "ORDER_PAID").await;
+
+        let delivered = dispatcher(store.clone(), 5)
+            .deliver(subscription.clone(), &order_paid())
+            .await
+            .unwrap();
+
+        assert!(delivered);
+        let received = receiver.received().await;
+        let attempt_headers: Vec<_> = received
+            .iter()
+            .map(|(headers, _)| headers[ATTEMPT_HEADER].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(attempt_headers, vec!["1", "2", "3"]);
+        assert_eq!(received[0].1, received[2].1);
+
+        let attempts = store.attempts(subscription.id, 10).await.unwrap();
+        let logged: Vec<_> = attempts
+            .iter()
+            .map(|a| (a.attempt, a.status_code, a.succeeded))
+            .collect();
+        assert_eq!(
+            logged,
+            vec![
+                (3, Some(200), true),
+                (2, Some(503), false),
+                (1, Some(500), false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delivery_gives_up_after_max_attempts_or_when_paused() {
+        let (receiver, url) = Receiver::start(vec![500; 10]).await;
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let subscription = subscribe(&store, &url, "ORDER_PAID").await;
+
+        let delivered = dispatcher(store.clone(), 3)
+            .deliver(subscription.clone(), &order_paid())
+            .await
+            .unwrap();
+        assert!(!delivered);
+        assert_eq!(receiver.received().await.len(), 3);
+
+        // Paused while the first attempt was in flight: no retry
+        store
+            .set_paused(&subscription.tenant_id, subscription.id, true)
+            .await
+            .unwrap();
+        let delivered = dispatcher(store.clone(), 3)
+            .deliver(subscription.clone(), &order_paid())
+            .await
+            .unwrap();
+        assert!(!delivered);
+        assert_eq!(receiver.received().await.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_endpoint_is_logged_as_transport_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        drop(listener);
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let subscription = subscribe(&store, &url, "ORDER_PAID").await;
+
+        let delivered = dispatcher(store.clone(), 2)
+            .deliver(subscription.clone(), &order_paid())
+            .await
+            .unwrap();
+
+        assert!(!delivered);
+        let attempts = store.attempts(subscription.id, 10).await.unwrap();
+        assert_eq!(attempts.len(), 2);
+        assert!(attempts
+            .iter()
+            .all(|a| a.status_code.is_none() && a.error.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_published_events_reach_matching_active_subscriptions_only() {
+        let (receiver, url) = Receiver::start(Vec::new()).await;
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let wanted = subscribe(&store, &url, "ORDER_PAID").await;
+        subscribe(&store, &url, "ORDER_CANCELLED").await;
+        let paused = subscribe(&store, &url, "ORDER_PAID").await;
+        store
+            .set_paused(&TenantId::default(), paused.id, true)
+            .await
+            .unwrap();
+
+        dispatcher(store.clone(), 1)
+            .on_event(&order_paid())
+            .await
+            .unwrap();
+
+        // Deliveries run in the background
+        for _ in 0..100 {
+            if !store.attempts(wanted.id, 1).await.unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let received = receiver.received().await;
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0].0[SUBSCRIPTION_HEADER].to_str().unwrap(),
+            wanted.id.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_only_reach_subscriptions_of_their_tenant() {
+        let (receiver, url) = Receiver::start(Vec::new()).await;
+        let store = Arc::new(InMemoryWebhookStore::new());
+        let acme = "acme".parse::<TenantId>().unwrap();
+        let globex = "globex".parse::<TenantId>().unwrap();
+        let acme_partner = subscribe_tenant(&store, acme.clone(), &url, "ORDER_PAID").await;
+        let globex_partner = subscribe_tenant(&store, globex, &url, "ORDER_PAID").await;
+
+        dispatcher(store.clone(), 1)
+            .on_event(&order_paid_for(acme))
+            .await
+            .unwrap();
+
+        for _ in 0..100 {
+            if !store.attempts(acme_partner.id, 1).await.unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let received = receiver.received().await;
+        assert_eq!(received.len(), 1);
+        assert_eq!(
+            received[0].0[SUBSCRIPTION_HEADER].to_str().unwrap(),
+            acme_partner.id.to_string()
+        );
+        assert!(store
+            .attempts(globex_partner.id, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+use super::{DeliveryAttempt, WebhookStore, WebhookSubscription};
+use crate::domain::{errors::InfrastructureError, value_objects::TenantId};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+#[derive(Default)]
+struct Registry {
+    subscriptions: Vec<WebhookSubscription>,
+    attempts: Vec<DeliveryAttempt>,
+}
+
+/// In-memory webhook store (single instance deployments and tests)
+#[derive(Default)]
+pub struct InMemoryWebhookStore {
+    registry: Arc<RwLock<Registry>>,
+}
+
+impl InMemoryWebhookStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookStore for InMemoryWebhookStore {
+    async fn register(&self, subscription: WebhookSubscription) -> Result<(), InfrastructureError> {
+        let mut registry = self.registry.write().await;
+        if registry
+            .subscriptions
+            .iter()
+            .any(|s| s.id == subscription.id)
+        {
+            return Err(InfrastructureError::storage(format!(
+                "webhook {} already exists",
+                subscription.id
+            )));
+        }
+        registry.subscriptions.push(subscription);
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, InfrastructureError> {
+        let registry = self.registry.read().await;
+        Ok(registry
+            .subscriptions
+            .iter()
+            .find(|s| s.id == id && &s.tenant_id == tenant_id)
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfrastructureError> {
+        let registry = self.registry.read().await;
+        let mut subscriptions: Vec<_> = registry
+            .subscriptions
+            .iter()
+            .filter(|s| &s.tenant_id == tenant_id)
+            .cloned()
+            .collect();
+        subscriptions.sort_by_key(|s| s.created_at);
+        Ok(subscriptions)
+    }
+
+    async fn set_paused(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+        paused: bool,
+    ) -> Result<bool, InfrastructureError> {
+        let mut registry = self.registry.write().await;
+        let Some(subscription) = registry
+            .subscriptions
+            .iter_mut()
+            .find(|s| s.id == id && &s.tenant_id == tenant_id)
+        else {
+            return Ok(false);
+        };
+        subscription.paused = paused;
+        Ok(true)
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: Uuid) -> Result<bool, InfrastructureError> {
+        let mut registry = self.registry.write().await;
+        let before = registry.subscriptions.len();
+        registry
+            .subscriptions
+            .retain(|s| s.id != id || &s.tenant_id != tenant_id);
+        if registry.subscriptions.len() == before {
+            return Ok(false);
+        }
+        registry.attempts.retain(|a| a.subscription_id != id);
+        Ok(true)
+    }
+
+    async fn record_attempt(&self, attempt: DeliveryAttempt) -> Result<(), InfrastructureError> {
+        let mut registry = self.registry.write().await;
+        registry.attempts.push(attempt);
+        Ok(())
+    }
+
+    async fn attempts(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<DeliveryAttempt>, InfrastructureError> {
+        let registry = self.registry.read().await;
+        // Recorded in chronological order
+        Ok(registry
+            .attempts
+            .iter()
+            .rev()
+            .filter(|a| a.subscription_id == subscription_id)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::webhooks::contract::webhook_store_contract_tests;
+
+    async fn store() -> InMemoryWebhookStore {
+        InMemoryWebhookStore::new()
+    }
+
+    webhook_store_contract_tests!(super::store);
+}
@@ -0,0 +1,401 @@
+//! Outgoing webhooks: partners register an endpoint and the order events it wants
+//!
+//! Every matching event of the tenant of the subscription is POSTed as its JSON envelope,
+//! signed with the secret of the subscription, and retried with exponential backoff.
+//! Each attempt is logged.
+
+#[cfg(test)]
+pub(crate) mod contract;
+pub mod dispatcher;
+pub mod in_memory;
+pub mod signature;
+pub mod sql;
+
+use crate::domain::{errors::InfrastructureError, events::OrderEvent, value_objects::TenantId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use thiserror::Error;
+use uuid::Uuid;
+
+pub use dispatcher::{RetryPolicy, WebhookDispatcher};
+pub use in_memory::InMemoryWebhookStore;
+pub use sql::SqlWebhookStore;
+
+/// Prefix of the generated signing secrets
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Endpoint of a partner and the events it receives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    /// Storefront whose order events are delivered, and only it
+    pub tenant_id: TenantId,
+    pub url: String,
+    /// Key of the HMAC signature, shown to the partner once at registration
+    pub secret: String,
+    /// `OrderEvent::event_name` values delivered to the endpoint
+    pub event_names: Vec<String>,
+    /// Paused subscriptions receive nothing and stop retrying pending deliveries
+    pub paused: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// New active subscription with a freshly generated secret
+    pub fn new(
+        tenant_id: TenantId,
+        url: &str,
+        event_names: Vec<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, InvalidWebhook> {
+        let parsed = validate_url(url)?;
+
+        let mut names: Vec<String> = Vec::with_capacity(event_names.len());
+        for name in event_names {
+            let name = name.trim().to_ascii_uppercase();
+            if !OrderEvent::EVENT_NAMES.contains(&name.as_str()) {
+                return Err(InvalidWebhook::UnknownEvent(name));
+            }
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        if names.is_empty() {
+            return Err(InvalidWebhook::NoEvents);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            url: parsed.to_string(),
+            secret: format!(
+                "{}{}{}",
+                SECRET_PREFIX,
+                Uuid::new_v4().simple(),
+                Uuid::new_v4().simple()
+            ),
+            event_names: names,
+            paused: false,
+            created_at: now,
+        })
+    }
+
+    /// Whether an event with this name must be delivered to the endpoint
+    pub fn wants(&self, event_name: &str) -> bool {
+        !self.paused && self.event_names.iter().any(|name| name == event_name)
+    }
+}
+
+/// Endpoint URL of a subscription: https, on a public host
+///
+/// Host names are checked again when they are resolved at delivery, so that a name
+/// pointing to an internal address cannot be used to reach it either.
+fn validate_url(url: &str) -> Result<reqwest::Url, InvalidWebhook> {
+    let invalid = |reason: &str| InvalidWebhook::Url(reason.to_string());
+    let parsed = reqwest::Url::parse(url.trim()).map_err(|err| invalid(&err.to_string()))?;
+    if parsed.scheme() != "https" {
+        return Err(invalid("expected an absolute https URL"));
+    }
+    let Some(host) = parsed.host_str() else {
+        return Err(invalid("expected an absolute https URL"));
+    };
+    // IP hosts come normalized by the parser (`[::1]`, `0x7f.1` as `127.0.0.1`)
+    let internal = match host.trim_start_matches('[').trim_end_matches(']').parse() {
+        Ok(ip) => !is_public_address(ip),
+        Err(_) => {
+            let domain = host.trim_end_matches('.');
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+    };
+    if internal {
+        return Err(invalid(
+            "the host is not a public address",
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Whether webhooks may be delivered to `ip`: not loopback, link-local, private,
+/// shared (carrier-grade NAT), benchmarking, documentation, reserved, multicast,
+/// unspecified nor broadcast, nor an IPv6 form of such an IPv4 address
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            !(a == 0
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                // Shared address space, 100.64.0.0/10
+                || (a == 100 && (b & 0xc0) == 64)
+                // IETF protocol assignments, 192.0.0.0/24
+                || (a == 192 && b == 0 && c == 0)
+                // Benchmarking, 198.18.0.0/15
+                || (a == 198 && (b & 0xfe) == 18)
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // Reserved, 240.0.0.0/4, broadcast included
+                || a >= 240)
+        }
+        IpAddr::V6(ip) => match embedded_ipv4(ip) {
+            Some(ip) => is_public_address(IpAddr::V4(ip)),
+            None => {
+                let documentation = ip.segments()[..2] == [0x2001, 0x0db8];
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+                    || documentation)
+            }
+        },
+    }
+}
+
+/// IPv4 address reached through an IPv6 one: IPv4-mapped (`::ffff:0:0/96`),
+/// IPv4-compatible (`::/96`), NAT64 (`64:ff9b::/96`) or 6to4 (`2002::/16`)
+fn embedded_ipv4(ip: Ipv6Addr) -> Option<Ipv4Addr> {
+    let [.., a, b, c, d] = ip.octets();
+    match ip.segments() {
+        [0, 0, 0, 0, 0, 0xffff, _, _]
+        | [0, 0, 0, 0, 0, 0, _, _]
+        | [0x64, 0xff9b, 0, 0, 0, 0, _, _] => Some(Ipv4Addr::new(a, b, c, d)),
+        [0x2002, high, low, ..] => {
+            let [a, b] = high.to_be_bytes();
+            let [c, d] = low.to_be_bytes();
+            Some(Ipv4Addr::new(a, b, c, d))
+        }
+        _ => None,
+    }
+}
+
+/// Registration rejected
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InvalidWebhook {
+    #[error("Invalid webhook URL: {0}")]
+    Url(String),
+
+    #[error("A webhook must subscribe to at least one event")]
+    NoEvents,
+
+    #[error("Unknown event: {0}")]
+    UnknownEvent(String),
+}
+
+impl InvalidWebhook {
+    /// Stable machine-readable code, e.g. `INVALID_WEBHOOK_URL`
+    pub fn code(&self) -> &'static str {
+        match self {
+            InvalidWebhook::Url(_) => "INVALID_WEBHOOK_URL",
+            InvalidWebhook::NoEvents => "NO_WEBHOOK_EVENT",
+            InvalidWebhook::UnknownEvent(_) => "UNKNOWN_EVENT",
+        }
+    }
+}
+
+/// One HTTP call made to deliver an event to a subscription
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryAttempt {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// `event_id` of the envelope, identical across the retries of a delivery
+    pub event_id: Uuid,
+    pub event_name: String,
+    /// 1 for the first try
+    pub attempt: u32,
+    /// `None` when no response was received
+    pub status_code: Option<u16>,
+    /// Transport failure (connection refused, timeout, ...)
+    pub error: Option<String>,
+    pub succeeded: bool,
+    pub duration_ms: u64,
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// Storage for webhook subscriptions and their delivery log (Port)
+///
+/// Subscriptions are isolated per tenant: one is only visible to the tenant owning it.
+#[async_trait]
+pub trait WebhookStore: Send + Sync {
+    async fn register(&self, subscription: WebhookSubscription) -> Result<(), InfrastructureError>;
+
+    async fn find(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, InfrastructureError>;
+
+    /// Every subscription of the tenant, oldest first
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfrastructureError>;
+
+    /// Pause or resume a subscription; `false` if the tenant has no such subscription
+    async fn set_paused(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+        paused: bool,
+    ) -> Result<bool, InfrastructureError>;
+
+    /// Remove a subscription and its delivery log; `false` if the tenant has no such
+    /// subscription
+    async fn delete(&self, tenant_id: &TenantId, id: Uuid) -> Result<bool, InfrastructureError>;
+
+    async fn record_attempt(&self, attempt: DeliveryAttempt) -> Result<(), InfrastructureError>;
+
+    /// Latest attempts made for a subscription, most recent first
+    async fn attempts(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<DeliveryAttempt>, InfrastructureError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_is_validated_and_normalized() {
+        let subscription = WebhookSubscription::new(
+            TenantId::default(),
+            "https://partner.example.com/hooks",
+            vec![
+                "order_paid".to_string(),
+                "ORDER_PAID".to_string(),
+                "SHIPMENT_SHIPPED".to_string(),
+            ],
+            Utc::now(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            subscription.event_names,
+            vec!["ORDER_PAID", "SHIPMENT_SHIPPED"]
+        );
+        assert!(subscription.secret.starts_with(SECRET_PREFIX));
+        assert!(subscription.wants("ORDER_PAID"));
+        assert!(!subscription.wants("ORDER_CREATED"));
+        assert!(!WebhookSubscription {
+            paused: true,
+            ..subscription
+        }
+        .wants("ORDER_PAID"));
+    }
+
+    #[test]
+    fn test_only_public_addresses_receive_webhooks() {
+        for (ip, public) in [
+            ("93.184.215.14", true),
+            ("0.1.2.3", false),
+            ("10.1.2.3", false),
+            ("100.64.0.1", false),
+            ("127.0.0.1", false),
+            ("169.254.169.254", false),
+            ("172.16.0.1", false),
+            ("192.0.0.8", false),
+            ("192.0.2.1", false),
+            ("192.168.0.10", false),
+            ("198.18.0.1", false),
+            ("198.19.255.255", false),
+            ("198.51.100.7", false),
+            ("203.0.113.9", false),
+            ("224.0.0.1", false),
+            ("239.255.255.250", false),
+            ("240.0.0.1", false),
+            ("255.255.255.255", false),
+            ("2606:2800:21f:cb07:6820:80da:af6b:8b2c", true),
+            ("::", false),
+            ("::1", false),
+            ("fd00::1", false),
+            ("fe80::1", false),
+            ("ff02::1", false),
+            ("ff0e::1", false),
+            ("2001:db8::1", false),
+            ("::ffff:93.184.215.14", true),
+            ("::ffff:10.0.0.1", false),
+            ("::93.184.215.14", true),
+            ("::10.0.0.1", false),
+            ("::127.0.0.1", false),
+            ("64:ff9b::93.184.215.14", true),
+            ("64:ff9b::10.0.0.1", false),
+            ("64:ff9b::a9fe:a9fe", false),
+            ("2002:5db8:d70e::1", true),
+            ("2002:a00:1::1", false),
+            ("2002:7f00:1::1", false),
+            ("2002:c0a8:a::1", false),
+        ] {
+            assert_eq!(is_public_address(ip.parse().unwrap()), public, "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_invalid_registrations_are_rejected() {
+        let events = || vec!["ORDER_PAID".to_string()];
+
+        assert!(matches!(
+            WebhookSubscription::new(
+                TenantId::default(),
+                "ftp://partner.example.com",
+                events(),
+                Utc::now()
+            ),
+            Err(InvalidWebhook::Url(_))
+        ));
+        assert!(matches!(
+            WebhookSubscription::new(TenantId::default(), "/hooks", events(), Utc::now()),
+            Err(InvalidWebhook::Url(_))
+        ));
+        for internal in [
+            "http://partner.example.com/hooks",
+            "https://localhost/hooks",
+            "https://127.0.0.1/hooks",
+            "https://0x7f.1/hooks",
+            "https://10.1.2.3/hooks",
+            "https://192.168.0.10/hooks",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/hooks",
+            "https://[::1]/hooks",
+            "https://[fd00::1]/hooks",
+            "https://[fe80::1]/hooks",
+            "https://[::ffff:10.0.0.1]/hooks",
+        ] {
+            assert!(
+                matches!(
+                    WebhookSubscription::new(TenantId::default(), internal, events(), Utc::now()),
+                    Err(InvalidWebhook::Url(_))
+                ),
+                "{} accepted",
+                internal
+            );
+        }
+        assert!(WebhookSubscription::new(
+            TenantId::default(),
+            "https://93.184.215.14/hooks",
+            events(),
+            Utc::now()
+        )
+        .is_ok());
+        assert_eq!(
+            WebhookSubscription::new(
+                TenantId::default(),
+                "https://partner.example.com",
+                Vec::new(),
+                Utc::now()
+            ),
+            Err(InvalidWebhook::NoEvents)
+        );
+        assert_eq!(
+            WebhookSubscription::new(
+                TenantId::default(),
+                "https://partner.example.com",
+                vec!["ORDER_LOST".to_string()],
+                Utc::now()
+            ),
+            Err(InvalidWebhook::UnknownEvent("ORDER_LOST".to_string()))
+        );
+    }
+}
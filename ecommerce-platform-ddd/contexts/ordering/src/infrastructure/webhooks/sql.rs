@@ -0,0 +1,195 @@
+use super::{DeliveryAttempt, WebhookStore, WebhookSubscription};
+use crate::domain::{errors::InfrastructureError, value_objects::TenantId};
+use crate::infrastructure::persistence::{
+    corrupted,
+    entities::{webhook_delivery, webhook_subscription},
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use uuid::Uuid;
+
+/// SeaORM implementation, shared by every instance of the service
+pub struct SqlWebhookStore {
+    db: DatabaseConnection,
+}
+
+impl SqlWebhookStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookStore for SqlWebhookStore {
+    async fn register(&self, subscription: WebhookSubscription) -> Result<(), InfrastructureError> {
+        let event_names = serde_json::to_string(&subscription.event_names).map_err(|err| {
+            InfrastructureError::storage(format!("webhook {}", subscription.id)).with_source(err)
+        })?;
+        let row = webhook_subscription::ActiveModel {
+            id: Set(subscription.id),
+            tenant_id: Set(subscription.tenant_id.to_string()),
+            url: Set(subscription.url),
+            secret: Set(subscription.secret),
+            event_names: Set(event_names),
+            paused: Set(subscription.paused),
+            created_at: Set(subscription.created_at),
+        };
+        webhook_subscription::Entity::insert(row)
+            .exec_without_returning(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+    ) -> Result<Option<WebhookSubscription>, InfrastructureError> {
+        webhook_subscription::Entity::find_by_id(id)
+            .filter(webhook_subscription::Column::TenantId.eq(tenant_id.as_str()))
+            .one(&self.db)
+            .await?
+            .map(to_subscription)
+            .transpose()
+    }
+
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfrastructureError> {
+        webhook_subscription::Entity::find()
+            .filter(webhook_subscription::Column::TenantId.eq(tenant_id.as_str()))
+            .order_by_asc(webhook_subscription::Column::CreatedAt)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(to_subscription)
+            .collect()
+    }
+
+    async fn set_paused(
+        &self,
+        tenant_id: &TenantId,
+        id: Uuid,
+        paused: bool,
+    ) -> Result<bool, InfrastructureError> {
+        let change = webhook_subscription::ActiveModel {
+            id: NotSet,
+            tenant_id: NotSet,
+            url: NotSet,
+            secret: NotSet,
+            event_names: NotSet,
+            paused: Set(paused),
+            created_at: NotSet,
+        };
+        let result = webhook_subscription::Entity::update_many()
+            .set(change)
+            .filter(webhook_subscription::Column::Id.eq(id))
+            .filter(webhook_subscription::Column::TenantId.eq(tenant_id.as_str()))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn delete(&self, tenant_id: &TenantId, id: Uuid) -> Result<bool, InfrastructureError> {
+        // The delivery log goes with the subscription (ON DELETE CASCADE)
+        let result = webhook_subscription::Entity::delete_by_id(id)
+            .filter(webhook_subscription::Column::TenantId.eq(tenant_id.as_str()))
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected == 1)
+    }
+
+    async fn record_attempt(&self, attempt: DeliveryAttempt) -> Result<(), InfrastructureError> {
+        let row = webhook_delivery::ActiveModel {
+            id: Set(attempt.id),
+            subscription_id: Set(attempt.subscription_id),
+            event_id: Set(attempt.event_id),
+            event_name: Set(attempt.event_name),
+            attempt: Set(attempt.attempt as i32),
+            status_code: Set(attempt.status_code.map(i32::from)),
+            error: Set(attempt.error),
+            succeeded: Set(attempt.succeeded),
+            duration_ms: Set(attempt.duration_ms as i64),
+            attempted_at: Set(attempt.attempted_at),
+        };
+        webhook_delivery::Entity::insert(row)
+            .exec_without_returning(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn attempts(
+        &self,
+        subscription_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<DeliveryAttempt>, InfrastructureError> {
+        Ok(webhook_delivery::Entity::find()
+            .filter(webhook_delivery::Column::SubscriptionId.eq(subscription_id))
+            .order_by_desc(webhook_delivery::Column::AttemptedAt)
+            .order_by_desc(webhook_delivery::Column::Attempt)
+            .limit(limit)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(to_attempt)
+            .collect())
+    }
+}
+
+fn to_subscription(
+    row: webhook_subscription::Model,
+) -> Result<WebhookSubscription, InfrastructureError> {
+    let event_names =
+        serde_json::from_str(&row.event_names).map_err(corrupted(format!("webhook {}", row.id)))?;
+    let tenant_id = row
+        .tenant_id
+        .parse()
+        .map_err(corrupted(format!("webhook {}", row.id)))?;
+    Ok(WebhookSubscription {
+        id: row.id,
+        tenant_id,
+        url: row.url,
+        secret: row.secret,
+        event_names,
+        paused: row.paused,
+        created_at: row.created_at,
+    })
+}
+
+fn to_attempt(row: webhook_delivery::Model) -> DeliveryAttempt {
+    DeliveryAttempt {
+        id: row.id,
+        subscription_id: row.subscription_id,
+        event_id: row.event_id,
+        event_name: row.event_name,
+        attempt: row.attempt as u32,
+        status_code: row.status_code.map(|code| code as u16),
+        error: row.error,
+        succeeded: row.succeeded,
+        duration_ms: row.duration_ms as u64,
+        attempted_at: row.attempted_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::Migrator;
+    use crate::infrastructure::webhooks::contract::webhook_store_contract_tests;
+    use sea_orm::{ConnectOptions, Database};
+    use sea_orm_migration::MigratorTrait;
+
+    async fn store() -> SqlWebhookStore {
+        let mut options = ConnectOptions::new("sqlite::memory:");
+        options.max_connections(1).sqlx_logging(false);
+        let db = Database::connect(options).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        SqlWebhookStore::new(db)
+    }
+
+    webhook_store_contract_tests!(super::store);
+}
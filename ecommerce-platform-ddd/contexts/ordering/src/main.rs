@@ -1,37 +1,182 @@
-use axum::{
-    routing::{get, post},
-    Router,
+use ordering_context::application::commands::{
+    DeliverShippedOrdersHandler, ExpireCartsHandler, ExpirePendingOrdersHandler,
+};
+use ordering_context::infrastructure::{
+    api,
+    bootstrap::{self, Adapters, Handlers},
+    config::Settings,
+    grpc::OrderingGrpcService,
+    observability,
+    scheduler::{DeliverShippedOrdersJob, ExpireCartsJob, ExpirePendingOrdersJob, Scheduler},
 };
 use std::net::SocketAddr;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tonic::transport::server::TcpIncoming;
 
 #[tokio::main]
-async fn main() {
+async fn main() -> anyhow::Result<()> {
+    // Load and validate configuration (defaults < TOML < .env < env vars)
+    let settings = Settings::load()?;
+
     // Initialize tracing
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "ordering_context=debug,tower_http=debug".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    observability::init_tracing(&settings.log)?;
+
+    // Wire adapters and application handlers
+    let adapters = Adapters::connect(&settings).await?;
+    let handlers = Handlers::new(&adapters);
+    let event_publisher = adapters.event_publisher.clone();
+    let tenant_resolver = bootstrap::tenant_resolver(&settings, &adapters);
+    let state = bootstrap::app_state(
+        &settings,
+        &adapters,
+        &handlers,
+        tenant_resolver.clone(),
+    );
 
     // Build application
-    let app = Router::new()
-        .route("/", get(root))
-        .route("/health", get(health_check));
+    let app = api::router(state);
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
+    let (drain_tx, drain_rx) = tokio::sync::watch::channel(());
+    let mut servers = JoinSet::new();
+
+    let addr = settings.server.addr();
+    let listener = tokio::net::TcpListener::bind(addr).await?;
     tracing::info!("🚀 Order Service listening on {}", addr);
+    let mut drain = drain_rx.clone();
+    servers.spawn(async move {
+        // Peer address feeds the per-IP rate limit
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+            .with_graceful_shutdown(async move {
+                drain.changed().await.ok();
+            })
+            .await?;
+        anyhow::Ok(())
+    });
 
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
-}
+    // gRPC interface, backed by the same application handlers
+    if settings.features.grpc {
+        let grpc_addr = settings.server.grpc_addr();
+        let incoming = TcpIncoming::bind(grpc_addr)?;
+        tracing::info!("🚀 gRPC service listening on {}", grpc_addr);
+        let service = OrderingGrpcService::new(
+            handlers.create_order,
+            handlers.confirm_order,
+            handlers.cancel_order,
+            handlers.get_order,
+            handlers.list_customer_orders,
+            tenant_resolver,
+        )
+        .into_server();
+        let mut drain = drain_rx.clone();
+        servers.spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(service)
+                .serve_with_incoming_shutdown(incoming, async move {
+                    drain.changed().await.ok();
+                })
+                .await?;
+            anyhow::Ok(())
+        });
+    }
 
-async fn root() -> &'static str {
-    "E-Commerce Platform - Order Service (DDD Architecture)"
+    // Scheduled jobs: expire stale pending orders, deliver shipped ones, purge expired carts
+    if settings.jobs.enabled {
+        let expire_pending = ExpirePendingOrdersHandler::new(
+            adapters.order_repository.clone(),
+            adapters.tenants.clone(),
+            event_publisher.clone(),
+            adapters.audit_log.clone(),
+            adapters.clock.clone(),
+        );
+        let deliver_shipped = DeliverShippedOrdersHandler::new(
+            adapters.order_repository,
+            adapters.tenants,
+            event_publisher.clone(),
+            adapters.audit_log,
+            adapters.clock.clone(),
+        );
+        let expire_carts = ExpireCartsHandler::new(adapters.cart_repository, adapters.clock);
+        let scheduler = Scheduler::new()
+            .every(
+                settings.jobs.interval(),
+                Arc::new(ExpirePendingOrdersJob::new(
+                    Arc::new(expire_pending),
+                    settings.jobs.pending_ttl(),
+                )),
+            )
+            .every(
+                settings.jobs.interval(),
+                Arc::new(DeliverShippedOrdersJob::new(
+                    Arc::new(deliver_shipped),
+                    settings.jobs.delivery_delay(),
+                )),
+            )
+            .every(
+                settings.jobs.interval(),
+                Arc::new(ExpireCartsJob::new(Arc::new(expire_carts))),
+            );
+        let drain = drain_rx.clone();
+        servers.spawn(async move {
+            scheduler.run(drain).await;
+            Ok(())
+        });
+    }
+
+    // Graceful shutdown: stop accepting, drain in-flight requests, flush events
+    tokio::select! {
+        Some(result) = servers.join_next() => return result?,
+        _ = shutdown_signal() => {}
+    }
+    let timeout = settings.server.shutdown_timeout();
+    tracing::info!("Shutdown signal received, draining in-flight requests");
+    drain_tx.send(()).ok();
+
+    let drained = async {
+        while let Some(result) = servers.join_next().await {
+            result??;
+        }
+        anyhow::Ok(())
+    };
+    match tokio::time::timeout(timeout, drained).await {
+        Ok(result) => result?,
+        Err(_) => tracing::warn!("In-flight requests still running after {:?}", timeout),
+    }
+
+    match tokio::time::timeout(timeout, event_publisher.flush()).await {
+        Ok(Ok(())) => tracing::info!("Pending events flushed"),
+        Ok(Err(err)) => tracing::error!("Failed to flush pending events: {}", err),
+        Err(_) => tracing::error!("Flushing pending events timed out after {:?}", timeout),
+    }
+
+    tracing::info!("Order Service stopped");
+    Ok(())
 }
 
-async fn health_check() -> &'static str {
-    "OK"
+/// Resolves on Ctrl+C or SIGTERM (sent by Docker / Kubernetes)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
 }